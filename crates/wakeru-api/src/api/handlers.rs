@@ -1,11 +1,23 @@
 //! HTTP Handler Definitions
 
-use axum::{Json, extract::State};
-use tracing::{debug, error, info};
+use axum::{
+  Json,
+  body::Body,
+  extract::State,
+  http::header,
+  response::{IntoResponse, Response},
+};
+use tracing::{debug, error, info, warn};
 
+use crate::config::MAX_TEXT_LENGTH;
 use crate::errors::ApiError;
-use crate::models::{WakeruRequest, WakeruResponse};
+use crate::models::{
+  BatchWakeruRequest, BatchWakeruResponse, ConfigResponse, HealthResponse, QueryTokensRequest,
+  QueryTokensResponse, SearchRequest, SearchResponse, TokenizeRequest, TokenizeResponse,
+  WakeruRequest, WakeruResponse,
+};
 
+use super::ndjson::{analyze_ndjson_body, search_results_ndjson_body};
 use super::state::AppState;
 
 /// POST /wakeru Endpoint
@@ -14,9 +26,12 @@ use super::state::AppState;
 ///
 /// # Request Body
 /// ```json
-/// { "text": "Text to analyze" }
+/// { "text": "Text to analyze", "index_pos": ["名詞", "助詞"] }
 /// ```
 ///
+/// `index_pos` is optional: POS feature prefixes that decide `should_index`
+/// (the built-in filter is used if omitted).
+///
 /// # Response
 /// - 200 OK: Analysis successful
 /// - 400 Bad Request: Input error (Empty text, Text too long)
@@ -34,11 +49,13 @@ pub async fn post_wakeru(
   // Morphological analysis is a heavy process, so separate it to avoid blocking the async runtime
   let service = state.service.clone();
 
-  let response =
+  let result =
     tokio::task::spawn_blocking(move || service.analyze(request)).await.map_err(|e| {
       error!(error = %e, "spawn_blocking error");
       ApiError::internal("Failed to execute processing")
-    })??;
+    })?;
+  state.metrics.record_analysis(&result);
+  let response = result?;
 
   info!(
     token_count = response.tokens.len(),
@@ -49,11 +66,366 @@ pub async fn post_wakeru(
   Ok(Json(response))
 }
 
+/// POST /wakeru/batch Endpoint
+///
+/// Performs morphological analysis on multiple texts in one request.
+/// All texts are processed inside a single blocking task and results keep the input order.
+///
+/// # Request Body
+/// ```json
+/// { "texts": ["Text to analyze", "Another text"] }
+/// ```
+///
+/// # Response
+/// - 200 OK: `{ "results": [{ "tokens": [...], "elapsed_ms": 0 }, ...] }`
+/// - 400 Bad Request: More texts than `max_batch_size`, or any text is invalid
+///   (the whole batch fails)
+/// - 500 Internal Server Error: Internal error
+pub async fn post_wakeru_batch(
+  State(state): State<AppState>,
+  Json(request): Json<BatchWakeruRequest>,
+) -> Result<Json<BatchWakeruResponse>, ApiError> {
+  let batch_size = request.texts.len();
+  debug!(batch_size, "Received batch morphological analysis request");
+
+  let max_batch_size = state.config.max_batch_size;
+  if batch_size > max_batch_size {
+    return Err(ApiError::invalid_input(format!(
+      "Too many texts: {batch_size} (max: {max_batch_size})"
+    )));
+  }
+
+  let service = state.service.clone();
+  let metrics = state.metrics.clone();
+
+  let results = tokio::task::spawn_blocking(move || {
+    request
+      .texts
+      .into_iter()
+      .enumerate()
+      .map(|(index, text)| {
+        let result = service.analyze(WakeruRequest {
+          text,
+          index_pos: None,
+          nbest: None,
+          compact: false,
+        });
+        metrics.record_analysis(&result);
+        result.inspect_err(|e| {
+          warn!(index, error = %e, "Batch morphological analysis failed");
+        })
+      })
+      .collect::<Result<Vec<_>, ApiError>>()
+  })
+  .await
+  .map_err(|e| {
+    error!(error = %e, "spawn_blocking error");
+    ApiError::internal("Failed to execute processing")
+  })??;
+
+  info!(batch_size, "Batch morphological analysis completed");
+
+  Ok(Json(BatchWakeruResponse { results }))
+}
+
+/// POST /wakeru/analyze-ndjson Endpoint
+///
+/// Performs morphological analysis on each line of an NDJSON (JSON Lines) body.
+/// Lines are processed lazily and results are streamed back in the same order.
+///
+/// # Request Body
+/// ```text
+/// {"id": "1", "text": "Text to analyze"}
+/// {"id": "2", "text": "Another text"}
+/// ```
+///
+/// # Response (`application/x-ndjson`)
+/// ```text
+/// {"id": "1", "tokens": [...], "elapsed_ms": 0}
+/// {"id": "2", "error": {"code": "invalid_input", "message": "..."}}
+/// ```
+///
+/// Malformed lines produce an error line (with `id` if it could be read) and processing continues.
+pub async fn post_analyze_ndjson(State(state): State<AppState>, body: Body) -> Response {
+  debug!("Received NDJSON morphological analysis request");
+
+  let body = analyze_ndjson_body(state.service.clone(), state.metrics.clone(), body);
+
+  ([(header::CONTENT_TYPE, "application/x-ndjson")], body).into_response()
+}
+
+/// POST /wakeru/query-tokens Endpoint
+///
+/// Returns the tokens that the indexer would produce for the text
+/// (part-of-speech filtered, optionally lemmatized).
+///
+/// # Request Body
+/// ```json
+/// { "text": "Search query", "lemmatize": false }
+/// ```
+///
+/// # Response
+/// - 200 OK: `{ "tokens": ["...", ...] }`
+/// - 400 Bad Request: Input error (Empty text, Text too long)
+/// - 500 Internal Server Error: Internal error
+pub async fn post_query_tokens(
+  State(state): State<AppState>,
+  Json(request): Json<QueryTokensRequest>,
+) -> Result<Json<QueryTokensResponse>, ApiError> {
+  debug!(
+    text_len = request.text.len(),
+    lemmatize = request.lemmatize,
+    "Received query tokens request"
+  );
+
+  let service = state.service.clone();
+
+  let response =
+    tokio::task::spawn_blocking(move || service.query_tokens(request)).await.map_err(|e| {
+      error!(error = %e, "spawn_blocking error");
+      ApiError::internal("Failed to execute processing")
+    })??;
+
+  info!(
+    token_count = response.tokens.len(),
+    "Query tokenization completed"
+  );
+
+  Ok(Json(response))
+}
+
+/// POST /tokenize Endpoint
+///
+/// Splits text into words (wakachi-gaki) without part-of-speech details.
+/// Only tokens that the indexer would keep (`should_index`) are returned, as surface forms.
+///
+/// # Request Body
+/// ```json
+/// { "text": "東京タワーに行く" }
+/// ```
+///
+/// # Response
+/// - 200 OK: `{ "tokens": ["東京", "タワー", ...], "wakachi": "東京 タワー ..." }`
+/// - 400 Bad Request: Input error (Empty text, Text too long)
+/// - 500 Internal Server Error: Internal error
+pub async fn post_tokenize(
+  State(state): State<AppState>,
+  Json(request): Json<TokenizeRequest>,
+) -> Result<Json<TokenizeResponse>, ApiError> {
+  debug!(text_len = request.text.len(), "Received tokenize request");
+
+  let service = state.service.clone();
+
+  // Query tokens without lemmatization are exactly the indexed surface forms
+  let request = QueryTokensRequest {
+    text: request.text,
+    lemmatize: false,
+  };
+  let tokens = tokio::task::spawn_blocking(move || service.query_tokens(request))
+    .await
+    .map_err(|e| {
+      error!(error = %e, "spawn_blocking error");
+      ApiError::internal("Failed to execute processing")
+    })??
+    .tokens;
+
+  info!(token_count = tokens.len(), "Tokenization completed");
+
+  Ok(Json(TokenizeResponse::new(tokens)))
+}
+
+/// POST /search Endpoint
+///
+/// Executes BM25 search against the index of the search service.
+///
+/// # Request Body
+/// ```json
+/// { "query": "Search query", "language": "ja", "limit": 10 }
+/// ```
+///
+/// `language` defaults to the default language of the search service, `limit` to 10.
+///
+/// # Response
+/// - 200 OK: `[{ "doc_id": "...", "source_id": "...", "score": 1.0, "text": "...", ... }]`
+/// - 400 Bad Request: Input error (Empty query, Unsupported language)
+/// - 503 Service Unavailable: Search is not enabled on this server
+/// - 500 Internal Server Error: Internal error
+pub async fn post_search(
+  State(state): State<AppState>,
+  Json(request): Json<SearchRequest>,
+) -> Result<Json<SearchResponse>, ApiError> {
+  debug!(
+    query_len = request.query.len(),
+    language = ?request.language,
+    limit = request.limit,
+    "Received search request"
+  );
+
+  let response = run_search(&state, request).await?;
+
+  info!(result_count = response.results.len(), "Search completed");
+
+  Ok(Json(response))
+}
+
+/// POST /search/stream Endpoint
+///
+/// Same as `POST /search`, but returns the results as NDJSON (one `SearchResult` per line).
+///
+/// This is an output format only: the search runs to completion first (top-`limit`
+/// ranking needs every hit), and only the serialization is streamed. Clients can parse
+/// results line by line instead of as one JSON array, but the first line is not sent
+/// earlier than the `POST /search` response would be, and server memory still grows
+/// with `limit`.
+///
+/// # Request Body
+/// ```json
+/// { "query": "Search query", "language": "ja", "limit": 1000 }
+/// ```
+///
+/// # Response
+/// - 200 OK (`application/x-ndjson`):
+///   ```text
+///   {"doc_id": "...", "source_id": "...", "score": 1.0, "text": "...", ...}
+///   {"doc_id": "...", "source_id": "...", "score": 0.5, "text": "...", ...}
+///   ```
+/// - 400 / 503 / 500: Same JSON errors as `POST /search` (returned before streaming starts)
+pub async fn post_search_stream(
+  State(state): State<AppState>,
+  Json(request): Json<SearchRequest>,
+) -> Result<Response, ApiError> {
+  debug!(
+    query_len = request.query.len(),
+    language = ?request.language,
+    limit = request.limit,
+    "Received streaming search request"
+  );
+
+  let response = run_search(&state, request).await?;
+
+  info!(
+    result_count = response.results.len(),
+    "Search completed, streaming results"
+  );
+
+  let body = search_results_ndjson_body(response.results);
+
+  Ok(([(header::CONTENT_TYPE, "application/x-ndjson")], body).into_response())
+}
+
+/// Runs the search on a blocking thread (shared by `/search` and `/search/stream`)
+async fn run_search(state: &AppState, request: SearchRequest) -> Result<SearchResponse, ApiError> {
+  let search_service = state
+    .search_service
+    .clone()
+    .ok_or_else(|| ApiError::unavailable("Search is not enabled (set WAKERU_SEARCH_CONFIG)"))?;
+
+  tokio::task::spawn_blocking(move || search_service.search(request)).await.map_err(|e| {
+    error!(error = %e, "spawn_blocking error");
+    ApiError::internal("Failed to execute processing")
+  })?
+}
+
 /// Health Check Endpoint
 ///
-/// Checks if the server is running.
-pub async fn health_check() -> &'static str {
-  "OK"
+/// Checks if the server is running, and reports the loaded dictionary
+/// so that operators can confirm the expected dictionary is deployed.
+///
+/// # Response
+/// ```json
+/// { "status": "ok", "dictionary": { "preset": "unidic-cwj" } }
+/// ```
+pub async fn health_check(State(state): State<AppState>) -> Json<HealthResponse> {
+  Json(HealthResponse {
+    status: "ok",
+    dictionary: state.service.dictionary_info(),
+  })
+}
+
+/// Text probed by `GET /health/ready` (tokenization and search)
+const READINESS_PROBE_TEXT: &str = "東京";
+
+/// Readiness Check Endpoint
+///
+/// Unlike `GET /health` (a cheap liveness probe), this verifies that requests can
+/// actually be served: it tokenizes a trivial text and, if search is enabled,
+/// runs a trivial search.
+///
+/// # Response
+/// - 200 OK: Same body as `GET /health`
+/// - 503 Service Unavailable: Tokenization or search failed (JSON error)
+pub async fn health_ready(State(state): State<AppState>) -> Result<Json<HealthResponse>, ApiError> {
+  let service = state.service.clone();
+  let search_service = state.search_service.clone();
+
+  tokio::task::spawn_blocking(move || {
+    service
+      .query_tokens(QueryTokensRequest {
+        text: READINESS_PROBE_TEXT.to_string(),
+        lemmatize: false,
+      })
+      .map_err(|e| ApiError::unavailable(format!("Tokenizer is not ready: {e}")))?;
+
+    if let Some(search_service) = search_service {
+      search_service
+        .search(SearchRequest {
+          query: READINESS_PROBE_TEXT.to_string(),
+          language: None,
+          limit: 1,
+        })
+        .map_err(|e| ApiError::unavailable(format!("Search is not ready: {e}")))?;
+    }
+
+    Ok::<_, ApiError>(())
+  })
+  .await
+  .map_err(|e| {
+    error!(error = %e, "spawn_blocking error");
+    ApiError::internal("Failed to execute processing")
+  })?
+  .inspect_err(|e| warn!(error = %e, "Readiness check failed"))?;
+
+  Ok(Json(HealthResponse {
+    status: "ok",
+    dictionary: state.service.dictionary_info(),
+  }))
+}
+
+/// GET /config Endpoint
+///
+/// Returns the non-sensitive part of the configuration the server loaded at startup
+/// (for diagnostics). Filesystem paths are never included.
+///
+/// # Response
+/// ```json
+/// {
+///   "bind_addr": "127.0.0.1:5530", "preset": "unidic-cwj", "local_dictionary": false,
+///   "search_enabled": false, "max_text_length": 10000000
+/// }
+/// ```
+pub async fn get_config(State(state): State<AppState>) -> Json<ConfigResponse> {
+  Json(ConfigResponse::new(&state.config, MAX_TEXT_LENGTH))
+}
+
+/// GET /metrics Endpoint
+///
+/// Returns analysis counters in Prometheus text exposition format.
+///
+/// # Response (`text/plain; version=0.0.4`)
+/// ```text
+/// # TYPE wakeru_analyze_requests_total counter
+/// wakeru_analyze_requests_total 42
+/// ...
+/// ```
+pub async fn get_metrics(State(state): State<AppState>) -> Response {
+  (
+    [(
+      header::CONTENT_TYPE,
+      "text/plain; version=0.0.4; charset=utf-8",
+    )],
+    state.metrics.render(),
+  )
+    .into_response()
 }
 
 /// POST /wakeru Endpoint (Synchronous version)
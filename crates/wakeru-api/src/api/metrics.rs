@@ -0,0 +1,197 @@
+//! Analysis Metrics (Prometheus exposition format)
+
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::errors::Result;
+use crate::models::WakeruResponse;
+
+/// Upper bounds (inclusive, in milliseconds) of the `elapsed_ms` histogram buckets
+///
+/// A final `+Inf` bucket is implied.
+const ELAPSED_MS_BUCKETS: [u64; 8] = [1, 5, 10, 50, 100, 500, 1000, 5000];
+
+/// Counters of morphological analysis calls
+///
+/// Updated with relaxed atomics after each `analyze` call and shared via `AppState`.
+/// Counters are cumulative since server start.
+#[derive(Debug, Default)]
+pub struct Metrics {
+  /// Number of analysis calls (including failed ones)
+  requests_total: AtomicU64,
+  /// Number of failed analysis calls
+  errors_total: AtomicU64,
+  /// Number of tokens returned by successful calls
+  tokens_total: AtomicU64,
+  /// Non-cumulative count per bucket of `ELAPSED_MS_BUCKETS` (last entry: `+Inf`)
+  elapsed_buckets: [AtomicU64; ELAPSED_MS_BUCKETS.len() + 1],
+  /// Sum of `elapsed_ms` of successful calls
+  elapsed_ms_sum: AtomicU64,
+}
+
+impl Metrics {
+  /// Creates metrics with all counters at zero
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Records the result of one `analyze` call
+  pub fn record_analysis(&self, result: &Result<WakeruResponse>) {
+    self.requests_total.fetch_add(1, Ordering::Relaxed);
+
+    match result {
+      Ok(response) => {
+        // N-best responses hold their tokens in `paths`
+        let path_tokens: usize =
+          response.paths.iter().flatten().map(|path| path.tokens.len()).sum();
+        let tokens = response.tokens.len() + path_tokens;
+        self.tokens_total.fetch_add(tokens as u64, Ordering::Relaxed);
+        self.elapsed_ms_sum.fetch_add(response.elapsed_ms, Ordering::Relaxed);
+        let bucket = ELAPSED_MS_BUCKETS
+          .iter()
+          .position(|&le| response.elapsed_ms <= le)
+          .unwrap_or(ELAPSED_MS_BUCKETS.len());
+        self.elapsed_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+      }
+      Err(_) => {
+        self.errors_total.fetch_add(1, Ordering::Relaxed);
+      }
+    }
+  }
+
+  /// Number of analysis calls recorded so far
+  #[must_use]
+  pub fn requests_total(&self) -> u64 {
+    self.requests_total.load(Ordering::Relaxed)
+  }
+
+  /// Number of tokens analyzed so far
+  #[must_use]
+  pub fn tokens_total(&self) -> u64 {
+    self.tokens_total.load(Ordering::Relaxed)
+  }
+
+  /// Renders all metrics in Prometheus text exposition format (version 0.0.4)
+  #[must_use]
+  pub fn render(&self) -> String {
+    let mut out = String::new();
+
+    write_counter(
+      &mut out,
+      "wakeru_analyze_requests_total",
+      "Total number of morphological analysis calls.",
+      self.requests_total(),
+    );
+    write_counter(
+      &mut out,
+      "wakeru_analyze_errors_total",
+      "Total number of failed morphological analysis calls.",
+      self.errors_total.load(Ordering::Relaxed),
+    );
+    write_counter(
+      &mut out,
+      "wakeru_analyzed_tokens_total",
+      "Total number of tokens returned by morphological analysis.",
+      self.tokens_total(),
+    );
+
+    let name = "wakeru_analyze_elapsed_ms";
+    let _ = writeln!(
+      out,
+      "# HELP {name} Elapsed time of successful morphological analysis calls in milliseconds."
+    );
+    let _ = writeln!(out, "# TYPE {name} histogram");
+
+    // Prometheus buckets are cumulative
+    let mut cumulative = 0;
+    for (i, bucket) in self.elapsed_buckets.iter().enumerate() {
+      cumulative += bucket.load(Ordering::Relaxed);
+      match ELAPSED_MS_BUCKETS.get(i) {
+        Some(le) => {
+          let _ = writeln!(out, "{name}_bucket{{le=\"{le}\"}} {cumulative}");
+        }
+        None => {
+          let _ = writeln!(out, "{name}_bucket{{le=\"+Inf\"}} {cumulative}");
+        }
+      }
+    }
+    let _ = writeln!(
+      out,
+      "{name}_sum {}",
+      self.elapsed_ms_sum.load(Ordering::Relaxed)
+    );
+    let _ = writeln!(out, "{name}_count {cumulative}");
+
+    out
+  }
+}
+
+/// Writes one counter with its HELP / TYPE lines
+fn write_counter(out: &mut String, name: &str, help: &str, value: u64) {
+  let _ = writeln!(out, "# HELP {name} {help}");
+  let _ = writeln!(out, "# TYPE {name} counter");
+  let _ = writeln!(out, "{name} {value}");
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::errors::ApiError;
+
+  fn response(tokens: usize, elapsed_ms: u64) -> Result<WakeruResponse> {
+    let token = crate::models::TokenDto::from_feature("東京", "名詞", 0, 6, true);
+    Ok(WakeruResponse {
+      tokens: vec![token; tokens],
+      paths: None,
+      elapsed_ms,
+    })
+  }
+
+  #[test]
+  fn render_empty_metrics() {
+    let text = Metrics::new().render();
+    assert!(text.contains("# TYPE wakeru_analyze_requests_total counter"));
+    assert!(text.contains("wakeru_analyze_requests_total 0\n"));
+    assert!(text.contains("wakeru_analyze_elapsed_ms_bucket{le=\"+Inf\"} 0\n"));
+    assert!(text.contains("wakeru_analyze_elapsed_ms_count 0\n"));
+  }
+
+  #[test]
+  fn record_analysis_updates_counters_and_histogram() {
+    let metrics = Metrics::new();
+    metrics.record_analysis(&response(3, 0));
+    metrics.record_analysis(&response(2, 7));
+    metrics.record_analysis(&response(1, 10_000));
+    metrics.record_analysis(&Err(ApiError::invalid_input("Text is empty")));
+
+    assert_eq!(metrics.requests_total(), 4);
+    assert_eq!(metrics.tokens_total(), 6);
+
+    let text = metrics.render();
+    assert!(text.contains("wakeru_analyze_errors_total 1\n"));
+    assert!(text.contains("wakeru_analyze_elapsed_ms_bucket{le=\"1\"} 1\n"));
+    assert!(text.contains("wakeru_analyze_elapsed_ms_bucket{le=\"10\"} 2\n"));
+    assert!(text.contains("wakeru_analyze_elapsed_ms_bucket{le=\"5000\"} 2\n"));
+    assert!(text.contains("wakeru_analyze_elapsed_ms_bucket{le=\"+Inf\"} 3\n"));
+    assert!(text.contains("wakeru_analyze_elapsed_ms_sum 10007\n"));
+    assert!(text.contains("wakeru_analyze_elapsed_ms_count 3\n"));
+  }
+
+  #[test]
+  fn record_analysis_counts_tokens_of_all_nbest_paths() {
+    let token = crate::models::TokenDto::from_feature("東京", "名詞", 0, 6, true);
+    let path = |tokens: usize| crate::models::PathDto {
+      cost: 0,
+      tokens: vec![token.clone(); tokens],
+    };
+    let metrics = Metrics::new();
+    metrics.record_analysis(&Ok(WakeruResponse {
+      tokens: Vec::new(),
+      paths: Some(vec![path(2), path(3)]),
+      elapsed_ms: 1,
+    }));
+
+    assert_eq!(metrics.tokens_total(), 5);
+  }
+}
@@ -1,9 +1,16 @@
 //! API module
 
 mod handlers;
+mod metrics;
+mod ndjson;
+mod rate_limit;
 mod routes;
 mod state;
 
-pub use handlers::{health_check, post_wakeru};
+pub use handlers::{
+  get_config, get_metrics, health_check, health_ready, post_analyze_ndjson, post_query_tokens,
+  post_search, post_search_stream, post_tokenize, post_wakeru, post_wakeru_batch,
+};
+pub use metrics::Metrics;
 pub use routes::{create_router, run_server};
 pub use state::AppState;
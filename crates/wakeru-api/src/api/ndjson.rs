@@ -0,0 +1,228 @@
+//! NDJSON (JSON Lines) Streaming Support
+//!
+//! Reads the request body line by line and emits one response line per request line,
+//! so memory usage is bounded by a single line instead of the whole body.
+//! Search results are streamed the same way, one serialized result per line.
+
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use axum::body::{Body, BodyDataStream, Bytes};
+use futures_util::{StreamExt, stream};
+use tracing::error;
+
+use super::metrics::Metrics;
+use crate::config::MAX_TEXT_LENGTH;
+use crate::errors::ApiError;
+use crate::models::{AnalyzeLineRequest, AnalyzeLineResponse, WakeruRequest};
+use crate::service::WakeruApiService;
+use wakeru::models::SearchResult;
+
+/// Maximum length of one request line (in bytes)
+///
+/// JSON escaping can expand the text, so allow headroom over `MAX_TEXT_LENGTH`.
+const MAX_LINE_LENGTH: usize = MAX_TEXT_LENGTH * 2;
+
+/// One unit read from the request body
+enum Line {
+  /// Line content (without the trailing newline)
+  Data(Vec<u8>),
+  /// Line exceeded `MAX_LINE_LENGTH` and was discarded
+  TooLong,
+  /// Failed to read the request body (terminates the stream)
+  ReadError(String),
+}
+
+/// Incremental line splitter over the request body stream
+struct LineReader {
+  body: BodyDataStream,
+  buf: Vec<u8>,
+  /// Currently skipping the rest of an oversized line
+  discarding: bool,
+  /// Body has been fully consumed
+  done: bool,
+}
+
+impl LineReader {
+  fn new(body: Body) -> Self {
+    Self {
+      body: body.into_data_stream(),
+      buf: Vec::new(),
+      discarding: false,
+      done: false,
+    }
+  }
+
+  /// Returns the next line, or `None` at the end of the body
+  async fn next_line(&mut self) -> Option<Line> {
+    loop {
+      if let Some(pos) = self.buf.iter().position(|&b| b == b'\n') {
+        let mut line: Vec<u8> = self.buf.drain(..=pos).collect();
+        line.pop();
+        // A chunk may hold a whole oversized line including its newline
+        if std::mem::take(&mut self.discarding) || line.len() > MAX_LINE_LENGTH {
+          return Some(Line::TooLong);
+        }
+        return Some(Line::Data(line));
+      }
+
+      if self.done {
+        if std::mem::take(&mut self.discarding) || self.buf.len() > MAX_LINE_LENGTH {
+          self.buf.clear();
+          return Some(Line::TooLong);
+        }
+        if self.buf.is_empty() {
+          return None;
+        }
+        return Some(Line::Data(std::mem::take(&mut self.buf)));
+      }
+
+      match self.body.next().await {
+        Some(Ok(chunk)) => {
+          self.buf.extend_from_slice(&chunk);
+          if self.buf.len() > MAX_LINE_LENGTH && !self.buf.contains(&b'\n') {
+            // Drop the oversized line and skip until the next newline
+            self.buf.clear();
+            self.discarding = true;
+          }
+        }
+        Some(Err(e)) => {
+          self.done = true;
+          self.buf.clear();
+          self.discarding = false;
+          return Some(Line::ReadError(e.to_string()));
+        }
+        None => self.done = true,
+      }
+    }
+  }
+}
+
+/// Serializes an error line: `{"id": ..., "error": {"code": ..., "message": ...}}`
+fn error_line(id: Option<String>, err: &ApiError) -> Vec<u8> {
+  let value = serde_json::json!({
+    "id": id,
+    "error": {
+      "code": err.code(),
+      "message": err.to_string(),
+    },
+  });
+  value.to_string().into_bytes()
+}
+
+/// Analyzes one request line and returns the serialized response line
+async fn process_line(
+  service: &Arc<dyn WakeruApiService>,
+  metrics: &Metrics,
+  line: &[u8],
+) -> Vec<u8> {
+  let request: AnalyzeLineRequest = match serde_json::from_slice(line) {
+    Ok(request) => request,
+    Err(e) => {
+      // Echo the id if the line is at least a JSON object with a string id
+      let id = serde_json::from_slice::<serde_json::Value>(line)
+        .ok()
+        .and_then(|v| v.get("id").and_then(|id| id.as_str()).map(String::from));
+      return error_line(id, &ApiError::invalid_input(format!("Malformed line: {e}")));
+    }
+  };
+
+  let AnalyzeLineRequest { id, text } = request;
+  let service = service.clone();
+
+  let result = tokio::task::spawn_blocking(move || {
+    service.analyze(WakeruRequest {
+      text,
+      index_pos: None,
+      nbest: None,
+      compact: false,
+    })
+  })
+  .await
+  .map_err(|e| {
+    error!(error = %e, "spawn_blocking error");
+    ApiError::internal("Failed to execute processing")
+  })
+  .and_then(|result| {
+    metrics.record_analysis(&result);
+    result
+  });
+
+  match result {
+    Ok(response) => {
+      let line = AnalyzeLineResponse {
+        id,
+        tokens: response.tokens,
+        elapsed_ms: response.elapsed_ms,
+      };
+      match serde_json::to_vec(&line) {
+        Ok(bytes) => bytes,
+        Err(e) => error_line(
+          Some(line.id),
+          &ApiError::internal(format!("Failed to serialize: {e}")),
+        ),
+      }
+    }
+    Err(err) => error_line(Some(id), &err),
+  }
+}
+
+/// Builds the streaming NDJSON response body for the request body
+///
+/// - Blank lines are skipped
+/// - Malformed lines emit an error line and processing continues
+pub(super) fn analyze_ndjson_body(
+  service: Arc<dyn WakeruApiService>,
+  metrics: Arc<Metrics>,
+  body: Body,
+) -> Body {
+  let lines = stream::unfold(
+    (LineReader::new(body), service, metrics),
+    |(mut reader, service, metrics)| async move {
+      loop {
+        let mut output = match reader.next_line().await? {
+          Line::Data(line) => {
+            if line.iter().all(u8::is_ascii_whitespace) {
+              continue;
+            }
+            process_line(&service, &metrics, &line).await
+          }
+          Line::TooLong => error_line(
+            None,
+            &ApiError::invalid_input(format!("Line exceeds {MAX_LINE_LENGTH} bytes")),
+          ),
+          Line::ReadError(reason) => error_line(
+            None,
+            &ApiError::invalid_input(format!("Failed to read body: {reason}")),
+          ),
+        };
+        output.push(b'\n');
+        return Some((
+          Ok::<_, Infallible>(Bytes::from(output)),
+          (reader, service, metrics),
+        ));
+      }
+    },
+  );
+
+  Body::from_stream(lines)
+}
+
+/// Builds the streaming NDJSON response body of search results
+///
+/// Each result is serialized only when the body is polled, so the whole
+/// serialized array is never held in memory.
+pub(super) fn search_results_ndjson_body(results: Vec<SearchResult>) -> Body {
+  let lines = stream::iter(results).map(|result| {
+    let mut output = serde_json::to_vec(&result).unwrap_or_else(|e| {
+      error_line(
+        Some(result.doc_id),
+        &ApiError::internal(format!("Failed to serialize: {e}")),
+      )
+    });
+    output.push(b'\n');
+    Ok::<_, Infallible>(Bytes::from(output))
+  });
+
+  Body::from_stream(lines)
+}
@@ -0,0 +1,178 @@
+//! Per-client Rate Limiting
+//!
+//! In-process token bucket keyed by client IP, applied as a middleware layer by
+//! `create_router` when `Config::requests_per_second` is set.
+
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::{
+  extract::{ConnectInfo, Request, State, rejection::ExtensionRejection},
+  middleware::Next,
+  response::Response,
+};
+use tracing::warn;
+
+use crate::errors::ApiError;
+
+/// Number of tracked clients above which refilled buckets are dropped
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Minimum time between two prunes, so that a large client set is not scanned per request
+const PRUNE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Token bucket of one client
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+  /// Remaining tokens (one request consumes one token)
+  tokens: f64,
+  /// Last time `tokens` was refilled
+  refilled_at: Instant,
+}
+
+/// Token-bucket rate limiter keyed by client IP
+///
+/// Each client may burst up to `requests_per_second` requests, and regains
+/// `requests_per_second` tokens per second after that.
+#[derive(Debug)]
+pub struct RateLimiter {
+  /// Tokens regained per second (also the bucket capacity)
+  rate: f64,
+  /// Buckets per client IP
+  buckets: Mutex<Buckets>,
+}
+
+/// Buckets per client IP, with the time they were last pruned
+#[derive(Debug)]
+struct Buckets {
+  by_client: HashMap<IpAddr, Bucket>,
+  pruned_at: Instant,
+}
+
+impl RateLimiter {
+  /// Creates a limiter allowing `requests_per_second` sustained requests per client
+  #[must_use]
+  pub fn new(requests_per_second: u32) -> Self {
+    Self {
+      rate: f64::from(requests_per_second.max(1)),
+      buckets: Mutex::new(Buckets {
+        by_client: HashMap::new(),
+        pruned_at: Instant::now(),
+      }),
+    }
+  }
+
+  /// Consumes one token of `client`, returning `false` if its bucket is empty
+  pub fn check(&self, client: IpAddr) -> bool {
+    self.check_at(client, Instant::now())
+  }
+
+  fn check_at(&self, client: IpAddr, now: Instant) -> bool {
+    let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+
+    if buckets.by_client.len() >= PRUNE_THRESHOLD
+      && now.saturating_duration_since(buckets.pruned_at) >= PRUNE_INTERVAL
+    {
+      // Full buckets carry no state, so they can be dropped to bound memory
+      let rate = self.rate;
+      buckets.by_client.retain(|_, bucket| {
+        bucket.tokens + now.saturating_duration_since(bucket.refilled_at).as_secs_f64() * rate
+          < rate
+      });
+      buckets.pruned_at = now;
+    }
+
+    let bucket = buckets.by_client.entry(client).or_insert(Bucket {
+      tokens: self.rate,
+      refilled_at: now,
+    });
+
+    let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+    bucket.tokens = (bucket.tokens + elapsed * self.rate).min(self.rate);
+    bucket.refilled_at = now;
+
+    if bucket.tokens >= 1.0 {
+      bucket.tokens -= 1.0;
+      true
+    } else {
+      false
+    }
+  }
+}
+
+/// Middleware rejecting requests over the limit with `429 Too Many Requests`
+///
+/// The client IP is taken from `ConnectInfo<SocketAddr>` (see `run_server`); requests
+/// without it (e.g. a router used without connection info) share a single bucket.
+pub async fn rate_limit(
+  State(limiter): State<Arc<RateLimiter>>,
+  connect_info: Result<ConnectInfo<SocketAddr>, ExtensionRejection>,
+  request: Request,
+  next: Next,
+) -> Result<Response, ApiError> {
+  let client = connect_info.map_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED), |info| info.0.ip());
+
+  if !limiter.check(client) {
+    warn!(%client, "Rate limit exceeded");
+    return Err(ApiError::rate_limited("Rate limit exceeded, retry later"));
+  }
+
+  Ok(next.run(request).await)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn bucket_refills_over_time() {
+    let limiter = RateLimiter::new(2);
+    let client = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let start = Instant::now();
+
+    assert!(limiter.check_at(client, start));
+    assert!(limiter.check_at(client, start));
+    assert!(!limiter.check_at(client, start));
+
+    // Half a second regains one token at 2 requests per second
+    assert!(limiter.check_at(client, start + Duration::from_millis(500)));
+    assert!(!limiter.check_at(client, start + Duration::from_millis(500)));
+  }
+
+  #[test]
+  fn clients_have_separate_buckets() {
+    let limiter = RateLimiter::new(1);
+    let start = Instant::now();
+
+    assert!(limiter.check_at(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), start));
+    assert!(!limiter.check_at(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), start));
+    assert!(limiter.check_at(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)), start));
+  }
+
+  #[test]
+  fn full_buckets_are_pruned_at_most_once_per_interval() {
+    let limiter = RateLimiter::new(1);
+    let start = Instant::now();
+    let client = |i: usize| IpAddr::V4(Ipv4Addr::from(u32::try_from(i).unwrap()));
+    let tracked = || limiter.buckets.lock().unwrap().by_client.len();
+
+    for i in 0..PRUNE_THRESHOLD {
+      assert!(limiter.check_at(client(i), start));
+    }
+    assert_eq!(tracked(), PRUNE_THRESHOLD);
+
+    // Every bucket has refilled, so the next check past the interval drops them all
+    let later = start + PRUNE_INTERVAL;
+    assert!(limiter.check_at(client(PRUNE_THRESHOLD), later));
+    assert_eq!(tracked(), 1);
+
+    // Within the interval, further clients are tracked without another scan
+    for i in 0..PRUNE_THRESHOLD {
+      assert!(limiter.check_at(client(i), later + Duration::from_secs(2)));
+    }
+    assert!(limiter.check_at(client(PRUNE_THRESHOLD + 1), later + Duration::from_secs(3)));
+    assert_eq!(tracked(), PRUNE_THRESHOLD + 2);
+  }
+}
@@ -1,28 +1,91 @@
 //! Router Definition
 
+use std::net::SocketAddr;
+use std::sync::Arc;
+
 use axum::{
   Router,
+  extract::DefaultBodyLimit,
+  http::{HeaderValue, Method, header},
+  middleware,
   routing::{get, post},
 };
+use tower_http::cors::{AllowOrigin, CorsLayer};
 use tower_http::trace::TraceLayer;
 
-use super::handlers::{health_check, post_wakeru};
+use super::handlers::{
+  get_config, get_metrics, health_check, health_ready, post_analyze_ndjson, post_query_tokens,
+  post_search, post_search_stream, post_tokenize, post_wakeru, post_wakeru_batch,
+};
+use super::rate_limit::{RateLimiter, rate_limit};
 use super::state::AppState;
+use crate::config::MAX_BODY_SIZE;
 use crate::errors::ApiError;
 
 /// Create API Router
 ///
+/// Request bodies are limited to `MAX_BODY_SIZE` (instead of axum's 2MB default),
+/// so oversized texts are reported by the service as `text_too_long`.
+/// A CORS layer is added only if `config.cors_origins` is not empty, and a per-client
+/// rate limit only if `config.requests_per_second` is set. The rate limit does not apply
+/// to `/health` and `/health/ready`, so probes keep working under load.
+///
 /// # Arguments
 /// * `state` - Application state
 ///
 /// # Returns
 /// Configured Router
 pub fn create_router(state: AppState) -> Router {
-  Router::new()
+  let cors = cors_layer(&state.config.cors_origins);
+  let limiter = state.config.requests_per_second.map(|rps| Arc::new(RateLimiter::new(rps)));
+
+  let api = Router::new()
     .route("/wakeru", post(post_wakeru))
+    .route("/wakeru/batch", post(post_wakeru_batch))
+    .route("/wakeru/analyze-ndjson", post(post_analyze_ndjson))
+    .route("/wakeru/query-tokens", post(post_query_tokens))
+    .route("/tokenize", post(post_tokenize))
+    .route("/search", post(post_search))
+    .route("/search/stream", post(post_search_stream))
+    .route("/config", get(get_config))
+    .route("/metrics", get(get_metrics));
+
+  // `route_layer` only wraps the routes above, leaving the health probes unlimited
+  let api = match limiter {
+    Some(limiter) => api.route_layer(middleware::from_fn_with_state(limiter, rate_limit)),
+    None => api,
+  };
+
+  let router = api
     .route("/health", get(health_check))
+    .route("/health/ready", get(health_ready))
+    .layer(DefaultBodyLimit::max(MAX_BODY_SIZE))
     .layer(TraceLayer::new_for_http())
-    .with_state(state)
+    .with_state(state);
+
+  match cors {
+    Some(cors) => router.layer(cors),
+    None => router,
+  }
+}
+
+/// Builds the CORS layer allowing `origins` (`None` if no origin is configured)
+///
+/// Origins are validated by `Config::from_env`, so invalid values are skipped here.
+fn cors_layer(origins: &[String]) -> Option<CorsLayer> {
+  let origins: Vec<HeaderValue> =
+    origins.iter().filter_map(|origin| HeaderValue::from_str(origin).ok()).collect();
+
+  if origins.is_empty() {
+    return None;
+  }
+
+  Some(
+    CorsLayer::new()
+      .allow_origin(AllowOrigin::list(origins))
+      .allow_methods([Method::GET, Method::POST])
+      .allow_headers([header::CONTENT_TYPE]),
+  )
 }
 
 /// Start the server
@@ -42,21 +105,23 @@ pub async fn run_server(state: AppState) -> crate::errors::Result<()> {
 
   let router = create_router(state);
 
-  axum::serve(listener, router)
-    .await
-    .map_err(|e| ApiError::internal(format!("Server error: {}", e)))?;
+  // Client addresses are needed by the rate limiter
+  axum::serve(
+    listener,
+    router.into_make_service_with_connect_info::<SocketAddr>(),
+  )
+  .await
+  .map_err(|e| ApiError::internal(format!("Server error: {}", e)))?;
 
   Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-  use std::sync::Arc;
-
   use super::*;
-  use crate::config::{Config, Preset};
+  use crate::config::{Config, DEFAULT_MAX_BATCH_SIZE, LogFormat, Preset};
   use crate::errors::Result as ApiResult;
-  use crate::models::{WakeruRequest, WakeruResponse};
+  use crate::models::{QueryTokensRequest, QueryTokensResponse, WakeruRequest, WakeruResponse};
   use crate::service::WakeruApiService;
 
   /// Dummy implementation for testing (Does not touch dictionary)
@@ -67,15 +132,29 @@ mod tests {
     fn analyze(&self, _request: WakeruRequest) -> ApiResult<WakeruResponse> {
       Ok(WakeruResponse {
         tokens: Vec::new(),
+        paths: None,
         elapsed_ms: 0,
       })
     }
+
+    fn query_tokens(&self, _request: QueryTokensRequest) -> ApiResult<QueryTokensResponse> {
+      Ok(QueryTokensResponse { tokens: Vec::new() })
+    }
   }
 
   fn create_test_state() -> AppState {
     let config = Config {
       bind_addr: "127.0.0.1:5531".to_string(),
       preset: Preset::UnidicCwj,
+      dictionary_path: None,
+      dictionary_cache_dir: None,
+      reject_blank_text: true,
+      failed_input_preview_chars: None,
+      max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+      search_config: None,
+      cors_origins: Vec::new(),
+      requests_per_second: None,
+      log_format: LogFormat::Text,
     };
 
     // Inject stub (No dictionary load needed)
@@ -2,8 +2,9 @@
 
 use std::sync::Arc;
 
+use super::metrics::Metrics;
 use crate::config::Config;
-use crate::service::WakeruApiService;
+use crate::service::{SearchApiService, WakeruApiService};
 
 /// Application State
 ///
@@ -18,12 +19,31 @@ pub struct AppState {
   /// - Production: `Arc::new(WakeruApiServiceFull::new(&config)?)`
   /// - Test: `Arc::new(StubWakeruApiService)`
   pub service: Arc<dyn WakeruApiService>,
+  /// BM25 Search Service (`POST /search` returns 503 if `None`)
+  ///
+  /// - Production: `Arc::new(WakeruService::init(&wakeru_config)?)`
+  /// - Test: stub implementing `SearchApiService`
+  pub search_service: Option<Arc<dyn SearchApiService>>,
+  /// Analysis metrics exposed by `GET /metrics`
+  pub metrics: Arc<Metrics>,
 }
 
 impl AppState {
-  /// Creates a new AppState
+  /// Creates a new AppState (search disabled)
   #[must_use]
   pub fn new(config: Config, service: Arc<dyn WakeruApiService>) -> Self {
-    Self { config, service }
+    Self {
+      config,
+      service,
+      search_service: None,
+      metrics: Arc::new(Metrics::new()),
+    }
+  }
+
+  /// Enables `POST /search` with the given search service
+  #[must_use]
+  pub fn with_search_service(mut self, search_service: Arc<dyn SearchApiService>) -> Self {
+    self.search_service = Some(search_service);
+    self
   }
 }
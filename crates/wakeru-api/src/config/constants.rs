@@ -6,6 +6,19 @@
 /// Limitation to prevent resource exhaustion due to processing large text.
 pub const MAX_TEXT_LENGTH: usize = 10_000_000;
 
+/// Maximum size of a request body (in bytes)
+///
+/// `MAX_TEXT_LENGTH` plus 1MB for JSON syntax and escapes, so that a text just over
+/// `MAX_TEXT_LENGTH` still reaches the service and is rejected as `text_too_long` (400)
+/// instead of by the body limit (413). Must stay larger than `MAX_TEXT_LENGTH`.
+pub const MAX_BODY_SIZE: usize = MAX_TEXT_LENGTH + 1_000_000;
+
+/// Maximum number of N-best paths in one analysis request
+///
+/// Each path is enumerated from the lattice of the whole text, so the work grows with
+/// `nbest` times the text length. Limits the cost a single request can cause.
+pub const MAX_NBEST: usize = 10;
+
 /// Default bind address
 ///
 /// Standard port for localhost, assumed for use in development environment.
@@ -16,3 +29,14 @@ pub const DEFAULT_BIND_ADDR: &str = "127.0.0.1:5530";
 /// Use UniDic (CWJ) as default.
 /// Dictionary based on Corpus of Contemporary Written Japanese.
 pub const DEFAULT_PRESET_DICT: &str = "unidic-cwj";
+
+/// Default maximum number of texts in one `POST /wakeru/batch` request
+///
+/// Bounds the work done inside a single request.
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 1000;
+
+/// Default for rejecting whitespace-only text
+///
+/// Disabled by default so that whitespace-only input is analyzed as before;
+/// set `WAKERU_REJECT_BLANK_TEXT=true` to reject it as `invalid_input`.
+pub const DEFAULT_REJECT_BLANK_TEXT: bool = false;
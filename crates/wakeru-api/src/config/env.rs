@@ -1,12 +1,21 @@
 //! Config loading from environment variables
 
+use std::path::PathBuf;
 use std::str::FromStr;
 
-use super::constants::{DEFAULT_BIND_ADDR, DEFAULT_PRESET_DICT};
+use axum::http::HeaderValue;
+use serde::Serialize;
+
+use super::constants::{
+  DEFAULT_BIND_ADDR, DEFAULT_MAX_BATCH_SIZE, DEFAULT_PRESET_DICT, DEFAULT_REJECT_BLANK_TEXT,
+};
 use crate::errors::ApiError;
 
 /// Dictionary Preset Type
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Serialized with the same names accepted by `FromStr` (e.g. `"unidic-cwj"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum Preset {
   /// IPAdic dictionary
   Ipadic,
@@ -34,13 +43,63 @@ impl FromStr for Preset {
 
 impl Preset {}
 
+/// Log Output Format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LogFormat {
+  /// Human-readable lines
+  #[default]
+  Text,
+  /// One JSON object per line (for log pipelines)
+  Json,
+}
+
+impl FromStr for LogFormat {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_lowercase().as_str() {
+      "text" => Ok(Self::Text),
+      "json" => Ok(Self::Json),
+      _ => Err(format!(
+        "Unknown log format: {}. Valid values: text, json",
+        s
+      )),
+    }
+  }
+}
+
 /// API Server Configuration
+///
+/// Not serialized directly: `GET /config` exposes an allowlist via `ConfigResponse`.
 #[derive(Debug, Clone)]
 pub struct Config {
   /// Bind address (e.g. "127.0.0.1:5530")
   pub bind_addr: String,
   /// Dictionary preset to use
   pub preset: Preset,
+  /// Path to a local compiled dictionary; overrides `preset` when set
+  /// (for air-gapped deployments that cannot download presets)
+  pub dictionary_path: Option<PathBuf>,
+  /// Root directory of preset dictionary caches (OS cache directory if `None`)
+  ///
+  /// Each preset is cached in its own subdirectory, so different presets can share it.
+  pub dictionary_cache_dir: Option<PathBuf>,
+  /// Reject whitespace-only text as `invalid_input`
+  pub reject_blank_text: bool,
+  /// Log a truncated, hashed preview (first N chars) of inputs that fail validation
+  /// (disabled if `None`)
+  pub failed_input_preview_chars: Option<usize>,
+  /// Maximum number of texts in one `POST /wakeru/batch` request
+  pub max_batch_size: usize,
+  /// Path to a wakeru TOML config enabling `POST /search` (disabled if `None`)
+  pub search_config: Option<PathBuf>,
+  /// Origins allowed to call the API cross-origin (CORS disabled if empty)
+  pub cors_origins: Vec<String>,
+  /// Maximum sustained requests per second per client IP (unlimited if `None`)
+  pub requests_per_second: Option<u32>,
+  /// Log output format
+  pub log_format: LogFormat,
 }
 
 impl Config {
@@ -57,10 +116,126 @@ impl Config {
 
     let preset = Preset::from_str(&preset_dict_str).map_err(ApiError::config)?;
 
-    Ok(Self { bind_addr, preset })
+    // Unset or empty uses the preset
+    let dictionary_path =
+      std::env::var("WAKERU_DICT_PATH").ok().filter(|value| !value.is_empty()).map(PathBuf::from);
+
+    // Unset or empty uses the OS cache directory
+    let dictionary_cache_dir = std::env::var("WAKERU_DICT_CACHE_DIR")
+      .ok()
+      .filter(|value| !value.is_empty())
+      .map(PathBuf::from);
+
+    let reject_blank_text = match std::env::var("WAKERU_REJECT_BLANK_TEXT") {
+      Ok(value) => parse_bool(&value).ok_or_else(|| {
+        ApiError::config(format!(
+          "Invalid WAKERU_REJECT_BLANK_TEXT: {}. Valid values: true, false, 1, 0",
+          value
+        ))
+      })?,
+      Err(_) => DEFAULT_REJECT_BLANK_TEXT,
+    };
+
+    // Unset or 0 disables failed input previews
+    let failed_input_preview_chars = match std::env::var("WAKERU_FAILED_INPUT_PREVIEW_CHARS") {
+      Ok(value) => {
+        let chars = value.parse::<usize>().map_err(|_| {
+          ApiError::config(format!(
+            "Invalid WAKERU_FAILED_INPUT_PREVIEW_CHARS: {}. Must be a non-negative integer",
+            value
+          ))
+        })?;
+        (chars > 0).then_some(chars)
+      }
+      Err(_) => None,
+    };
+
+    let max_batch_size = match std::env::var("WAKERU_MAX_BATCH_SIZE") {
+      Ok(value) => value.parse::<usize>().ok().filter(|&size| size > 0).ok_or_else(|| {
+        ApiError::config(format!(
+          "Invalid WAKERU_MAX_BATCH_SIZE: {}. Must be a positive integer",
+          value
+        ))
+      })?,
+      Err(_) => DEFAULT_MAX_BATCH_SIZE,
+    };
+
+    // Unset or empty disables search
+    let search_config = std::env::var("WAKERU_SEARCH_CONFIG")
+      .ok()
+      .filter(|value| !value.is_empty())
+      .map(PathBuf::from);
+
+    // Unset or empty disables CORS
+    let cors_origins = match std::env::var("WAKERU_CORS_ORIGINS") {
+      Ok(value) => parse_cors_origins(&value).map_err(|origin| {
+        ApiError::config(format!(
+          "Invalid WAKERU_CORS_ORIGINS: {}. Must be a comma-separated list of origins",
+          origin
+        ))
+      })?,
+      Err(_) => Vec::new(),
+    };
+
+    // Unset or 0 disables rate limiting
+    let requests_per_second = match std::env::var("WAKERU_REQUESTS_PER_SECOND") {
+      Ok(value) => {
+        let rps = value.parse::<u32>().map_err(|_| {
+          ApiError::config(format!(
+            "Invalid WAKERU_REQUESTS_PER_SECOND: {}. Must be a non-negative integer",
+            value
+          ))
+        })?;
+        (rps > 0).then_some(rps)
+      }
+      Err(_) => None,
+    };
+
+    // Unset or empty uses text
+    let log_format = match std::env::var("WAKERU_LOG_FORMAT") {
+      Ok(value) if !value.is_empty() => LogFormat::from_str(&value).map_err(ApiError::config)?,
+      _ => LogFormat::default(),
+    };
+
+    Ok(Self {
+      bind_addr,
+      preset,
+      dictionary_path,
+      dictionary_cache_dir,
+      reject_blank_text,
+      failed_input_preview_chars,
+      max_batch_size,
+      search_config,
+      cors_origins,
+      requests_per_second,
+      log_format,
+    })
+  }
+}
+
+/// Parses a boolean environment variable value (case-insensitive)
+fn parse_bool(value: &str) -> Option<bool> {
+  match value.to_lowercase().as_str() {
+    "true" | "1" => Some(true),
+    "false" | "0" => Some(false),
+    _ => None,
   }
 }
 
+/// Parses a comma-separated list of CORS origins, skipping empty entries
+///
+/// Returns the first origin that is not a valid header value as the error.
+fn parse_cors_origins(value: &str) -> Result<Vec<String>, String> {
+  value
+    .split(',')
+    .map(str::trim)
+    .filter(|origin| !origin.is_empty())
+    .map(|origin| {
+      HeaderValue::from_str(origin).map(|_| origin.to_string()).map_err(|_| origin.to_string())
+    })
+    .collect()
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -87,6 +262,45 @@ mod tests {
     assert!(Preset::from_str("invalid").is_err());
   }
 
+  #[test]
+  fn preset_serializes_as_from_str_name() {
+    for name in ["ipadic", "unidic-cwj", "unidic-csj"] {
+      let preset = Preset::from_str(name).unwrap();
+      assert_eq!(serde_json::to_value(preset).unwrap(), name);
+    }
+  }
+
+  #[test]
+  fn log_format_from_str() {
+    assert_eq!(LogFormat::from_str("text").unwrap(), LogFormat::Text);
+    assert_eq!(LogFormat::from_str("JSON").unwrap(), LogFormat::Json);
+    assert!(LogFormat::from_str("yaml").is_err());
+    assert_eq!(serde_json::to_value(LogFormat::Json).unwrap(), "json");
+  }
+
+  #[test]
+  fn parse_bool_accepts_known_values() {
+    assert_eq!(parse_bool("true"), Some(true));
+    assert_eq!(parse_bool("TRUE"), Some(true));
+    assert_eq!(parse_bool("1"), Some(true));
+    assert_eq!(parse_bool("false"), Some(false));
+    assert_eq!(parse_bool("0"), Some(false));
+    assert_eq!(parse_bool("yes"), None);
+  }
+
+  #[test]
+  fn parse_cors_origins_splits_and_trims() {
+    assert_eq!(
+      parse_cors_origins("https://a.example, https://b.example,").unwrap(),
+      vec!["https://a.example", "https://b.example"]
+    );
+    assert!(parse_cors_origins("").unwrap().is_empty());
+    assert_eq!(
+      parse_cors_origins("https://a.example,bad\norigin").unwrap_err(),
+      "bad\norigin"
+    );
+  }
+
   #[test]
   fn config_from_env_defaults() {
     // Verify default values when environment variables are not set
@@ -96,5 +310,8 @@ mod tests {
     let config = Config::from_env().unwrap();
     // If environment variable is set, it's that value, otherwise default value
     assert!(!config.bind_addr.is_empty());
+    if std::env::var("WAKERU_LOG_FORMAT").is_err() {
+      assert_eq!(config.log_format, LogFormat::Text);
+    }
   }
 }
@@ -3,5 +3,8 @@
 mod constants;
 mod env;
 
-pub use constants::{DEFAULT_BIND_ADDR, DEFAULT_PRESET_DICT, MAX_TEXT_LENGTH};
-pub use env::{Config, Preset};
+pub use constants::{
+  DEFAULT_BIND_ADDR, DEFAULT_MAX_BATCH_SIZE, DEFAULT_PRESET_DICT, DEFAULT_REJECT_BLANK_TEXT,
+  MAX_BODY_SIZE, MAX_NBEST, MAX_TEXT_LENGTH,
+};
+pub use env::{Config, LogFormat, Preset};
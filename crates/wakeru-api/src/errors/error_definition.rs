@@ -22,6 +22,10 @@ pub enum ApiErrorKind {
   Internal,
   /// Configuration error
   Config,
+  /// Feature is not enabled on this server
+  Unavailable,
+  /// Client exceeded the request rate limit
+  RateLimited,
 }
 
 impl ApiErrorKind {
@@ -33,6 +37,8 @@ impl ApiErrorKind {
       Self::TextTooLong => "text_too_long",
       Self::Internal => "internal_error",
       Self::Config => "config_error",
+      Self::Unavailable => "service_unavailable",
+      Self::RateLimited => "rate_limited",
     }
   }
 
@@ -42,6 +48,8 @@ impl ApiErrorKind {
     match self {
       Self::InvalidInput | Self::TextTooLong => StatusCode::BAD_REQUEST,
       Self::Internal | Self::Config => StatusCode::INTERNAL_SERVER_ERROR,
+      Self::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+      Self::RateLimited => StatusCode::TOO_MANY_REQUESTS,
     }
   }
 }
@@ -64,6 +72,14 @@ pub enum ApiError {
   /// Configuration error
   #[error("Config error: {0}")]
   Config(String),
+
+  /// Feature is not enabled on this server
+  #[error("Service unavailable: {0}")]
+  Unavailable(String),
+
+  /// Client exceeded the request rate limit
+  #[error("Too many requests: {0}")]
+  RateLimited(String),
 }
 
 impl ApiError {
@@ -75,6 +91,8 @@ impl ApiError {
       Self::TextTooLong(_, _) => ApiErrorKind::TextTooLong,
       Self::Internal(_) => ApiErrorKind::Internal,
       Self::Config(_) => ApiErrorKind::Config,
+      Self::Unavailable(_) => ApiErrorKind::Unavailable,
+      Self::RateLimited(_) => ApiErrorKind::RateLimited,
     }
   }
 
@@ -113,6 +131,18 @@ impl ApiError {
   pub fn config(message: impl Into<String>) -> Self {
     Self::Config(message.into())
   }
+
+  /// Create service unavailable error
+  #[must_use]
+  pub fn unavailable(message: impl Into<String>) -> Self {
+    Self::Unavailable(message.into())
+  }
+
+  /// Create rate limited error
+  #[must_use]
+  pub fn rate_limited(message: impl Into<String>) -> Self {
+    Self::RateLimited(message.into())
+  }
 }
 
 /// JSON structure for error response
@@ -207,6 +237,22 @@ mod tests {
     assert_eq!(err.status(), StatusCode::INTERNAL_SERVER_ERROR);
   }
 
+  #[test]
+  fn unavailable_creation() {
+    let err = ApiError::unavailable("Search is not enabled");
+    assert_eq!(err.kind(), ApiErrorKind::Unavailable);
+    assert_eq!(err.code(), "service_unavailable");
+    assert_eq!(err.status(), StatusCode::SERVICE_UNAVAILABLE);
+  }
+
+  #[test]
+  fn rate_limited_creation() {
+    let err = ApiError::rate_limited("Rate limit exceeded");
+    assert_eq!(err.kind(), ApiErrorKind::RateLimited);
+    assert_eq!(err.code(), "rate_limited");
+    assert_eq!(err.status(), StatusCode::TOO_MANY_REQUESTS);
+  }
+
   #[test]
   fn from_wakeru_error_invalid_input() {
     let wakeru_err = WakeruError::Tokenizer(TokenizerError::InvalidInput {
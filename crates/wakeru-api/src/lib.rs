@@ -4,7 +4,16 @@
 //!
 //! ## Endpoints
 //! - `POST /wakeru` - Morphological Analysis
-//! - `GET /health` - Health Check
+//! - `POST /wakeru/batch` - Morphological Analysis of multiple texts
+//! - `POST /wakeru/analyze-ndjson` - Morphological Analysis of NDJSON lines (streaming)
+//! - `POST /wakeru/query-tokens` - Indexer-equivalent query tokens
+//! - `POST /tokenize` - Surface forms and wakachi-gaki string
+//! - `POST /search` - BM25 search (enabled by `WAKERU_SEARCH_CONFIG`)
+//! - `POST /search/stream` - BM25 search results as NDJSON (one result per line)
+//! - `GET /health` - Health Check (with the loaded dictionary)
+//! - `GET /health/ready` - Readiness Check (trivial tokenization and search)
+//! - `GET /config` - Effective server configuration (diagnostics)
+//! - `GET /metrics` - Analysis metrics (Prometheus exposition format)
 //!
 //! ## Usage Example
 //! ```bash
@@ -22,5 +31,9 @@ pub mod service;
 pub use api::AppState;
 pub use config::Config;
 pub use errors::{ApiError, ApiErrorKind};
-pub use models::{TokenDto, WakeruRequest, WakeruResponse};
-pub use service::WakeruApiServiceFull;
+pub use models::{
+  BatchWakeruRequest, BatchWakeruResponse, DictionaryInfo, HealthResponse, PathDto,
+  QueryTokensRequest, QueryTokensResponse, SearchRequest, SearchResponse, TokenDto,
+  TokenizeRequest, TokenizeResponse, WakeruRequest, WakeruResponse,
+};
+pub use service::{SearchApiService, WakeruApiServiceFull};
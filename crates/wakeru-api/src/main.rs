@@ -4,27 +4,63 @@ use std::sync::Arc;
 
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+use wakeru::{WakeruConfig, WakeruError, WakeruService};
 use wakeru_api::ApiError;
 use wakeru_api::api::AppState;
 use wakeru_api::api::run_server;
-use wakeru_api::config::Config;
+use wakeru_api::config::{Config, LogFormat};
 use wakeru_api::service::WakeruApiServiceFull;
 
 #[tokio::main]
 async fn main() -> Result<(), ApiError> {
-  // Initialize logging
-  tracing_subscriber::registry().with(tracing_subscriber::fmt::layer()).init();
-
   // Load configuration
   let config = Config::from_env()?;
-  tracing::info!(preset = ?config.preset, "Config loaded");
+
+  // Initialize logging
+  let fmt_layer = tracing_subscriber::fmt::layer();
+  match config.log_format {
+    LogFormat::Text => tracing_subscriber::registry().with(fmt_layer).init(),
+    LogFormat::Json => tracing_subscriber::registry().with(fmt_layer.json()).init(),
+  }
+  tracing::info!(
+    preset = ?config.preset,
+    log_format = ?config.log_format,
+    dictionary_path = ?config.dictionary_path,
+    "Config loaded"
+  );
 
   // Initialize service
   let service = Arc::new(WakeruApiServiceFull::new(&config)?);
+  service.preload()?;
   tracing::info!("Morphological analysis service initialized");
 
+  // Initialize search service (optional)
+  let search_service = match &config.search_config {
+    Some(path) => {
+      let mut wakeru_config = WakeruConfig::from_toml_path(path).map_err(WakeruError::from)?;
+      wakeru_config.apply_env_overrides().map_err(WakeruError::from)?;
+      // Share the analysis dictionary instead of loading a second copy when possible
+      let shared_dictionary = service.shared_dictionary_for(&wakeru_config);
+      let shares_dictionary = shared_dictionary.is_some();
+      let search_service = Arc::new(match shared_dictionary {
+        Some(dict) => WakeruService::init_with_dictionary(&wakeru_config, dict)?,
+        None => WakeruService::init(&wakeru_config)?,
+      });
+      tracing::info!(
+        path = %path.display(),
+        shares_dictionary,
+        "Search service initialized"
+      );
+      Some(search_service)
+    }
+    None => None,
+  };
+
   // Create application state
-  let state = AppState::new(config, service);
+  let mut state = AppState::new(config, service);
+  if let Some(search_service) = search_service {
+    state = state.with_search_service(search_service);
+  }
 
   // Start server
   run_server(state).await
@@ -3,5 +3,11 @@
 mod request;
 mod response;
 
-pub use request::WakeruRequest;
-pub use response::{TokenDto, WakeruResponse};
+pub use request::{
+  AnalyzeLineRequest, BatchWakeruRequest, QueryTokensRequest, SearchRequest, TokenizeRequest,
+  WakeruRequest,
+};
+pub use response::{
+  AnalyzeLineResponse, BatchWakeruResponse, ConfigResponse, DictionaryInfo, HealthResponse,
+  PathDto, QueryTokensResponse, SearchResponse, TokenDto, TokenizeResponse, WakeruResponse,
+};
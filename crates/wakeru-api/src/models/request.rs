@@ -1,12 +1,94 @@
 //! Request Model Definition
 
 use serde::Deserialize;
+use wakeru::config::Language;
+
+/// Default number of search results
+const DEFAULT_SEARCH_LIMIT: usize = 10;
 
 /// Morphological Analysis Request
 #[derive(Debug, Deserialize)]
 pub struct WakeruRequest {
   /// Text to analyze
   pub text: String,
+  /// POS feature prefixes counted as indexable (e.g. `["名詞", "助詞"]`)
+  ///
+  /// When present, `should_index` is true exactly for tokens whose feature starts with
+  /// one of these prefixes. When absent, the built-in filter is used.
+  #[serde(default)]
+  pub index_pos: Option<Vec<String>>,
+  /// Number of best segmentation paths to return (single best if omitted)
+  ///
+  /// Must be between 1 and `MAX_NBEST`. When present, the response holds `paths`
+  /// instead of `tokens`.
+  #[serde(default)]
+  pub nbest: Option<usize>,
+  /// Serialize only `surface`, `pos`, `start_byte`, `end_byte` and `should_index` of each
+  /// token (default: false)
+  #[serde(default)]
+  pub compact: bool,
+}
+
+/// One line of an NDJSON analysis request
+///
+/// `{"id": "...", "text": "..."}`
+#[derive(Debug, Deserialize)]
+pub struct AnalyzeLineRequest {
+  /// Caller-defined identifier echoed back in the response line
+  pub id: String,
+  /// Text to analyze
+  pub text: String,
+}
+
+/// Batch Morphological Analysis Request
+///
+/// `{"texts": ["...", "..."]}`
+#[derive(Debug, Deserialize)]
+pub struct BatchWakeruRequest {
+  /// Texts to analyze (results are returned in the same order)
+  pub texts: Vec<String>,
+}
+
+/// Query Tokens Request
+///
+/// Requests the token sequence that the indexer would produce for `text`.
+#[derive(Debug, Deserialize)]
+pub struct QueryTokensRequest {
+  /// Text to tokenize (typically a search query)
+  pub text: String,
+  /// Whether to return lemmas instead of surface forms (default: false)
+  ///
+  /// The index stores surface forms, so `false` guarantees query/index parity.
+  #[serde(default)]
+  pub lemmatize: bool,
+}
+
+/// Tokenize Request
+///
+/// `{"text": "..."}`
+#[derive(Debug, Deserialize)]
+pub struct TokenizeRequest {
+  /// Text to split into words
+  pub text: String,
+}
+
+/// Search Request
+///
+/// `{"query": "...", "language": "ja", "limit": 10}`
+#[derive(Debug, Deserialize)]
+pub struct SearchRequest {
+  /// Search query
+  pub query: String,
+  /// Index language to search (default language of the search service if omitted)
+  #[serde(default)]
+  pub language: Option<Language>,
+  /// Maximum number of results (default: 10)
+  #[serde(default = "default_search_limit")]
+  pub limit: usize,
+}
+
+fn default_search_limit() -> usize {
+  DEFAULT_SEARCH_LIMIT
 }
 
 #[cfg(test)]
@@ -18,6 +100,17 @@ mod tests {
     let json = r#"{"text": "東京"}"#;
     let req: WakeruRequest = serde_json::from_str(json).unwrap();
     assert_eq!(req.text, "東京");
+    assert_eq!(req.index_pos, None);
+  }
+
+  #[test]
+  fn deserialize_request_with_index_pos() {
+    let json = r#"{"text": "東京の寺", "index_pos": ["名詞", "助詞"]}"#;
+    let req: WakeruRequest = serde_json::from_str(json).unwrap();
+    assert_eq!(
+      req.index_pos,
+      Some(vec!["名詞".to_string(), "助詞".to_string()])
+    );
   }
 
   #[test]
@@ -26,4 +119,58 @@ mod tests {
     let req: WakeruRequest = serde_json::from_str(json).unwrap();
     assert_eq!(req.text, "");
   }
+
+  #[test]
+  fn deserialize_analyze_line_request() {
+    let json = r#"{"id": "line-1", "text": "東京"}"#;
+    let req: AnalyzeLineRequest = serde_json::from_str(json).unwrap();
+    assert_eq!(req.id, "line-1");
+    assert_eq!(req.text, "東京");
+  }
+
+  #[test]
+  fn deserialize_query_tokens_request_defaults() {
+    let json = r#"{"text": "東京"}"#;
+    let req: QueryTokensRequest = serde_json::from_str(json).unwrap();
+    assert_eq!(req.text, "東京");
+    assert!(!req.lemmatize);
+  }
+
+  #[test]
+  fn deserialize_query_tokens_request_lemmatize() {
+    let json = r#"{"text": "食べた", "lemmatize": true}"#;
+    let req: QueryTokensRequest = serde_json::from_str(json).unwrap();
+    assert!(req.lemmatize);
+  }
+
+  #[test]
+  fn deserialize_tokenize_request() {
+    let json = r#"{"text": "東京タワー"}"#;
+    let req: TokenizeRequest = serde_json::from_str(json).unwrap();
+    assert_eq!(req.text, "東京タワー");
+  }
+
+  #[test]
+  fn deserialize_batch_request() {
+    let json = r#"{"texts": ["東京", "大阪"]}"#;
+    let req: BatchWakeruRequest = serde_json::from_str(json).unwrap();
+    assert_eq!(req.texts, ["東京", "大阪"]);
+  }
+
+  #[test]
+  fn deserialize_search_request_defaults() {
+    let json = r#"{"query": "東京"}"#;
+    let req: SearchRequest = serde_json::from_str(json).unwrap();
+    assert_eq!(req.query, "東京");
+    assert_eq!(req.language, None);
+    assert_eq!(req.limit, DEFAULT_SEARCH_LIMIT);
+  }
+
+  #[test]
+  fn deserialize_search_request_full() {
+    let json = r#"{"query": "Tokyo", "language": "en", "limit": 3}"#;
+    let req: SearchRequest = serde_json::from_str(json).unwrap();
+    assert_eq!(req.language, Some(Language::En));
+    assert_eq!(req.limit, 3);
+  }
 }
@@ -1,6 +1,12 @@
 //! Response Model Definition
 
-use serde::Serialize;
+use std::path::PathBuf;
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use wakeru::models::SearchResult;
+
+use crate::config::{Config, LogFormat, Preset};
 
 /// Constants for feature array indices
 ///
@@ -16,16 +22,148 @@ const IDX_PRONUNCIATION: usize = 8;
 /// Morphological Analysis Response
 #[derive(Debug, Serialize)]
 pub struct WakeruResponse {
+  /// Token sequence of analysis result (empty and omitted when `paths` is returned)
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub tokens: Vec<TokenDto>,
+  /// N-best segmentation paths, best first (only when `nbest` was requested)
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub paths: Option<Vec<PathDto>>,
+  /// Elapsed time (milliseconds)
+  pub elapsed_ms: u64,
+}
+
+/// One N-best segmentation path
+#[derive(Debug, Clone, Serialize)]
+pub struct PathDto {
+  /// Total path cost (lower is better)
+  pub cost: i32,
+  /// Token sequence of this path
+  pub tokens: Vec<TokenDto>,
+}
+
+/// Batch Morphological Analysis Response
+#[derive(Debug, Serialize)]
+pub struct BatchWakeruResponse {
+  /// Analysis results in the same order as the request texts
+  pub results: Vec<WakeruResponse>,
+}
+
+/// One line of an NDJSON analysis response
+#[derive(Debug, Serialize)]
+pub struct AnalyzeLineResponse {
+  /// Identifier from the request line
+  pub id: String,
   /// Token sequence of analysis result
   pub tokens: Vec<TokenDto>,
   /// Elapsed time (milliseconds)
   pub elapsed_ms: u64,
 }
 
+/// Query Tokens Response
+#[derive(Debug, Serialize)]
+pub struct QueryTokensResponse {
+  /// Tokens that the indexer would produce for the input (POS-filtered)
+  pub tokens: Vec<String>,
+}
+
+/// Tokenize Response
+#[derive(Debug, Serialize)]
+pub struct TokenizeResponse {
+  /// Surface forms of the indexable tokens (POS-filtered)
+  pub tokens: Vec<String>,
+  /// `tokens` joined with single spaces (wakachi-gaki)
+  pub wakachi: String,
+}
+
+impl TokenizeResponse {
+  /// Builds the response, joining `tokens` into the wakachi-gaki string
+  #[must_use]
+  pub fn new(tokens: Vec<String>) -> Self {
+    let wakachi = tokens.join(" ");
+    Self { tokens, wakachi }
+  }
+}
+
+/// Search Response
+///
+/// Serialized as a plain JSON array of results ordered by BM25 score.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct SearchResponse {
+  /// Search results
+  pub results: Vec<SearchResult>,
+}
+
+/// Health Check Response
+#[derive(Debug, Serialize)]
+pub struct HealthResponse {
+  /// Always `"ok"` while the server is serving requests (or is ready, for `GET /health/ready`)
+  pub status: &'static str,
+  /// Dictionary loaded by the analysis service (omitted if unknown)
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub dictionary: Option<DictionaryInfo>,
+}
+
+/// Dictionary in use by the analysis service
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DictionaryInfo {
+  /// Preset dictionary name (e.g. `"unidic-cwj"`, `None` for a local dictionary)
+  pub preset: Option<String>,
+  /// Local dictionary file (`None` for a preset dictionary)
+  ///
+  /// Kept for logging only; never serialized, so responses do not reveal server paths.
+  #[serde(skip)]
+  pub path: Option<PathBuf>,
+}
+
+/// Effective Server Configuration Response
+///
+/// An explicit allowlist of `Config` fields: filesystem paths, CORS origins and the
+/// rate limit are deliberately not exposed.
+#[derive(Debug, Serialize)]
+pub struct ConfigResponse {
+  /// Bind address (e.g. "127.0.0.1:5530")
+  pub bind_addr: String,
+  /// Dictionary preset (ignored when `local_dictionary` is `true`)
+  pub preset: Preset,
+  /// Whether a local dictionary file is used instead of the preset
+  pub local_dictionary: bool,
+  /// Whether whitespace-only text is rejected as `invalid_input`
+  pub reject_blank_text: bool,
+  /// Length of logged previews of failed inputs (disabled if `None`)
+  pub failed_input_preview_chars: Option<usize>,
+  /// Maximum number of texts in one `POST /wakeru/batch` request
+  pub max_batch_size: usize,
+  /// Whether `POST /search` is enabled
+  pub search_enabled: bool,
+  /// Log output format
+  pub log_format: LogFormat,
+  /// Maximum length of input text (in bytes)
+  pub max_text_length: usize,
+}
+
+impl ConfigResponse {
+  /// Builds the response from the configuration loaded at startup
+  pub fn new(config: &Config, max_text_length: usize) -> Self {
+    Self {
+      bind_addr: config.bind_addr.clone(),
+      preset: config.preset,
+      local_dictionary: config.dictionary_path.is_some(),
+      reject_blank_text: config.reject_blank_text,
+      failed_input_preview_chars: config.failed_input_preview_chars,
+      max_batch_size: config.max_batch_size,
+      search_enabled: config.search_config.is_some(),
+      log_format: config.log_format,
+      max_text_length,
+    }
+  }
+}
+
 /// Token Information (DTO)
 ///
 /// Converted from vibrato-rkyv token information for API response.
-#[derive(Debug, Clone, Serialize)]
+/// Compact tokens serialize only `surface`, `pos`, `start_byte`, `end_byte` and `should_index`.
+#[derive(Debug, Clone)]
 pub struct TokenDto {
   /// Surface form (string appearing in original text)
   pub surface: String,
@@ -39,14 +177,11 @@ pub struct TokenDto {
   pub pos_detail2: String,
   /// POS detail 3 (4th element)
   pub pos_detail3: String,
-  /// Lemma (dictionary form reading)
-  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Lemma (dictionary form reading, omitted if `None`)
   pub lemma: Option<String>,
-  /// Reading
-  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Reading (omitted if `None`)
   pub reading: Option<String>,
-  /// Pronunciation
-  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Pronunciation (omitted if `None`)
   pub pronunciation: Option<String>,
   /// Start byte position
   pub start_byte: usize,
@@ -54,6 +189,8 @@ pub struct TokenDto {
   pub end_byte: usize,
   /// Whether to index (for filtering in RAG usage)
   pub should_index: bool,
+  /// Omit feature details when serialized (see `compact`)
+  pub compact: bool,
 }
 
 impl TokenDto {
@@ -119,7 +256,52 @@ impl TokenDto {
       start_byte,
       end_byte,
       should_index,
+      compact: false,
+    }
+  }
+
+  /// Marks the token to be serialized without feature details
+  ///
+  /// Only `surface`, `pos`, `start_byte`, `end_byte` and `should_index` are serialized,
+  /// which roughly halves the payload size.
+  #[must_use]
+  pub fn compact(mut self) -> Self {
+    self.compact = true;
+    self
+  }
+}
+
+impl Serialize for TokenDto {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("TokenDto", 12)?;
+    state.serialize_field("surface", &self.surface)?;
+
+    if self.compact {
+      state.skip_field("feature")?;
+    } else {
+      state.serialize_field("feature", &self.feature)?;
+    }
+    state.serialize_field("pos", &self.pos)?;
+
+    let details = [
+      ("pos_detail1", Some(&self.pos_detail1)),
+      ("pos_detail2", Some(&self.pos_detail2)),
+      ("pos_detail3", Some(&self.pos_detail3)),
+      ("lemma", self.lemma.as_ref()),
+      ("reading", self.reading.as_ref()),
+      ("pronunciation", self.pronunciation.as_ref()),
+    ];
+    for (key, value) in details {
+      match value {
+        Some(value) if !self.compact => state.serialize_field(key, value)?,
+        _ => state.skip_field(key)?,
+      }
     }
+
+    state.serialize_field("start_byte", &self.start_byte)?;
+    state.serialize_field("end_byte", &self.end_byte)?;
+    state.serialize_field("should_index", &self.should_index)?;
+    state.end()
   }
 }
 
@@ -159,6 +341,17 @@ mod tests {
     assert!(!dto.should_index);
   }
 
+  #[test]
+  fn dictionary_info_does_not_serialize_path() {
+    let info = DictionaryInfo {
+      preset: None,
+      path: Some(PathBuf::from("/srv/wakeru/system.dic")),
+    };
+
+    let json = serde_json::to_string(&info).unwrap();
+    assert_eq!(json, r#"{"preset":null}"#);
+  }
+
   #[test]
   fn wakeru_response_serialization() {
     let response = WakeruResponse {
@@ -169,6 +362,7 @@ mod tests {
         6,
         true,
       )],
+      paths: None,
       elapsed_ms: 42,
     };
 
@@ -177,5 +371,45 @@ mod tests {
     assert!(json.contains("\"elapsed_ms\":42"));
     assert!(json.contains("\"surface\":\"東京\""));
     assert!(json.contains("\"should_index\":true"));
+    assert!(!json.contains("\"paths\""));
+  }
+
+  #[test]
+  fn compact_token_serializes_only_core_fields() {
+    let feature = "名詞,固有名詞,地名,一般,*,*,東京,トウキョウ,トーキョー";
+    let full = serde_json::to_value(TokenDto::from_feature("東京", feature, 0, 6, true)).unwrap();
+    let compact =
+      serde_json::to_value(TokenDto::from_feature("東京", feature, 0, 6, true).compact()).unwrap();
+
+    let keys = |json: &serde_json::Value| -> Vec<String> {
+      let mut keys: Vec<String> = json.as_object().unwrap().keys().cloned().collect();
+      keys.sort_unstable();
+      keys
+    };
+    assert_eq!(
+      keys(&compact),
+      vec!["end_byte", "pos", "should_index", "start_byte", "surface"]
+    );
+    assert_eq!(keys(&full).len(), 12);
+    for key in keys(&compact) {
+      assert_eq!(compact[&key], full[&key]);
+    }
+  }
+
+  #[test]
+  fn wakeru_response_with_paths_omits_tokens() {
+    let response = WakeruResponse {
+      tokens: Vec::new(),
+      paths: Some(vec![PathDto {
+        cost: 100,
+        tokens: vec![TokenDto::from_feature("東京", "名詞", 0, 6, true)],
+      }]),
+      elapsed_ms: 0,
+    };
+
+    let json = serde_json::to_value(&response).unwrap();
+    assert!(json.get("tokens").is_none());
+    assert_eq!(json["paths"][0]["cost"], 100);
+    assert_eq!(json["paths"][0]["tokens"][0]["surface"], "東京");
   }
 }
@@ -1,5 +1,7 @@
 //! Service module
 
+mod search_service;
 mod wakeru_api_service;
 
+pub use search_service::SearchApiService;
 pub use wakeru_api_service::{WakeruApiService, WakeruApiServiceFull};
@@ -0,0 +1,65 @@
+//! Search Service
+
+use wakeru::WakeruService;
+
+use crate::config::MAX_TEXT_LENGTH;
+use crate::errors::{ApiError, Result};
+use crate::models::{SearchRequest, SearchResponse};
+
+/// Common interface for BM25 search service
+///
+/// Implemented by `wakeru::WakeruService` for production and by stubs in tests.
+pub trait SearchApiService: Send + Sync {
+  /// Executes BM25 search
+  ///
+  /// # Errors
+  /// - Input error (empty query, length exceeded, unsupported language)
+  /// - Internal error
+  fn search(&self, request: SearchRequest) -> Result<SearchResponse>;
+}
+
+/// Validates search query length
+///
+/// # Errors
+/// - If query is empty or whitespace-only
+/// - If query exceeds maximum length
+fn validate_query(query: &str) -> Result<()> {
+  if query.trim().is_empty() {
+    return Err(ApiError::invalid_input("Query is empty"));
+  }
+
+  if query.len() > MAX_TEXT_LENGTH {
+    return Err(ApiError::text_too_long(query.len(), MAX_TEXT_LENGTH));
+  }
+
+  Ok(())
+}
+
+impl SearchApiService for WakeruService {
+  fn search(&self, request: SearchRequest) -> Result<SearchResponse> {
+    validate_query(&request.query)?;
+
+    let language = request.language.unwrap_or_else(|| self.default_language());
+    if !self.is_language_supported(language) {
+      return Err(ApiError::invalid_input(format!(
+        "Unsupported language: {language:?}"
+      )));
+    }
+
+    let results = self.search_tokens_or_with_language(language, &request.query, request.limit)?;
+
+    Ok(SearchResponse { results })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn validate_query_rejects_blank() {
+    assert_eq!(validate_query("").unwrap_err().code(), "invalid_input");
+    assert_eq!(validate_query("  \t").unwrap_err().code(), "invalid_input");
+    assert!(validate_query("東京").is_ok());
+  }
+}
@@ -1,15 +1,25 @@
 //! Morphological Analysis Service
 
-use std::time::Instant;
+use std::fmt::Write as _;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use vibrato_rkyv::Tokenizer as VibratoImpl;
+use sha2::{Digest, Sha256};
+use tracing::{info, warn};
+use vibrato_rkyv::{Dictionary, Tokenizer as VibratoImpl};
+use wakeru::WakeruConfig;
 use wakeru::dictionary::DictionaryManager;
-use wakeru::tokenizer::should_index;
+use wakeru::tokenizer::{PosFilter, should_index};
 
-use crate::config::MAX_TEXT_LENGTH;
 use crate::config::{Config, Preset};
+use crate::config::{MAX_NBEST, MAX_TEXT_LENGTH};
 use crate::errors::{ApiError, Result};
-use crate::models::{TokenDto, WakeruRequest, WakeruResponse};
+use crate::models::{
+  DictionaryInfo, PathDto, QueryTokensRequest, QueryTokensResponse, TokenDto, WakeruRequest,
+  WakeruResponse,
+};
 
 /// Common interface for morphological analysis service
 ///
@@ -22,6 +32,81 @@ pub trait WakeruApiService: Send + Sync {
   /// - Input error (empty string, length exceeded, etc.)
   /// - Internal error
   fn analyze(&self, request: WakeruRequest) -> Result<WakeruResponse>;
+
+  /// Returns the tokens that the indexer would produce for the text
+  ///
+  /// # Errors
+  /// - Input error (empty string, length exceeded, etc.)
+  fn query_tokens(&self, request: QueryTokensRequest) -> Result<QueryTokensResponse>;
+
+  /// Returns the dictionary in use (`None` if unknown, e.g. test stubs)
+  fn dictionary_info(&self) -> Option<DictionaryInfo> {
+    None
+  }
+}
+
+/// Validates input text length
+///
+/// # Errors
+/// - If text is empty
+/// - If text is whitespace-only (when `reject_blank` is set)
+/// - If text exceeds maximum length
+fn validate_text(text: &str, reject_blank: bool) -> Result<()> {
+  let text_bytes = text.len();
+  if text_bytes == 0 {
+    return Err(ApiError::invalid_input("Text is empty"));
+  }
+
+  if text_bytes > MAX_TEXT_LENGTH {
+    return Err(ApiError::text_too_long(text_bytes, MAX_TEXT_LENGTH));
+  }
+
+  if reject_blank && text.trim().is_empty() {
+    return Err(ApiError::invalid_input("Text is blank"));
+  }
+
+  Ok(())
+}
+
+/// Validates the requested number of N-best paths
+///
+/// # Errors
+/// - If `nbest` is 0 or greater than `MAX_NBEST`
+fn validate_nbest(nbest: Option<usize>) -> Result<()> {
+  match nbest {
+    Some(n) if n == 0 || n > MAX_NBEST => Err(ApiError::invalid_input(format!(
+      "nbest must be between 1 and {MAX_NBEST}"
+    ))),
+    _ => Ok(()),
+  }
+}
+
+/// Builds a log-safe preview of an input: first `max_chars` characters, byte length and SHA-256
+///
+/// The full text is never included, but the hash allows matching reports of the same input.
+fn failed_input_preview(text: &str, max_chars: usize) -> String {
+  let prefix: String = text.chars().take(max_chars).collect();
+  let ellipsis = if prefix.len() < text.len() { "..." } else { "" };
+
+  let mut sha256 = String::with_capacity(64);
+  for byte in Sha256::digest(text.as_bytes()) {
+    let _ = write!(sha256, "{byte:02x}");
+  }
+
+  format!(
+    "{prefix:?}{ellipsis} (len={} bytes, sha256={sha256})",
+    text.len()
+  )
+}
+
+/// Builds the POS filter deciding `should_index` for an analysis request
+///
+/// `index_pos` replaces the built-in policy entirely (allow list only, nothing denied);
+/// `None` keeps the built-in policy.
+fn request_pos_filter(index_pos: Option<Vec<String>>) -> PosFilter {
+  index_pos.map_or_else(PosFilter::default, |prefixes| {
+    PosFilter::new(prefixes, Vec::<String>::new())
+  })
 }
 
 /// Converts Preset to PresetDictionaryKind of vibrato-rkyv
@@ -45,30 +130,105 @@ fn preset_to_vibrato_kind(preset: &Preset) -> vibrato_rkyv::dictionary::PresetDi
 pub struct WakeruApiServiceFull {
   /// vibrato tokenizer (internal implementation)
   inner: VibratoImpl,
+  /// Reject whitespace-only text
+  reject_blank_text: bool,
+  /// Number of characters to preview when logging failed inputs (disabled if `None`)
+  failed_input_preview_chars: Option<usize>,
+  /// Dictionary loaded at initialization (reported by `GET /health`)
+  dictionary_info: DictionaryInfo,
+  /// Loaded dictionary, shared with `inner` (and with a search service, see
+  /// [`shared_dictionary_for`](Self::shared_dictionary_for))
+  dictionary: Arc<Dictionary>,
 }
 
 impl WakeruApiServiceFull {
   /// Initializes the service
   ///
   /// # Arguments
-  /// * `config` - Configuration (including dictionary preset)
+  /// * `config` - Configuration (dictionary preset, or local dictionary path which takes precedence)
   ///
   /// # Errors
-  /// Returns an error if dictionary load fails
+  /// Returns an error if the local dictionary path does not exist or dictionary load fails
   pub fn new(config: &Config) -> Result<Self> {
-    let kind = preset_to_vibrato_kind(&config.preset);
-
     // Create dictionary manager and load dictionary
-    let manager = DictionaryManager::with_preset(kind)
-      .map_err(|e| ApiError::config(format!("Failed to create dictionary manager: {}", e)))?;
+    let manager = dictionary_manager(config)?
+      // The first preset download can take minutes, so log each phase
+      .with_progress(|progress| info!(?progress, "Loading dictionary"));
 
+    let start = Instant::now();
     let dict =
       manager.load().map_err(|e| ApiError::config(format!("Failed to load dictionary: {}", e)))?;
+    info!(
+      elapsed_ms = start.elapsed().as_millis() as u64,
+      "Dictionary loaded"
+    );
+
+    let dictionary_info = DictionaryInfo {
+      preset: manager.preset_kind().map(|kind| kind.name().to_string()),
+      path: manager.dictionary_path().map(Path::to_path_buf),
+    };
 
     // Create VibratoImpl directly
-    let inner = VibratoImpl::from_shared_dictionary(dict);
+    let inner = VibratoImpl::from_shared_dictionary(Arc::clone(&dict));
+
+    Ok(Self {
+      inner,
+      dictionary_info,
+      dictionary: dict,
+      reject_blank_text: config.reject_blank_text,
+      failed_input_preview_chars: config.failed_input_preview_chars,
+    })
+  }
+
+  /// Warms up the tokenizer so that the first request is not slow
+  ///
+  /// Runs one throwaway analysis, which pages in the dictionary and allocates the
+  /// worker's internal buffers. Call once at startup, before serving requests.
+  ///
+  /// # Returns
+  /// Time taken by the warm-up analysis
+  ///
+  /// # Errors
+  /// Returns an error if the warm-up analysis fails
+  pub fn preload(&self) -> Result<Duration> {
+    let start = Instant::now();
+    self.analyze(WakeruRequest {
+      text: "東京スカイツリーは墨田区にある電波塔です。".to_string(),
+      index_pos: None,
+      nbest: None,
+      compact: false,
+    })?;
+    let elapsed = start.elapsed();
+    info!(
+      elapsed_ms = elapsed.as_millis() as u64,
+      "Tokenizer warmed up"
+    );
+    Ok(elapsed)
+  }
+
+  /// Returns the loaded dictionary if `wakeru_config` would load the same one
+  ///
+  /// Pass it to `WakeruService::init_with_dictionary` so that the analysis and search
+  /// services share one copy in memory. `None` (load separately) if this service uses a
+  /// local dictionary file, or `wakeru_config` has another preset or a user dictionary.
+  pub fn shared_dictionary_for(&self, wakeru_config: &WakeruConfig) -> Option<Arc<Dictionary>> {
+    let same_preset =
+      self.dictionary_info.preset.as_deref() == Some(wakeru_config.dictionary_preset().name());
+    (same_preset && wakeru_config.dictionary_user_dict().is_none())
+      .then(|| Arc::clone(&self.dictionary))
+  }
 
-    Ok(Self { inner })
+  /// Validates text, logging a preview of rejected input when enabled
+  fn validate(&self, text: &str) -> Result<()> {
+    validate_text(text, self.reject_blank_text).inspect_err(|err| {
+      if let Some(max_chars) = self.failed_input_preview_chars {
+        warn!(
+          code = err.code(),
+          input = %failed_input_preview(text, max_chars),
+          "Input rejected"
+        );
+      }
+    })
   }
 
   /// Executes morphological analysis (returns all tokens)
@@ -77,49 +237,102 @@ impl WakeruApiServiceFull {
   /// * `request` - Analysis request
   ///
   /// # Returns
-  /// Analysis result (all token sequence and processing time)
+  /// Analysis result (all token sequence, or the N-best paths when `nbest` is set,
+  /// and processing time)
   ///
   /// # Errors
   /// - If text is empty
+  /// - If text is whitespace-only (when `reject_blank_text` is enabled)
   /// - If text exceeds maximum length
+  /// - If `nbest` is 0 or greater than `MAX_NBEST`
   pub fn analyze(&self, request: WakeruRequest) -> Result<WakeruResponse> {
     // Validate text length
-    let text_bytes = request.text.len();
-    if text_bytes == 0 {
-      return Err(ApiError::invalid_input("Text is empty"));
-    }
-
-    if text_bytes > MAX_TEXT_LENGTH {
-      return Err(ApiError::text_too_long(text_bytes, MAX_TEXT_LENGTH));
-    }
+    self.validate(&request.text)?;
+    validate_nbest(request.nbest)?;
 
     // Start measuring processing time
     let start = Instant::now();
 
+    let pos_filter = request_pos_filter(request.index_pos);
+    let token_dto = |surface: &str, feature: &str, range: Range<usize>| {
+      // Determine whether to index
+      let should_index_flag = pos_filter.should_index(feature);
+      let dto = TokenDto::from_feature(surface, feature, range.start, range.end, should_index_flag);
+      if request.compact { dto.compact() } else { dto }
+    };
+
     // Create worker and analyze
     let mut worker = self.inner.new_worker();
     worker.reset_sentence(&request.text);
-    worker.tokenize();
 
-    let mut tokens = Vec::with_capacity(worker.num_tokens());
+    let (tokens, paths) = match request.nbest {
+      Some(n) => {
+        worker.tokenize_nbest(n);
 
-    for token in worker.token_iter() {
-      let surface = token.surface();
-      let feature = token.feature();
-      let start_byte = token.range_byte().start;
-      let end_byte = token.range_byte().end;
+        let paths = (0..worker.num_nbest_paths())
+          .map(|path_idx| PathDto {
+            cost: worker.path_cost(path_idx).unwrap_or_default(),
+            tokens: worker
+              .nbest_token_iter(path_idx)
+              .into_iter()
+              .flatten()
+              .map(|token| token_dto(token.surface(), token.feature(), token.range_byte()))
+              .collect(),
+          })
+          .collect();
+        (Vec::new(), Some(paths))
+      }
+      None => {
+        worker.tokenize();
 
-      // Determine whether to index
-      let should_index_flag = should_index(feature);
-
-      let dto = TokenDto::from_feature(surface, feature, start_byte, end_byte, should_index_flag);
-      tokens.push(dto);
-    }
+        let tokens = worker
+          .token_iter()
+          .map(|token| token_dto(token.surface(), token.feature(), token.range_byte()))
+          .collect();
+        (tokens, None)
+      }
+    };
 
     // End measuring processing time
     let elapsed_ms = start.elapsed().as_millis() as u64;
 
-    Ok(WakeruResponse { tokens, elapsed_ms })
+    Ok(WakeruResponse {
+      tokens,
+      paths,
+      elapsed_ms,
+    })
+  }
+
+  /// Returns the tokens that the indexer would produce for `text`
+  ///
+  /// Applies the same part-of-speech filter (`should_index`) as `VibratoTokenizer`,
+  /// so the result can be matched against the index externally.
+  ///
+  /// # Arguments
+  /// * `text` - Text to tokenize
+  /// * `lemmatize` - Return lemmas instead of surface forms (falls back to the surface form
+  ///   when the dictionary has no lemma). Note that the index stores surface forms.
+  #[must_use]
+  pub fn query_tokens(&self, text: &str, lemmatize: bool) -> Vec<String> {
+    let mut worker = self.inner.new_worker();
+    worker.reset_sentence(text);
+    worker.tokenize();
+
+    worker
+      .token_iter()
+      .filter(|token| should_index(token.feature()))
+      .map(|token| {
+        let surface = token.surface();
+        if lemmatize {
+          let range = token.range_byte();
+          TokenDto::from_feature(surface, token.feature(), range.start, range.end, true)
+            .lemma
+            .unwrap_or_else(|| surface.to_string())
+        } else {
+          surface.to_string()
+        }
+      })
+      .collect()
   }
 }
 
@@ -130,20 +343,128 @@ impl WakeruApiService for WakeruApiServiceFull {
     // so explicitly call the inherent method.
     WakeruApiServiceFull::analyze(self, request)
   }
+
+  fn query_tokens(&self, request: QueryTokensRequest) -> Result<QueryTokensResponse> {
+    self.validate(&request.text)?;
+
+    let tokens = WakeruApiServiceFull::query_tokens(self, &request.text, request.lemmatize);
+    Ok(QueryTokensResponse { tokens })
+  }
+
+  fn dictionary_info(&self) -> Option<DictionaryInfo> {
+    Some(self.dictionary_info.clone())
+  }
+}
+
+/// Creates the dictionary manager selected by `config`
+///
+/// A local `dictionary_path` takes precedence; otherwise the preset is cached under
+/// `dictionary_cache_dir` (or the OS cache directory).
+///
+/// # Errors
+/// Returns an error if the local dictionary path does not exist or the OS cache
+/// directory cannot be determined
+fn dictionary_manager(config: &Config) -> Result<DictionaryManager> {
+  let kind = preset_to_vibrato_kind(&config.preset);
+  match (&config.dictionary_path, &config.dictionary_cache_dir) {
+    (Some(path), _) => DictionaryManager::from_local_path(path),
+    (None, Some(cache_dir)) => Ok(DictionaryManager::with_preset_and_cache_dir(
+      kind, cache_dir,
+    )),
+    (None, None) => DictionaryManager::with_preset(kind),
+  }
+  .map_err(|e| ApiError::config(format!("Failed to create dictionary manager: {}", e)))
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::config::Preset;
+  use crate::config::{DEFAULT_MAX_BATCH_SIZE, LogFormat, Preset};
 
   fn create_test_config() -> Config {
     Config {
       bind_addr: "127.0.0.1:5531".to_string(),
       preset: Preset::UnidicCwj,
+      dictionary_path: None,
+      dictionary_cache_dir: None,
+      reject_blank_text: true,
+      failed_input_preview_chars: None,
+      max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+      search_config: None,
+      cors_origins: Vec::new(),
+      requests_per_second: None,
+      log_format: LogFormat::Text,
     }
   }
 
+  #[test]
+  fn failed_input_preview_truncates_and_hashes() {
+    let text = "x".repeat(MAX_TEXT_LENGTH + 1);
+    let preview = failed_input_preview(&text, 8);
+
+    assert!(preview.starts_with("\"xxxxxxxx\"..."));
+    assert!(preview.contains(&format!("len={} bytes", MAX_TEXT_LENGTH + 1)));
+    assert!(preview.len() < 200);
+    // Stable hash for the same input, different for another input
+    assert_eq!(preview, failed_input_preview(&text, 8));
+    assert_ne!(preview, failed_input_preview(&text[1..], 8));
+  }
+
+  #[test]
+  fn failed_input_preview_short_input_has_no_ellipsis() {
+    // SHA-256 of "abc"
+    assert_eq!(
+      failed_input_preview("abc", 8),
+      "\"abc\" (len=3 bytes, sha256=\
+       ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad)"
+    );
+  }
+
+  #[test]
+  fn validate_nbest_accepts_up_to_max() {
+    assert!(validate_nbest(None).is_ok());
+    assert!(validate_nbest(Some(1)).is_ok());
+    assert!(validate_nbest(Some(MAX_NBEST)).is_ok());
+    assert_eq!(validate_nbest(Some(0)).unwrap_err().code(), "invalid_input");
+    assert_eq!(
+      validate_nbest(Some(MAX_NBEST + 1)).unwrap_err().code(),
+      "invalid_input"
+    );
+    assert_eq!(
+      validate_nbest(Some(1_000_000)).unwrap_err().code(),
+      "invalid_input"
+    );
+  }
+
+  // This does not require dictionary download so can always be run
+  #[test]
+  fn test_invalid_dictionary_path_error() {
+    let config = Config {
+      dictionary_path: Some(std::env::temp_dir().join("wakeru-missing-dictionary.dic")),
+      ..create_test_config()
+    };
+
+    let err = WakeruApiServiceFull::new(&config).err().expect("Invalid path should fail");
+    assert_eq!(err.code(), "config_error");
+  }
+
+  #[test]
+  fn dictionary_manager_uses_configured_cache_dir() {
+    // Nothing is created until the dictionary is loaded
+    let cache_dir = std::env::temp_dir().join("wakeru-api-dict-cache");
+    let config = Config {
+      dictionary_cache_dir: Some(cache_dir.clone()),
+      ..create_test_config()
+    };
+
+    let manager = dictionary_manager(&config).expect("Failed to create dictionary manager");
+    assert_eq!(manager.cache_dir(), cache_dir);
+    assert_eq!(
+      manager.preset_dir(),
+      Some(cache_dir.join(preset_to_vibrato_kind(&config.preset).name()))
+    );
+  }
+
   // Dictionary-dependent tests are opt-in with with_dict_tests feature
   #[test]
   #[cfg_attr(not(feature = "with_dict_tests"), ignore)]
@@ -155,10 +476,111 @@ mod tests {
       .expect("Failed to load dictionary: check test environment");
     let response = service.analyze(WakeruRequest {
       text: "東京".to_string(),
+      index_pos: None,
+      nbest: None,
+      compact: false,
     });
     assert!(response.is_ok());
     let response = response.unwrap();
     assert!(!response.tokens.is_empty());
+
+    // Loaded preset is reported (for GET /health)
+    let info = WakeruApiService::dictionary_info(&service).unwrap();
+    assert_eq!(info.preset.as_deref(), Some("unidic-cwj"));
+    assert_eq!(info.path, None);
+  }
+
+  #[test]
+  #[cfg_attr(not(feature = "with_dict_tests"), ignore)]
+  fn test_preload() {
+    let config = create_test_config();
+    let service = WakeruApiServiceFull::new(&config)
+      .expect("Failed to load dictionary: check test environment");
+    let cold = service.preload().expect("Preload failed");
+
+    // Warm analysis is not slower than the cold warm-up
+    let start = Instant::now();
+    service
+      .analyze(WakeruRequest {
+        text: "東京スカイツリーは墨田区にある電波塔です。".to_string(),
+        index_pos: None,
+        nbest: None,
+        compact: false,
+      })
+      .expect("Analysis failed");
+    assert!(start.elapsed() <= cold.max(Duration::from_millis(50)));
+  }
+
+  #[test]
+  #[cfg_attr(not(feature = "with_dict_tests"), ignore)]
+  fn test_nbest_returns_multiple_paths() {
+    let config = create_test_config();
+    let service = WakeruApiServiceFull::new(&config)
+      .expect("Failed to load dictionary: check test environment");
+
+    // Ambiguous segmentation (すもも/も/もも/も/もも/の/うち)
+    let text = "すもももももももものうち";
+    let response = service
+      .analyze(WakeruRequest {
+        text: text.to_string(),
+        index_pos: None,
+        nbest: Some(3),
+        compact: false,
+      })
+      .expect("Analysis failed");
+
+    assert!(response.tokens.is_empty());
+    let paths = response.paths.expect("paths should be returned");
+    assert!(paths.len() > 1);
+    assert!(paths.len() <= 3);
+    // Best path first, and every path covers the whole text
+    assert!(paths.windows(2).all(|pair| pair[0].cost <= pair[1].cost));
+    for path in &paths {
+      let surface: String = path.tokens.iter().map(|token| token.surface.as_str()).collect();
+      assert_eq!(surface, text);
+    }
+  }
+
+  #[test]
+  #[cfg_attr(not(feature = "with_dict_tests"), ignore)]
+  fn test_nbest_zero_error() {
+    let config = create_test_config();
+    let service = WakeruApiServiceFull::new(&config)
+      .expect("Failed to load dictionary: check test environment");
+    let err = service
+      .analyze(WakeruRequest {
+        text: "東京".to_string(),
+        index_pos: None,
+        nbest: Some(0),
+        compact: false,
+      })
+      .unwrap_err();
+    assert_eq!(err.code(), "invalid_input");
+  }
+
+  #[test]
+  #[cfg_attr(not(feature = "with_dict_tests"), ignore)]
+  fn test_compact_response_is_smaller() {
+    let config = create_test_config();
+    let service = WakeruApiServiceFull::new(&config)
+      .expect("Failed to load dictionary: check test environment");
+
+    let analyze_json = |compact: bool| -> String {
+      let response = service
+        .analyze(WakeruRequest {
+          text: "東京スカイツリーは墨田区にある電波塔です。".to_string(),
+          index_pos: None,
+          nbest: None,
+          compact,
+        })
+        .expect("Analysis failed");
+      serde_json::to_string(&response).unwrap()
+    };
+
+    let full = analyze_json(false);
+    let compact = analyze_json(true);
+    assert!(!compact.contains("\"feature\""));
+    assert!(compact.len() < full.len() / 2);
   }
 
   #[test]
@@ -169,12 +591,42 @@ mod tests {
       .expect("Failed to load dictionary: check test environment");
     let result = service.analyze(WakeruRequest {
       text: "".to_string(),
+      index_pos: None,
+      nbest: None,
+      compact: false,
     });
     assert!(result.is_err());
     let err = result.unwrap_err();
     assert_eq!(err.code(), "invalid_input");
   }
 
+  #[test]
+  #[cfg_attr(not(feature = "with_dict_tests"), ignore)]
+  fn test_blank_text_error() {
+    let mut config = create_test_config();
+    let service = WakeruApiServiceFull::new(&config)
+      .expect("Failed to load dictionary: check test environment");
+    let result = service.analyze(WakeruRequest {
+      text: "   \n\t  ".to_string(),
+      index_pos: None,
+      nbest: None,
+      compact: false,
+    });
+    assert_eq!(result.unwrap_err().code(), "invalid_input");
+
+    // Disabled: whitespace is analyzed as is
+    config.reject_blank_text = false;
+    let service = WakeruApiServiceFull::new(&config)
+      .expect("Failed to load dictionary: check test environment");
+    let result = service.analyze(WakeruRequest {
+      text: "   \n\t  ".to_string(),
+      index_pos: None,
+      nbest: None,
+      compact: false,
+    });
+    assert!(result.is_ok());
+  }
+
   #[test]
   #[cfg_attr(not(feature = "with_dict_tests"), ignore)]
   fn test_text_too_long_error() {
@@ -182,12 +634,137 @@ mod tests {
     let service = WakeruApiServiceFull::new(&config)
       .expect("Failed to load dictionary: check test environment");
     let long_text = "a".repeat(MAX_TEXT_LENGTH + 1);
-    let result = service.analyze(WakeruRequest { text: long_text });
+    let result = service.analyze(WakeruRequest {
+      text: long_text,
+      index_pos: None,
+      nbest: None,
+      compact: false,
+    });
     assert!(result.is_err());
     let err = result.unwrap_err();
     assert_eq!(err.code(), "text_too_long");
   }
 
+  #[test]
+  #[cfg_attr(not(feature = "with_dict_tests"), ignore)]
+  fn test_query_tokens_match_indexed_tokens() {
+    use tantivy::tokenizer::{TokenStream, Tokenizer};
+    use wakeru::tokenizer::VibratoTokenizer;
+
+    let config = create_test_config();
+    let service = WakeruApiServiceFull::new(&config)
+      .expect("Failed to load dictionary: check test environment");
+
+    let text = "東京タワーは東京の観光名所です";
+    let query_tokens = service.query_tokens(text, false);
+
+    // Collect the tokens that VibratoTokenizer emits when indexing the same text
+    let dict = DictionaryManager::with_preset(preset_to_vibrato_kind(&config.preset))
+      .and_then(|manager| manager.load())
+      .expect("Failed to load dictionary: check test environment");
+    let mut tokenizer = VibratoTokenizer::from_shared_dictionary(dict);
+    let mut stream = tokenizer.token_stream(text);
+    let mut indexed_tokens = Vec::new();
+    while stream.advance() {
+      indexed_tokens.push(stream.token().text.clone());
+    }
+
+    assert!(!query_tokens.is_empty());
+    assert_eq!(query_tokens, indexed_tokens);
+  }
+
+  #[test]
+  #[cfg_attr(not(feature = "with_dict_tests"), ignore)]
+  fn test_shared_dictionary_requires_same_preset() {
+    use wakeru::config::DictionaryPreset;
+
+    let service = WakeruApiServiceFull::new(&create_test_config())
+      .expect("Failed to load dictionary: check test environment");
+
+    let same = WakeruConfig::builder().preset(DictionaryPreset::UnidicCwj).build().unwrap();
+    let dict = service.shared_dictionary_for(&same).expect("Same preset should be shared");
+    assert!(Arc::ptr_eq(&dict, &service.dictionary));
+
+    let other = WakeruConfig::builder().preset(DictionaryPreset::Ipadic).build().unwrap();
+    assert!(service.shared_dictionary_for(&other).is_none());
+  }
+
+  #[test]
+  #[cfg_attr(not(feature = "with_dict_tests"), ignore)]
+  fn test_query_tokens_empty_text_error() {
+    let config = create_test_config();
+    let service = WakeruApiServiceFull::new(&config)
+      .expect("Failed to load dictionary: check test environment");
+    let result = WakeruApiService::query_tokens(
+      &service,
+      QueryTokensRequest {
+        text: String::new(),
+        lemmatize: false,
+      },
+    );
+    assert_eq!(result.unwrap_err().code(), "invalid_input");
+  }
+
+  #[test]
+  #[cfg_attr(not(feature = "with_dict_tests"), ignore)]
+  fn test_index_pos_marks_particles_indexable() {
+    let config = create_test_config();
+    let service = WakeruApiServiceFull::new(&config)
+      .expect("Failed to load dictionary: check test environment");
+
+    let particle_flags = |index_pos: Option<Vec<String>>| -> Vec<bool> {
+      let response = service
+        .analyze(WakeruRequest {
+          text: "東京の寺".to_string(),
+          index_pos,
+          nbest: None,
+          compact: false,
+        })
+        .expect("Analysis failed");
+      response.tokens.iter().filter(|t| t.pos == "助詞").map(|t| t.should_index).collect()
+    };
+
+    // Default filter: particles are not indexed
+    let default_flags = particle_flags(None);
+    assert!(!default_flags.is_empty());
+    assert!(default_flags.iter().all(|&flag| !flag));
+
+    // index_pos including 助詞 flips them to true
+    let custom_flags = particle_flags(Some(vec!["名詞".to_string(), "助詞".to_string()]));
+    assert!(custom_flags.iter().all(|&flag| flag));
+  }
+
+  // This does not require dictionary download so can always be run
+  #[test]
+  fn test_request_pos_filter() {
+    let particle = "助詞,格助詞,一般,*,*,*,の,ノ,ノ";
+    let noun = "名詞,固有名詞,地域,一般,*,*,東京,トウキョウ,トーキョー";
+
+    // Absent: built-in filter
+    assert!(!request_pos_filter(None).should_index(particle));
+    assert!(request_pos_filter(None).should_index(noun));
+    assert_eq!(
+      request_pos_filter(None).should_index(noun),
+      should_index(noun)
+    );
+
+    // Present: exactly the given prefixes
+    let filter = request_pos_filter(Some(vec!["助詞".to_string()]));
+    assert!(filter.should_index(particle));
+    assert!(!filter.should_index(noun));
+  }
+
+  // This does not require dictionary download so can always be run
+  #[test]
+  fn test_validate_text_blank() {
+    let err = validate_text("   \n\t  ", true).unwrap_err();
+    assert_eq!(err.code(), "invalid_input");
+    assert!(err.to_string().contains("blank"));
+
+    assert!(validate_text("   \n\t  ", false).is_ok());
+    assert!(validate_text(" text ", true).is_ok());
+  }
+
   // This does not require dictionary download so can always be run
   #[test]
   fn test_preset_to_vibrato_kind() {
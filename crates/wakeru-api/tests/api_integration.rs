@@ -3,63 +3,208 @@
 //! Verify behavior of HTTP endpoints via Router.
 //! Uses stub service, so no dictionary loading required, lightweight and fast.
 
+use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum::{
   Router,
   body::Body,
+  extract::connect_info::MockConnectInfo,
   http::{Request, StatusCode},
   routing::{get, post},
 };
 use tower::ServiceExt;
 
+use wakeru::Language;
+use wakeru::models::SearchResult;
 use wakeru_api::{
-  api::{AppState, health_check, post_wakeru},
-  config::{Config, MAX_TEXT_LENGTH, Preset},
+  api::{
+    AppState, create_router, get_config, get_metrics, health_check, health_ready,
+    post_analyze_ndjson, post_query_tokens, post_search, post_search_stream, post_tokenize,
+    post_wakeru, post_wakeru_batch,
+  },
+  config::{Config, LogFormat, MAX_BODY_SIZE, MAX_TEXT_LENGTH, Preset},
   errors::{ApiError, Result as ApiResult},
-  models::{WakeruRequest, WakeruResponse},
-  service::WakeruApiService,
+  models::{
+    DictionaryInfo, QueryTokensRequest, QueryTokensResponse, SearchRequest, SearchResponse,
+    TokenDto, WakeruRequest, WakeruResponse,
+  },
+  service::{SearchApiService, WakeruApiService},
 };
 
 /// Lightweight stub service for integration tests
 ///
 /// - Empty string: `invalid_input` error
+/// - Whitespace-only string: `invalid_input` error
 /// - Length exceeded: `text_too_long` error
-/// - Otherwise: Returns empty tokens and 0ms
+/// - Otherwise: Returns a single token echoing the whole text and 0ms
+/// - query_tokens: Splits on whitespace (lowercased when `lemmatize` is set)
 struct StubWakeruApiService;
 
+/// Same validation as the production service (with `reject_blank_text` enabled)
+fn validate_text(text: &str) -> ApiResult<()> {
+  let text_bytes = text.len();
+
+  if text_bytes == 0 {
+    return Err(ApiError::invalid_input("Text is empty"));
+  }
+
+  if text_bytes > MAX_TEXT_LENGTH {
+    return Err(ApiError::text_too_long(text_bytes, MAX_TEXT_LENGTH));
+  }
+
+  if text.trim().is_empty() {
+    return Err(ApiError::invalid_input("Text is blank"));
+  }
+
+  Ok(())
+}
+
 impl WakeruApiService for StubWakeruApiService {
   fn analyze(&self, request: WakeruRequest) -> ApiResult<WakeruResponse> {
-    let text_bytes = request.text.len();
+    validate_text(&request.text)?;
+
+    Ok(WakeruResponse {
+      tokens: vec![TokenDto::from_feature(
+        &request.text,
+        "名詞",
+        0,
+        request.text.len(),
+        true,
+      )],
+      paths: None,
+      elapsed_ms: 0,
+    })
+  }
+
+  fn query_tokens(&self, request: QueryTokensRequest) -> ApiResult<QueryTokensResponse> {
+    validate_text(&request.text)?;
+
+    let tokens = request
+      .text
+      .split_whitespace()
+      .map(|t| {
+        if request.lemmatize {
+          t.to_lowercase()
+        } else {
+          t.to_string()
+        }
+      })
+      .collect();
 
-    if text_bytes == 0 {
-      return Err(ApiError::invalid_input("Text is empty"));
+    Ok(QueryTokensResponse { tokens })
+  }
+
+  fn dictionary_info(&self) -> Option<DictionaryInfo> {
+    Some(DictionaryInfo {
+      preset: Some("unidic-cwj".to_string()),
+      path: None,
+    })
+  }
+}
+
+/// Lightweight stub search service for integration tests
+///
+/// - Blank query: `invalid_input` error
+/// - Language other than `ja`: `invalid_input` error (unsupported)
+/// - Otherwise: Returns `limit` results whose text echoes the query
+struct StubSearchService;
+
+impl SearchApiService for StubSearchService {
+  fn search(&self, request: SearchRequest) -> ApiResult<SearchResponse> {
+    if request.query.trim().is_empty() {
+      return Err(ApiError::invalid_input("Query is empty"));
     }
 
-    if text_bytes > MAX_TEXT_LENGTH {
-      return Err(ApiError::text_too_long(text_bytes, MAX_TEXT_LENGTH));
+    let language = request.language.unwrap_or(Language::Ja);
+    if language != Language::Ja {
+      return Err(ApiError::invalid_input(format!(
+        "Unsupported language: {language:?}"
+      )));
     }
 
-    Ok(WakeruResponse {
-      tokens: Vec::new(),
-      elapsed_ms: 0,
-    })
+    let results = (0..request.limit)
+      .map(|i| SearchResult {
+        doc_id: format!("doc-{i}"),
+        source_id: "src-1".to_string(),
+        score: 1.0 / (i as f32 + 1.0),
+        text: request.query.clone(),
+        metadata: Default::default(),
+        match_kind: None,
+        matched_terms: Vec::new(),
+      })
+      .collect();
+
+    Ok(SearchResponse { results })
+  }
+}
+
+/// Stub analysis service whose tokenizer always fails (for readiness checks)
+struct FailingWakeruApiService;
+
+impl WakeruApiService for FailingWakeruApiService {
+  fn analyze(&self, _request: WakeruRequest) -> ApiResult<WakeruResponse> {
+    Err(ApiError::internal("Tokenizer is broken"))
+  }
+
+  fn query_tokens(&self, _request: QueryTokensRequest) -> ApiResult<QueryTokensResponse> {
+    Err(ApiError::internal("Tokenizer is broken"))
+  }
+}
+
+/// Stub search service whose index always fails (for readiness checks)
+struct FailingSearchService;
+
+impl SearchApiService for FailingSearchService {
+  fn search(&self, _request: SearchRequest) -> ApiResult<SearchResponse> {
+    Err(ApiError::internal("Index is broken"))
   }
 }
 
 /// Build Router for testing
 fn test_app() -> Router {
+  test_router(test_state())
+}
+
+/// Build Router for testing with `POST /search` enabled
+fn test_app_with_search() -> Router {
+  test_router(test_state().with_search_service(Arc::new(StubSearchService)))
+}
+
+fn test_state() -> AppState {
   let config = Config {
     bind_addr: "127.0.0.1:0".to_string(),
     preset: Preset::UnidicCwj,
+    dictionary_path: None,
+    dictionary_cache_dir: None,
+    reject_blank_text: true,
+    failed_input_preview_chars: None,
+    max_batch_size: 3,
+    search_config: None,
+    cors_origins: Vec::new(),
+    requests_per_second: None,
+    log_format: LogFormat::Text,
   };
 
   let service: Arc<dyn WakeruApiService> = Arc::new(StubWakeruApiService);
-  let state = AppState::new(config, service);
+  AppState::new(config, service)
+}
 
+fn test_router(state: AppState) -> Router {
   Router::new()
     .route("/health", get(health_check))
+    .route("/health/ready", get(health_ready))
+    .route("/config", get(get_config))
+    .route("/metrics", get(get_metrics))
     .route("/wakeru", post(post_wakeru))
+    .route("/wakeru/batch", post(post_wakeru_batch))
+    .route("/wakeru/analyze-ndjson", post(post_analyze_ndjson))
+    .route("/wakeru/query-tokens", post(post_query_tokens))
+    .route("/tokenize", post(post_tokenize))
+    .route("/search", post(post_search))
+    .route("/search/stream", post(post_search_stream))
     .with_state(state)
 }
 
@@ -79,7 +224,78 @@ async fn health_check_returns_ok() {
   assert_eq!(response.status(), StatusCode::OK);
 
   let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
-  assert_eq!(body_bytes.as_ref(), b"OK");
+  let json: serde_json::Value = serde_json::from_slice(&body_bytes).expect("valid json");
+  assert_eq!(json["status"], "ok");
+  assert_eq!(json["dictionary"]["preset"], "unidic-cwj");
+  assert!(json["dictionary"].get("path").is_none());
+}
+
+#[tokio::test]
+async fn health_ready_returns_ok_when_service_is_healthy() {
+  let app = test_app_with_search();
+
+  let response = app
+    .oneshot(Request::builder().method("GET").uri("/health/ready").body(Body::empty()).unwrap())
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::OK);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let json: serde_json::Value = serde_json::from_slice(&body_bytes).expect("valid json");
+  assert_eq!(json["status"], "ok");
+}
+
+#[tokio::test]
+async fn get_config_returns_effective_config() {
+  let mut state = test_state();
+  state.config.dictionary_path = Some(PathBuf::from("/srv/wakeru/system.dic"));
+  state.config.dictionary_cache_dir = Some(PathBuf::from("/var/cache/wakeru"));
+  state.config.search_config = Some(PathBuf::from("/etc/wakeru/search.toml"));
+  state.config.cors_origins = vec!["https://app.example.com".to_string()];
+  state.config.requests_per_second = Some(5);
+  let app = test_router(state);
+
+  let response = app
+    .oneshot(Request::builder().method("GET").uri("/config").body(Body::empty()).unwrap())
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::OK);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let json: serde_json::Value = serde_json::from_slice(&body_bytes).expect("valid json");
+
+  assert_eq!(json["bind_addr"], "127.0.0.1:0");
+  assert_eq!(json["preset"], "unidic-cwj");
+  assert_eq!(json["local_dictionary"], true);
+  assert_eq!(json["search_enabled"], true);
+  assert_eq!(json["log_format"], "text");
+  assert_eq!(json["max_text_length"], MAX_TEXT_LENGTH);
+
+  // Only allowlisted fields are exposed
+  let mut keys: Vec<&str> = json.as_object().unwrap().keys().map(String::as_str).collect();
+  keys.sort_unstable();
+  assert_eq!(
+    keys,
+    vec![
+      "bind_addr",
+      "failed_input_preview_chars",
+      "local_dictionary",
+      "log_format",
+      "max_batch_size",
+      "max_text_length",
+      "preset",
+      "reject_blank_text",
+      "search_enabled"
+    ]
+  );
+
+  // No filesystem path or origin leaks into the body
+  let body = String::from_utf8(body_bytes.to_vec()).expect("utf-8 body");
+  for secret in ["/srv/", "/var/cache/", "/etc/", "app.example.com"] {
+    assert!(!body.contains(secret), "{secret} leaked: {body}");
+  }
 }
 
 #[tokio::test]
@@ -112,17 +328,30 @@ async fn post_wakeru_success_returns_200() {
   assert!(json.get("elapsed_ms").is_some());
 }
 
-// ============================================================================
-// Abnormal Case Tests (Service Error)
-// ============================================================================
-
 #[tokio::test]
-async fn post_wakeru_empty_text_returns_400() {
+async fn get_metrics_counts_analysis_requests() {
   let app = test_app();
 
-  let payload = serde_json::json!({ "text": "" });
+  let scrape = |app: Router| async move {
+    let response = app
+      .oneshot(Request::builder().method("GET").uri("/metrics").body(Body::empty()).unwrap())
+      .await
+      .expect("request should succeed");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+      response.headers().get("content-type").unwrap().to_str().unwrap().starts_with("text/plain")
+    );
+    let body_bytes =
+      axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+    String::from_utf8(body_bytes.to_vec()).expect("utf-8 body")
+  };
+
+  let before = scrape(app.clone()).await;
+  assert!(before.contains("wakeru_analyze_requests_total 0\n"));
 
+  let payload = serde_json::json!({ "text": "Test" });
   let response = app
+    .clone()
     .oneshot(
       Request::builder()
         .method("POST")
@@ -133,32 +362,87 @@ async fn post_wakeru_empty_text_returns_400() {
     )
     .await
     .expect("request should succeed");
+  assert_eq!(response.status(), StatusCode::OK);
 
-  assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+  let after = scrape(app).await;
+  assert!(after.contains("wakeru_analyze_requests_total 1\n"));
+  assert!(after.contains("wakeru_analyzed_tokens_total 1\n"));
+  assert!(after.contains("wakeru_analyze_elapsed_ms_count 1\n"));
+}
+
+#[tokio::test]
+async fn post_query_tokens_returns_tokens() {
+  let app = test_app();
+
+  let payload = serde_json::json!({ "text": "Tokyo Tower", "lemmatize": true });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/wakeru/query-tokens")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::OK);
 
   let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let json: serde_json::Value =
+    serde_json::from_slice(&body_bytes).expect("body should be valid json");
+
+  assert_eq!(json["tokens"], serde_json::json!(["tokyo", "tower"]));
+}
+
+#[tokio::test]
+async fn post_tokenize_returns_tokens_and_wakachi() {
+  let app = test_app();
+
+  let payload = serde_json::json!({ "text": "Tokyo  Tower\tview" });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/tokenize")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::OK);
 
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
   let json: serde_json::Value =
     serde_json::from_slice(&body_bytes).expect("body should be valid json");
 
-  assert_eq!(json["error"]["code"], "invalid_input");
+  // Surface forms (not lemmatized), joined by single spaces
+  assert_eq!(
+    json["tokens"],
+    serde_json::json!(["Tokyo", "Tower", "view"])
+  );
+  let tokens: Vec<&str> =
+    json["tokens"].as_array().unwrap().iter().map(|t| t.as_str().unwrap()).collect();
+  assert_eq!(json["wakachi"], tokens.join(" "));
+  assert_eq!(json["wakachi"], "Tokyo Tower view");
 }
 
 #[tokio::test]
-async fn post_wakeru_too_long_text_returns_413() {
+async fn post_wakeru_batch_preserves_input_order() {
   let app = test_app();
 
-  // Send text of MAX_TEXT_LENGTH + 1 bytes
-  // Note: Axum's default request size limit (2MB) applies first,
-  // so 413 PAYLOAD_TOO_LARGE returns
-  let long_text = "a".repeat(MAX_TEXT_LENGTH + 1);
-  let payload = serde_json::json!({ "text": long_text });
+  let payload = serde_json::json!({ "texts": ["Tokyo", "Osaka", "Kyoto"] });
 
   let response = app
     .oneshot(
       Request::builder()
         .method("POST")
-        .uri("/wakeru")
+        .uri("/wakeru/batch")
         .header("content-type", "application/json")
         .body(Body::from(payload.to_string()))
         .unwrap(),
@@ -166,9 +450,690 @@ async fn post_wakeru_too_long_text_returns_413() {
     .await
     .expect("request should succeed");
 
-  // Confirm 413 returns due to Axum's request size limit
-  // text_too_long error in service layer is covered by unit test
-  assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+  assert_eq!(response.status(), StatusCode::OK);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let json: serde_json::Value =
+    serde_json::from_slice(&body_bytes).expect("body should be valid json");
+
+  let surfaces: Vec<&str> = json["results"]
+    .as_array()
+    .expect("results should be an array")
+    .iter()
+    .map(|result| result["tokens"][0]["surface"].as_str().unwrap())
+    .collect();
+  assert_eq!(surfaces, ["Tokyo", "Osaka", "Kyoto"]);
+}
+
+#[tokio::test]
+async fn post_wakeru_batch_empty_texts_returns_empty_results() {
+  let app = test_app();
+
+  let payload = serde_json::json!({ "texts": [] });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/wakeru/batch")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::OK);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let json: serde_json::Value =
+    serde_json::from_slice(&body_bytes).expect("body should be valid json");
+  assert_eq!(json["results"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn post_search_returns_results_array() {
+  let app = test_app_with_search();
+
+  let payload = serde_json::json!({ "query": "東京", "language": "ja", "limit": 2 });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/search")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::OK);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let json: serde_json::Value =
+    serde_json::from_slice(&body_bytes).expect("body should be valid json");
+
+  let results = json.as_array().expect("response should be an array");
+  assert_eq!(results.len(), 2);
+  assert_eq!(results[0]["doc_id"], "doc-0");
+  assert_eq!(results[0]["text"], "東京");
+  assert!(results[0]["score"].is_number());
+}
+
+#[tokio::test]
+async fn post_search_uses_default_limit() {
+  let app = test_app_with_search();
+
+  let payload = serde_json::json!({ "query": "東京" });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/search")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::OK);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let json: serde_json::Value =
+    serde_json::from_slice(&body_bytes).expect("body should be valid json");
+  assert_eq!(json.as_array().unwrap().len(), 10);
+}
+
+#[tokio::test]
+async fn post_search_stream_returns_one_result_per_line() {
+  let app = test_app_with_search();
+
+  let payload = serde_json::json!({ "query": "東京", "language": "ja", "limit": 3 });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/search/stream")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::OK);
+  assert_eq!(
+    response.headers().get("content-type").unwrap(),
+    "application/x-ndjson"
+  );
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let body = String::from_utf8(body_bytes.to_vec()).expect("body should be utf-8");
+  assert!(body.ends_with('\n'));
+
+  let results: Vec<SearchResult> = body
+    .lines()
+    .map(|line| serde_json::from_str(line).expect("each line should be a SearchResult"))
+    .collect();
+  assert_eq!(results.len(), 3);
+  let doc_ids: Vec<_> = results.iter().map(|r| r.doc_id.as_str()).collect();
+  assert_eq!(doc_ids, ["doc-0", "doc-1", "doc-2"]);
+  assert_eq!(results[0].text, "東京");
+}
+
+#[tokio::test]
+async fn post_search_stream_errors_before_streaming() {
+  let payload = serde_json::json!({ "query": "   " });
+  let request = || {
+    Request::builder()
+      .method("POST")
+      .uri("/search/stream")
+      .header("content-type", "application/json")
+      .body(Body::from(payload.to_string()))
+      .unwrap()
+  };
+
+  // Blank query -> JSON error, not an NDJSON body
+  let response = test_app_with_search().oneshot(request()).await.expect("request should succeed");
+  assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+  // Search disabled -> 503
+  let response = test_app().oneshot(request()).await.expect("request should succeed");
+  assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+}
+
+#[tokio::test]
+async fn post_analyze_ndjson_streams_per_line_results() {
+  let app = test_app();
+
+  let body = [
+    r#"{"id": "a", "text": "Tokyo"}"#,
+    r#"{"id": "b", "text": }"#,
+    "",
+    r#"{"id": "c", "text": ""}"#,
+    r#"{"id": "d"}"#,
+    r#"{"id": "e", "text": "Osaka"}"#,
+  ]
+  .join("\n");
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/wakeru/analyze-ndjson")
+        .header("content-type", "application/x-ndjson")
+        .body(Body::from(body))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::OK);
+  assert_eq!(
+    response.headers().get("content-type").unwrap(),
+    "application/x-ndjson"
+  );
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let lines: Vec<serde_json::Value> = std::str::from_utf8(&body_bytes)
+    .expect("utf-8 body")
+    .lines()
+    .map(|line| serde_json::from_str(line).expect("each line should be valid json"))
+    .collect();
+
+  // Blank line is skipped, every other line yields exactly one result line
+  assert_eq!(lines.len(), 5);
+
+  // Valid lines
+  assert_eq!(lines[0]["id"], "a");
+  assert!(lines[0]["tokens"].is_array());
+  assert!(lines[0]["elapsed_ms"].is_number());
+  assert_eq!(lines[4]["id"], "e");
+  assert!(lines[4]["tokens"].is_array());
+
+  // Unparseable JSON: error line without id
+  assert!(lines[1]["id"].is_null());
+  assert_eq!(lines[1]["error"]["code"], "invalid_input");
+
+  // Service error: error line with id
+  assert_eq!(lines[2]["id"], "c");
+  assert_eq!(lines[2]["error"]["code"], "invalid_input");
+
+  // Missing text field: error line with id
+  assert_eq!(lines[3]["id"], "d");
+  assert_eq!(lines[3]["error"]["code"], "invalid_input");
+}
+
+#[tokio::test]
+async fn post_analyze_ndjson_rejects_oversized_line_in_single_chunk() {
+  let app = test_app();
+
+  // The oversized line and its newline arrive in the same chunk
+  let long_line = format!(
+    r#"{{"id": "long", "text": "{}"}}"#,
+    "a".repeat(MAX_TEXT_LENGTH * 2)
+  );
+  let body = [long_line.as_str(), r#"{"id": "next", "text": "Tokyo"}"#].join("\n");
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/wakeru/analyze-ndjson")
+        .header("content-type", "application/x-ndjson")
+        .body(Body::from(body))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::OK);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let lines: Vec<serde_json::Value> = std::str::from_utf8(&body_bytes)
+    .expect("utf-8 body")
+    .lines()
+    .map(|line| serde_json::from_str(line).expect("each line should be valid json"))
+    .collect();
+
+  assert_eq!(lines.len(), 2);
+  assert!(lines[0]["id"].is_null());
+  assert_eq!(lines[0]["error"]["code"], "invalid_input");
+  assert!(
+    lines[0]["error"]["message"].as_str().unwrap().contains("exceeds"),
+    "unexpected error: {}",
+    lines[0]
+  );
+  assert_eq!(lines[1]["id"], "next");
+  assert!(lines[1]["tokens"].is_array());
+}
+
+// ============================================================================
+// Abnormal Case Tests (Service Error)
+// ============================================================================
+
+#[tokio::test]
+async fn post_wakeru_empty_text_returns_400() {
+  let app = test_app();
+
+  let payload = serde_json::json!({ "text": "" });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/wakeru")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+
+  let json: serde_json::Value =
+    serde_json::from_slice(&body_bytes).expect("body should be valid json");
+
+  assert_eq!(json["error"]["code"], "invalid_input");
+}
+
+#[tokio::test]
+async fn post_wakeru_blank_text_returns_400() {
+  let app = test_app();
+
+  let payload = serde_json::json!({ "text": "   \n\t  " });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/wakeru")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+
+  let json: serde_json::Value =
+    serde_json::from_slice(&body_bytes).expect("body should be valid json");
+
+  assert_eq!(json["error"]["code"], "invalid_input");
+  assert!(json["error"]["message"].as_str().unwrap().contains("blank"));
+}
+
+#[tokio::test]
+async fn post_wakeru_too_long_text_returns_400() {
+  // Production router (with its body limit) instead of the test router
+  let app = create_router(test_state());
+
+  // Send text of MAX_TEXT_LENGTH + 1 bytes (within MAX_BODY_SIZE)
+  let long_text = "a".repeat(MAX_TEXT_LENGTH + 1);
+  let payload = serde_json::json!({ "text": long_text });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/wakeru")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  // The body limit lets the request through, so the service rejects it
+  assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let json: serde_json::Value =
+    serde_json::from_slice(&body_bytes).expect("body should be valid json");
+  assert_eq!(json["error"]["code"], "text_too_long");
+}
+
+#[tokio::test]
+async fn cors_preflight_allows_configured_origin() {
+  let mut state = test_state();
+  state.config.cors_origins = vec!["https://app.example".to_string()];
+  let app = create_router(state);
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("OPTIONS")
+        .uri("/wakeru")
+        .header("origin", "https://app.example")
+        .header("access-control-request-method", "POST")
+        .header("access-control-request-headers", "content-type")
+        .body(Body::empty())
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::OK);
+  assert_eq!(
+    response.headers()["access-control-allow-origin"],
+    "https://app.example"
+  );
+}
+
+#[tokio::test]
+async fn cors_is_disabled_without_configured_origins() {
+  let app = create_router(test_state());
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("GET")
+        .uri("/health")
+        .header("origin", "https://app.example")
+        .body(Body::empty())
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::OK);
+  assert!(!response.headers().contains_key("access-control-allow-origin"));
+}
+
+/// Sends `GET uri` from `client` through `app`, returning the status
+async fn get_from(app: &Router, uri: &str, client: SocketAddr) -> StatusCode {
+  app
+    .clone()
+    .layer(MockConnectInfo(client))
+    .oneshot(Request::builder().method("GET").uri(uri).body(Body::empty()).unwrap())
+    .await
+    .expect("request should succeed")
+    .status()
+}
+
+#[tokio::test]
+async fn rapid_requests_are_rate_limited() {
+  let mut state = test_state();
+  state.config.requests_per_second = Some(5);
+  let app = create_router(state);
+  let client: SocketAddr = "192.0.2.1:40000".parse().unwrap();
+
+  let mut statuses = Vec::new();
+  for _ in 0..20 {
+    statuses.push(get_from(&app, "/config", client).await);
+  }
+  assert_eq!(statuses[0], StatusCode::OK);
+  assert!(statuses.contains(&StatusCode::TOO_MANY_REQUESTS));
+
+  // Other clients are not affected
+  let other: SocketAddr = "192.0.2.2:40000".parse().unwrap();
+  assert_eq!(get_from(&app, "/config", other).await, StatusCode::OK);
+
+  // Health probes are never limited
+  assert_eq!(get_from(&app, "/health", client).await, StatusCode::OK);
+  assert_eq!(
+    get_from(&app, "/health/ready", client).await,
+    StatusCode::OK
+  );
+
+  let response = app
+    .layer(MockConnectInfo(client))
+    .oneshot(Request::builder().method("GET").uri("/config").body(Body::empty()).unwrap())
+    .await
+    .expect("request should succeed");
+  assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let json: serde_json::Value =
+    serde_json::from_slice(&body_bytes).expect("body should be valid json");
+  assert_eq!(json["error"]["code"], "rate_limited");
+}
+
+#[tokio::test]
+async fn slow_requests_are_not_rate_limited() {
+  let mut state = test_state();
+  state.config.requests_per_second = Some(10);
+  let app = create_router(state);
+  let client: SocketAddr = "192.0.2.1:40000".parse().unwrap();
+
+  // 5 requests per second stays under the limit of 10
+  for _ in 0..5 {
+    assert_eq!(get_from(&app, "/config", client).await, StatusCode::OK);
+    tokio::time::sleep(Duration::from_millis(200)).await;
+  }
+}
+
+#[tokio::test]
+async fn post_wakeru_body_over_max_body_size_returns_413() {
+  let app = create_router(test_state());
+
+  let long_text = "a".repeat(MAX_BODY_SIZE);
+  let payload = serde_json::json!({ "text": long_text });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/wakeru")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn post_query_tokens_empty_text_returns_400() {
+  let app = test_app();
+
+  let payload = serde_json::json!({ "text": "" });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/wakeru/query-tokens")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn post_tokenize_empty_text_returns_400() {
+  let app = test_app();
+
+  let payload = serde_json::json!({ "text": "" });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/tokenize")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn post_wakeru_batch_over_max_batch_size_returns_400() {
+  let app = test_app();
+
+  // test_app() allows at most 3 texts
+  let payload = serde_json::json!({ "texts": ["a", "b", "c", "d"] });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/wakeru/batch")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let json: serde_json::Value =
+    serde_json::from_slice(&body_bytes).expect("body should be valid json");
+  assert_eq!(json["error"]["code"], "invalid_input");
+  assert!(json["error"]["message"].as_str().unwrap().contains("max: 3"));
+}
+
+#[tokio::test]
+async fn post_wakeru_batch_invalid_text_fails_whole_batch() {
+  let app = test_app();
+
+  let payload = serde_json::json!({ "texts": ["Tokyo", ""] });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/wakeru/batch")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn post_search_without_search_service_returns_503() {
+  let app = test_app();
+
+  let payload = serde_json::json!({ "query": "東京" });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/search")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let json: serde_json::Value =
+    serde_json::from_slice(&body_bytes).expect("body should be valid json");
+  assert_eq!(json["error"]["code"], "service_unavailable");
+}
+
+#[tokio::test]
+async fn health_ready_failing_tokenizer_returns_503() {
+  let mut state = test_state();
+  state.service = Arc::new(FailingWakeruApiService);
+  let app = test_router(state);
+
+  let response = app
+    .oneshot(Request::builder().method("GET").uri("/health/ready").body(Body::empty()).unwrap())
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let json: serde_json::Value =
+    serde_json::from_slice(&body_bytes).expect("body should be valid json");
+  assert_eq!(json["error"]["code"], "service_unavailable");
+}
+
+#[tokio::test]
+async fn health_ready_failing_search_returns_503() {
+  let app = test_router(test_state().with_search_service(Arc::new(FailingSearchService)));
+
+  let response = app
+    .oneshot(Request::builder().method("GET").uri("/health/ready").body(Body::empty()).unwrap())
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let json: serde_json::Value =
+    serde_json::from_slice(&body_bytes).expect("body should be valid json");
+  assert_eq!(json["error"]["code"], "service_unavailable");
+}
+
+#[tokio::test]
+async fn post_search_blank_query_returns_400() {
+  let app = test_app_with_search();
+
+  let payload = serde_json::json!({ "query": "  " });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/search")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn post_search_unsupported_language_returns_400() {
+  let app = test_app_with_search();
+
+  let payload = serde_json::json!({ "query": "Tokyo", "language": "en" });
+
+  let response = app
+    .oneshot(
+      Request::builder()
+        .method("POST")
+        .uri("/search")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap(),
+    )
+    .await
+    .expect("request should succeed");
+
+  assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+  let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.expect("read body");
+  let json: serde_json::Value =
+    serde_json::from_slice(&body_bytes).expect("body should be valid json");
+  assert_eq!(json["error"]["code"], "invalid_input");
 }
 
 // ============================================================================
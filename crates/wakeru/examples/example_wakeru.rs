@@ -19,7 +19,7 @@ type AppResult<T> = Result<T, Box<dyn std::error::Error>>;
 ///
 /// Depending on `language`:
 /// - Language::Ja: Vibrato + Japanese index
-/// - Language::En: SimpleTokenizer + LowerCaser set on IndexManager side
+/// - Language::En / Language::Ko: Analyzers set on IndexManager side
 fn init_index_manager(index_dir: &str, language: Language) -> AppResult<IndexManager> {
   match language {
     Language::Ja => {
@@ -45,6 +45,11 @@ fn init_index_manager(index_dir: &str, language: Language) -> AppResult<IndexMan
       let index_manager = IndexManager::open_or_create(index_dir, Language::En, None)?;
       Ok(index_manager)
     }
+    Language::Ko => {
+      // Korean index: no dictionary needed either
+      let index_manager = IndexManager::open_or_create(index_dir, Language::Ko, None)?;
+      Ok(index_manager)
+    }
   }
 }
 
@@ -62,6 +67,7 @@ where
   }
 
   let report = index_manager.add_documents(docs)?;
+  index_manager.commit()?;
   println!(
     "Addition result: total={}, added={}, skipped={}",
     report.total, report.added, report.skipped_duplicates
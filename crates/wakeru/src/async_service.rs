@@ -0,0 +1,226 @@
+//! AsyncWakeruService: Async facade over WakeruService.
+//!
+//! `WakeruService` methods are synchronous and CPU / IO bound (tokenization, Tantivy
+//! segment IO), so calling them directly from an async runtime blocks its worker
+//! threads. Each method here runs the sync call on Tokio's blocking thread pool with
+//! `tokio::task::spawn_blocking` and awaits the result.
+//!
+//! Must be used within a Tokio runtime.
+
+use std::sync::Arc;
+
+use crate::config::Language;
+use crate::errors::{WakeruError, WakeruResult};
+use crate::indexer::AddDocumentsReport;
+use crate::models::{Document, SearchResult};
+use crate::service::WakeruService;
+
+/// Async facade over [`WakeruService`]
+///
+/// Cheap to clone: all clones share the same `WakeruService`.
+///
+/// # Examples
+/// ```ignore
+/// let service = AsyncWakeruService::new(Arc::new(WakeruService::init(&config)?));
+/// service.index_documents(documents).await?;
+/// let results = service.search("東京タワー", 10).await?;
+/// ```
+#[derive(Clone)]
+pub struct AsyncWakeruService {
+  inner: Arc<WakeruService>,
+}
+
+impl AsyncWakeruService {
+  /// Wraps a shared `WakeruService`
+  #[must_use]
+  pub fn new(inner: Arc<WakeruService>) -> Self {
+    Self { inner }
+  }
+
+  /// Underlying sync service (for operations without an async wrapper)
+  #[must_use]
+  pub fn inner(&self) -> &Arc<WakeruService> {
+    &self.inner
+  }
+
+  /// Runs `f` with the service on the blocking thread pool
+  ///
+  /// # Errors
+  /// - Error returned by `f`
+  /// - `WakeruError::BlockingTask` if the task panicked or was cancelled
+  async fn run_blocking<T, F>(&self, f: F) -> WakeruResult<T>
+  where
+    F: FnOnce(&WakeruService) -> WakeruResult<T> + Send + 'static,
+    T: Send + 'static,
+  {
+    let service = Arc::clone(&self.inner);
+    tokio::task::spawn_blocking(move || f(&service)).await.map_err(|e| {
+      WakeruError::BlockingTask {
+        reason: e.to_string(),
+      }
+    })?
+  }
+
+  /// Async version of [`WakeruService::index_documents_with_language`]
+  ///
+  /// # Errors
+  /// Same as the sync version, plus `WakeruError::BlockingTask`
+  pub async fn index_documents_with_language(
+    &self,
+    language: Language,
+    documents: Vec<Document>,
+  ) -> WakeruResult<AddDocumentsReport> {
+    self
+      .run_blocking(move |service| service.index_documents_with_language(language, &documents))
+      .await
+  }
+
+  /// Async version of [`WakeruService::index_documents`]
+  ///
+  /// # Errors
+  /// Same as the sync version, plus `WakeruError::BlockingTask`
+  pub async fn index_documents(
+    &self,
+    documents: Vec<Document>,
+  ) -> WakeruResult<AddDocumentsReport> {
+    self.run_blocking(move |service| service.index_documents(&documents)).await
+  }
+
+  /// Async version of [`WakeruService::index_documents_auto`]
+  ///
+  /// # Errors
+  /// Same as the sync version, plus `WakeruError::BlockingTask`
+  pub async fn index_documents_auto(
+    &self,
+    documents: Vec<Document>,
+  ) -> WakeruResult<AddDocumentsReport> {
+    self.run_blocking(move |service| service.index_documents_auto(&documents)).await
+  }
+
+  /// Async version of [`WakeruService::search_with_language`]
+  ///
+  /// # Errors
+  /// Same as the sync version, plus `WakeruError::BlockingTask`
+  pub async fn search_with_language(
+    &self,
+    language: Language,
+    query: &str,
+    limit: usize,
+  ) -> WakeruResult<Vec<SearchResult>> {
+    let query = query.to_string();
+    self.run_blocking(move |service| service.search_with_language(language, &query, limit)).await
+  }
+
+  /// Async version of [`WakeruService::search`]
+  ///
+  /// # Errors
+  /// Same as the sync version, plus `WakeruError::BlockingTask`
+  pub async fn search(&self, query: &str, limit: usize) -> WakeruResult<Vec<SearchResult>> {
+    let query = query.to_string();
+    self.run_blocking(move |service| service.search(&query, limit)).await
+  }
+
+  /// Async version of [`WakeruService::search_all_languages`]
+  ///
+  /// # Errors
+  /// Same as the sync version, plus `WakeruError::BlockingTask`
+  pub async fn search_all_languages(
+    &self,
+    query: &str,
+    limit: usize,
+  ) -> WakeruResult<Vec<SearchResult>> {
+    let query = query.to_string();
+    self.run_blocking(move |service| service.search_all_languages(&query, limit)).await
+  }
+
+  /// Async version of [`WakeruService::smart_search`]
+  ///
+  /// # Errors
+  /// Same as the sync version, plus `WakeruError::BlockingTask`
+  pub async fn smart_search(&self, query: &str, limit: usize) -> WakeruResult<Vec<SearchResult>> {
+    let query = query.to_string();
+    self.run_blocking(move |service| service.smart_search(&query, limit)).await
+  }
+
+  /// Async version of [`WakeruService::delete_source`]
+  ///
+  /// # Errors
+  /// Same as the sync version, plus `WakeruError::BlockingTask`
+  pub async fn delete_source(&self, language: Language, source_id: &str) -> WakeruResult<()> {
+    let source_id = source_id.to_string();
+    self.run_blocking(move |service| service.delete_source(language, &source_id)).await
+  }
+
+  /// Async version of [`WakeruService::reload_all`]
+  ///
+  /// # Errors
+  /// Same as the sync version, plus `WakeruError::BlockingTask`
+  pub async fn reload_all(&self) -> WakeruResult<()> {
+    self.run_blocking(|service| service.reload_all()).await
+  }
+}
+
+impl From<WakeruService> for AsyncWakeruService {
+  fn from(service: WakeruService) -> Self {
+    Self::new(Arc::new(service))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::config::WakeruConfig;
+
+  /// Create English-only AsyncWakeruService (no dictionary needed)
+  fn create_english_service() -> (tempfile::TempDir, AsyncWakeruService) {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let base = temp_dir.path().display();
+    let config: WakeruConfig = toml::from_str(&format!(
+      r#"
+[dictionary]
+preset = "ipadic"
+cache_dir = "{base}/dict"
+
+[index]
+data_dir = "{base}/index"
+writer_memory_bytes = 50000000
+batch_commit_size = 1000
+languages = ["en"]
+default_language = "en"
+
+[search]
+default_limit = 10
+max_limit = 100
+
+[logging]
+level = "info"
+"#
+    ))
+    .expect("Failed to parse config");
+    let service = WakeruService::init(&config).expect("Failed to initialize WakeruService");
+    (temp_dir, AsyncWakeruService::from(service))
+  }
+
+  #[tokio::test]
+  async fn async_search_matches_sync_search() {
+    let (_temp_dir, service) = create_english_service();
+    let documents = vec![
+      Document::new("doc-1", "src-1", "Tokyo Tower is a famous landmark"),
+      Document::new("doc-2", "src-1", "Tokyo station is busy"),
+      Document::new("doc-3", "src-2", "Osaka castle"),
+    ];
+    let report = service.index_documents(documents).await.expect("Failed to index documents");
+    assert_eq!(report.added, 3);
+    service.reload_all().await.expect("Reload failed");
+
+    let async_results = service.search("tokyo tower", 10).await.expect("Async search failed");
+    let sync_results = service.inner().search("tokyo tower", 10).expect("Sync search failed");
+
+    assert!(!async_results.is_empty());
+    assert_eq!(async_results.len(), sync_results.len());
+    for (a, s) in async_results.iter().zip(&sync_results) {
+      assert_eq!(a.doc_id, s.doc_id);
+      assert_eq!(a.score, s.score);
+    }
+  }
+}
@@ -4,6 +4,9 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use serde::Deserialize;
+use tantivy::schema::IndexRecordOption;
+use tantivy::store::{Compressor, ZstdCompressor};
+use tantivy::tokenizer::Language as StemmerLanguage;
 use vibrato_rkyv::dictionary::PresetDictionaryKind;
 
 use crate::errors::ConfigError;
@@ -19,6 +22,8 @@ pub enum Language {
   Ja,
   /// English (Space separated: SimpleTokenizer + LowerCaser)
   En,
+  /// Korean (Space separated eojeol: SimpleTokenizer + LowerCaser, plus N-gram field)
+  Ko,
 }
 
 impl Language {
@@ -27,10 +32,12 @@ impl Language {
   /// # Examples
   /// - `Language::Ja` → `"ja"`
   /// - `Language::En` → `"en"`
+  /// - `Language::Ko` → `"ko"`
   pub fn code(&self) -> &'static str {
     match self {
       Language::Ja => "ja",
       Language::En => "en",
+      Language::Ko => "ko",
     }
   }
 
@@ -38,20 +45,58 @@ impl Language {
   ///
   /// - Japanese: `"lang_ja"` (VibratoTokenizer)
   /// - English: `"lang_en"` (SimpleTokenizer + LowerCaser)
+  /// - Korean: `"lang_ko"` (SimpleTokenizer + LowerCaser)
   pub fn text_tokenizer_name(&self) -> &'static str {
     match self {
       Language::Ja => "lang_ja",
       Language::En => "lang_en",
+      Language::Ko => "lang_ko",
     }
   }
 
-  /// Returns the N-gram tokenizer name (Japanese only).
+  /// Returns the tokenizer name of text fields under `mode`.
+  ///
+  /// Same as [`text_tokenizer_name`](Self::text_tokenizer_name), except that Japanese
+  /// uses `"cjk_bigram"` (CjkBigramTokenizer) under `TokenizerMode::CjkBigram`.
+  pub fn text_tokenizer_name_for(&self, mode: TokenizerMode) -> &'static str {
+    match (self, mode) {
+      (Language::Ja, TokenizerMode::CjkBigram) => "cjk_bigram",
+      _ => self.text_tokenizer_name(),
+    }
+  }
+
+  /// Returns the tokenizer name of the unstemmed exact-match field (English only).
+  ///
+  /// - Japanese: `None` (No exact field)
+  /// - English: `Some("en_exact")` (SimpleTokenizer + LowerCaser, no stemming)
+  /// - Korean: `None` (No stemming, so no exact field)
+  pub fn exact_tokenizer_name(&self) -> Option<&'static str> {
+    match self {
+      Language::Ja | Language::Ko => None,
+      Language::En => Some("en_exact"),
+    }
+  }
+
+  /// Returns the tokenizer name of the reading (yomi) field (Japanese only).
+  ///
+  /// - Japanese: `Some("ja_reading")` (VibratoTokenizer emitting katakana readings)
+  /// - English / Korean: `None` (No reading field)
+  pub fn reading_tokenizer_name(&self) -> Option<&'static str> {
+    match self {
+      Language::Ja => Some("ja_reading"),
+      Language::En | Language::Ko => None,
+    }
+  }
+
+  /// Returns the N-gram tokenizer name (CJK languages only).
   ///
   /// - Japanese: `Some("ja_ngram")` (For single character search)
+  /// - Korean: `Some("ko_ngram")` (For matching stems with attached particles)
   /// - English: `None` (No N-gram field)
   pub fn ngram_tokenizer_name(&self) -> Option<&'static str> {
     match self {
       Language::Ja => Some("ja_ngram"),
+      Language::Ko => Some("ko_ngram"),
       Language::En => None,
     }
   }
@@ -83,9 +128,15 @@ pub struct DictionaryConfig {
   pub preset: DictionaryPreset,
   /// Dictionary cache directory.
   ///
-  /// If omitted in TOML, it becomes `None`, and the actual default is assumed to be determined by `DictionaryManager`.
+  /// If omitted in TOML, it becomes `None`, and the OS default cache directory of `DictionaryManager` is used.
   #[serde(default)]
   pub cache_dir: Option<PathBuf>,
+  /// User dictionary CSV merged into the preset dictionary.
+  ///
+  /// Each line follows the system lexicon format:
+  /// `surface,left_id,right_id,cost,features...`
+  #[serde(default)]
+  pub user_dict: Option<PathBuf>,
 }
 
 /// Preset dictionary type.
@@ -141,6 +192,160 @@ pub struct IndexConfig {
   /// Default language (must be included in `languages`)
   #[serde(default = "default_language")]
   pub default_language: Language,
+  /// Minimum token length (in characters) for the English analyzer (1 = no filtering)
+  ///
+  /// Applied on both index and query sides. Changing this value requires
+  /// reindexing existing English documents, since tokens already stored are not re-filtered.
+  #[serde(default = "default_min_token_len")]
+  pub min_token_len: usize,
+  /// Minimum N-gram length (in characters) of the Japanese `text_ngram` field (default: 1)
+  ///
+  /// Changing the N-gram range requires reindexing existing Japanese documents.
+  #[serde(default = "default_min_gram")]
+  pub min_gram: usize,
+  /// Maximum N-gram length (in characters) of the Japanese `text_ngram` field (default: 1)
+  ///
+  /// Query tokens of `min_gram..=max_gram` characters are also searched in the N-gram field.
+  #[serde(default = "default_max_gram")]
+  pub max_gram: usize,
+  /// Compression of stored fields such as `text` (default: lz4)
+  ///
+  /// Recorded in the index's `meta.json` at creation time; opening an existing index with
+  /// another value fails. Changing it requires reindexing (removing and recreating the
+  /// index directory).
+  #[serde(default)]
+  pub store_compression: StoreCompression,
+  /// Add an unstemmed `text_exact` field to new English indexes (default: false)
+  ///
+  /// Exact (lowercased, unstemmed) matches are then boosted above stemmed-only matches.
+  /// Part of the schema, so it only applies to new indexes (reindex to change it).
+  #[serde(default)]
+  pub english_exact_field: bool,
+  /// Add a `text_reading` (katakana reading) field to new Japanese indexes (default: false)
+  ///
+  /// Kana queries ("とうきょう") then also match kanji text ("東京").
+  /// Part of the schema, so it only applies to new indexes (reindex to change it).
+  #[serde(default)]
+  pub japanese_reading_field: bool,
+  /// Tokenizer of new Japanese indexes (default: morphological)
+  ///
+  /// `cjk-bigram` needs no dictionary, so `[dictionary]` is not loaded at all.
+  /// Part of the schema, so it only applies to new indexes (reindex to change it).
+  #[serde(default)]
+  pub tokenizer_mode: TokenizerMode,
+  /// Stemmer language of the English (`en`) analyzer (default: english)
+  ///
+  /// Any Tantivy stemmer language, case-insensitive (e.g. "french", "german").
+  /// Changing this value requires reindexing existing English documents.
+  #[serde(default)]
+  pub stemmer_language: Option<String>,
+  /// Drop stop words ("the", "is", "of", ...) in the English analyzer (default: false)
+  ///
+  /// Uses the stop list of `stemmer_language` (English by default). Applied on both
+  /// index and query sides, so changing it requires reindexing existing English documents.
+  #[serde(default)]
+  pub english_stop_words: bool,
+  /// Index `source_id` lowercased in new indexes (default: false)
+  ///
+  /// Source lookups and deletions then ignore case ("Docs/Guide.md" == "docs/guide.md").
+  /// Part of the schema, so it only applies to new indexes (reindex to change it).
+  #[serde(default)]
+  pub case_insensitive_source_id: bool,
+  /// What the `text` field of new indexes records per term (default: with-positions)
+  ///
+  /// `freqs-only` drops term positions for a smaller index, at the cost of phrase search.
+  /// Part of the schema, so it only applies to new indexes (reindex to change it).
+  #[serde(default)]
+  pub text_record_option: TextRecordOption,
+  /// Store the byte offsets of each `text` token in new indexes (default: false)
+  ///
+  /// Offsets are kept in a stored `text_offsets` field for client-side highlighting
+  /// (see `SearchEngine::token_offsets`), which makes the index larger.
+  /// Part of the schema, so it only applies to new indexes (reindex to change it).
+  #[serde(default)]
+  pub with_offsets: bool,
+  /// Keep tags in the `metadata` JSON besides the dedicated `tags` field (default: true)
+  ///
+  /// `false` stores tags only in the `tags` field (smaller doc store); they are merged
+  /// back into `metadata` when documents are read. Applies to newly added documents.
+  #[serde(default = "default_tags_in_metadata")]
+  pub tags_in_metadata: bool,
+  /// How per-language index directories are opened (default: create-if-missing)
+  ///
+  /// Use `open-existing` in production so that a mistyped `data_dir` fails at startup
+  /// instead of silently creating an empty index, and `read-only` for search-only
+  /// services sharing `data_dir` with an indexing service.
+  #[serde(default)]
+  pub open_mode: OpenMode,
+}
+
+/// How an index directory is opened.
+///
+/// - `create-if-missing`: Open the existing index, or create a new one (default)
+/// - `open-existing`: Open the existing index; fail if there is none
+/// - `create-new`: Create a new index; fail if one already exists
+/// - `read-only`: Open the existing index for searching only; fail if there is none.
+///   The index writer lock is never taken, so query-only replicas can run next to
+///   the indexing process on the same `data_dir`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OpenMode {
+  /// Open or create
+  #[default]
+  CreateIfMissing,
+  /// Open only
+  OpenExisting,
+  /// Create only
+  CreateNew,
+  /// Open only, without a writer
+  ReadOnly,
+}
+
+/// Tokenizer of the Japanese `text` field.
+///
+/// - `morphological`: VibratoTokenizer with the `[dictionary]` preset (default)
+/// - `cjk-bigram`: Overlapping 2-grams of CJK runs; no dictionary download, but
+///   larger index and less precise ranking than morphological analysis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TokenizerMode {
+  /// Morphological analysis with a dictionary
+  #[default]
+  Morphological,
+  /// Dictionary-free CJK bigrams
+  CjkBigram,
+}
+
+/// Postings recorded for the `text` field.
+///
+/// - `with-positions`: Term frequencies and positions; supports phrase search (default)
+/// - `freqs-only`: Term frequencies only; smaller index, but phrase search is unavailable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TextRecordOption {
+  /// Frequencies and positions
+  #[default]
+  WithPositions,
+  /// Frequencies without positions
+  FreqsOnly,
+}
+
+/// Compression algorithm for stored fields (Tantivy doc store).
+///
+/// Stored `text` usually dominates index size. Trade-off between CPU and size:
+/// - `none`: No CPU cost on write/read, largest index
+/// - `lz4`: Very fast, moderate compression (default)
+/// - `zstd`: Slower to write and read, smallest index
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StoreCompression {
+  /// No compression
+  None,
+  /// LZ4 block compression
+  #[default]
+  Lz4,
+  /// Zstandard compression (default level)
+  Zstd,
 }
 
 /// Default language list (Japanese only)
@@ -148,11 +353,31 @@ fn default_languages() -> Vec<Language> {
   vec![Language::Ja]
 }
 
+/// Tags are kept in `metadata` by default (backward compatibility)
+fn default_tags_in_metadata() -> bool {
+  true
+}
+
 /// Default language (Japanese)
 fn default_language() -> Language {
   Language::Ja
 }
 
+/// Default minimum token length (no filtering)
+fn default_min_token_len() -> usize {
+  1
+}
+
+/// Default minimum N-gram length (1-char N-gram)
+fn default_min_gram() -> usize {
+  1
+}
+
+/// Default maximum N-gram length (1-char N-gram)
+fn default_max_gram() -> usize {
+  1
+}
+
 /// [search] section configuration.
 #[derive(Debug, Clone, Deserialize)]
 pub struct SearchConfig {
@@ -160,6 +385,76 @@ pub struct SearchConfig {
   pub default_limit: usize,
   /// Maximum search result limit
   pub max_limit: usize,
+  /// Optional penalty for redundant chunks from the same source (disabled if omitted)
+  #[serde(default)]
+  pub overlap_penalty: Option<OverlapPenaltyConfig>,
+  /// Optional BM25 parameters of the `text` field (see `SearchEngine::set_bm25_params`)
+  ///
+  /// If omitted, BM25-scored searches use Tantivy's `k1 = 1.2`, `b = 0.75`, and OR searches
+  /// (`search_tokens_or` and the searches built on it) match `text` with a constant score.
+  #[serde(default)]
+  pub bm25: Option<Bm25Config>,
+}
+
+/// [search.overlap_penalty] section configuration.
+///
+/// Heuristic post-retrieval penalty: a result whose text largely overlaps with a
+/// higher-ranked result from the same `source_id` gets its score multiplied by `factor`.
+/// Diversifies the top-k when overlapping chunks crowd the results.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct OverlapPenaltyConfig {
+  /// Multiplicative penalty applied to redundant chunks (0.0 < factor <= 1.0)
+  pub factor: f32,
+  /// Minimum text overlap ratio to consider two chunks redundant (0.0 - 1.0)
+  #[serde(default = "default_min_overlap")]
+  pub min_overlap: f32,
+}
+
+/// [search.bm25] section configuration.
+///
+/// - `k1`: Term frequency saturation (`k1 >= 0.0`). Higher values let repeated terms
+///   keep raising the score; `0.0` ignores term frequency.
+/// - `b`: Length normalization (`0.0 <= b <= 1.0`). `1.0` fully normalizes by document
+///   length (favoring short documents); `0.0` ignores document length.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Bm25Config {
+  /// Term frequency saturation (default: 1.2)
+  #[serde(default = "default_bm25_k1")]
+  pub k1: f32,
+  /// Length normalization (default: 0.75)
+  #[serde(default = "default_bm25_b")]
+  pub b: f32,
+}
+
+impl Bm25Config {
+  /// Whether `k1` and `b` are within their valid ranges
+  pub fn is_valid(&self) -> bool {
+    self.k1 >= 0.0 && self.k1.is_finite() && (0.0..=1.0).contains(&self.b)
+  }
+}
+
+impl Default for Bm25Config {
+  fn default() -> Self {
+    Self {
+      k1: default_bm25_k1(),
+      b: default_bm25_b(),
+    }
+  }
+}
+
+/// Default BM25 k1 (same as Tantivy)
+fn default_bm25_k1() -> f32 {
+  1.2
+}
+
+/// Default BM25 b (same as Tantivy)
+fn default_bm25_b() -> f32 {
+  0.75
+}
+
+/// Default minimum overlap ratio (half of the shorter chunk)
+fn default_min_overlap() -> f32 {
+  0.5
 }
 
 /// [logging] section configuration.
@@ -192,6 +487,85 @@ pub enum LogLevel {
 // ===== Accessor Methods =====
 
 impl WakeruConfig {
+  /// Loads the configuration from a TOML file and validates it.
+  ///
+  /// # Errors
+  /// - `ConfigError::ReadFile` if the file cannot be read
+  /// - `ConfigError::TomlParse` if the content is not valid TOML for `WakeruConfig`
+  /// - Any error of [`WakeruConfig::validate`]
+  ///
+  /// Environment variables are not applied; call [`WakeruConfig::apply_env_overrides`]
+  /// afterwards so that they take precedence over the file.
+  pub fn from_toml_path(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+    let path = path.as_ref();
+    let content = std::fs::read_to_string(path).map_err(|e| ConfigError::ReadFile {
+      path: path.to_path_buf(),
+      source: Arc::new(e),
+    })?;
+
+    let config: Self = toml::from_str(&content).map_err(|e| ConfigError::TomlParse {
+      source: Arc::new(e),
+    })?;
+    config.validate()?;
+
+    Ok(config)
+  }
+
+  /// Overrides settings with environment variables and re-validates.
+  ///
+  /// Precedence: environment variable > TOML file > default.
+  ///
+  /// | Variable                | Overrides           | Values                                        |
+  /// |-------------------------|---------------------|-----------------------------------------------|
+  /// | `WAKERU_INDEX_DATA_DIR` | `index.data_dir`    | Non-empty path                                |
+  /// | `WAKERU_DICT_PRESET`    | `dictionary.preset` | `ipadic` / `unidic-cwj` / `unidic-csj`        |
+  /// | `WAKERU_LOG_LEVEL`      | `logging.level`     | `trace` / `debug` / `info` / `warn` / `error` |
+  ///
+  /// Unset variables leave the current value unchanged. Values are case-insensitive.
+  ///
+  /// # Errors
+  /// - `ConfigError::InvalidEnvValue` if a variable has an unknown value
+  /// - Any error of [`WakeruConfig::validate`]
+  pub fn apply_env_overrides(&mut self) -> Result<(), ConfigError> {
+    self.apply_overrides_from(|name| std::env::var(name).ok())
+  }
+
+  /// Applies overrides read through `lookup` (environment variable name -> value)
+  fn apply_overrides_from(
+    &mut self,
+    lookup: impl Fn(&str) -> Option<String>,
+  ) -> Result<(), ConfigError> {
+    if let Some(value) = lookup("WAKERU_INDEX_DATA_DIR") {
+      if value.is_empty() {
+        return Err(ConfigError::InvalidEnvValue {
+          name: "WAKERU_INDEX_DATA_DIR",
+          value,
+          expected: "non-empty directory path",
+        });
+      }
+      self.index.data_dir = PathBuf::from(value);
+    }
+
+    if let Some(value) = lookup("WAKERU_DICT_PRESET") {
+      self.dictionary.preset =
+        parse_dictionary_preset(&value).ok_or(ConfigError::InvalidEnvValue {
+          name: "WAKERU_DICT_PRESET",
+          value,
+          expected: "ipadic, unidic-cwj, unidic-csj",
+        })?;
+    }
+
+    if let Some(value) = lookup("WAKERU_LOG_LEVEL") {
+      self.logging.level = parse_log_level(&value).ok_or(ConfigError::InvalidEnvValue {
+        name: "WAKERU_LOG_LEVEL",
+        value,
+        expected: "trace, debug, info, warn, error",
+      })?;
+    }
+
+    self.validate()
+  }
+
   /// Returns the preset dictionary type to pass to DictionaryManager.
   ///
   /// Corresponds to:
@@ -207,12 +581,18 @@ impl WakeruConfig {
 
   /// Returns the configured dictionary cache directory.
   ///
-  /// `None` if unspecified in TOML.
-  /// The actual path determination is assumed to be done on the DictionaryManager side.
+  /// `None` if unspecified in TOML (the OS default cache directory is used).
   pub fn dictionary_cache_dir(&self) -> Option<&Path> {
     self.dictionary.cache_dir.as_deref()
   }
 
+  /// Returns the configured user dictionary CSV path.
+  ///
+  /// `None` if unspecified in TOML (preset dictionary only).
+  pub fn dictionary_user_dict(&self) -> Option<&Path> {
+    self.dictionary.user_dict.as_deref()
+  }
+
   /// Returns the base directory of the index.
   ///
   /// e.g., "/opt/wakeru/data/index"
@@ -273,6 +653,83 @@ impl WakeruConfig {
     self.index.batch_commit_size
   }
 
+  /// Returns the minimum token length for the English analyzer.
+  pub fn min_token_len(&self) -> usize {
+    self.index.min_token_len
+  }
+
+  /// Returns the minimum N-gram length of the Japanese N-gram field.
+  pub fn min_gram(&self) -> usize {
+    self.index.min_gram
+  }
+
+  /// Returns the maximum N-gram length of the Japanese N-gram field.
+  pub fn max_gram(&self) -> usize {
+    self.index.max_gram
+  }
+
+  /// Returns the compression of stored fields.
+  pub fn store_compression(&self) -> StoreCompression {
+    self.index.store_compression
+  }
+
+  /// Returns whether new English indexes get an unstemmed `text_exact` field.
+  pub fn english_exact_field(&self) -> bool {
+    self.index.english_exact_field
+  }
+
+  /// Returns whether new Japanese indexes get a `text_reading` field.
+  pub fn japanese_reading_field(&self) -> bool {
+    self.index.japanese_reading_field
+  }
+
+  /// Returns the tokenizer mode of new Japanese indexes.
+  pub fn tokenizer_mode(&self) -> TokenizerMode {
+    self.index.tokenizer_mode
+  }
+
+  /// Returns the stemmer language of the English analyzer (English if unset).
+  ///
+  /// Unknown values are rejected by [`WakeruConfig::validate`].
+  pub fn stemmer_language(&self) -> StemmerLanguage {
+    self
+      .index
+      .stemmer_language
+      .as_deref()
+      .and_then(parse_stemmer_language)
+      .unwrap_or(StemmerLanguage::English)
+  }
+
+  /// Returns whether the English analyzer drops stop words.
+  pub fn english_stop_words(&self) -> bool {
+    self.index.english_stop_words
+  }
+
+  /// Returns whether new indexes store `source_id` terms lowercased.
+  pub fn case_insensitive_source_id(&self) -> bool {
+    self.index.case_insensitive_source_id
+  }
+
+  /// Returns what the `text` field of new indexes records per term.
+  pub fn text_record_option(&self) -> TextRecordOption {
+    self.index.text_record_option
+  }
+
+  /// Returns whether new indexes store token byte offsets of `text`.
+  pub fn with_offsets(&self) -> bool {
+    self.index.with_offsets
+  }
+
+  /// Returns whether tags are also kept in the `metadata` JSON.
+  pub fn tags_in_metadata(&self) -> bool {
+    self.index.tags_in_metadata
+  }
+
+  /// Returns how index directories are opened.
+  pub fn open_mode(&self) -> OpenMode {
+    self.index.open_mode
+  }
+
   /// Returns the list of supported languages.
   pub fn supported_languages(&self) -> &[Language] {
     &self.index.languages
@@ -292,7 +749,13 @@ impl WakeruConfig {
   /// - `search.max_limit` >= `search.default_limit`
   /// - `index.writer_memory_bytes` is within allowable range (1MB - 1GB)
   /// - `index.batch_commit_size` >= 1
+  /// - `index.min_token_len` >= 1
+  /// - `1 <= index.min_gram <= index.max_gram`
+  /// - `index.stemmer_language` (if set) is a Tantivy stemmer language
+  /// - `search.overlap_penalty` (if set) has `0.0 < factor <= 1.0` and `0.0 <= min_overlap <= 1.0`
+  /// - `search.bm25` (if set) has `k1 >= 0.0` and `0.0 <= b <= 1.0`
   /// - `dictionary.cache_dir` exists or can be created
+  /// - `dictionary.user_dict` (if set) is an existing file
   ///
   /// # Errors
   /// Returns the corresponding `ConfigError` if validation fails.
@@ -343,6 +806,59 @@ impl WakeruConfig {
       });
     }
 
+    // index.min_token_len >= 1
+    if self.index.min_token_len < 1 {
+      return Err(ConfigError::InvalidMinTokenLen {
+        actual: self.index.min_token_len,
+      });
+    }
+
+    // 1 <= index.min_gram <= index.max_gram
+    if self.index.min_gram < 1 || self.index.max_gram < self.index.min_gram {
+      return Err(ConfigError::InvalidNgramRange {
+        min_gram: self.index.min_gram,
+        max_gram: self.index.max_gram,
+      });
+    }
+
+    // index.stemmer_language is a supported stemmer language
+    if let Some(value) = &self.index.stemmer_language
+      && parse_stemmer_language(value).is_none()
+    {
+      return Err(ConfigError::InvalidStemmerLanguage {
+        value: value.clone(),
+      });
+    }
+
+    // Readings come from the dictionary, so cjk-bigram cannot produce them
+    if self.index.japanese_reading_field && self.index.tokenizer_mode == TokenizerMode::CjkBigram {
+      return Err(ConfigError::ReadingFieldRequiresDictionary);
+    }
+
+    // search.overlap_penalty is within range
+    if let Some(penalty) = &self.search.overlap_penalty {
+      if !(penalty.factor > 0.0 && penalty.factor <= 1.0) {
+        return Err(ConfigError::InvalidOverlapPenaltyFactor {
+          actual: penalty.factor,
+        });
+      }
+      if !(0.0..=1.0).contains(&penalty.min_overlap) {
+        return Err(ConfigError::InvalidOverlapPenaltyMinOverlap {
+          actual: penalty.min_overlap,
+        });
+      }
+    }
+
+    // search.bm25 is within range
+    if let Some(bm25) = &self.search.bm25
+      && !bm25.is_valid()
+    {
+      return Err(ConfigError::InvalidBm25Params {
+        k1: bm25.k1,
+        b: bm25.b,
+      });
+    }
+
     // dictionary.cache_dir exists or can be created
     if let Some(cache_dir) = &self.dictionary.cache_dir {
       if cache_dir.exists() {
@@ -363,6 +879,15 @@ impl WakeruConfig {
       }
     }
 
+    // dictionary.user_dict is an existing file
+    if let Some(user_dict) = &self.dictionary.user_dict
+      && !user_dict.is_file()
+    {
+      return Err(ConfigError::InvalidUserDictionary {
+        path: user_dict.clone(),
+      });
+    }
+
     Ok(())
   }
 
@@ -376,6 +901,16 @@ impl WakeruConfig {
     self.search.max_limit
   }
 
+  /// Returns the overlap penalty configuration (`None` if disabled).
+  pub fn overlap_penalty(&self) -> Option<OverlapPenaltyConfig> {
+    self.search.overlap_penalty
+  }
+
+  /// Returns the BM25 parameters (`None`: Tantivy defaults).
+  pub fn bm25(&self) -> Option<Bm25Config> {
+    self.search.bm25
+  }
+
   /// Returns the log level.
   pub fn log_level(&self) -> LogLevel {
     self.logging.level
@@ -398,6 +933,374 @@ impl From<DictionaryPreset> for PresetDictionaryKind {
   }
 }
 
+/// Parses a dictionary preset name (case-insensitive)
+fn parse_dictionary_preset(value: &str) -> Option<DictionaryPreset> {
+  match value.to_lowercase().as_str() {
+    "ipadic" => Some(DictionaryPreset::Ipadic),
+    "unidic-cwj" => Some(DictionaryPreset::UnidicCwj),
+    "unidic-csj" => Some(DictionaryPreset::UnidicCsj),
+    _ => None,
+  }
+}
+
+/// Parses a log level name (case-insensitive)
+fn parse_log_level(value: &str) -> Option<LogLevel> {
+  match value.to_lowercase().as_str() {
+    "trace" => Some(LogLevel::Trace),
+    "debug" => Some(LogLevel::Debug),
+    "info" => Some(LogLevel::Info),
+    "warn" => Some(LogLevel::Warn),
+    "error" => Some(LogLevel::Error),
+    _ => None,
+  }
+}
+
+/// Parses a Tantivy stemmer language name (case-insensitive)
+fn parse_stemmer_language(value: &str) -> Option<StemmerLanguage> {
+  match value.to_lowercase().as_str() {
+    "arabic" => Some(StemmerLanguage::Arabic),
+    "danish" => Some(StemmerLanguage::Danish),
+    "dutch" => Some(StemmerLanguage::Dutch),
+    "english" => Some(StemmerLanguage::English),
+    "finnish" => Some(StemmerLanguage::Finnish),
+    "french" => Some(StemmerLanguage::French),
+    "german" => Some(StemmerLanguage::German),
+    "greek" => Some(StemmerLanguage::Greek),
+    "hungarian" => Some(StemmerLanguage::Hungarian),
+    "italian" => Some(StemmerLanguage::Italian),
+    "norwegian" => Some(StemmerLanguage::Norwegian),
+    "portuguese" => Some(StemmerLanguage::Portuguese),
+    "romanian" => Some(StemmerLanguage::Romanian),
+    "russian" => Some(StemmerLanguage::Russian),
+    "spanish" => Some(StemmerLanguage::Spanish),
+    "swedish" => Some(StemmerLanguage::Swedish),
+    "tamil" => Some(StemmerLanguage::Tamil),
+    "turkish" => Some(StemmerLanguage::Turkish),
+    _ => None,
+  }
+}
+
+// ===== Builder =====
+
+/// Default index storage directory of [`WakeruConfigBuilder`]
+const DEFAULT_DATA_DIR: &str = "data/index";
+
+/// Default IndexWriter memory buffer of [`WakeruConfigBuilder`] (50MB)
+const DEFAULT_WRITER_MEMORY_BYTES: usize = 50_000_000;
+
+/// Default batch commit size of [`WakeruConfigBuilder`]
+const DEFAULT_BATCH_COMMIT_SIZE: usize = 1000;
+
+/// Default search result limit of [`WakeruConfigBuilder`]
+const DEFAULT_SEARCH_LIMIT: usize = 10;
+
+/// Default maximum search result limit of [`WakeruConfigBuilder`]
+const DEFAULT_MAX_SEARCH_LIMIT: usize = 100;
+
+impl WakeruConfig {
+  /// Returns a builder for constructing the configuration in code (without TOML).
+  ///
+  /// See [`WakeruConfigBuilder`] for the defaults.
+  pub fn builder() -> WakeruConfigBuilder {
+    WakeruConfigBuilder::default()
+  }
+}
+
+/// Fluent builder of [`WakeruConfig`] for embedders that do not load TOML.
+///
+/// Settings not set explicitly take these defaults:
+/// - dictionary: `unidic-cwj` preset, OS default cache directory, no user dictionary
+/// - index: `data/index`, 50MB writer memory, batch commit size 1000, Japanese only
+/// - search: default limit 10, max limit 100, no overlap penalty
+/// - logging: `info`
+/// - Other `[index]` options: same defaults as omitted TOML keys
+///
+/// The default language is the first of `languages` unless set with
+/// [`default_language`](Self::default_language).
+///
+/// # Examples
+/// ```ignore
+/// let config = WakeruConfig::builder()
+///   .data_dir("/opt/wakeru/data/index")
+///   .languages(vec![Language::Ja, Language::En])
+///   .preset(DictionaryPreset::Ipadic)
+///   .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct WakeruConfigBuilder {
+  config: WakeruConfig,
+  /// Explicit default language (`None`: first of `languages`)
+  default_language: Option<Language>,
+}
+
+impl Default for WakeruConfigBuilder {
+  fn default() -> Self {
+    Self {
+      config: WakeruConfig {
+        dictionary: DictionaryConfig {
+          preset: DictionaryPreset::UnidicCwj,
+          cache_dir: None,
+          user_dict: None,
+        },
+        index: IndexConfig {
+          data_dir: PathBuf::from(DEFAULT_DATA_DIR),
+          writer_memory_bytes: DEFAULT_WRITER_MEMORY_BYTES,
+          batch_commit_size: DEFAULT_BATCH_COMMIT_SIZE,
+          languages: default_languages(),
+          default_language: default_language(),
+          min_token_len: default_min_token_len(),
+          min_gram: default_min_gram(),
+          max_gram: default_max_gram(),
+          store_compression: StoreCompression::default(),
+          english_exact_field: false,
+          japanese_reading_field: false,
+          tokenizer_mode: TokenizerMode::default(),
+          stemmer_language: None,
+          english_stop_words: false,
+          case_insensitive_source_id: false,
+          text_record_option: TextRecordOption::default(),
+          with_offsets: false,
+          tags_in_metadata: default_tags_in_metadata(),
+          open_mode: OpenMode::default(),
+        },
+        search: SearchConfig {
+          default_limit: DEFAULT_SEARCH_LIMIT,
+          max_limit: DEFAULT_MAX_SEARCH_LIMIT,
+          overlap_penalty: None,
+          bm25: None,
+        },
+        logging: LoggingConfig {
+          level: LogLevel::Info,
+        },
+      },
+      default_language: None,
+    }
+  }
+}
+
+impl WakeruConfigBuilder {
+  /// Sets the preset dictionary (`[dictionary].preset`).
+  #[must_use]
+  pub fn preset(mut self, preset: DictionaryPreset) -> Self {
+    self.config.dictionary.preset = preset;
+    self
+  }
+
+  /// Sets the dictionary cache directory (`[dictionary].cache_dir`).
+  #[must_use]
+  pub fn cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+    self.config.dictionary.cache_dir = Some(cache_dir.into());
+    self
+  }
+
+  /// Sets the user dictionary CSV (`[dictionary].user_dict`).
+  #[must_use]
+  pub fn user_dict(mut self, user_dict: impl Into<PathBuf>) -> Self {
+    self.config.dictionary.user_dict = Some(user_dict.into());
+    self
+  }
+
+  /// Sets the index storage directory (`[index].data_dir`).
+  #[must_use]
+  pub fn data_dir(mut self, data_dir: impl Into<PathBuf>) -> Self {
+    self.config.index.data_dir = data_dir.into();
+    self
+  }
+
+  /// Sets the IndexWriter memory buffer in bytes (`[index].writer_memory_bytes`).
+  #[must_use]
+  pub fn writer_memory_bytes(mut self, bytes: usize) -> Self {
+    self.config.index.writer_memory_bytes = bytes;
+    self
+  }
+
+  /// Sets the batch commit size (`[index].batch_commit_size`).
+  #[must_use]
+  pub fn batch_commit_size(mut self, size: usize) -> Self {
+    self.config.index.batch_commit_size = size;
+    self
+  }
+
+  /// Sets the supported languages (`[index].languages`).
+  #[must_use]
+  pub fn languages(mut self, languages: impl Into<Vec<Language>>) -> Self {
+    self.config.index.languages = languages.into();
+    self
+  }
+
+  /// Sets the default language (`[index].default_language`).
+  #[must_use]
+  pub fn default_language(mut self, language: Language) -> Self {
+    self.default_language = Some(language);
+    self
+  }
+
+  /// Sets the minimum English token length (`[index].min_token_len`).
+  #[must_use]
+  pub fn min_token_len(mut self, len: usize) -> Self {
+    self.config.index.min_token_len = len;
+    self
+  }
+
+  /// Sets the N-gram length range (`[index].min_gram` / `[index].max_gram`).
+  #[must_use]
+  pub fn ngram_range(mut self, min_gram: usize, max_gram: usize) -> Self {
+    self.config.index.min_gram = min_gram;
+    self.config.index.max_gram = max_gram;
+    self
+  }
+
+  /// Sets the stored field compression (`[index].store_compression`).
+  #[must_use]
+  pub fn store_compression(mut self, compression: StoreCompression) -> Self {
+    self.config.index.store_compression = compression;
+    self
+  }
+
+  /// Enables the English `text_exact` field (`[index].english_exact_field`).
+  #[must_use]
+  pub fn english_exact_field(mut self, enabled: bool) -> Self {
+    self.config.index.english_exact_field = enabled;
+    self
+  }
+
+  /// Enables the Japanese `text_reading` field (`[index].japanese_reading_field`).
+  #[must_use]
+  pub fn japanese_reading_field(mut self, enabled: bool) -> Self {
+    self.config.index.japanese_reading_field = enabled;
+    self
+  }
+
+  /// Sets the Japanese tokenizer (`[index].tokenizer_mode`).
+  #[must_use]
+  pub fn tokenizer_mode(mut self, mode: TokenizerMode) -> Self {
+    self.config.index.tokenizer_mode = mode;
+    self
+  }
+
+  /// Sets the English stemmer language, e.g. "french" (`[index].stemmer_language`).
+  #[must_use]
+  pub fn stemmer_language(mut self, language: impl Into<String>) -> Self {
+    self.config.index.stemmer_language = Some(language.into());
+    self
+  }
+
+  /// Enables English stop word removal (`[index].english_stop_words`).
+  #[must_use]
+  pub fn english_stop_words(mut self, enabled: bool) -> Self {
+    self.config.index.english_stop_words = enabled;
+    self
+  }
+
+  /// Enables case-insensitive `source_id` (`[index].case_insensitive_source_id`).
+  #[must_use]
+  pub fn case_insensitive_source_id(mut self, enabled: bool) -> Self {
+    self.config.index.case_insensitive_source_id = enabled;
+    self
+  }
+
+  /// Sets what the `text` field records per term (`[index].text_record_option`).
+  #[must_use]
+  pub fn text_record_option(mut self, option: TextRecordOption) -> Self {
+    self.config.index.text_record_option = option;
+    self
+  }
+
+  /// Enables storing token byte offsets (`[index].with_offsets`).
+  #[must_use]
+  pub fn with_offsets(mut self, enabled: bool) -> Self {
+    self.config.index.with_offsets = enabled;
+    self
+  }
+
+  /// Sets whether tags are also kept in `metadata` (`[index].tags_in_metadata`).
+  #[must_use]
+  pub fn tags_in_metadata(mut self, enabled: bool) -> Self {
+    self.config.index.tags_in_metadata = enabled;
+    self
+  }
+
+  /// Sets how index directories are opened (`[index].open_mode`).
+  #[must_use]
+  pub fn open_mode(mut self, mode: OpenMode) -> Self {
+    self.config.index.open_mode = mode;
+    self
+  }
+
+  /// Sets the default search result limit (`[search].default_limit`).
+  #[must_use]
+  pub fn default_limit(mut self, limit: usize) -> Self {
+    self.config.search.default_limit = limit;
+    self
+  }
+
+  /// Sets the maximum search result limit (`[search].max_limit`).
+  #[must_use]
+  pub fn max_limit(mut self, limit: usize) -> Self {
+    self.config.search.max_limit = limit;
+    self
+  }
+
+  /// Sets the overlap penalty (`[search.overlap_penalty]`).
+  #[must_use]
+  pub fn overlap_penalty(mut self, penalty: OverlapPenaltyConfig) -> Self {
+    self.config.search.overlap_penalty = Some(penalty);
+    self
+  }
+
+  /// Sets the BM25 parameters (`[search.bm25]`).
+  #[must_use]
+  pub fn bm25(mut self, k1: f32, b: f32) -> Self {
+    self.config.search.bm25 = Some(Bm25Config { k1, b });
+    self
+  }
+
+  /// Sets the log level (`[logging].level`).
+  #[must_use]
+  pub fn log_level(mut self, level: LogLevel) -> Self {
+    self.config.logging.level = level;
+    self
+  }
+
+  /// Builds and validates the configuration.
+  ///
+  /// # Errors
+  /// Any error of [`WakeruConfig::validate`]
+  pub fn build(self) -> Result<WakeruConfig, ConfigError> {
+    let mut config = self.config;
+    config.index.default_language = match self.default_language {
+      Some(language) => language,
+      None => config.index.languages.first().copied().unwrap_or_else(default_language),
+    };
+    config.validate()?;
+
+    Ok(config)
+  }
+}
+
+// Implements conversion from StoreCompression (for configuration file) -> Compressor (for tantivy).
+
+impl From<StoreCompression> for Compressor {
+  fn from(compression: StoreCompression) -> Self {
+    match compression {
+      StoreCompression::None => Compressor::None,
+      StoreCompression::Lz4 => Compressor::Lz4,
+      StoreCompression::Zstd => Compressor::Zstd(ZstdCompressor::default()),
+    }
+  }
+}
+
+// Implements conversion from TextRecordOption (for configuration file) -> IndexRecordOption (for tantivy).
+
+impl From<TextRecordOption> for IndexRecordOption {
+  fn from(option: TextRecordOption) -> Self {
+    match option {
+      TextRecordOption::WithPositions => IndexRecordOption::WithFreqsAndPositions,
+      TextRecordOption::FreqsOnly => IndexRecordOption::WithFreqs,
+    }
+  }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Test Module
 // ─────────────────────────────────────────────────────────────────────────────
@@ -416,6 +1319,7 @@ mod tests {
       dictionary: DictionaryConfig {
         preset: DictionaryPreset::Ipadic,
         cache_dir: Some(temp_dir.path().join("dict")),
+        user_dict: None,
       },
       index: IndexConfig {
         data_dir: temp_dir.path().join("index"),
@@ -423,10 +1327,26 @@ mod tests {
         batch_commit_size: 1_000,
         languages: vec![Language::Ja, Language::En],
         default_language: Language::Ja,
+        min_token_len: 1,
+        min_gram: 1,
+        max_gram: 1,
+        store_compression: StoreCompression::Lz4,
+        english_exact_field: false,
+        japanese_reading_field: false,
+        tokenizer_mode: TokenizerMode::Morphological,
+        stemmer_language: None,
+        english_stop_words: false,
+        case_insensitive_source_id: false,
+        text_record_option: TextRecordOption::WithPositions,
+        with_offsets: false,
+        tags_in_metadata: true,
+        open_mode: OpenMode::CreateIfMissing,
       },
       search: SearchConfig {
         default_limit: 10,
         max_limit: 100,
+        overlap_penalty: None,
+        bm25: None,
       },
       logging: LoggingConfig {
         level: LogLevel::Info,
@@ -440,24 +1360,42 @@ mod tests {
   fn language_code_returns_correct_value() {
     assert_eq!(Language::Ja.code(), "ja");
     assert_eq!(Language::En.code(), "en");
+    assert_eq!(Language::Ko.code(), "ko");
   }
 
   #[test]
   fn language_text_tokenizer_name() {
     assert_eq!(Language::Ja.text_tokenizer_name(), "lang_ja");
     assert_eq!(Language::En.text_tokenizer_name(), "lang_en");
+    assert_eq!(Language::Ko.text_tokenizer_name(), "lang_ko");
   }
 
   #[test]
   fn language_ngram_tokenizer_name() {
     assert_eq!(Language::Ja.ngram_tokenizer_name(), Some("ja_ngram"));
     assert_eq!(Language::En.ngram_tokenizer_name(), None);
+    assert_eq!(Language::Ko.ngram_tokenizer_name(), Some("ko_ngram"));
+  }
+
+  #[test]
+  fn language_exact_tokenizer_name() {
+    assert_eq!(Language::Ja.exact_tokenizer_name(), None);
+    assert_eq!(Language::En.exact_tokenizer_name(), Some("en_exact"));
+    assert_eq!(Language::Ko.exact_tokenizer_name(), None);
+  }
+
+  #[test]
+  fn language_reading_tokenizer_name() {
+    assert_eq!(Language::Ja.reading_tokenizer_name(), Some("ja_reading"));
+    assert_eq!(Language::En.reading_tokenizer_name(), None);
+    assert_eq!(Language::Ko.reading_tokenizer_name(), None);
   }
 
   #[test]
   fn language_display() {
     assert_eq!(format!("{}", Language::Ja), "ja");
     assert_eq!(format!("{}", Language::En), "en");
+    assert_eq!(format!("{}", Language::Ko), "ko");
   }
 
   // ─── validate() Normal Case Tests ────────────────────────────────────────────
@@ -588,6 +1526,68 @@ mod tests {
     }
   }
 
+  #[test]
+  fn validate_accepts_overlap_penalty() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = create_valid_config(&temp_dir);
+    config.search.overlap_penalty = Some(OverlapPenaltyConfig {
+      factor: 1.0,
+      min_overlap: 0.0,
+    });
+
+    assert!(config.validate().is_ok());
+  }
+
+  #[test]
+  fn validate_rejects_overlap_penalty_factor_out_of_range() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = create_valid_config(&temp_dir);
+
+    for factor in [0.0, -0.5, 1.5, f32::NAN] {
+      config.search.overlap_penalty = Some(OverlapPenaltyConfig {
+        factor,
+        min_overlap: 0.5,
+      });
+      let err = config.validate().unwrap_err();
+      assert!(matches!(
+        err,
+        ConfigError::InvalidOverlapPenaltyFactor { .. }
+      ));
+    }
+  }
+
+  #[test]
+  fn validate_rejects_bm25_params_out_of_range() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = create_valid_config(&temp_dir);
+    config.search.bm25 = Some(Bm25Config { k1: 2.0, b: 0.0 });
+    assert!(config.validate().is_ok());
+
+    for (k1, b) in [(-0.1, 0.75), (1.2, 1.5), (1.2, -0.1), (f32::NAN, 0.75)] {
+      config.search.bm25 = Some(Bm25Config { k1, b });
+      let err = config.validate().unwrap_err();
+      assert!(matches!(err, ConfigError::InvalidBm25Params { .. }));
+    }
+  }
+
+  #[test]
+  fn validate_rejects_overlap_penalty_min_overlap_out_of_range() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = create_valid_config(&temp_dir);
+    config.search.overlap_penalty = Some(OverlapPenaltyConfig {
+      factor: 0.5,
+      min_overlap: 1.1,
+    });
+
+    let err = config.validate().unwrap_err();
+    match err {
+      ConfigError::InvalidOverlapPenaltyMinOverlap { actual } => {
+        assert!((actual - 1.1).abs() < f32::EPSILON);
+      }
+      _ => panic!("expected InvalidOverlapPenaltyMinOverlap error"),
+    }
+  }
+
   // ─── validate() index Abnormal Cases ───────────────────────────────────────────────
 
   #[test]
@@ -637,6 +1637,63 @@ mod tests {
     }
   }
 
+  #[test]
+  fn validate_rejects_min_token_len_zero() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = create_valid_config(&temp_dir);
+    config.index.min_token_len = 0;
+
+    let err = config.validate().unwrap_err();
+    match err {
+      ConfigError::InvalidMinTokenLen { actual } => {
+        assert_eq!(actual, 0);
+      }
+      _ => panic!("expected InvalidMinTokenLen error"),
+    }
+  }
+
+  #[test]
+  fn validate_rejects_invalid_ngram_range() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = create_valid_config(&temp_dir);
+
+    config.index.min_gram = 0;
+    assert!(matches!(
+      config.validate().unwrap_err(),
+      ConfigError::InvalidNgramRange { min_gram: 0, .. }
+    ));
+
+    config.index.min_gram = 3;
+    config.index.max_gram = 2;
+    assert!(matches!(
+      config.validate().unwrap_err(),
+      ConfigError::InvalidNgramRange {
+        min_gram: 3,
+        max_gram: 2
+      }
+    ));
+
+    config.index.min_gram = 1;
+    assert!(config.validate().is_ok());
+  }
+
+  #[test]
+  fn validate_rejects_unknown_stemmer_language() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = create_valid_config(&temp_dir);
+    assert_eq!(config.stemmer_language(), StemmerLanguage::English);
+
+    config.index.stemmer_language = Some("French".to_string());
+    assert!(config.validate().is_ok());
+    assert_eq!(config.stemmer_language(), StemmerLanguage::French);
+
+    config.index.stemmer_language = Some("klingon".to_string());
+    assert!(matches!(
+      config.validate().unwrap_err(),
+      ConfigError::InvalidStemmerLanguage { value } if value == "klingon"
+    ));
+  }
+
   // ─── validate() dictionary.cache_dir Tests ───────────────────────────────
 
   #[test]
@@ -720,6 +1777,31 @@ mod tests {
     }
   }
 
+  #[test]
+  fn validate_rejects_missing_user_dict() {
+    let temp_dir = TempDir::new().unwrap();
+    let missing = temp_dir.path().join("missing_user.csv");
+
+    let mut config = create_valid_config(&temp_dir);
+    config.dictionary.user_dict = Some(missing.clone());
+
+    let err = config.validate().unwrap_err();
+    match err {
+      ConfigError::InvalidUserDictionary { path } => {
+        assert_eq!(path, missing);
+      }
+      _ => panic!("expected InvalidUserDictionary error"),
+    }
+
+    fs::write(
+      &missing,
+      "東京スカイツリー,1288,1288,-1000,名詞,固有名詞,一般,*,*,*\n",
+    )
+    .unwrap();
+    assert!(config.validate().is_ok());
+    assert_eq!(config.dictionary_user_dict(), Some(missing.as_path()));
+  }
+
   // ─── Error Priority Tests ────────────────────────────────────────────────
 
   #[test]
@@ -749,6 +1831,93 @@ mod tests {
     ));
   }
 
+  // ─── Builder Tests ───────────────────────────────────────────────────────
+
+  #[test]
+  fn builder_applies_defaults() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = WakeruConfig::builder()
+      .data_dir(temp_dir.path().join("index"))
+      .build()
+      .expect("minimal builder config should be valid");
+
+    assert_eq!(config.index_base_dir(), temp_dir.path().join("index"));
+    assert_eq!(config.writer_memory_bytes(), 50_000_000);
+    assert_eq!(config.batch_commit_size(), 1000);
+    assert_eq!(config.supported_languages(), &[Language::Ja]);
+    assert_eq!(config.default_language(), Language::Ja);
+    assert_eq!(config.min_token_len(), 1);
+    assert_eq!((config.min_gram(), config.max_gram()), (1, 1));
+    assert_eq!(config.store_compression(), StoreCompression::Lz4);
+    assert_eq!(config.tokenizer_mode(), TokenizerMode::Morphological);
+    assert_eq!(config.open_mode(), OpenMode::CreateIfMissing);
+    assert_eq!(config.search.default_limit, 10);
+    assert_eq!(config.search.max_limit, 100);
+    assert!(config.search.overlap_penalty.is_none());
+    assert_eq!(config.dictionary.preset, DictionaryPreset::UnidicCwj);
+    assert!(config.dictionary_cache_dir().is_none());
+    assert_eq!(config.logging.level, LogLevel::Info);
+  }
+
+  #[test]
+  fn builder_applies_setters() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = WakeruConfig::builder()
+      .data_dir(temp_dir.path().join("index"))
+      .cache_dir(temp_dir.path().join("dict"))
+      .preset(DictionaryPreset::Ipadic)
+      .languages(vec![Language::Ja, Language::En])
+      .default_language(Language::En)
+      .writer_memory_bytes(20_000_000)
+      .ngram_range(1, 2)
+      .store_compression(StoreCompression::Zstd)
+      .default_limit(5)
+      .max_limit(50)
+      .build()
+      .expect("builder config should be valid");
+
+    assert_eq!(config.dictionary.preset, DictionaryPreset::Ipadic);
+    assert_eq!(
+      config.dictionary_cache_dir(),
+      Some(temp_dir.path().join("dict").as_path())
+    );
+    assert_eq!(config.supported_languages(), &[Language::Ja, Language::En]);
+    assert_eq!(config.default_language(), Language::En);
+    assert_eq!(config.writer_memory_bytes(), 20_000_000);
+    assert_eq!((config.min_gram(), config.max_gram()), (1, 2));
+    assert_eq!(config.store_compression(), StoreCompression::Zstd);
+    assert_eq!(config.search.default_limit, 5);
+    assert_eq!(config.search.max_limit, 50);
+  }
+
+  #[test]
+  fn builder_defaults_to_first_language() {
+    let config = WakeruConfig::builder()
+      .languages(vec![Language::En, Language::Ko])
+      .build()
+      .expect("builder config should be valid");
+    assert_eq!(config.default_language(), Language::En);
+  }
+
+  #[test]
+  fn builder_validates_config() {
+    let err = WakeruConfig::builder().default_limit(20).max_limit(10).build().unwrap_err();
+    assert!(matches!(err, ConfigError::InvalidSearchMaxLimit { .. }));
+
+    let err = WakeruConfig::builder()
+      .languages(vec![Language::En])
+      .default_language(Language::Ja)
+      .build()
+      .unwrap_err();
+    assert!(matches!(
+      err,
+      ConfigError::DefaultLanguageNotInLanguages { .. }
+    ));
+
+    let err = WakeruConfig::builder().languages(Vec::new()).build().unwrap_err();
+    assert!(matches!(err, ConfigError::EmptyLanguages));
+  }
+
   // ─── Accessor Method Tests ───────────────────────────────────────────────
 
   #[test]
@@ -847,6 +2016,219 @@ mod tests {
     );
   }
 
+  // ─── from_toml_path() Tests ──────────────────────────────────────────────
+
+  /// Writes `wakeru.toml` into `temp_dir` (paths relative to `temp_dir`)
+  fn write_toml(temp_dir: &TempDir, content: &str) -> PathBuf {
+    let path = temp_dir.path().join("wakeru.toml");
+    fs::write(&path, content).unwrap();
+    path
+  }
+
+  fn valid_toml(temp_dir: &TempDir) -> String {
+    let base = temp_dir.path().display().to_string().replace('\\', "/");
+    format!(
+      r#"
+[dictionary]
+preset = "unidic-cwj"
+cache_dir = "{base}/dict"
+
+[index]
+data_dir = "{base}/index"
+writer_memory_bytes = 50000000
+batch_commit_size = 500
+languages = ["ja", "en"]
+default_language = "en"
+store_compression = "zstd"
+
+[search]
+default_limit = 5
+max_limit = 50
+
+[logging]
+level = "debug"
+"#
+    )
+  }
+
+  #[test]
+  fn from_toml_path_loads_valid_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = write_toml(&temp_dir, &valid_toml(&temp_dir));
+
+    let config = WakeruConfig::from_toml_path(&path).expect("valid TOML should load");
+
+    assert_eq!(config.dictionary.preset, DictionaryPreset::UnidicCwj);
+    assert_eq!(
+      config.dictionary_cache_dir(),
+      Some(temp_dir.path().join("dict").as_path())
+    );
+    assert_eq!(config.batch_commit_size(), 500);
+    assert_eq!(config.supported_languages(), [Language::Ja, Language::En]);
+    assert_eq!(config.default_language(), Language::En);
+    assert_eq!(config.store_compression(), StoreCompression::Zstd);
+    assert_eq!(config.default_search_limit(), 5);
+    assert_eq!(config.log_level(), LogLevel::Debug);
+    // Omitted keys use their defaults
+    assert_eq!(config.min_token_len(), 1);
+    assert!(config.overlap_penalty().is_none());
+    assert_eq!(config.tokenizer_mode(), TokenizerMode::Morphological);
+    assert_eq!(config.open_mode(), OpenMode::CreateIfMissing);
+  }
+
+  #[test]
+  fn from_toml_path_loads_cjk_bigram_mode() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = valid_toml(&temp_dir).replace(
+      r#"store_compression = "zstd""#,
+      r#"tokenizer_mode = "cjk-bigram""#,
+    );
+    let path = write_toml(&temp_dir, &content);
+
+    let config = WakeruConfig::from_toml_path(&path).expect("valid TOML should load");
+    assert_eq!(config.tokenizer_mode(), TokenizerMode::CjkBigram);
+    assert_eq!(
+      Language::Ja.text_tokenizer_name_for(config.tokenizer_mode()),
+      "cjk_bigram"
+    );
+    // Other languages keep their analyzers
+    assert_eq!(
+      Language::En.text_tokenizer_name_for(config.tokenizer_mode()),
+      "lang_en"
+    );
+
+    // Readings need the dictionary
+    let content = content.replace(
+      r#"tokenizer_mode = "cjk-bigram""#,
+      "tokenizer_mode = \"cjk-bigram\"\njapanese_reading_field = true",
+    );
+    let path = write_toml(&temp_dir, &content);
+    let err = WakeruConfig::from_toml_path(&path).unwrap_err();
+    assert!(matches!(err, ConfigError::ReadingFieldRequiresDictionary));
+  }
+
+  #[test]
+  fn from_toml_path_rejects_malformed_toml() {
+    let temp_dir = TempDir::new().unwrap();
+
+    // Syntax error
+    let path = write_toml(&temp_dir, "[dictionary\npreset = ");
+    let err = WakeruConfig::from_toml_path(&path).unwrap_err();
+    assert!(
+      matches!(err, ConfigError::TomlParse { .. }),
+      "unexpected error: {err:?}"
+    );
+
+    // Valid TOML but missing required section
+    let path = write_toml(&temp_dir, "[dictionary]\npreset = \"ipadic\"\n");
+    let err = WakeruConfig::from_toml_path(&path).unwrap_err();
+    assert!(
+      matches!(err, ConfigError::TomlParse { .. }),
+      "unexpected error: {err:?}"
+    );
+  }
+
+  #[test]
+  fn from_toml_path_runs_validate() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = valid_toml(&temp_dir).replace(r#"languages = ["ja", "en"]"#, "languages = []");
+    let path = write_toml(&temp_dir, &content);
+
+    let err = WakeruConfig::from_toml_path(&path).unwrap_err();
+    assert!(matches!(err, ConfigError::EmptyLanguages));
+  }
+
+  #[test]
+  fn from_toml_path_reports_missing_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let missing = temp_dir.path().join("missing.toml");
+
+    let err = WakeruConfig::from_toml_path(&missing).unwrap_err();
+    match err {
+      ConfigError::ReadFile { path, .. } => assert_eq!(path, missing),
+      _ => panic!("expected ReadFile error"),
+    }
+  }
+
+  // ─── Environment Override Tests ──────────────────────────────────────────
+
+  /// Lookup backed by fixed (name, value) pairs instead of the process environment
+  fn env_lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let vars: Vec<(String, String)> =
+      vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    move |name| vars.iter().find(|(k, _)| k == name).map(|(_, v)| v.clone())
+  }
+
+  #[test]
+  fn env_overrides_replace_file_values() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = create_valid_config(&temp_dir);
+    let data_dir = temp_dir.path().join("env_index");
+    let data_dir_str = data_dir.display().to_string();
+
+    config
+      .apply_overrides_from(env_lookup(&[
+        ("WAKERU_INDEX_DATA_DIR", &data_dir_str),
+        ("WAKERU_DICT_PRESET", "UniDic-CSJ"),
+        ("WAKERU_LOG_LEVEL", "warn"),
+      ]))
+      .unwrap();
+
+    assert_eq!(config.index_base_dir(), data_dir);
+    assert_eq!(config.dictionary.preset, DictionaryPreset::UnidicCsj);
+    assert_eq!(config.log_level(), LogLevel::Warn);
+  }
+
+  #[test]
+  fn env_overrides_keep_values_when_unset() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = create_valid_config(&temp_dir);
+    let before = config.clone();
+
+    config.apply_overrides_from(env_lookup(&[])).unwrap();
+
+    assert_eq!(config.index_base_dir(), before.index_base_dir());
+    assert_eq!(config.dictionary.preset, before.dictionary.preset);
+    assert_eq!(config.log_level(), before.log_level());
+  }
+
+  #[test]
+  fn env_overrides_reject_unknown_values() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = create_valid_config(&temp_dir);
+
+    let err =
+      config.apply_overrides_from(env_lookup(&[("WAKERU_DICT_PRESET", "jumandic")])).unwrap_err();
+    match &err {
+      ConfigError::InvalidEnvValue { name, value, .. } => {
+        assert_eq!(*name, "WAKERU_DICT_PRESET");
+        assert_eq!(value, "jumandic");
+      }
+      _ => panic!("expected InvalidEnvValue error"),
+    }
+    assert!(err.to_string().contains("unidic-cwj"));
+
+    let err =
+      config.apply_overrides_from(env_lookup(&[("WAKERU_LOG_LEVEL", "verbose")])).unwrap_err();
+    assert!(matches!(
+      err,
+      ConfigError::InvalidEnvValue {
+        name: "WAKERU_LOG_LEVEL",
+        ..
+      }
+    ));
+
+    let err =
+      config.apply_overrides_from(env_lookup(&[("WAKERU_INDEX_DATA_DIR", "")])).unwrap_err();
+    assert!(matches!(
+      err,
+      ConfigError::InvalidEnvValue {
+        name: "WAKERU_INDEX_DATA_DIR",
+        ..
+      }
+    ));
+  }
+
   // ─── Multiple Error Combination Tests ──────────────────────────────────────────
 
   #[test]
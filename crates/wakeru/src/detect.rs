@@ -0,0 +1,134 @@
+//! Language Auto-detection
+//!
+//! Simple script-based heuristic deciding which language index a text belongs to,
+//! for RAG pipelines that ingest mixed content without language labels.
+
+use crate::config::Language;
+
+/// Minimum ratio of Japanese characters among letters for a text to be detected as Japanese
+///
+/// Kept well below 0.5 because one kanji or kana carries more than one Latin letter,
+/// so Japanese text with English terms ("RustでWebアプリを作る") is still Japanese.
+const JAPANESE_RATIO_THRESHOLD: f64 = 0.3;
+
+/// Minimum ratio of Hangul characters among letters for a text to be detected as Korean
+///
+/// Same reasoning as `JAPANESE_RATIO_THRESHOLD`: each Hangul syllable is a whole syllable.
+const KOREAN_RATIO_THRESHOLD: f64 = 0.3;
+
+/// Detects the language of `text` from the scripts it is written in
+///
+/// Counts letters only (digits, spaces, punctuation and symbols are ignored) and returns
+/// `Language::Ko` if at least 30% of them are Hangul (and Hangul outnumbers kanji and kana),
+/// `Language::Ja` if at least 30% of them are kanji or kana, otherwise `Language::En`.
+///
+/// # Limitations
+/// - Other Latin-script languages (French, German, ...) are detected as `Language::En`
+/// - Chinese text is detected as `Language::Ja`, since kanji and hanzi are not distinguished
+/// - Text without letters (empty, numbers or symbols only) is detected as `Language::En`
+/// - Very short or heavily mixed texts may be misdetected; pass the language explicitly
+///   when it is known
+#[must_use]
+pub fn detect_language(text: &str) -> Language {
+  let mut letters = 0usize;
+  let mut japanese = 0usize;
+  let mut korean = 0usize;
+
+  for c in text.chars().filter(|c| c.is_alphabetic()) {
+    letters += 1;
+    if is_japanese(c) {
+      japanese += 1;
+    } else if is_korean(c) {
+      korean += 1;
+    }
+  }
+
+  if letters == 0 {
+    return Language::En;
+  }
+  let ratio = |count: usize| count as f64 / letters as f64;
+
+  if korean >= japanese && ratio(korean) >= KOREAN_RATIO_THRESHOLD {
+    Language::Ko
+  } else if ratio(japanese) >= JAPANESE_RATIO_THRESHOLD {
+    Language::Ja
+  } else {
+    Language::En
+  }
+}
+
+/// Whether `c` is a kanji or kana character
+fn is_japanese(c: char) -> bool {
+  matches!(
+    c,
+    // Kanji (CJK Unified Ideographs, Extension A, Compatibility, Extension B+) and `々`
+    '\u{4E00}'..='\u{9FFF}'
+      | '\u{3400}'..='\u{4DBF}'
+      | '\u{F900}'..='\u{FAFF}'
+      | '\u{20000}'..='\u{2FFFF}'
+      | '\u{3005}'
+      // Hiragana, Katakana (including `ー`), Katakana Phonetic Extensions, half-width katakana
+      | '\u{3041}'..='\u{309F}'
+      | '\u{30A0}'..='\u{30FF}'
+      | '\u{31F0}'..='\u{31FF}'
+      | '\u{FF66}'..='\u{FF9F}'
+  )
+}
+
+/// Whether `c` is a Hangul character
+fn is_korean(c: char) -> bool {
+  matches!(
+    c,
+    // Hangul Syllables, Hangul Jamo, Hangul Compatibility Jamo
+    '\u{AC00}'..='\u{D7AF}' | '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}'
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn detects_pure_japanese() {
+    assert_eq!(
+      detect_language("東京タワーは東京の観光名所です。"),
+      Language::Ja
+    );
+    assert_eq!(detect_language("すもももももももものうち"), Language::Ja);
+  }
+
+  #[test]
+  fn detects_pure_english() {
+    assert_eq!(
+      detect_language("Tokyo Tower is a famous landmark."),
+      Language::En
+    );
+  }
+
+  #[test]
+  fn detects_mixed_text_by_dominant_script() {
+    // Japanese sentence with English terms
+    assert_eq!(detect_language("RustでWebアプリを作る"), Language::Ja);
+    // English sentence with a Japanese term
+    assert_eq!(
+      detect_language("The best ramen shop in Tokyo is called 一蘭"),
+      Language::En
+    );
+  }
+
+  #[test]
+  fn detects_korean() {
+    assert_eq!(detect_language("서울은 한국의 수도입니다."), Language::Ko);
+    // Korean sentence with English terms and hanja
+    assert_eq!(detect_language("Rust로 웹 앱을 만든다"), Language::Ko);
+    assert_eq!(detect_language("大韓民國의 수도는 서울이다"), Language::Ko);
+    // Compatibility jamo (e.g. "ㅋㅋㅋ")
+    assert_eq!(detect_language("ㅋㅋㅋ"), Language::Ko);
+  }
+
+  #[test]
+  fn text_without_letters_is_english() {
+    assert_eq!(detect_language(""), Language::En);
+    assert_eq!(detect_language("2024-01-01 12:00 !?"), Language::En);
+  }
+}
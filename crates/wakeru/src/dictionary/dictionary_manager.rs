@@ -4,14 +4,38 @@
 //! Automatically downloads on the first run, and loads from the cache directory from the second time onwards.
 //! Preset dictionaries include IPADIC, UniDic, etc.
 //! It is also possible to load a local dictionary directly.
+//! A user dictionary CSV can be merged into a preset dictionary at load time.
+//! Load phases can be observed with a progress callback, as the first download can take long.
 
 use crate::errors::error_definition::DictionaryError;
 use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, Read};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, OnceLock};
 use vibrato_rkyv::Dictionary;
 use vibrato_rkyv::dictionary::LoadMode;
 use vibrato_rkyv::dictionary::PresetDictionaryKind;
+use vibrato_rkyv::dictionary::{ArchivedDictionaryInner, DictionaryInner};
+
+/// Phase of [`DictionaryManager::load`], reported to the progress callback
+///
+/// vibrato-rkyv reports neither downloaded bytes nor a separate verification step,
+/// so only coarse phases are available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DownloadProgress {
+  /// Downloading the preset archive and verifying its SHA-256 hash
+  /// (returns immediately when the preset is already cached; presets only)
+  Downloading,
+  /// Decompressing, validating and loading the dictionary file
+  Loading,
+  /// Dictionary is loaded (including the user dictionary, if any)
+  Loaded,
+}
+
+/// Progress callback shared by the manager
+type ProgressCallback = Arc<dyn Fn(DownloadProgress) + Send + Sync>;
 
 /// Dictionary manager structure for vibrato-rkyv
 pub struct DictionaryManager {
@@ -25,6 +49,12 @@ pub struct DictionaryManager {
   /// Dictionary file path (Required when setting a local dictionary, unnecessary for preset dictionaries `None`)
   dictionary_path: Option<PathBuf>,
 
+  /// User dictionary CSV merged into the loaded dictionary (`None` if not used)
+  user_dict_path: Option<PathBuf>,
+
+  /// Called at each phase of the first load (`None` if not set)
+  progress: Option<ProgressCallback>,
+
   /// Cache of loaded dictionary (Initialized only once at the first load)
   /// Held in Arc for sharing
   /// DictionaryError implements Clone so it can hold Result
@@ -42,12 +72,106 @@ impl DictionaryManager {
   pub fn with_preset(preset_kind: PresetDictionaryKind) -> Result<Self, DictionaryError> {
     let cache_dir = default_cache_dir()?;
 
-    Ok(Self {
+    Ok(Self::with_preset_and_cache_dir(preset_kind, cache_dir))
+  }
+
+  /// Returns the directory the preset dictionary is cached in (`cache_dir/<preset name>`)
+  ///
+  /// `None` for local dictionaries.
+  pub fn preset_dir(&self) -> Option<PathBuf> {
+    self.preset_kind.map(|kind| self.cache_dir.join(kind.name()))
+  }
+
+  /// Constructor for DictionaryManager using a preset dictionary cached in `cache_dir`
+  ///
+  /// The preset is downloaded to / loaded from `cache_dir/<preset name>` instead of the
+  /// OS default cache directory (e.g. a fixture directory in tests). Each preset has its
+  /// own subdirectory, so managers of different presets can share `cache_dir`, and
+  /// managers with different `cache_dir`s never share files (e.g. A/B testing IPADIC
+  /// against UniDic with separate caches).
+  pub fn with_preset_and_cache_dir<P: AsRef<Path>>(
+    preset_kind: PresetDictionaryKind,
+    cache_dir: P,
+  ) -> Self {
+    let cache_dir = cache_dir.as_ref().to_path_buf();
+
+    Self {
       cache_dir,
       preset_kind: Some(preset_kind),
       dictionary_path: None, // Dictionary path is not needed when using a preset dictionary
+      user_dict_path: None,
+      progress: None,
       dictionary: OnceLock::new(), // New load
-    })
+    }
+  }
+
+  /// Constructor for DictionaryManager using a preset dictionary and a progress callback
+  ///
+  /// `progress` is called at each [`DownloadProgress`] phase of the first
+  /// [`load`](Self::load), e.g. to log startup progress during a long download.
+  pub fn with_preset_and_progress(
+    preset_kind: PresetDictionaryKind,
+    progress: impl Fn(DownloadProgress) + Send + Sync + 'static,
+  ) -> Result<Self, DictionaryError> {
+    Ok(Self::with_preset(preset_kind)?.with_progress(progress))
+  }
+
+  /// Sets the callback called at each phase of the first [`load`](Self::load) (builder)
+  pub fn with_progress(
+    mut self,
+    progress: impl Fn(DownloadProgress) + Send + Sync + 'static,
+  ) -> Self {
+    self.progress = Some(Arc::new(progress));
+    self
+  }
+
+  /// Constructor for DictionaryManager using a preset dictionary and a user dictionary CSV
+  ///
+  /// Each CSV line follows the system lexicon format
+  /// (`surface,left_id,right_id,cost,features...`), with connection ids of the preset.
+  /// The entries are merged into the preset dictionary on [`load`](Self::load).
+  ///
+  /// # Errors
+  /// - `DictionaryNotFound` if `user_csv_path` is not an existing file
+  pub fn with_preset_and_user_dict<P: AsRef<Path>>(
+    preset_kind: PresetDictionaryKind,
+    user_csv_path: P,
+  ) -> Result<Self, DictionaryError> {
+    Self::with_preset(preset_kind)?.with_user_dict(user_csv_path)
+  }
+
+  /// Sets the user dictionary CSV merged on [`load`](Self::load) (builder)
+  ///
+  /// # Errors
+  /// - `DictionaryNotFound` if `user_csv_path` is not an existing file
+  pub fn with_user_dict<P: AsRef<Path>>(
+    mut self,
+    user_csv_path: P,
+  ) -> Result<Self, DictionaryError> {
+    let user_csv_path = user_csv_path.as_ref().to_path_buf();
+
+    if !user_csv_path.is_file() {
+      let s = user_csv_path.display().to_string();
+      return Err(DictionaryError::DictionaryNotFound(s));
+    }
+
+    self.user_dict_path = Some(user_csv_path);
+    Ok(self)
+  }
+
+  /// Returns the preset dictionary type (`None` for a local dictionary)
+  pub fn preset_kind(&self) -> Option<PresetDictionaryKind> {
+    self.preset_kind
+  }
+
+  /// Returns the path of the local dictionary file (`None` for a preset dictionary)
+  pub fn dictionary_path(&self) -> Option<&Path> {
+    self.dictionary_path.as_deref()
+  }
+
+  /// Returns the path of the user dictionary CSV (`None` if not used)
+  pub fn user_dict_path(&self) -> Option<&Path> {
+    self.user_dict_path.as_deref()
   }
 
   /// Constructor for DictionaryManager using a local dictionary file
@@ -67,6 +191,8 @@ impl DictionaryManager {
       cache_dir,
       preset_kind: None,
       dictionary_path: Some(path),
+      user_dict_path: None,
+      progress: None,
       dictionary: OnceLock::new(),
     })
   }
@@ -80,12 +206,47 @@ impl DictionaryManager {
     self.dictionary.get_or_init(|| self.load_inner().map(Arc::new)).clone()
   }
 
+  /// Returns the already loaded dictionary without triggering a load
+  ///
+  /// - Returns `Some(Arc<Dictionary>)` if `load()` has already succeeded
+  /// - Returns `None` if `load()` has not been called yet, or if it failed
+  ///
+  /// Intended for advanced use such as building a custom `vibrato_rkyv::Tokenizer`
+  /// (N-best, custom workers, etc.) from the shared dictionary without a second disk load.
+  pub fn dictionary(&self) -> Option<Arc<Dictionary>> {
+    self.dictionary.get().and_then(|result| result.as_ref().ok().cloned())
+  }
+
   /// Internal implementation of dictionary loading
   fn load_inner(&self) -> Result<Dictionary, DictionaryError> {
+    let dict = self.load_system()?;
+
+    let dict = match &self.user_dict_path {
+      Some(path) => {
+        let file = File::open(path)
+          .map_err(|e| DictionaryError::UserDictionaryRead(path.clone(), Arc::new(e)))?;
+        merge_user_dictionary(&dict, BufReader::new(file))?
+      }
+      None => dict,
+    };
+
+    self.report(DownloadProgress::Loaded);
+    Ok(dict)
+  }
+
+  /// Calls the progress callback, if any
+  fn report(&self, progress: DownloadProgress) {
+    if let Some(callback) = &self.progress {
+      callback(progress);
+    }
+  }
+
+  /// Loads the system dictionary (local file or preset)
+  fn load_system(&self) -> Result<Dictionary, DictionaryError> {
     match (&self.dictionary_path, self.preset_kind) {
       /* Match with a tuple of dictionary path and preset dictionary type */
       // Case of local dictionary specification: dictionary path exists, no preset dictionary type
-      (Some(path), _) => Self::load_from_local_path(path),
+      (Some(path), _) => self.load_from_local_path(path),
 
       // Case of preset dictionary specification: no dictionary path, preset dictionary type exists
       (None, Some(preset_kind)) => self.load_from_preset(preset_kind),
@@ -99,7 +260,8 @@ impl DictionaryManager {
   }
 
   /// Loads a dictionary from a local dictionary file
-  fn load_from_local_path(path: &Path) -> Result<Dictionary, DictionaryError> {
+  fn load_from_local_path(&self, path: &Path) -> Result<Dictionary, DictionaryError> {
+    self.report(DownloadProgress::Loading);
     Dictionary::from_path(path, LoadMode::TrustCache)
       .map_err(|e| DictionaryError::VibratoLoad(Arc::new(e)))
   }
@@ -120,11 +282,53 @@ impl DictionaryManager {
     let dict_dir = self.cache_dir.join(preset_kind.name());
 
     // Download for the first time, load from cache from the second time onwards
-    Dictionary::from_preset_with_download(preset_kind, &dict_dir)
-      .map_err(|e| DictionaryError::PresetDictDownloadFailed(Arc::new(e)))
+    // (same steps as `Dictionary::from_preset_with_download`, split to report progress)
+    self.report(DownloadProgress::Downloading);
+    let zstd_path = Dictionary::download_dictionary(preset_kind, &dict_dir)
+      .map_err(|e| DictionaryError::PresetDictDownloadFailed(Arc::new(e)))?;
+
+    self.report(DownloadProgress::Loading);
+    Dictionary::from_zstd_with_options(zstd_path, &dict_dir)
+      .map_err(|e| DictionaryError::VibratoLoad(Arc::new(e)))
   }
 }
 
+/// Merges user dictionary CSV entries into `dict`
+///
+/// Preset and local dictionaries are loaded as zero-copy archives, which are read-only.
+/// The archive is therefore deserialized into an owned dictionary first, then the user
+/// lexicon is attached. This costs one copy of the dictionary in memory.
+fn merge_user_dictionary<R: Read>(
+  dict: &Dictionary,
+  user_csv: R,
+) -> Result<Dictionary, DictionaryError> {
+  let inner = match dict {
+    Dictionary::Archived(archived) => deserialize_inner(archived)?,
+    Dictionary::Owned { dict, .. } => {
+      // Round-trip through the archive format, as DictionaryInner is not Clone
+      let mut bytes = Vec::new();
+      dict.write(&mut bytes).map_err(|e| DictionaryError::UserDictionaryMerge(Arc::new(e)))?;
+      let archived = Dictionary::read(bytes.as_slice())
+        .map_err(|e| DictionaryError::UserDictionaryMerge(Arc::new(e)))?;
+      return merge_user_dictionary(&archived, user_csv);
+    }
+  };
+
+  let inner = inner
+    .reset_user_lexicon_from_reader(Some(user_csv))
+    .map_err(|e| DictionaryError::UserDictionaryMerge(Arc::new(e)))?;
+
+  Ok(Dictionary::from_inner(inner))
+}
+
+/// Deserializes an archived dictionary into an owned `DictionaryInner`
+fn deserialize_inner(
+  archived: &ArchivedDictionaryInner,
+) -> Result<DictionaryInner, DictionaryError> {
+  rkyv::deserialize::<DictionaryInner, rkyv::rancor::Error>(archived)
+    .map_err(|e| DictionaryError::UserDictionaryMerge(Arc::new(e)))
+}
+
 /// Returns the default cache directory path according to the OS
 ///
 /// | OS      | Example Path                              |
@@ -148,9 +352,147 @@ impl fmt::Debug for DictionaryManager {
       .field("cache_dir", &self.cache_dir)
       .field("preset_kind", &self.preset_kind)
       .field("dictionary_path", &self.dictionary_path)
+      .field("user_dict_path", &self.user_dict_path)
+      .field("progress", &self.progress.is_some())
       // The inner Dictionary is defined in vibrato_rkyv,
       // and since the Debug trait is not implemented, show only the initialized flag
       .field("dictionary_initialized", &self.dictionary.get().is_some())
       .finish()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use vibrato_rkyv::Tokenizer;
+
+  /// Minimal in-memory system dictionary (no download required)
+  fn build_system_inner() -> DictionaryInner {
+    let lexicon_csv = "東京,0,0,1,名詞,固有名詞,地域,一般,*,*,東京,トウキョウ,トーキョー
+スカイ,0,0,1,名詞,一般,*,*,*,*,スカイ,スカイ,スカイ
+ツリー,0,0,1,名詞,一般,*,*,*,*,ツリー,ツリー,ツリー";
+    crate::dictionary::in_memory_dictionary(lexicon_csv)
+  }
+
+  /// Same dictionary, loaded as a zero-copy archive like preset dictionaries
+  fn build_archived_dictionary() -> Dictionary {
+    let mut bytes = Vec::new();
+    build_system_inner().write(&mut bytes).unwrap();
+    Dictionary::read(bytes.as_slice()).unwrap()
+  }
+
+  fn surfaces(dict: Dictionary, text: &str) -> Vec<String> {
+    let tokenizer = Tokenizer::new(dict);
+    let mut worker = tokenizer.new_worker();
+    worker.reset_sentence(text);
+    worker.tokenize();
+    worker.token_iter().map(|t| t.surface().to_string()).collect()
+  }
+
+  const USER_CSV: &str = "東京スカイツリー,0,0,-100,名詞,固有名詞,一般,*,*,*,東京スカイツリー,トウキョウスカイツリー,トーキョースカイツリー\n";
+
+  #[test]
+  fn preset_kind_round_trips() {
+    let cache_dir = tempfile::TempDir::new().unwrap();
+    for kind in [
+      PresetDictionaryKind::Ipadic,
+      PresetDictionaryKind::UnidicCwj,
+      PresetDictionaryKind::UnidicCsj,
+    ] {
+      let manager = DictionaryManager::with_preset_and_cache_dir(kind, cache_dir.path());
+      assert_eq!(manager.preset_kind(), Some(kind));
+      assert_eq!(manager.dictionary_path(), None);
+    }
+  }
+
+  #[test]
+  fn preset_caches_do_not_collide() {
+    let root_a = tempfile::TempDir::new().unwrap();
+    let root_b = tempfile::TempDir::new().unwrap();
+
+    // Same preset in different roots
+    let ipadic_a =
+      DictionaryManager::with_preset_and_cache_dir(PresetDictionaryKind::Ipadic, root_a.path());
+    let ipadic_b =
+      DictionaryManager::with_preset_and_cache_dir(PresetDictionaryKind::Ipadic, root_b.path());
+    assert_eq!(ipadic_a.cache_dir(), root_a.path());
+    assert_eq!(ipadic_b.cache_dir(), root_b.path());
+    assert_ne!(ipadic_a.preset_dir(), ipadic_b.preset_dir());
+
+    // Different presets side by side in the same root
+    let unidic_a =
+      DictionaryManager::with_preset_and_cache_dir(PresetDictionaryKind::UnidicCwj, root_a.path());
+    let (ipadic_dir, unidic_dir) = (
+      ipadic_a.preset_dir().unwrap(),
+      unidic_a.preset_dir().unwrap(),
+    );
+    assert_ne!(ipadic_dir, unidic_dir);
+    assert!(ipadic_dir.starts_with(root_a.path()) && unidic_dir.starts_with(root_a.path()));
+  }
+
+  #[test]
+  fn local_dictionary_has_no_preset_kind() {
+    let dict_file = tempfile::NamedTempFile::new().unwrap();
+    let manager = DictionaryManager::from_local_path(dict_file.path()).unwrap();
+    assert_eq!(manager.preset_kind(), None);
+    assert_eq!(manager.dictionary_path(), Some(dict_file.path()));
+    assert_eq!(manager.preset_dir(), None);
+  }
+
+  #[test]
+  fn progress_reports_local_dictionary_phases() {
+    let mut dict_file = tempfile::NamedTempFile::new().unwrap();
+    build_system_inner().write(&mut dict_file).unwrap();
+
+    let phases = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&phases);
+    let manager = DictionaryManager::from_local_path(dict_file.path())
+      .unwrap()
+      .with_progress(move |progress| recorded.lock().unwrap().push(progress));
+
+    manager.load().expect("Failed to load local dictionary");
+    // The cached dictionary is returned without reporting again
+    manager.load().expect("Failed to load local dictionary");
+
+    assert_eq!(
+      *phases.lock().unwrap(),
+      [DownloadProgress::Loading, DownloadProgress::Loaded]
+    );
+  }
+
+  #[test]
+  fn merge_user_dictionary_into_archived_dictionary() {
+    let dict = build_archived_dictionary();
+    assert!(matches!(dict, Dictionary::Archived(_)));
+    assert_eq!(
+      surfaces(build_archived_dictionary(), "東京スカイツリー"),
+      vec!["東京", "スカイ", "ツリー"]
+    );
+
+    let merged = merge_user_dictionary(&dict, USER_CSV.as_bytes()).unwrap();
+    assert_eq!(
+      surfaces(merged, "東京スカイツリー"),
+      vec!["東京スカイツリー"]
+    );
+  }
+
+  #[test]
+  fn merge_user_dictionary_into_owned_dictionary() {
+    let dict = Dictionary::from_inner(build_system_inner());
+
+    let merged = merge_user_dictionary(&dict, USER_CSV.as_bytes()).unwrap();
+    assert_eq!(
+      surfaces(merged, "東京スカイツリー"),
+      vec!["東京スカイツリー"]
+    );
+  }
+
+  #[test]
+  fn merge_user_dictionary_rejects_invalid_connection_ids() {
+    let dict = build_archived_dictionary();
+    let invalid_csv = "東京スカイツリー,9999,9999,-100,名詞,固有名詞,一般,*,*,*\n";
+
+    let err = merge_user_dictionary(&dict, invalid_csv.as_bytes()).err().unwrap();
+    assert!(matches!(err, DictionaryError::UserDictionaryMerge(_)));
+  }
+}
@@ -2,4 +2,18 @@
 pub mod dictionary_manager;
 
 /// Re-exports
-pub use dictionary_manager::DictionaryManager;
+pub use dictionary_manager::{DictionaryManager, DownloadProgress};
+
+/// Builds a minimal in-memory system dictionary from `lexicon_csv` (no download required)
+///
+/// All entries share a single connection id and unknown words get the bare `*` feature.
+#[cfg(test)]
+pub(crate) fn in_memory_dictionary(lexicon_csv: &str) -> vibrato_rkyv::dictionary::DictionaryInner {
+  vibrato_rkyv::SystemDictionaryBuilder::from_readers(
+    lexicon_csv.as_bytes(),
+    "1 1\n0 0 0".as_bytes(),
+    "DEFAULT 0 1 0".as_bytes(),
+    "DEFAULT,0,0,100,*".as_bytes(),
+  )
+  .expect("Failed to build test dictionary")
+}
@@ -12,6 +12,35 @@ use crate::config::Language;
 #[derive(Debug, Error, Clone)]
 #[non_exhaustive]
 pub enum ConfigError {
+  /// Failed to read the configuration file
+  #[error("Failed to read configuration file: path={path:?}, error={source}")]
+  ReadFile {
+    /// Path of the configuration file
+    path: PathBuf,
+    /// Original IO error
+    #[source]
+    source: Arc<io::Error>,
+  },
+
+  /// Failed to parse the configuration file as TOML
+  #[error("Failed to parse TOML configuration: {source}")]
+  TomlParse {
+    /// Original TOML parse error
+    #[source]
+    source: Arc<toml::de::Error>,
+  },
+
+  /// Environment variable override has an unknown value
+  #[error("Invalid {name}: {value:?}. Valid values: {expected}")]
+  InvalidEnvValue {
+    /// Environment variable name
+    name: &'static str,
+    /// Specified value
+    value: String,
+    /// Description of valid values
+    expected: &'static str,
+  },
+
   /// index.languages is empty
   #[error("Please specify at least one language in languages")]
   EmptyLanguages,
@@ -62,6 +91,62 @@ pub enum ConfigError {
     actual: usize,
   },
 
+  /// index.min_token_len < 1
+  #[error("index.min_token_len must be 1 or greater: actual={actual}")]
+  InvalidMinTokenLen {
+    /// Actually specified value
+    actual: usize,
+  },
+
+  /// index.min_gram / index.max_gram do not satisfy 1 <= min_gram <= max_gram
+  #[error(
+    "index.min_gram and index.max_gram must satisfy 1 <= min_gram <= max_gram: min_gram={min_gram}, max_gram={max_gram}"
+  )]
+  InvalidNgramRange {
+    /// Specified min_gram
+    min_gram: usize,
+    /// Specified max_gram
+    max_gram: usize,
+  },
+
+  /// index.stemmer_language is not a Tantivy stemmer language
+  #[error(
+    "index.stemmer_language is not supported: {value:?}. Valid values: arabic, danish, dutch, \
+     english, finnish, french, german, greek, hungarian, italian, norwegian, portuguese, \
+     romanian, russian, spanish, swedish, tamil, turkish"
+  )]
+  InvalidStemmerLanguage {
+    /// Specified value
+    value: String,
+  },
+
+  /// index.japanese_reading_field is set with index.tokenizer_mode = "cjk-bigram"
+  #[error("index.japanese_reading_field requires index.tokenizer_mode = \"morphological\"")]
+  ReadingFieldRequiresDictionary,
+
+  /// search.overlap_penalty.factor is out of range
+  #[error("search.overlap_penalty.factor must be in the range of (0.0, 1.0]: actual={actual}")]
+  InvalidOverlapPenaltyFactor {
+    /// Actually specified value
+    actual: f32,
+  },
+
+  /// search.overlap_penalty.min_overlap is out of range
+  #[error("search.overlap_penalty.min_overlap must be in the range of [0.0, 1.0]: actual={actual}")]
+  InvalidOverlapPenaltyMinOverlap {
+    /// Actually specified value
+    actual: f32,
+  },
+
+  /// search.bm25.k1 or search.bm25.b is out of range
+  #[error("search.bm25 requires k1 >= 0.0 and 0.0 <= b <= 1.0: k1={k1}, b={b}")]
+  InvalidBm25Params {
+    /// Specified k1
+    k1: f32,
+    /// Specified b
+    b: f32,
+  },
+
   /// dictionary.cache_dir is not an "existing directory" (e.g. it is a file)
   #[error("dictionary.cache_dir is not a directory: path={path:?}")]
   InvalidDictionaryCacheDir {
@@ -78,6 +163,13 @@ pub enum ConfigError {
     #[source]
     source: Arc<io::Error>,
   },
+
+  /// dictionary.user_dict is not an existing file
+  #[error("dictionary.user_dict is not a file: path={path:?}")]
+  InvalidUserDictionary {
+    /// Invalid path
+    path: PathBuf,
+  },
 }
 
 /// Dictionary related errors
@@ -121,6 +213,14 @@ pub enum DictionaryError {
   /// Failed to download preset dictionary by vibrato-rkyv
   #[error("vibrato-rkyv preset dictionary download failed: {0}")]
   PresetDictDownloadFailed(Arc<dyn std::error::Error + Send + Sync + 'static>),
+
+  /// Failed to read user dictionary CSV
+  #[error("Failed to read user dictionary {0:?}: {1}")]
+  UserDictionaryRead(PathBuf, Arc<io::Error>),
+
+  /// Failed to merge user dictionary entries into the system dictionary
+  #[error("Failed to merge user dictionary: {0}")]
+  UserDictionaryMerge(Arc<dyn std::error::Error + Send + Sync + 'static>),
 }
 
 /// Tokenizer related errors
@@ -169,10 +269,26 @@ pub enum IndexerError {
   #[error("Index not found: {0}")]
   IndexNotFound(PathBuf),
 
+  /// Write operation on an index opened with `IndexManager::open_read_only`
+  #[error("Index is opened read-only")]
+  ReadOnlyIndex,
+
+  /// Non-blocking write operation found the writer lock held by another call
+  #[error("Index writer is busy")]
+  WriterBusy,
+
   /// Japanese tokenizer is not provided
   #[error("VibratoTokenizer is required for Japanese index")]
   MissingJapaneseTokenizer,
 
+  /// Reading tokenizer is not provided for a Japanese index with a `text_reading` field
+  #[error("Reading tokenizer is required for Japanese index with text_reading field")]
+  MissingReadingTokenizer,
+
+  /// `IndexOptions::text_tokenizer_name` is the tokenizer name of another field
+  #[error("Tokenizer name is reserved for another field: {0}")]
+  ReservedTokenizerName(String),
+
   /// Mismatch between schema and language
   #[error("Schema and language mismatch: expected={expected}, actual={actual}")]
   LanguageSchemaMismatch {
@@ -182,6 +298,51 @@ pub enum IndexerError {
     actual: String,
   },
 
+  /// POS filter differs from the one the index was created with
+  #[error("POS filter mismatch: expected={expected}, actual={actual}")]
+  FilterMismatch {
+    /// POS filter recorded in the index (`wakeru_meta.json`)
+    expected: String,
+    /// POS filter passed in `IndexOptions`
+    actual: String,
+  },
+
+  /// `min_gram` / `max_gram` differ from the N-gram range the index was created with
+  #[error("N-gram range mismatch: expected={expected}, actual={actual} (reindex required)")]
+  NgramRangeMismatch {
+    /// N-gram range recorded in the index (`wakeru_meta.json`)
+    expected: String,
+    /// N-gram range passed in `IndexOptions`
+    actual: String,
+  },
+
+  /// `store_compression` differs from the compression the index was created with
+  #[error("Store compression mismatch: expected={expected}, actual={actual} (reindex required)")]
+  StoreCompressionMismatch {
+    /// Compression recorded in the index (`meta.json`)
+    expected: String,
+    /// Compression passed in `IndexOptions`
+    actual: String,
+  },
+
+  /// Index was created with another schema version and cannot be migrated
+  #[error("Schema version mismatch: found={found}, expected={expected} (reindex required)")]
+  SchemaVersionMismatch {
+    /// Schema version recorded in the index (`wakeru_meta.json`)
+    found: u32,
+    /// Schema version of this version of wakeru
+    expected: u32,
+  },
+
+  /// `wakeru_meta.json` could not be read or written
+  #[error("Invalid index meta file: path={path:?}, reason={reason}")]
+  InvalidIndexMeta {
+    /// Path of `wakeru_meta.json`
+    path: PathBuf,
+    /// Reason for failure
+    reason: String,
+  },
+
   /// Metadata JSON serialization failed
   #[error("Failed to serialize metadata: doc_id={doc_id}, error={source}")]
   MetadataSerialize {
@@ -191,6 +352,19 @@ pub enum IndexerError {
     #[source]
     source: Arc<serde_json::Error>,
   },
+
+  /// Failed to read import input
+  #[error("Failed to read input: {0}")]
+  ReadInput(#[source] Arc<io::Error>),
+
+  /// Document failed validation (atomic indexing aborted)
+  #[error("Invalid document: doc_id={doc_id}, reason={reason}")]
+  InvalidDocument {
+    /// Offending document ID
+    doc_id: String,
+    /// Reason for invalidity
+    reason: String,
+  },
 }
 
 /// Search related errors
@@ -217,6 +391,31 @@ pub enum SearcherError {
     reason: String,
   },
 
+  /// Phrase search on a field indexed without term positions
+  #[error(
+    "Phrase search unavailable: field={field} was indexed without positions (text_record_option = \"freqs-only\")"
+  )]
+  PositionsNotIndexed {
+    /// Field name without positions
+    field: String,
+  },
+
+  /// BM25 parameters out of range
+  #[error("Invalid BM25 parameters (k1 >= 0.0, 0.0 <= b <= 1.0): k1={k1}, b={b}")]
+  InvalidBm25Params {
+    /// Specified k1
+    k1: f32,
+    /// Specified b
+    b: f32,
+  },
+
+  /// No committed document has the requested ID
+  #[error("Document not found: doc_id={doc_id}")]
+  DocumentNotFound {
+    /// Requested document ID
+    doc_id: String,
+  },
+
   /// Metadata JSON deserialization failed
   #[error("Failed to deserialize metadata: doc_id={doc_id}, error={source}")]
   MetadataDeserialize {
@@ -260,6 +459,13 @@ pub enum WakeruError {
   /// Configuration error
   #[error(transparent)]
   Config(#[from] ConfigError),
+
+  /// Blocking task of `AsyncWakeruService` panicked or was cancelled
+  #[error("Blocking task failed: {reason}")]
+  BlockingTask {
+    /// Reason for failure
+    reason: String,
+  },
 }
 
 /// Standard Result type alias for wakeru crate
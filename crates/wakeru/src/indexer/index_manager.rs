@@ -3,22 +3,42 @@
 //! Responsible for index creation, management, and document addition.
 //! Supports Language argument and language-specific tokenizer registration for multi-language support.
 
+use std::borrow::Cow;
 use std::collections::{BTreeMap, HashSet};
+use std::io::BufRead;
 use std::path::Path;
-use std::sync::Arc;
-
-use tantivy::schema::{FieldType, OwnedValue};
-use tantivy::tokenizer::{LowerCaser, NgramTokenizer, SimpleTokenizer, Stemmer, TextAnalyzer};
-use tantivy::{Index, IndexReader, IndexWriter, Term};
-
-use crate::config::Language;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, TryLockError};
+
+use tantivy::collector::Count;
+use tantivy::query::TermQuery;
+use tantivy::schema::{FieldType, IndexRecordOption, OwnedValue, Value};
+use tantivy::store::Compressor;
+use tantivy::tokenizer::{
+  LowerCaser, NgramTokenizer, RawTokenizer, SimpleTokenizer, Stemmer, StopWordFilter, TextAnalyzer,
+};
+use tantivy::{DocAddress, Index, IndexReader, IndexSettings, IndexWriter, Searcher, Term};
+use tracing::{Span, error, field, info_span, warn};
+use vibrato_rkyv::Dictionary;
+
+use crate::config::{Language, OpenMode, TokenizerMode};
 use crate::errors::IndexerError;
+use crate::indexer::index_meta::{NgramRange, SCHEMA_VERSION, WakeruMeta};
+use crate::indexer::options::IndexOptions;
 use crate::indexer::report::AddDocumentsReport;
-use crate::indexer::schema_builder::{SchemaFields, build_schema};
-use crate::models::Document;
+use crate::indexer::schema_builder::{
+  RESERVED_TOKENIZER_NAMES, SOURCE_ID_LOWERCASE_TOKENIZER, SchemaFields, build_schema_with_options,
+  field_analyzer, source_id_term, tag_term,
+};
+use crate::indexer::stats::{IndexStats, SegmentInfo};
+use crate::models::model_definition::TAGS_KEY;
+use crate::models::{Document, Metadata};
+use crate::searcher::bm25_searcher::compact_value_to_json;
+use crate::span::timed;
+use crate::tokenizer::{CjkBigramTokenizer, MinLengthFilter, PosFilter, VibratoTokenizer};
 
 /// Meta file name used to determine index existence
-const META_JSON: &str = "meta.json";
+pub(crate) const META_JSON: &str = "meta.json";
 
 // ─────────────────────────────────────────────────────────────────────────────
 // JSON Conversion Helper Functions
@@ -64,6 +84,108 @@ fn metadata_to_tantivy_object(metadata: &crate::models::Metadata) -> BTreeMap<St
   metadata.iter().map(|(k, v)| (k.clone(), serde_json_to_owned(v))).collect()
 }
 
+/// Whether `value` is a JSON array of strings (the shape `restore_tags` rebuilds)
+fn is_string_array(value: Option<&serde_json::Value>) -> bool {
+  value
+    .and_then(|value| value.as_array())
+    .is_some_and(|values| values.iter().all(serde_json::Value::is_string))
+}
+
+/// Merges the stored `tags` field values back into `metadata[TAGS_KEY]`
+///
+/// Documents added with `tags_in_metadata = false` keep their tags only in the `tags`
+/// field; this restores them so `Document::tags()` / `SearchResult::tags()` still work.
+/// Metadata that already has `TAGS_KEY` is left unchanged.
+pub(crate) fn restore_tags(
+  doc: &tantivy::TantivyDocument,
+  fields: &SchemaFields,
+  metadata: &mut Metadata,
+) {
+  let Some(tags_field) = fields.tags else {
+    return;
+  };
+  if metadata.contains_key(TAGS_KEY) {
+    return;
+  }
+  let tags: Vec<serde_json::Value> = doc
+    .get_all(tags_field)
+    .filter_map(|value| value.as_str())
+    .map(|tag| serde_json::Value::String(tag.to_string()))
+    .collect();
+  if !tags.is_empty() {
+    metadata.insert(TAGS_KEY.to_string(), serde_json::Value::Array(tags));
+  }
+}
+
+/// Reads all alive documents stored in the index visible to `searcher`.
+///
+/// Restores `Document` from stored fields (`id`, `source_id`, `text`, `metadata`),
+/// so it works without any tokenizer being registered.
+///
+/// # Errors
+/// - Failed to read the document store
+/// - Required stored field (`id` / `source_id`) is missing
+pub(crate) fn read_stored_documents(
+  searcher: &Searcher,
+  fields: &SchemaFields,
+) -> Result<Vec<Document>, IndexerError> {
+  stored_documents(searcher.clone(), *fields).collect()
+}
+
+/// Lazily restores all alive documents visible to `searcher` (see [`read_stored_documents`])
+///
+/// Only the addresses of alive documents are collected up front; each document is
+/// read from the document store when the iterator reaches it.
+fn stored_documents(
+  searcher: Searcher,
+  fields: SchemaFields,
+) -> impl Iterator<Item = Result<Document, IndexerError>> {
+  let addresses: Vec<DocAddress> = searcher
+    .segment_readers()
+    .iter()
+    .enumerate()
+    .flat_map(|(segment_ord, segment_reader)| {
+      segment_reader.doc_ids_alive().map(move |doc_id| DocAddress::new(segment_ord as u32, doc_id))
+    })
+    .collect();
+
+  addresses.into_iter().map(move |address| read_stored_document(&searcher, &fields, address))
+}
+
+/// Restores one `Document` from its stored fields
+fn read_stored_document(
+  searcher: &Searcher,
+  fields: &SchemaFields,
+  address: DocAddress,
+) -> Result<Document, IndexerError> {
+  let doc: tantivy::TantivyDocument = searcher.doc(address)?;
+
+  let get_text = |field| doc.get_first(field).and_then(|v| v.as_str()).map(String::from);
+  let missing = |name: &str| {
+    IndexerError::Tantivy(tantivy::TantivyError::InvalidArgument(format!(
+      "Stored field '{name}' not found"
+    )))
+  };
+
+  let id = get_text(fields.id).ok_or_else(|| missing("id"))?;
+  let source_id = get_text(fields.source_id).ok_or_else(|| missing("source_id"))?;
+  let text = get_text(fields.text).unwrap_or_default();
+
+  let mut metadata: Metadata = doc
+    .get_first(fields.metadata)
+    .and_then(|value| value.as_object())
+    .map(|iter| iter.map(|(k, v)| (k.to_string(), compact_value_to_json(&v))).collect())
+    .unwrap_or_default();
+  restore_tags(&doc, fields, &mut metadata);
+
+  Ok(Document {
+    id,
+    source_id,
+    text,
+    metadata,
+  })
+}
+
 /// Structure for Tantivy index creation and management.
 ///
 /// # Responsibilities
@@ -77,6 +199,23 @@ fn metadata_to_tantivy_object(metadata: &crate::models::Metadata) -> BTreeMap<St
 ///
 /// - Japanese (`Language::Ja`): VibratoTokenizer + N-gram Tokenizer
 /// - English (`Language::En`): SimpleTokenizer + LowerCaser
+/// - Korean (`Language::Ko`): SimpleTokenizer + LowerCaser + N-gram Tokenizer
+///
+/// # Writer lifecycle
+///
+/// A single `IndexWriter` is kept for the lifetime of the manager (it holds the index lock).
+/// `add_documents` only stages documents (committing automatically every `batch_commit_size`
+/// staged documents to bound memory); call [`IndexManager::commit`] to persist the rest
+/// and make them searchable. Uncommitted documents are discarded when the manager is dropped.
+///
+/// A manager opened with [`IndexManager::open_read_only`] has no writer (and takes no lock);
+/// write operations return `IndexerError::ReadOnlyIndex`.
+///
+/// # Reader freshness
+///
+/// The manager's own reader (used for duplicate checks) is reloaded on every commit.
+/// A `SearchEngine` has its own reader; pass it [`IndexManager::commit_counter`]
+/// (`SearchEngine::with_commit_counter`) so it reloads lazily after this manager commits.
 pub struct IndexManager {
   /// Tantivy Index handle
   index: Index,
@@ -84,11 +223,52 @@ pub struct IndexManager {
   /// IndexReader (for searching)
   reader: IndexReader,
 
+  /// Long-lived IndexWriter (serializes concurrent writes, `None` if read-only)
+  writer: Option<Mutex<WriterState>>,
+
+  /// Memory buffer size (bytes) the writer was created with (`None` if read-only)
+  writer_memory_bytes: Option<usize>,
+
+  /// Number of staged documents that triggers an automatic commit
+  batch_commit_size: usize,
+
+  /// Keep tags in the `metadata` JSON besides the `tags` field (`options.tags_in_metadata`)
+  tags_in_metadata: bool,
+
   /// Schema fields reference
   fields: SchemaFields,
 
   /// Language of this index
   language: Language,
+
+  /// Number of commits made through this manager (shared with paired `SearchEngine`s)
+  commit_counter: CommitCounter,
+}
+
+/// Shared count of the commits made through an [`IndexManager`]
+///
+/// Cheap to clone: all clones observe the same count. A `SearchEngine` holding one
+/// compares it to the count it last reloaded at, and reloads its reader when stale.
+#[derive(Debug, Clone, Default)]
+pub struct CommitCounter(Arc<AtomicU64>);
+
+impl CommitCounter {
+  /// Current number of commits
+  pub fn get(&self) -> u64 {
+    self.0.load(Ordering::Acquire)
+  }
+
+  /// Records a commit
+  fn increment(&self) {
+    self.0.fetch_add(1, Ordering::AcqRel);
+  }
+}
+
+/// IndexWriter and IDs staged since the last commit
+struct WriterState {
+  writer: IndexWriter,
+  /// IDs added but not yet committed (invisible to the reader)
+  pending_ids: HashSet<String>,
 }
 
 impl std::fmt::Debug for IndexManager {
@@ -96,6 +276,7 @@ impl std::fmt::Debug for IndexManager {
     f.debug_struct("IndexManager")
       .field("language", &self.language)
       .field("fields", &self.fields)
+      .field("read_only", &self.is_read_only())
       .finish_non_exhaustive()
   }
 }
@@ -116,7 +297,7 @@ impl IndexManager {
   ///
   /// # Design Notes
   ///
-  /// - **New creation**: Build schema with `build_schema(language)`
+  /// - **New creation**: Build schema with `build_schema_with_options(language, &options)`
   /// - **Opening existing index**: Reconstruct with `SchemaFields::from_schema(&schema)`
   /// - **Loose coupling**: `tokenizer_ja` is `Option<TextAnalyzer>` and does not depend on VibratoTokenizer
   pub fn open_or_create<P: AsRef<Path>>(
@@ -124,13 +305,164 @@ impl IndexManager {
     language: Language,
     tokenizer_ja: Option<TextAnalyzer>,
   ) -> Result<Self, IndexerError> {
-    let index_path = index_path.as_ref();
+    Self::open_or_create_with_options(index_path, language, tokenizer_ja, IndexOptions::default())
+  }
+
+  /// Opens an index with the given options. Creates a new one if it does not exist.
+  ///
+  /// Same as [`IndexManager::open_or_create`], plus:
+  /// - `options.min_token_len`: English tokens shorter than this are dropped (`1` = no filtering).
+  ///   Part of the registered analyzer, so it applies to both indexing and query tokenization.
+  ///   Ignored for Japanese.
+  /// - `options.min_gram` / `options.max_gram`: N-gram range of the Japanese `ja_ngram`
+  ///   and Korean `ko_ngram` analyzers. Recorded in `wakeru_meta.json` when such an index
+  ///   is created and checked when it is reopened. Ignored for English.
+  /// - `options.store_compression`: Doc store compression of a newly created index.
+  ///   Must match the compression recorded in the `meta.json` of an existing index.
+  /// - `options.tokenizer_mode`: With `TokenizerMode::CjkBigram`, Japanese indexes use the
+  ///   dictionary-free `CjkBigramTokenizer`, so `tokenizer_ja` is not required.
+  /// - `options.pos_filter`: Not applied to `tokenizer_ja`, whose filter is set when it is
+  ///   built, and neither recorded nor checked (see
+  ///   [`IndexManager::open_or_create_with_dictionary`]).
+  /// - `options.open_mode`: `OpenExisting` never creates an index and `CreateNew` never
+  ///   opens one (default: `CreateIfMissing`). `ReadOnly` opens like
+  ///   [`IndexManager::open_read_only_with_reading`], without taking the writer lock.
+  ///
+  /// Changing any of these options (except `open_mode`) for an existing index requires
+  /// reindexing.
+  ///
+  /// # Errors
+  /// Same as [`IndexManager::open_or_create`], plus:
+  /// - `IndexerError::NgramRangeMismatch` if `options.min_gram` / `options.max_gram` differ
+  ///   from the recorded range
+  /// - `IndexerError::StoreCompressionMismatch` if `options.store_compression` differs from
+  ///   the compression of an existing index
+  /// - `IndexerError::IndexNotFound` with `OpenMode::OpenExisting` / `OpenMode::ReadOnly`
+  ///   if there is no index
+  /// - `IndexerError::IndexAlreadyExists` with `OpenMode::CreateNew` if an index exists
+  /// - `IndexerError::SchemaVersionMismatch` if an existing index has another schema version
+  /// - `IndexerError::ReservedTokenizerName` if `options.text_tokenizer_name` is the
+  ///   tokenizer name of another field
+  pub fn open_or_create_with_options<P: AsRef<Path>>(
+    index_path: P,
+    language: Language,
+    tokenizer_ja: Option<TextAnalyzer>,
+    options: IndexOptions,
+  ) -> Result<Self, IndexerError> {
+    Self::open_or_create_with_reading(index_path, language, tokenizer_ja, None, options)
+  }
+
+  /// Opens an index with the given options and Japanese reading tokenizer.
+  /// Creates a new one if it does not exist.
+  ///
+  /// Same as [`IndexManager::open_or_create_with_options`], plus:
+  /// - `reading_ja`: Tokenizer emitting katakana readings (e.g. `VibratoTokenizer` with
+  ///   `with_reading_output(true)`), registered for the `text_reading` field.
+  ///   Required when the index has that field (`options.japanese_reading_field`).
+  ///
+  /// # Errors
+  /// Same as [`IndexManager::open_or_create`], plus:
+  /// - Reading tokenizer not provided for a Japanese index with `text_reading`
+  pub fn open_or_create_with_reading<P: AsRef<Path>>(
+    index_path: P,
+    language: Language,
+    tokenizer_ja: Option<TextAnalyzer>,
+    reading_ja: Option<TextAnalyzer>,
+    options: IndexOptions,
+  ) -> Result<Self, IndexerError> {
+    Self::open_or_create_inner(
+      index_path.as_ref(),
+      language,
+      tokenizer_ja,
+      reading_ja,
+      options,
+      None,
+    )
+  }
+
+  /// Opens an index, building the Japanese analyzers from `dict` and `options.pos_filter`.
+  /// Creates a new one if it does not exist.
+  ///
+  /// Unlike the variants taking a prebuilt `tokenizer_ja`, the POS filter of the analyzer
+  /// is known here, so it is recorded in `wakeru_meta.json` when a Japanese morphological
+  /// index is created and checked against the recorded one when it is reopened.
+  /// `options.open_mode` applies as in [`IndexManager::open_or_create_with_options`].
+  ///
+  /// # Arguments
+  /// - `dict`: Japanese dictionary (required for Japanese morphological indexes, ignored
+  ///   otherwise)
+  ///
+  /// # Errors
+  /// Same as [`IndexManager::open_or_create_with_options`], plus:
+  /// - `IndexerError::FilterMismatch` if `options.pos_filter` differs from the recorded filter
+  pub fn open_or_create_with_dictionary<P: AsRef<Path>>(
+    index_path: P,
+    language: Language,
+    dict: Option<Arc<Dictionary>>,
+    options: IndexOptions,
+  ) -> Result<Self, IndexerError> {
+    let pos_filter = options.pos_filter.clone();
+    let (tokenizer_ja, reading_ja) = match dict {
+      Some(dict) if language == Language::Ja => {
+        let tokenizer =
+          VibratoTokenizer::from_shared_dictionary_with_filter(dict, Arc::new(pos_filter.clone()));
+        let reading = TextAnalyzer::from(tokenizer.clone().with_reading_output(true));
+        (Some(TextAnalyzer::from(tokenizer)), Some(reading))
+      }
+      _ => (None, None),
+    };
+
+    Self::open_or_create_inner(
+      index_path.as_ref(),
+      language,
+      tokenizer_ja,
+      reading_ja,
+      options,
+      Some(&pos_filter),
+    )
+  }
+
+  /// Shared body of the `open_or_create_*` variants
+  ///
+  /// `pos_filter` is the POS filter of `tokenizer_ja` if known (`None` for analyzers built
+  /// by the caller, whose filter cannot be inspected).
+  fn open_or_create_inner(
+    index_path: &Path,
+    language: Language,
+    tokenizer_ja: Option<TextAnalyzer>,
+    reading_ja: Option<TextAnalyzer>,
+    options: IndexOptions,
+    pos_filter: Option<&PosFilter>,
+  ) -> Result<Self, IndexerError> {
+    Self::assert_text_tokenizer_name_allowed(&options)?;
+
+    if options.open_mode == OpenMode::ReadOnly {
+      return Self::open_read_only_inner(
+        index_path,
+        language,
+        tokenizer_ja,
+        reading_ja,
+        options,
+        pos_filter,
+      );
+    }
 
     // Determine index existence by meta.json existence
     let meta_json_exists = index_path.join(META_JSON).exists();
 
+    match (options.open_mode, meta_json_exists) {
+      (OpenMode::OpenExisting, false) => {
+        return Err(IndexerError::IndexNotFound(index_path.to_path_buf()));
+      }
+      (OpenMode::CreateNew, true) => {
+        return Err(IndexerError::IndexAlreadyExists(index_path.to_path_buf()));
+      }
+      _ => {}
+    }
+
     let (index, fields) = if meta_json_exists {
-      // Open existing index
+      // Open existing index (after migrating it to the current schema version)
+      Self::assert_schema_version_matches(index_path)?;
       let index = Index::open_in_dir(index_path)?;
       let schema = index.schema();
 
@@ -138,7 +470,18 @@ impl IndexManager {
       let fields = SchemaFields::from_schema(&schema)?;
 
       // Check consistency between schema and language
-      Self::assert_schema_matches_language(&schema, language)?;
+      Self::assert_schema_matches_language(&schema, language, &options)?;
+      Self::assert_pos_filter_matches(index_path, language, &options, pos_filter)?;
+      Self::assert_ngram_range_matches(index_path, language, &options)?;
+
+      // Compression is fixed at creation time (reindex required to change it)
+      let configured_compression = Compressor::from(options.store_compression);
+      if index.settings().docstore_compression != configured_compression {
+        return Err(IndexerError::StoreCompressionMismatch {
+          expected: format!("{:?}", index.settings().docstore_compression),
+          actual: format!("{configured_compression:?}"),
+        });
+      }
 
       (index, fields)
     } else {
@@ -150,53 +493,276 @@ impl IndexManager {
         })?;
       }
       // Use build_schema only when creating new index
-      let (schema, fields) = build_schema(language);
-      let index = Index::create_in_dir(index_path, schema)?;
+      let (schema, fields) = build_schema_with_options(language, &options);
+      let settings = IndexSettings {
+        docstore_compression: options.store_compression.into(),
+        ..IndexSettings::default()
+      };
+      let index = Index::builder().schema(schema).settings(settings).create_in_dir(index_path)?;
+      WakeruMeta {
+        schema_version: SCHEMA_VERSION,
+        pos_filter: Self::pos_filter_for(language, &options, pos_filter),
+        ngram_range: Self::ngram_range_for(language, &options),
+      }
+      .write(index_path)?;
       (index, fields)
     };
 
-    // Register tokenizer according to language
+    Self::register_tokenizers(
+      &index,
+      &fields,
+      language,
+      tokenizer_ja,
+      reading_ja,
+      &options,
+    )?;
+
+    // Create Reader
+    let reader = index.reader()?;
+
+    // Create Writer (acquires the index lock)
+    let writer = index.writer(options.writer_memory_bytes)?;
+
+    Ok(Self {
+      index,
+      reader,
+      writer: Some(Mutex::new(WriterState {
+        writer,
+        pending_ids: HashSet::new(),
+      })),
+      writer_memory_bytes: Some(options.writer_memory_bytes),
+      batch_commit_size: options.batch_commit_size.max(1),
+      tags_in_metadata: options.tags_in_metadata,
+      fields,
+      language,
+      commit_counter: CommitCounter::default(),
+    })
+  }
+
+  /// Opens an existing index for searching only (no writer, no index lock).
+  ///
+  /// Useful for query-only replicas next to a separate indexing process.
+  /// Tokenizers are registered for the fields present in the stored schema.
+  ///
+  /// # Arguments
+  /// - `index_path`: Directory of an existing index
+  /// - `language`: Language of the index
+  /// - `tokenizer_ja`: Japanese tokenizer (Required for Japanese index, ignored for English)
+  ///
+  /// # Errors
+  /// - `IndexerError::IndexNotFound` if no index exists at `index_path`
+  /// - `IndexerError::SchemaVersionMismatch` if the index has another schema version
+  /// - Tantivy index open error
+  /// - Tokenizer not provided for Japanese index
+  /// - Mismatch between existing index and language
+  pub fn open_read_only<P: AsRef<Path>>(
+    index_path: P,
+    language: Language,
+    tokenizer_ja: Option<TextAnalyzer>,
+  ) -> Result<Self, IndexerError> {
+    Self::open_read_only_with_reading(
+      index_path,
+      language,
+      tokenizer_ja,
+      None,
+      IndexOptions::default(),
+    )
+  }
+
+  /// Opens an existing index for searching only, with options and Japanese reading tokenizer.
+  ///
+  /// Same as [`IndexManager::open_read_only`], plus:
+  /// - `reading_ja`: Required when the index has a `text_reading` field
+  /// - `options`: Must match the analyzer options the index was built with
+  ///   (`min_token_len`, `min_gram`, `max_gram`); write-related options are ignored
+  ///
+  /// # Errors
+  /// Same as [`IndexManager::open_read_only`], plus:
+  /// - Reading tokenizer not provided for a Japanese index with `text_reading`
+  /// - `IndexerError::NgramRangeMismatch` if `options.min_gram` / `options.max_gram` differ
+  ///   from the range recorded in `wakeru_meta.json`
+  pub fn open_read_only_with_reading<P: AsRef<Path>>(
+    index_path: P,
+    language: Language,
+    tokenizer_ja: Option<TextAnalyzer>,
+    reading_ja: Option<TextAnalyzer>,
+    options: IndexOptions,
+  ) -> Result<Self, IndexerError> {
+    Self::open_read_only_inner(
+      index_path.as_ref(),
+      language,
+      tokenizer_ja,
+      reading_ja,
+      options,
+      None,
+    )
+  }
+
+  /// Shared body of the `open_read_only*` variants (`pos_filter` as in `open_or_create_inner`)
+  fn open_read_only_inner(
+    index_path: &Path,
+    language: Language,
+    tokenizer_ja: Option<TextAnalyzer>,
+    reading_ja: Option<TextAnalyzer>,
+    options: IndexOptions,
+    pos_filter: Option<&PosFilter>,
+  ) -> Result<Self, IndexerError> {
+    Self::assert_text_tokenizer_name_allowed(&options)?;
+    if !index_path.join(META_JSON).exists() {
+      return Err(IndexerError::IndexNotFound(index_path.to_path_buf()));
+    }
+
+    Self::assert_schema_version_matches(index_path)?;
+    let index = Index::open_in_dir(index_path)?;
+    let schema = index.schema();
+    let fields = SchemaFields::from_schema(&schema)?;
+    Self::assert_schema_matches_language(&schema, language, &options)?;
+    Self::assert_pos_filter_matches(index_path, language, &options, pos_filter)?;
+    Self::assert_ngram_range_matches(index_path, language, &options)?;
+
+    Self::register_tokenizers(
+      &index,
+      &fields,
+      language,
+      tokenizer_ja,
+      reading_ja,
+      &options,
+    )?;
+
+    let reader = index.reader()?;
+
+    Ok(Self {
+      index,
+      reader,
+      writer: None,
+      writer_memory_bytes: None,
+      batch_commit_size: options.batch_commit_size.max(1),
+      tags_in_metadata: options.tags_in_metadata,
+      fields,
+      language,
+      commit_counter: CommitCounter::default(),
+    })
+  }
+
+  /// Registers the analyzers used by `fields` according to language
+  fn register_tokenizers(
+    index: &Index,
+    fields: &SchemaFields,
+    language: Language,
+    tokenizer_ja: Option<TextAnalyzer>,
+    reading_ja: Option<TextAnalyzer>,
+    options: &IndexOptions,
+  ) -> Result<(), IndexerError> {
+    // Case-insensitive `source_id` (used only by indexes created with that option)
+    let source_id_lowercase =
+      TextAnalyzer::builder(RawTokenizer::default()).filter(LowerCaser).build();
+    index.tokenizers().register(SOURCE_ID_LOWERCASE_TOKENIZER, source_id_lowercase);
+
+    let text_tokenizer_name = options.text_tokenizer_name(language);
+
     match language {
       Language::Ja => {
-        // Japanese tokenizer is required
-        let tokenizer = tokenizer_ja.ok_or(IndexerError::MissingJapaneseTokenizer)?;
-        index.tokenizers().register(language.text_tokenizer_name(), tokenizer);
-
-        // Register 1-char N-gram tokenizer (for partial match search)
+        match options.tokenizer_mode {
+          TokenizerMode::Morphological => {
+            // Japanese tokenizer is required
+            let tokenizer = tokenizer_ja.ok_or(IndexerError::MissingJapaneseTokenizer)?;
+            index.tokenizers().register(text_tokenizer_name, tokenizer);
+          }
+          TokenizerMode::CjkBigram => {
+            // No dictionary: overlapping CJK bigrams + lowercased Latin words
+            let cjk_bigram = TextAnalyzer::builder(CjkBigramTokenizer).filter(LowerCaser).build();
+            index.tokenizers().register(text_tokenizer_name, cjk_bigram);
+          }
+        }
+
+        // Register N-gram tokenizer (for partial match search, 1-char by default)
         // Tantivy 0.25.0: NgramTokenizer::new() returns Result
-        let ja_ngram_tokenizer = NgramTokenizer::new(1, 1, false)?;
+        let ja_ngram_tokenizer = NgramTokenizer::new(options.min_gram, options.max_gram, false)?;
         let ja_ngram = TextAnalyzer::builder(ja_ngram_tokenizer).build();
         index.tokenizers().register("ja_ngram", ja_ngram);
+
+        // Reading tokenizer is required only for indexes with the `text_reading` field
+        if let (Some(_), Some(tokenizer_name)) =
+          (fields.text_reading, language.reading_tokenizer_name())
+        {
+          let reading = reading_ja.ok_or(IndexerError::MissingReadingTokenizer)?;
+          index.tokenizers().register(tokenizer_name, reading);
+        }
       }
       Language::En => {
-        // English: SimpleTokenizer + LowerCaser
-        // Tantivy 0.25.0: Use builder pattern
-        let en_analyzer = TextAnalyzer::builder(SimpleTokenizer::default())
+        // English: SimpleTokenizer + LowerCaser + MinLengthFilter + Stemmer (English by default)
+        let en_analyzer = Self::english_analyzer(options, true);
+        index.tokenizers().register(text_tokenizer_name, en_analyzer);
+
+        // Unstemmed analyzer for the optional `text_exact` field
+        let en_exact_analyzer = Self::english_analyzer(options, false);
+        index.tokenizers().register("en_exact", en_exact_analyzer);
+      }
+      Language::Ko => {
+        // Korean: Eojeol (space separated) + LowerCaser for mixed Latin text.
+        // Particles stay attached to eojeol (e.g. "서울에서"), so no stemming here
+        let ko_analyzer = TextAnalyzer::builder(SimpleTokenizer::default())
           .filter(LowerCaser)
-          .filter(Stemmer::new(tantivy::tokenizer::Language::English))
+          .filter(MinLengthFilter::new(options.min_token_len))
           .build();
-        index.tokenizers().register(language.text_tokenizer_name(), en_analyzer);
+        index.tokenizers().register(text_tokenizer_name, ko_analyzer);
+
+        // N-gram field lets a stem ("서울") match eojeol with particles ("서울에서")
+        let ko_ngram_tokenizer = NgramTokenizer::new(options.min_gram, options.max_gram, false)?;
+        let ko_ngram = TextAnalyzer::builder(ko_ngram_tokenizer).filter(LowerCaser).build();
+        index.tokenizers().register("ko_ngram", ko_ngram);
       }
     }
 
-    // Create Reader
-    let reader = index.reader()?;
+    Ok(())
+  }
 
-    Ok(Self {
-      index,
-      reader,
-      fields,
-      language,
-    })
+  /// Builds the English analyzer (`stem = false` for the unstemmed `text_exact` field)
+  ///
+  /// SimpleTokenizer + LowerCaser + MinLengthFilter, then the stop words of
+  /// `options.stemmer_language` when `options.english_stop_words` is set, then the Stemmer.
+  fn english_analyzer(options: &IndexOptions, stem: bool) -> TextAnalyzer {
+    // Tantivy 0.25.0: dynamic builder for optional filters
+    let mut builder = TextAnalyzer::builder(SimpleTokenizer::default())
+      .filter(LowerCaser)
+      .filter(MinLengthFilter::new(options.min_token_len))
+      .dynamic();
+
+    if options.english_stop_words {
+      match StopWordFilter::new(options.stemmer_language) {
+        Some(stop_words) => builder = builder.filter_dynamic(stop_words),
+        None => warn!(
+          language = ?options.stemmer_language,
+          "No stop word list for the stemmer language; stop words are kept"
+        ),
+      }
+    }
+    if stem {
+      builder = builder.filter_dynamic(Stemmer::new(options.stemmer_language));
+    }
+
+    builder.build()
+  }
+
+  /// Checks that `options.text_tokenizer_name` does not replace another field's tokenizer.
+  fn assert_text_tokenizer_name_allowed(options: &IndexOptions) -> Result<(), IndexerError> {
+    match options.text_tokenizer_name.as_deref() {
+      Some(name) if RESERVED_TOKENIZER_NAMES.contains(&name) => {
+        Err(IndexerError::ReservedTokenizerName(name.to_string()))
+      }
+      _ => Ok(()),
+    }
   }
 
   /// Checks consistency between schema and language.
   ///
   /// Verifies if the tokenizer name of the text field in the existing index
-  /// matches the tokenizer name expected for the specified language.
+  /// matches the tokenizer name expected for the specified language and options
+  /// (tokenizer mode or explicit `text_tokenizer_name`).
   fn assert_schema_matches_language(
     schema: &tantivy::schema::Schema,
     language: Language,
+    options: &IndexOptions,
   ) -> Result<(), IndexerError> {
     let text_field = schema
       .get_field("text")
@@ -222,7 +788,7 @@ impl IndexManager {
     })?;
 
     let actual_tokenizer = indexing_options.tokenizer();
-    let expected_tokenizer = language.text_tokenizer_name();
+    let expected_tokenizer = options.text_tokenizer_name(language);
 
     if actual_tokenizer != expected_tokenizer {
       return Err(IndexerError::LanguageSchemaMismatch {
@@ -234,11 +800,136 @@ impl IndexManager {
     Ok(())
   }
 
-  /// Adds documents to the index.
+  /// Checks that the index was created with the current `SCHEMA_VERSION`.
+  ///
+  /// Other versions are handed to [`IndexManager::migrate`]. Indexes created before the
+  /// version was recorded are treated as version 1.
+  fn assert_schema_version_matches(index_path: &Path) -> Result<(), IndexerError> {
+    let found = WakeruMeta::read_schema_version(index_path)?;
+    if found == SCHEMA_VERSION {
+      return Ok(());
+    }
+
+    Self::migrate(index_path, found)
+  }
+
+  /// Migrates the index in `index_path` from schema version `found` to `SCHEMA_VERSION`.
+  ///
+  /// Hook for future schema changes: no migration exists yet, so every version other than
+  /// the current one is rejected. Indexes of a newer version (`found > SCHEMA_VERSION`)
+  /// must never be migrated down.
+  ///
+  /// # Errors
+  /// - `IndexerError::SchemaVersionMismatch` if the index cannot be migrated (reindex required)
+  fn migrate(index_path: &Path, found: u32) -> Result<(), IndexerError> {
+    warn!(path = ?index_path, found, expected = SCHEMA_VERSION, "Index schema version differs");
+    Err(IndexerError::SchemaVersionMismatch {
+      found,
+      expected: SCHEMA_VERSION,
+    })
+  }
+
+  /// POS filter to record for an index
+  ///
+  /// Only Japanese morphological indexes use one, and only a known analyzer filter
+  /// (`pos_filter`) is recorded.
+  fn pos_filter_for(
+    language: Language,
+    options: &IndexOptions,
+    pos_filter: Option<&PosFilter>,
+  ) -> Option<PosFilter> {
+    (language == Language::Ja && options.tokenizer_mode == TokenizerMode::Morphological)
+      .then_some(pos_filter)
+      .flatten()
+      .map(PosFilter::normalized)
+  }
+
+  /// Checks that the analyzer's POS filter matches the filter recorded in `wakeru_meta.json`.
+  ///
+  /// Indexes created before `wakeru_meta.json` existed are accepted with a warning, and so
+  /// are caller-built analyzers, whose filter is unknown (`pos_filter` is `None`).
+  fn assert_pos_filter_matches(
+    index_path: &Path,
+    language: Language,
+    options: &IndexOptions,
+    pos_filter: Option<&PosFilter>,
+  ) -> Result<(), IndexerError> {
+    if language != Language::Ja || options.tokenizer_mode != TokenizerMode::Morphological {
+      return Ok(());
+    }
+    let Some(actual) = Self::pos_filter_for(language, options, pos_filter) else {
+      if WakeruMeta::read(index_path)?.and_then(|meta| meta.pos_filter).is_some() {
+        warn!(
+          path = ?index_path,
+          "POS filter of a caller-built Japanese analyzer cannot be checked; \
+           use open_or_create_with_dictionary to verify it"
+        );
+      }
+      return Ok(());
+    };
+    let Some(expected) = WakeruMeta::read(index_path)?.and_then(|meta| meta.pos_filter) else {
+      warn!(path = ?index_path, "No POS filter recorded for existing index; skipping check");
+      return Ok(());
+    };
+
+    if actual != expected {
+      return Err(IndexerError::FilterMismatch {
+        expected: expected.to_string(),
+        actual: actual.to_string(),
+      });
+    }
+
+    Ok(())
+  }
+
+  /// N-gram range to record for an index (only Japanese and Korean indexes use one)
+  fn ngram_range_for(language: Language, options: &IndexOptions) -> Option<NgramRange> {
+    matches!(language, Language::Ja | Language::Ko).then_some(NgramRange {
+      min_gram: options.min_gram,
+      max_gram: options.max_gram,
+    })
+  }
+
+  /// Checks that `options.min_gram` / `max_gram` match the range recorded in `wakeru_meta.json`.
+  ///
+  /// Indexes created before the range was recorded are accepted with a warning.
+  fn assert_ngram_range_matches(
+    index_path: &Path,
+    language: Language,
+    options: &IndexOptions,
+  ) -> Result<(), IndexerError> {
+    let Some(actual) = Self::ngram_range_for(language, options) else {
+      return Ok(());
+    };
+    let Some(expected) = WakeruMeta::read(index_path)?.and_then(|meta| meta.ngram_range) else {
+      warn!(path = ?index_path, "No N-gram range recorded for existing index; skipping check");
+      return Ok(());
+    };
+
+    if actual != expected {
+      return Err(IndexerError::NgramRangeMismatch {
+        expected: expected.to_string(),
+        actual: actual.to_string(),
+      });
+    }
+
+    Ok(())
+  }
+
+  /// Stages documents for addition to the index (does not commit).
   ///
-  /// - Skips duplicate documents (same ID)
+  /// - Skips duplicate documents (same ID), including ones staged by earlier uncommitted calls
+  /// - Skips invalid documents (blank `id` / `text`, see [`Document::validate`])
+  ///   and counts them in `invalid`
   /// - Continues processing until the end (does not fail-fast)
   /// - Returns result as `AddDocumentsReport`
+  /// - Concurrent calls are serialized by the internal writer lock
+  /// - Commits automatically whenever `batch_commit_size` documents are staged
+  ///   (see [`IndexOptions`]), so memory stays bounded for huge inputs
+  ///
+  /// Remaining staged documents become persistent and searchable only after
+  /// [`IndexManager::commit`]. Many small batches can be staged and committed once
+  /// to reduce fsync overhead.
   ///
   /// # Arguments
   /// - `documents`: Slice of documents to add
@@ -247,27 +938,79 @@ impl IndexManager {
   /// - `Ok(AddDocumentsReport)`: Processing statistics (success/skipped count)
   /// - `Err(IndexerError)`: Tantivy level fatal error
   pub fn add_documents(&self, documents: &[Document]) -> Result<AddDocumentsReport, IndexerError> {
-    let mut report = AddDocumentsReport::default();
-    let mut seen_ids: HashSet<String> = HashSet::with_capacity(documents.len());
+    let span = info_span!(
+      "add_documents",
+      language = %self.language,
+      num_docs = documents.len(),
+      added = field::Empty,
+      elapsed_ms = field::Empty,
+    );
+    timed(&span, || {
+      let mut state = self.lock_writer()?;
+      let report = self.add_documents_locked(&mut state, documents)?;
+      Span::current().record("added", report.added);
+      Ok(report)
+    })
+  }
+
+  /// Non-blocking variant of [`IndexManager::add_documents`].
+  ///
+  /// Stages documents only if the writer lock is free; otherwise returns immediately
+  /// without staging anything, so the caller can retry or report the index as busy.
+  ///
+  /// # Returns
+  /// - `Ok(AddDocumentsReport)`: Processing statistics (success/skipped count)
+  /// - `Err(IndexerError::WriterBusy)`: Another call holds the writer lock
+  /// - `Err(IndexerError)`: Tantivy level fatal error
+  pub fn try_add_documents(
+    &self,
+    documents: &[Document],
+  ) -> Result<AddDocumentsReport, IndexerError> {
+    let mut state = self.try_lock_writer()?;
+    self.add_documents_locked(&mut state, documents)
+  }
+
+  /// Non-blocking variant of [`IndexManager::add_documents`] followed by a commit.
+  ///
+  /// Staging and committing happen under one writer lock, so no other call (e.g. `clear`)
+  /// can discard the staged documents in between. `WriterBusy` means nothing was indexed.
+  ///
+  /// # Returns
+  /// - `Ok(AddDocumentsReport)`: Processing statistics; added documents are committed
+  /// - `Err(IndexerError::WriterBusy)`: Another call holds the writer lock
+  /// - `Err(IndexerError)`: Tantivy level fatal error
+  pub fn try_add_documents_and_commit(
+    &self,
+    documents: &[Document],
+  ) -> Result<AddDocumentsReport, IndexerError> {
+    let mut state = self.try_lock_writer()?;
+    let report = self.add_documents_locked(&mut state, documents)?;
+    self.commit_locked(&mut state)?;
+    Ok(report)
+  }
 
-    // Create IndexWriter (50MB buffer)
-    let mut writer: IndexWriter = self.index.writer(50_000_000)?;
+  /// [`IndexManager::add_documents`] with the writer lock already held
+  fn add_documents_locked(
+    &self,
+    state: &mut WriterState,
+    documents: &[Document],
+  ) -> Result<AddDocumentsReport, IndexerError> {
+    let mut report = AddDocumentsReport::default();
 
-    // Searcher for searching
-    let searcher = self.reader.searcher();
+    // Searcher for searching (committed documents)
+    let mut searcher = self.reader.searcher();
 
     for doc in documents {
       report.record_total();
-      let id = doc.id.clone();
-
-      // Duplicate in batch
-      let in_batch = !seen_ids.insert(id.clone());
 
-      // Duplicate in index (fast check with doc_freq)
-      let term = Term::from_field_text(self.fields.id, &id);
-      let in_index = searcher.doc_freq(&term)? > 0;
+      if let Err(err) = doc.validate() {
+        warn!(error = %err, "Skipping invalid document");
+        report.record_invalid();
+        continue;
+      }
 
-      if in_batch || in_index {
+      // Duplicate in staged documents (this or earlier uncommitted batches) or in index
+      if state.pending_ids.contains(&doc.id) || self.contains_id(&searcher, &doc.id)? {
         // Skip duplicates
         report.record_skipped();
         continue;
@@ -275,28 +1018,343 @@ impl IndexManager {
 
       // No duplicate -> Add
       let tantivy_doc = self.to_tantivy_document(doc)?;
-      writer.add_document(tantivy_doc)?;
+      state.writer.add_document(tantivy_doc)?;
+      state.pending_ids.insert(doc.id.clone());
       report.record_added();
-    }
-
-    // Commit: Persist to disk
-    writer.commit()?;
 
-    // Reload Reader (make new documents visible for subsequent searches)
-    self.reader.reload()?;
+      if state.pending_ids.len() >= self.batch_commit_size {
+        self.commit_locked(state)?;
+        // Committed IDs left `pending_ids`, so refresh the snapshot for duplicate checks
+        searcher = self.reader.searcher();
+      }
+    }
 
     Ok(report)
   }
 
-  /// Document -> TantivyDocument conversion (internal method)
+  /// Adds documents all-or-nothing and commits them.
+  ///
+  /// Every document is validated and converted before anything is staged.
+  /// If any document is invalid, nothing is staged and the index is left unchanged.
+  ///
+  /// - Duplicates (same ID) are skipped as in [`IndexManager::add_documents`]
+  /// - `batch_commit_size` is ignored; the whole batch is committed once
+  /// - Documents staged earlier by `add_documents` are committed at the same time
+  ///
+  /// # Arguments
+  /// - `documents`: Slice of documents to add
   ///
   /// # Returns
-  /// - `Ok(TantivyDocument)`: Conversion successful
-  fn to_tantivy_document(&self, doc: &Document) -> Result<tantivy::TantivyDocument, IndexerError> {
-    let mut tantivy_doc = tantivy::TantivyDocument::default();
+  /// - `Ok(AddDocumentsReport)`: Processing statistics (success/skipped count)
+  /// - `Err(IndexerError::InvalidDocument)`: A document failed validation (nothing indexed)
+  /// - `Err(IndexerError)`: Tantivy level fatal error (all uncommitted changes are rolled back)
+  pub fn add_documents_atomic(
+    &self,
+    documents: &[Document],
+  ) -> Result<AddDocumentsReport, IndexerError> {
+    // Validate and convert everything up front so a bad document never reaches the writer
+    let converted = documents
+      .iter()
+      .map(|doc| {
+        doc.validate()?;
+        Ok((doc, self.to_tantivy_document(doc)?))
+      })
+      .collect::<Result<Vec<_>, IndexerError>>()?;
 
-    tantivy_doc.add_text(self.fields.id, &doc.id);
-    tantivy_doc.add_text(self.fields.source_id, &doc.source_id);
+    let mut report = AddDocumentsReport::default();
+    let mut state = self.lock_writer()?;
+    let searcher = self.reader.searcher();
+
+    let result = (|| {
+      for (doc, tantivy_doc) in converted {
+        report.record_total();
+
+        if state.pending_ids.contains(&doc.id) || self.contains_id(&searcher, &doc.id)? {
+          report.record_skipped();
+          continue;
+        }
+
+        state.writer.add_document(tantivy_doc)?;
+        state.pending_ids.insert(doc.id.clone());
+        report.record_added();
+      }
+      self.commit_locked(&mut state)
+    })();
+
+    if let Err(e) = result {
+      // Discard partially staged documents (rollback returns to the last commit).
+      // The batch error is what the caller needs, so a rollback failure is only logged.
+      if let Err(rollback_err) = state.writer.rollback() {
+        error!(error = %rollback_err, batch_error = %e, "Failed to roll back atomic batch");
+      }
+      state.pending_ids.clear();
+      return Err(e);
+    }
+
+    Ok(report)
+  }
+
+  /// Imports documents from NDJSON (one `Document` JSON per line) and commits them.
+  ///
+  /// - Reads line by line and ingests in `batch_commit_size` batches,
+  ///   so memory stays flat for huge inputs
+  /// - Blank lines are ignored
+  /// - Lines that cannot be parsed as `Document` are counted in `malformed` and skipped
+  /// - Duplicates are skipped as in [`IndexManager::add_documents`]
+  ///
+  /// # Arguments
+  /// - `reader`: NDJSON input
+  ///
+  /// # Returns
+  /// - `Ok(AddDocumentsReport)`: Processing statistics (including `malformed`)
+  /// - `Err(IndexerError::ReadInput)`: Failed to read from `reader`
+  /// - `Err(IndexerError)`: Tantivy level fatal error
+  pub fn import_ndjson<R: BufRead>(&self, reader: R) -> Result<AddDocumentsReport, IndexerError> {
+    let mut report = AddDocumentsReport::default();
+    let mut batch = Vec::with_capacity(self.batch_commit_size);
+
+    for (line_index, line) in reader.lines().enumerate() {
+      let line = line.map_err(|e| IndexerError::ReadInput(Arc::new(e)))?;
+      if line.trim().is_empty() {
+        continue;
+      }
+
+      match serde_json::from_str::<Document>(&line) {
+        Ok(doc) => batch.push(doc),
+        Err(e) => {
+          warn!(line = line_index + 1, error = %e, "Skipping malformed NDJSON line");
+          report.record_malformed();
+          continue;
+        }
+      }
+
+      if batch.len() >= self.batch_commit_size {
+        report.merge(&self.add_documents(&batch)?);
+        batch.clear();
+      }
+    }
+
+    report.merge(&self.add_documents(&batch)?);
+    self.commit()?;
+
+    Ok(report)
+  }
+
+  /// Commits staged changes and reloads the reader.
+  ///
+  /// After this call, documents added by [`IndexManager::add_documents`] are persisted
+  /// and visible to subsequent searches through this manager's reader.
+  ///
+  /// # Errors
+  /// - Tantivy commit / reader reload error
+  pub fn commit(&self) -> Result<(), IndexerError> {
+    let mut state = self.lock_writer()?;
+    self.commit_locked(&mut state)
+  }
+
+  /// Non-blocking variant of [`IndexManager::commit`].
+  ///
+  /// # Errors
+  /// - `IndexerError::WriterBusy` if another call holds the writer lock
+  /// - Tantivy commit / reader reload error
+  pub fn try_commit(&self) -> Result<(), IndexerError> {
+    let mut state = self.try_lock_writer()?;
+    self.commit_locked(&mut state)
+  }
+
+  /// Commits outstanding writes and releases the index resources.
+  ///
+  /// Staged documents are committed, background merges are awaited, and the writer
+  /// lock, reader and file handles of this manager are dropped. Call this before
+  /// removing the index directory (open handles block removal on Windows) or at
+  /// process exit. Read-only managers only drop their reader.
+  ///
+  /// Handles held elsewhere (e.g. by a `SearchEngine` on the same index) stay open.
+  ///
+  /// # Errors
+  /// - Tantivy commit / merge thread error
+  pub fn close(self) -> Result<(), IndexerError> {
+    let Self {
+      reader,
+      writer,
+      commit_counter,
+      ..
+    } = self;
+    drop(reader);
+
+    if let Some(writer) = writer {
+      let mut state = writer.into_inner().unwrap_or_else(PoisonError::into_inner);
+      state.writer.commit()?;
+      commit_counter.increment();
+      state.writer.wait_merging_threads()?;
+    }
+
+    Ok(())
+  }
+
+  /// Commit with the writer lock already held
+  fn commit_locked(&self, state: &mut WriterState) -> Result<(), IndexerError> {
+    // Commit: Persist to disk
+    state.writer.commit()?;
+    state.pending_ids.clear();
+
+    // Reload Reader (make changes visible for subsequent searches)
+    self.reader.reload()?;
+    self.commit_counter.increment();
+
+    Ok(())
+  }
+
+  /// Acquires the writer lock.
+  ///
+  /// A panic while holding the lock cannot leave the writer half-updated
+  /// (each tantivy operation is atomic), so poisoning is ignored.
+  ///
+  /// # Errors
+  /// - `IndexerError::ReadOnlyIndex` if the index was opened read-only
+  fn lock_writer(&self) -> Result<MutexGuard<'_, WriterState>, IndexerError> {
+    let writer = self.writer.as_ref().ok_or(IndexerError::ReadOnlyIndex)?;
+    Ok(writer.lock().unwrap_or_else(PoisonError::into_inner))
+  }
+
+  /// Acquires the writer lock without blocking (poisoning is ignored as in `lock_writer`).
+  ///
+  /// # Errors
+  /// - `IndexerError::ReadOnlyIndex` if the index was opened read-only
+  /// - `IndexerError::WriterBusy` if the lock is held by another call
+  fn try_lock_writer(&self) -> Result<MutexGuard<'_, WriterState>, IndexerError> {
+    let writer = self.writer.as_ref().ok_or(IndexerError::ReadOnlyIndex)?;
+    match writer.try_lock() {
+      Ok(state) => Ok(state),
+      Err(TryLockError::Poisoned(poisoned)) => Ok(poisoned.into_inner()),
+      Err(TryLockError::WouldBlock) => Err(IndexerError::WriterBusy),
+    }
+  }
+
+  /// Memory buffer size (bytes) of the writer (`options.writer_memory_bytes`)
+  ///
+  /// `None` if the index was opened read-only.
+  pub fn writer_memory_bytes(&self) -> Option<usize> {
+    self.writer_memory_bytes
+  }
+
+  /// Whether this manager was opened with [`IndexManager::open_read_only`]
+  pub fn is_read_only(&self) -> bool {
+    self.writer.is_none()
+  }
+
+  /// Deletes documents with the given IDs from the index.
+  ///
+  /// - IDs that do not exist in the index (or among staged documents) are ignored
+  ///   (no delete operation issued)
+  /// - Commits and reloads the reader, so subsequent searches no longer return deleted documents.
+  ///   Documents staged by `add_documents` are committed at the same time.
+  ///
+  /// # Arguments
+  /// - `ids`: IDs of documents to delete
+  ///
+  /// # Returns
+  /// - `Ok(usize)`: Number of delete operations issued (IDs found in the index or staged)
+  /// - `Err(IndexerError)`: Tantivy level fatal error
+  ///
+  /// # Note
+  /// `SearchEngine` has its own reader (`OnCommitWithDelay`), so deletions become
+  /// visible there after a short delay.
+  pub fn delete_documents(&self, ids: &[&str]) -> Result<usize, IndexerError> {
+    let mut state = self.lock_writer()?;
+    let searcher = self.reader.searcher();
+    let mut seen_ids: HashSet<&str> = HashSet::with_capacity(ids.len());
+    let mut deleted = 0;
+
+    for &id in ids {
+      if !seen_ids.insert(id) {
+        continue;
+      }
+      // Delete also applies to documents staged before this operation
+      let staged = state.pending_ids.remove(id);
+      if !staged && !self.contains_id(&searcher, id)? {
+        continue;
+      }
+
+      state.writer.delete_term(Term::from_field_text(self.fields.id, id));
+      deleted += 1;
+    }
+
+    // Commit and reload (hide deleted documents from subsequent searches)
+    self.commit_locked(&mut state)?;
+
+    Ok(deleted)
+  }
+
+  /// Deletes all documents (chunks) with the given `source_id`.
+  ///
+  /// Used before re-ingesting a whole source document. Staged documents of the source
+  /// are deleted as well. Commits and reloads the reader.
+  /// Case is ignored for indexes created with `case_insensitive_source_id`.
+  ///
+  /// # Errors
+  /// - `IndexerError::ReadOnlyIndex` if the index was opened read-only
+  /// - Tantivy commit / reader reload error
+  pub fn delete_by_source_id(&self, source_id: &str) -> Result<(), IndexerError> {
+    let mut state = self.lock_writer()?;
+    state.writer.delete_term(source_id_term(
+      &self.index,
+      self.fields.source_id,
+      source_id,
+    ));
+    self.commit_locked(&mut state)
+  }
+
+  /// Deletes all documents (chunks) having `tag` (exact match).
+  ///
+  /// Matches the dedicated `tags` field, or `metadata.tags` for indexes created without
+  /// it. Staged documents with the tag are deleted as well. Commits and reloads the reader.
+  ///
+  /// # Errors
+  /// - `IndexerError::ReadOnlyIndex` if the index was opened read-only
+  /// - Tantivy commit / reader reload error
+  pub fn delete_by_tag(&self, tag: &str) -> Result<(), IndexerError> {
+    let mut state = self.lock_writer()?;
+    let term = tag_term(&self.fields, tag);
+    state.writer.delete_term(term);
+    self.commit_locked(&mut state)
+  }
+
+  /// Deletes all documents, keeping the index directory and schema.
+  ///
+  /// Documents staged by `add_documents` are discarded as well. Commits and reloads
+  /// the reader, so `num_docs()` returns 0 afterwards.
+  ///
+  /// # Errors
+  /// - `IndexerError::ReadOnlyIndex` if the index was opened read-only
+  /// - Tantivy commit / reader reload error
+  pub fn clear(&self) -> Result<(), IndexerError> {
+    let mut state = self.lock_writer()?;
+    // delete_all_documents only drops committed segments, so discard staged documents first
+    state.writer.rollback()?;
+    state.writer.delete_all_documents()?;
+    self.commit_locked(&mut state)
+  }
+
+  /// Checks whether an alive (not deleted) document with `id` exists.
+  ///
+  /// `doc_freq` also counts deleted documents until segments are merged,
+  /// so a `TermQuery` is used to honor deletions.
+  fn contains_id(&self, searcher: &Searcher, id: &str) -> Result<bool, IndexerError> {
+    let term = Term::from_field_text(self.fields.id, id);
+    let query = TermQuery::new(term, IndexRecordOption::Basic);
+    Ok(searcher.search(&query, &Count)? > 0)
+  }
+
+  /// Document -> TantivyDocument conversion (internal method)
+  ///
+  /// # Returns
+  /// - `Ok(TantivyDocument)`: Conversion successful
+  fn to_tantivy_document(&self, doc: &Document) -> Result<tantivy::TantivyDocument, IndexerError> {
+    let mut tantivy_doc = tantivy::TantivyDocument::default();
+
+    tantivy_doc.add_text(self.fields.id, &doc.id);
+    tantivy_doc.add_text(self.fields.source_id, &doc.source_id);
     tantivy_doc.add_text(self.fields.text, &doc.text);
 
     // Add same text to N-gram field (for partial match search)
@@ -305,17 +1363,122 @@ impl IndexManager {
       tantivy_doc.add_text(text_ngram_field, &doc.text);
     }
 
+    // Add same text to unstemmed field (English index created with `english_exact_field`)
+    if let Some(text_exact_field) = self.fields.text_exact {
+      tantivy_doc.add_text(text_exact_field, &doc.text);
+    }
+
+    // Add same text to reading field (Japanese index created with `japanese_reading_field`)
+    if let Some(text_reading_field) = self.fields.text_reading {
+      tantivy_doc.add_text(text_reading_field, &doc.text);
+    }
+
+    // Store byte offsets of the indexed text tokens (index created with `with_offsets`)
+    if let Some(text_offsets_field) = self.fields.text_offsets {
+      for (from, to) in self.text_token_offsets(&doc.text)? {
+        tantivy_doc.add_u64(text_offsets_field, from as u64);
+        tantivy_doc.add_u64(text_offsets_field, to as u64);
+      }
+    }
+
+    // Add each tag to the dedicated tags field (indexes created with it)
+    let mut metadata = Cow::Borrowed(&doc.metadata);
+    if let Some(tags_field) = self.fields.tags {
+      for tag in doc.tags() {
+        tantivy_doc.add_text(tags_field, &tag);
+      }
+      // Drop the JSON copy only if `restore_tags` can rebuild it exactly
+      if !self.tags_in_metadata && is_string_array(doc.metadata.get(TAGS_KEY)) {
+        metadata.to_mut().remove(TAGS_KEY);
+      }
+    }
+
     // Insert entire metadata as JsonObject
-    // tags is also included in metadata["tags"], so double holding is unnecessary
     // Tantivy 0.25: add_object expects BTreeMap<String, OwnedValue>, so conversion is needed
-    if !doc.metadata.is_empty() {
-      let json_obj = metadata_to_tantivy_object(&doc.metadata);
+    if !metadata.is_empty() {
+      let json_obj = metadata_to_tantivy_object(&metadata);
       tantivy_doc.add_object(self.fields.metadata, json_obj);
     }
 
     Ok(tantivy_doc)
   }
 
+  /// Byte offsets `(from, to)` of the tokens the `text` analyzer emits for `text`
+  ///
+  /// # Errors
+  /// - Tantivy error if the `text` analyzer is not registered
+  fn text_token_offsets(&self, text: &str) -> Result<Vec<(usize, usize)>, IndexerError> {
+    let mut analyzer = field_analyzer(&self.index, self.fields.text).ok_or_else(|| {
+      tantivy::TantivyError::InvalidArgument("Tokenizer of field 'text' is not registered".into())
+    })?;
+    let mut stream = analyzer.token_stream(text);
+    let mut offsets = Vec::new();
+    while stream.advance() {
+      let token = stream.token();
+      if !token.text.is_empty() {
+        offsets.push((token.offset_from, token.offset_to));
+      }
+    }
+    Ok(offsets)
+  }
+
+  /// Returns per-segment information of the currently searchable index.
+  ///
+  /// Reflects the state as of the last reader reload (i.e. the last commit through this manager).
+  ///
+  /// # Errors
+  /// - Failed to compute segment file sizes
+  pub fn segment_info(&self) -> Result<Vec<SegmentInfo>, IndexerError> {
+    let searcher = self.reader.searcher();
+
+    searcher
+      .segment_readers()
+      .iter()
+      .map(|segment_reader| {
+        let space_usage = segment_reader.space_usage().map_err(tantivy::TantivyError::from)?;
+        Ok(SegmentInfo {
+          num_docs: segment_reader.num_docs(),
+          num_deleted: segment_reader.num_deleted_docs(),
+          size_bytes: space_usage.total().get_bytes(),
+        })
+      })
+      .collect()
+  }
+
+  /// Iterates over all documents in the currently searchable index.
+  ///
+  /// Documents are restored from stored fields (`id`, `source_id`, `text`, `metadata`)
+  /// and read lazily, so the whole index is never held in memory. Staged (uncommitted)
+  /// documents are not included. Intended for export and reindex workflows.
+  ///
+  /// # Errors
+  /// Each item fails if the document store cannot be read or a required stored
+  /// field (`id` / `source_id`) is missing.
+  pub fn iter_documents(&self) -> impl Iterator<Item = Result<Document, IndexerError>> + use<> {
+    stored_documents(self.reader.searcher(), self.fields)
+  }
+
+  /// Returns the number of alive documents in the currently searchable index.
+  ///
+  /// Staged (uncommitted) documents are not counted.
+  pub fn num_docs(&self) -> u64 {
+    self.reader.searcher().num_docs()
+  }
+
+  /// Returns summary statistics of the currently searchable index.
+  pub fn stats(&self) -> IndexStats {
+    let searcher = self.reader.searcher();
+    IndexStats {
+      num_docs: searcher.num_docs(),
+      num_segments: searcher.segment_readers().len(),
+    }
+  }
+
+  /// Returns the commit counter of this manager (see [`CommitCounter`])
+  pub fn commit_counter(&self) -> CommitCounter {
+    self.commit_counter.clone()
+  }
+
   /// Returns reference to Tantivy Index (used in SearchEngine)
   pub fn index(&self) -> &Index {
     &self.index
@@ -340,7 +1503,8 @@ impl IndexManager {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use tantivy::tokenizer::TextAnalyzer;
+  use crate::config::StoreCompression;
+  use tantivy::tokenizer::{TextAnalyzer, TokenStream};
   use vibrato_rkyv::dictionary::PresetDictionaryKind;
 
   /// Confirm that creating a Japanese index and adding documents works correctly.
@@ -413,6 +1577,37 @@ mod tests {
     assert_eq!(report.skipped_duplicates, 0);
   }
 
+  /// Confirm that creating a Korean index and adding documents works correctly.
+  #[test]
+  fn open_or_create_korean_and_add_documents() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    {
+      // No external tokenizer needed for Korean
+      let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::Ko, None)
+        .expect("Failed to create index");
+      assert_eq!(index_manager.language(), Language::Ko);
+
+      // Korean has the N-gram field, but neither exact nor reading field
+      assert!(index_manager.fields().text_ngram.is_some());
+      assert!(index_manager.fields().text_exact.is_none());
+      assert!(index_manager.fields().text_reading.is_none());
+
+      let docs = vec![
+        Document::new("1", "src-1", "서울은 한국의 수도입니다").with_tag("category:geo"),
+        Document::new("2", "src-1", "부산은 큰 항구 도시입니다").with_tag("category:geo"),
+      ];
+      let report = index_manager.add_documents(&docs).expect("Failed to add documents");
+      assert_eq!(report.added, 2);
+      assert_eq!(report.skipped_duplicates, 0);
+      index_manager.commit().expect("Failed to commit");
+      assert_eq!(index_manager.num_docs(), 2);
+    }
+
+    // Reopening as another language is rejected
+    let err = IndexManager::open_or_create(tmp_dir.path(), Language::En, None).unwrap_err();
+    assert!(matches!(err, IndexerError::LanguageSchemaMismatch { .. }));
+  }
+
   /// Error test when tokenizer is not provided for Japanese index
   #[test]
   fn missing_japanese_tokenizer_error() {
@@ -424,6 +1619,115 @@ mod tests {
     assert!(matches!(err, IndexerError::MissingJapaneseTokenizer));
   }
 
+  /// Read-only manager searches an index while the writer process holds the lock
+  #[test]
+  fn open_read_only_searches_without_writer_lock() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let writer_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+    writer_manager
+      .add_documents(&[Document::new("1", "src-1", "Tokyo is the capital of Japan")])
+      .expect("Failed to add documents");
+    writer_manager.commit().expect("Failed to commit");
+
+    // Opening while writer_manager is alive does not conflict on the index lock
+    let read_only = IndexManager::open_read_only(tmp_dir.path(), Language::En, None)
+      .expect("Failed to open read-only");
+    assert!(read_only.is_read_only());
+    assert!(!writer_manager.is_read_only());
+    assert_eq!(read_only.num_docs(), 1);
+
+    let search_engine =
+      crate::searcher::SearchEngine::new(read_only.index(), *read_only.fields(), Language::En)
+        .expect("Failed to create SearchEngine");
+    let results = search_engine.search_tokens_or("capital", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+
+    // Write operations are rejected
+    let err = read_only.add_documents(&[Document::new("2", "src-1", "Osaka")]).unwrap_err();
+    assert!(matches!(err, IndexerError::ReadOnlyIndex));
+    assert!(matches!(
+      read_only.commit(),
+      Err(IndexerError::ReadOnlyIndex)
+    ));
+    assert!(matches!(
+      read_only.delete_documents(&["1"]),
+      Err(IndexerError::ReadOnlyIndex)
+    ));
+  }
+
+  #[test]
+  fn clear_deletes_all_documents_and_keeps_schema() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let options = IndexOptions {
+      english_exact_field: true,
+      ..IndexOptions::default()
+    };
+
+    {
+      let index_manager =
+        IndexManager::open_or_create_with_options(tmp_dir.path(), Language::En, None, options)
+          .expect("Failed to create index");
+      index_manager
+        .add_documents(&[
+          Document::new("1", "src-1", "Tokyo is the capital of Japan"),
+          Document::new("2", "src-1", "Osaka is a major city"),
+        ])
+        .expect("Failed to add documents");
+      index_manager.commit().expect("Failed to commit");
+      // Staged but uncommitted documents are discarded too
+      index_manager
+        .add_documents(&[Document::new("3", "src-1", "Kyoto has many temples")])
+        .expect("Failed to add documents");
+
+      index_manager.clear().expect("Failed to clear");
+      assert_eq!(index_manager.num_docs(), 0);
+
+      let search_engine = crate::searcher::SearchEngine::new(
+        index_manager.index(),
+        *index_manager.fields(),
+        Language::En,
+      )
+      .expect("Failed to create SearchEngine");
+      assert!(search_engine.search_tokens_or("tokyo kyoto", 10).unwrap().is_empty());
+
+      // The same ID can be added again after clearing
+      let report = index_manager
+        .add_documents(&[Document::new("1", "src-1", "Tokyo again")])
+        .expect("Failed to add documents");
+      assert_eq!(report.added, 1);
+    }
+
+    // Reopens with the same schema
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to reopen index");
+    assert!(index_manager.fields().text_exact.is_some());
+  }
+
+  #[test]
+  fn open_read_only_requires_existing_index() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let err = IndexManager::open_read_only(tmp_dir.path(), Language::En, None).unwrap_err();
+    assert!(matches!(err, IndexerError::IndexNotFound(_)));
+  }
+
+  #[test]
+  fn open_read_only_japanese_requires_tokenizer() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let analyzer = TextAnalyzer::from(SimpleTokenizer::default());
+    drop(
+      IndexManager::open_or_create(tmp_dir.path(), Language::Ja, Some(analyzer.clone()))
+        .expect("Failed to create index"),
+    );
+
+    let err = IndexManager::open_read_only(tmp_dir.path(), Language::Ja, None).unwrap_err();
+    assert!(matches!(err, IndexerError::MissingJapaneseTokenizer));
+
+    let read_only = IndexManager::open_read_only(tmp_dir.path(), Language::Ja, Some(analyzer))
+      .expect("Failed to open read-only");
+    assert!(read_only.fields().text_ngram.is_some());
+  }
+
   /// Test duplicate skip (Japanese)
   #[test]
   fn duplicate_documents_are_skipped_japanese() {
@@ -459,6 +1763,51 @@ mod tests {
     assert_eq!(report2.skipped_duplicates, 1);
   }
 
+  /// Confirm that each commit creates a segment and segment doc counts add up.
+  #[test]
+  fn segment_info_reports_segments_per_commit() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+
+    // Empty index has no segment
+    assert!(index_manager.segment_info().expect("Failed to get segment info").is_empty());
+
+    // 3 commits -> 3 segments (below the merge policy threshold)
+    let batches = vec![
+      vec![Document::new("1", "src-1", "Tokyo is the capital of Japan")],
+      vec![
+        Document::new("2", "src-1", "Osaka is a major city"),
+        Document::new("3", "src-1", "Kyoto has many temples"),
+      ],
+      vec![Document::new("4", "src-2", "Sapporo is in Hokkaido")],
+    ];
+    for batch in &batches {
+      index_manager.add_documents(batch).expect("Failed to add");
+      index_manager.commit().expect("Failed to commit");
+    }
+
+    let segments = index_manager.segment_info().expect("Failed to get segment info");
+    assert_eq!(segments.len(), 3);
+
+    assert_eq!(index_manager.num_docs(), 4);
+    assert_eq!(
+      index_manager.stats(),
+      IndexStats {
+        num_docs: 4,
+        num_segments: 3
+      }
+    );
+
+    let total_docs: u32 = segments.iter().map(|s| s.num_docs).sum();
+    assert_eq!(total_docs, 4);
+    assert!(segments.iter().all(|s| s.num_deleted == 0 && s.size_bytes > 0));
+
+    let mut doc_counts: Vec<u32> = segments.iter().map(|s| s.num_docs).collect();
+    doc_counts.sort_unstable();
+    assert_eq!(doc_counts, vec![1, 1, 2]);
+  }
+
   /// Test duplicate skip (English)
   #[test]
   fn duplicate_documents_are_skipped_english() {
@@ -478,4 +1827,1016 @@ mod tests {
     assert_eq!(report2.added, 0);
     assert_eq!(report2.skipped_duplicates, 1);
   }
+
+  /// Confirm that invalid documents are counted and skipped without aborting the batch.
+  #[test]
+  fn add_documents_skips_invalid_documents() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+
+    let docs = vec![
+      Document::new("1", "src-1", "Tokyo is the capital of Japan"),
+      Document::new("", "src-1", "missing id"),
+      Document::new("2", "src-1", ""),
+      Document::new("3", "src-1", " \n\t "),
+      Document::new("4", "src-1", "Osaka is a major city"),
+      Document::new("1", "src-1", "duplicate of 1"),
+    ];
+    let report = index_manager.add_documents(&docs).expect("Failed to add");
+    index_manager.commit().expect("Failed to commit");
+
+    assert_eq!(report.total, 6);
+    assert_eq!(report.added, 2);
+    assert_eq!(report.invalid, 3);
+    assert_eq!(report.skipped_duplicates, 1);
+    assert!(!report.is_all_added());
+    assert_eq!(index_manager.num_docs(), 2);
+  }
+
+  /// Confirm that staged documents become visible only after commit and that
+  /// duplicates across uncommitted batches are skipped.
+  #[test]
+  fn add_documents_stages_until_commit() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+
+    for i in 0..100 {
+      let doc = Document::new(i.to_string(), "src-1", format!("chunk number {i}"));
+      index_manager.add_documents(&[doc]).expect("Failed to add");
+    }
+
+    // Same ID as an uncommitted document -> Skipped
+    let report = index_manager
+      .add_documents(&[Document::new("0", "src-1", "duplicate")])
+      .expect("Failed to add");
+    assert_eq!(report.skipped_duplicates, 1);
+
+    // Nothing visible before commit
+    assert_eq!(index_manager.reader().searcher().num_docs(), 0);
+
+    index_manager.commit().expect("Failed to commit");
+
+    // One commit -> all documents in a single segment
+    assert_eq!(index_manager.reader().searcher().num_docs(), 100);
+    assert_eq!(
+      index_manager.segment_info().expect("Failed to get segment info").len(),
+      1
+    );
+  }
+
+  /// Confirm that close() commits staged documents and releases the writer lock.
+  #[test]
+  fn close_commits_staged_documents_and_releases_lock() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Tokyo tower"),
+      Document::new("doc-2", "src-1", "Osaka castle"),
+    ];
+    index_manager.add_documents(&docs).expect("Failed to add documents");
+    index_manager.close().expect("Failed to close index");
+
+    // Reopening needs the writer lock, which close() released
+    let reopened = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to reopen index");
+    assert_eq!(reopened.num_docs(), 2);
+    reopened.close().expect("Failed to close index");
+  }
+
+  /// Confirm that the writer is created with the configured memory budget.
+  #[test]
+  fn writer_memory_bytes_is_taken_from_options() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let options = IndexOptions {
+      writer_memory_bytes: 20_000_000,
+      ..IndexOptions::default()
+    };
+    let index_manager =
+      IndexManager::open_or_create_with_options(tmp_dir.path(), Language::En, None, options)
+        .expect("Failed to create index");
+    assert_eq!(index_manager.writer_memory_bytes(), Some(20_000_000));
+
+    index_manager
+      .add_documents(&[Document::new("1", "src-1", "Tokyo is the capital of Japan")])
+      .expect("Failed to add");
+    index_manager.commit().expect("Failed to commit");
+    assert_eq!(index_manager.num_docs(), 1);
+
+    let read_only = IndexManager::open_read_only(tmp_dir.path(), Language::En, None)
+      .expect("Failed to open read-only");
+    assert_eq!(read_only.writer_memory_bytes(), None);
+  }
+
+  /// Confirm that add_documents commits every batch_commit_size staged documents.
+  #[test]
+  fn add_documents_commits_every_batch_commit_size() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let options = IndexOptions {
+      batch_commit_size: 10,
+      ..IndexOptions::default()
+    };
+    let index_manager =
+      IndexManager::open_or_create_with_options(tmp_dir.path(), Language::En, None, options)
+        .expect("Failed to create index");
+
+    // 25 unique documents + a duplicate of one committed by an automatic commit
+    let mut docs: Vec<Document> =
+      (0..25).map(|i| Document::new(i.to_string(), "src-1", format!("chunk {i}"))).collect();
+    docs.push(Document::new("3", "src-1", "duplicate"));
+
+    let report = index_manager.add_documents(&docs).expect("Failed to add");
+    assert_eq!(report.added, 25);
+    assert_eq!(report.skipped_duplicates, 1);
+
+    // Two automatic commits (20 documents), the remaining 5 are still staged
+    assert_eq!(index_manager.reader().searcher().num_docs(), 20);
+
+    index_manager.commit().expect("Failed to commit");
+    assert_eq!(index_manager.reader().searcher().num_docs(), 25);
+  }
+
+  /// Confirm that add_documents_atomic commits a valid batch at once.
+  #[test]
+  fn add_documents_atomic_commits_valid_batch() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+
+    let docs = vec![
+      Document::new("1", "src-1", "Tokyo is the capital of Japan"),
+      Document::new("2", "src-1", "Osaka is a major city"),
+      Document::new("1", "src-1", "duplicate"),
+    ];
+    let report = index_manager.add_documents_atomic(&docs).expect("Failed to add");
+    assert_eq!(report.added, 2);
+    assert_eq!(report.skipped_duplicates, 1);
+
+    // Visible without an explicit commit
+    assert_eq!(index_manager.reader().searcher().num_docs(), 2);
+  }
+
+  /// Confirm that one invalid document aborts the whole atomic batch.
+  #[test]
+  fn add_documents_atomic_leaves_index_unchanged_on_invalid_document() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+
+    index_manager
+      .add_documents_atomic(&[Document::new("0", "src-0", "existing document")])
+      .expect("Failed to add");
+
+    let docs = vec![
+      Document::new("1", "src-1", "Tokyo is the capital of Japan"),
+      Document::new("2", "src-1", "  "),
+      Document::new("3", "src-1", "Osaka is a major city"),
+    ];
+    let err = index_manager.add_documents_atomic(&docs).expect_err("Should fail");
+    match err {
+      IndexerError::InvalidDocument { doc_id, .. } => assert_eq!(doc_id, "2"),
+      other => panic!("Unexpected error: {other:?}"),
+    }
+
+    // Nothing from the failed batch is visible, even after a commit
+    index_manager.commit().expect("Failed to commit");
+    let searcher = index_manager.reader().searcher();
+    assert_eq!(searcher.num_docs(), 1);
+    for id in ["1", "2", "3"] {
+      assert!(!index_manager.contains_id(&searcher, id).expect("Failed to search"));
+    }
+
+    // IDs of the failed batch can still be added afterwards
+    let report = index_manager.add_documents_atomic(&docs[..1]).expect("Failed to add");
+    assert_eq!(report.added, 1);
+  }
+
+  /// Confirm that import_ndjson ingests valid lines and counts malformed ones.
+  #[test]
+  fn import_ndjson_counts_added_and_malformed_lines() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let options = IndexOptions {
+      batch_commit_size: 2,
+      ..IndexOptions::default()
+    };
+    let index_manager =
+      IndexManager::open_or_create_with_options(tmp_dir.path(), Language::En, None, options)
+        .expect("Failed to create index");
+
+    let input = concat!(
+      r#"{"id":"1","source_id":"src-1","text":"Tokyo is the capital of Japan"}"#,
+      "\n",
+      "not json\n",
+      "\n",
+      r#"{"id":"2","source_id":"src-1","text":"Osaka is a major city","metadata":{"tags":["city"]}}"#,
+      "\n",
+      r#"{"id":"3","text":"missing source_id"}"#,
+      "\n",
+      r#"{"id":"1","source_id":"src-1","text":"duplicate"}"#,
+      "\n",
+      r#"{"id":"4","source_id":"src-2","text":"Kyoto has many temples"}"#,
+    );
+
+    let report = index_manager.import_ndjson(input.as_bytes()).expect("Failed to import");
+    assert_eq!(report.total, 6);
+    assert_eq!(report.added, 3);
+    assert_eq!(report.skipped_duplicates, 1);
+    assert_eq!(report.malformed, 2);
+    assert!(!report.is_all_added());
+
+    // Committed at the end
+    assert_eq!(index_manager.reader().searcher().num_docs(), 3);
+  }
+
+  /// Confirm that concurrent add_documents calls are serialized safely.
+  #[test]
+  fn add_documents_concurrent_calls_are_serialized() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+
+    std::thread::scope(|scope| {
+      for t in 0..4 {
+        let index_manager = &index_manager;
+        scope.spawn(move || {
+          for i in 0..25 {
+            // Every thread also tries the shared ID "dup"
+            let docs = vec![
+              Document::new(format!("{t}-{i}"), "src-1", "concurrent chunk"),
+              Document::new("dup", "src-1", "shared chunk"),
+            ];
+            index_manager.add_documents(&docs).expect("Failed to add");
+          }
+        });
+      }
+    });
+
+    index_manager.commit().expect("Failed to commit");
+    assert_eq!(index_manager.reader().searcher().num_docs(), 4 * 25 + 1);
+  }
+
+  /// Non-blocking calls fail with WriterBusy while the writer lock is held
+  #[test]
+  fn try_add_documents_reports_busy_writer() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+    let docs = vec![Document::new("1", "src-1", "Tokyo is the capital of Japan")];
+
+    {
+      let _state = index_manager.lock_writer().expect("Failed to lock writer");
+      let err = index_manager.try_add_documents(&docs).unwrap_err();
+      assert!(matches!(err, IndexerError::WriterBusy));
+      assert!(matches!(
+        index_manager.try_commit(),
+        Err(IndexerError::WriterBusy)
+      ));
+    }
+
+    // Nothing was staged while busy; succeeds once the lock is released
+    let report = index_manager.try_add_documents(&docs).expect("Failed to add");
+    assert_eq!(report.added, 1);
+    index_manager.try_commit().expect("Failed to commit");
+    assert_eq!(index_manager.num_docs(), 1);
+  }
+
+  /// Staging and committing without blocking happen under a single writer lock
+  #[test]
+  fn try_add_documents_and_commit_commits_or_indexes_nothing() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+    let docs = vec![Document::new("1", "src-1", "Tokyo is the capital of Japan")];
+
+    {
+      let _state = index_manager.lock_writer().expect("Failed to lock writer");
+      let err = index_manager.try_add_documents_and_commit(&docs).unwrap_err();
+      assert!(matches!(err, IndexerError::WriterBusy));
+    }
+    index_manager.commit().expect("Failed to commit");
+    assert_eq!(index_manager.num_docs(), 0);
+
+    let report = index_manager.try_add_documents_and_commit(&docs).expect("Failed to add");
+    assert_eq!(report.added, 1);
+    assert_eq!(index_manager.num_docs(), 1);
+  }
+
+  /// Confirm that deleted English documents disappear and unknown IDs are ignored.
+  #[test]
+  fn delete_documents_english() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+
+    let docs = vec![
+      Document::new("1", "src-1", "Tokyo is the capital of Japan"),
+      Document::new("2", "src-1", "Osaka is a major city"),
+    ];
+    index_manager.add_documents(&docs).expect("Failed to add");
+
+    let deleted = index_manager.delete_documents(&["1", "missing", "1"]).expect("Failed to delete");
+    assert_eq!(deleted, 1);
+    assert_eq!(index_manager.reader().searcher().num_docs(), 1);
+
+    // Deleted ID can be added again
+    let report = index_manager
+      .add_documents(&[Document::new("1", "src-1", "Tokyo again")])
+      .expect("Failed to add");
+    assert_eq!(report.added, 1);
+    index_manager.commit().expect("Failed to commit");
+    assert_eq!(index_manager.reader().searcher().num_docs(), 2);
+
+    // Only unknown IDs -> no delete operation
+    let deleted = index_manager.delete_documents(&["missing"]).expect("Failed to delete");
+    assert_eq!(deleted, 0);
+    assert_eq!(index_manager.reader().searcher().num_docs(), 2);
+  }
+
+  /// Confirm that only chunks of the given source are deleted.
+  #[test]
+  fn delete_by_source_id_keeps_other_sources() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+
+    let docs = vec![
+      Document::new("1", "src-1", "Tokyo is the capital of Japan"),
+      Document::new("2", "src-1", "Tokyo has many stations"),
+      Document::new("3", "src-2", "Tokyo tower is tall"),
+    ];
+    index_manager.add_documents(&docs).expect("Failed to add");
+
+    index_manager.delete_by_source_id("src-1").expect("Failed to delete");
+    assert_eq!(index_manager.reader().searcher().num_docs(), 1);
+
+    let search_engine = crate::searcher::SearchEngine::new(
+      index_manager.index(),
+      *index_manager.fields(),
+      Language::En,
+    )
+    .expect("Failed to create SearchEngine");
+    let results = search_engine.search_tokens_or("tokyo", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "3");
+    assert_eq!(results[0].source_id, "src-2");
+
+    // Unknown source -> nothing deleted
+    index_manager.delete_by_source_id("missing").expect("Failed to delete");
+    assert_eq!(index_manager.reader().searcher().num_docs(), 1);
+  }
+
+  /// Confirm that only documents with the given tag are deleted.
+  #[test]
+  fn delete_by_tag_keeps_untagged_and_other_tagged_documents() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+
+    let docs = vec![
+      Document::new("1", "src-1", "Tokyo staging page").with_tags(["env:staging"]),
+      Document::new("2", "src-1", "Tokyo staging draft").with_tags(["env:staging", "draft"]),
+      Document::new("3", "src-2", "Tokyo production page").with_tags(["env:production"]),
+      Document::new("4", "src-3", "Tokyo untagged page"),
+    ];
+    index_manager.add_documents(&docs).expect("Failed to add");
+    index_manager.commit().expect("Failed to commit");
+
+    index_manager.delete_by_tag("env:staging").expect("Failed to delete");
+
+    let mut ids: Vec<String> =
+      index_manager.iter_documents().map(|doc| doc.expect("Failed to read document").id).collect();
+    ids.sort();
+    assert_eq!(ids, vec!["3", "4"]);
+
+    // Prefix of a tag and unknown tags -> nothing deleted
+    index_manager.delete_by_tag("env").expect("Failed to delete");
+    index_manager.delete_by_tag("env:missing").expect("Failed to delete");
+    assert_eq!(index_manager.reader().searcher().num_docs(), 2);
+  }
+
+  /// Confirm that deleted Japanese documents are no longer found by search.
+  #[test]
+  fn delete_documents_japanese() {
+    let manager = crate::dictionary::DictionaryManager::with_preset(PresetDictionaryKind::Ipadic)
+      .expect("Failed to build DictionaryManager");
+
+    let cache_dir = manager.cache_dir();
+    if !cache_dir.join(PresetDictionaryKind::Ipadic.name()).exists() {
+      eprintln!("No dictionary cache -> Skip");
+      return;
+    }
+
+    let dict = manager.load().expect("Failed to load dictionary");
+    let tokenizer =
+      crate::tokenizer::vibrato_tokenizer::VibratoTokenizer::from_shared_dictionary(dict);
+    let text_analyzer = TextAnalyzer::from(tokenizer);
+
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager =
+      IndexManager::open_or_create(tmp_dir.path(), Language::Ja, Some(text_analyzer))
+        .expect("Failed to create index");
+
+    let docs = vec![
+      Document::new("1", "src-1", "東京は日本の首都です"),
+      Document::new("2", "src-1", "大阪は西日本の中心都市です"),
+    ];
+    index_manager.add_documents(&docs).expect("Failed to add");
+
+    let deleted = index_manager.delete_documents(&["1", "missing"]).expect("Failed to delete");
+    assert_eq!(deleted, 1);
+
+    let search_engine = crate::searcher::SearchEngine::new(
+      index_manager.index(),
+      *index_manager.fields(),
+      Language::Ja,
+    )
+    .expect("Failed to create SearchEngine");
+    let results = search_engine.search_tokens_or("東京", 10).expect("Search failed");
+    assert!(results.is_empty());
+  }
+
+  /// Confirm that every store compression returns identical text and that compression shrinks
+  /// a repetitive corpus.
+  #[test]
+  fn store_compression_preserves_text_and_reduces_size() {
+    let text = "Tokyo is the capital of Japan and a major city. ".repeat(40);
+    let docs: Vec<Document> =
+      (0..50).map(|i| Document::new(i.to_string(), "src-1", text.clone())).collect();
+
+    let store_size = |compression: StoreCompression| {
+      let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+      let options = IndexOptions {
+        store_compression: compression,
+        ..IndexOptions::default()
+      };
+      let index_manager =
+        IndexManager::open_or_create_with_options(tmp_dir.path(), Language::En, None, options)
+          .expect("Failed to create index");
+      index_manager.add_documents(&docs).expect("Failed to add");
+      index_manager.commit().expect("Failed to commit");
+
+      assert_eq!(
+        index_manager.index().settings().docstore_compression,
+        Compressor::from(compression)
+      );
+
+      let searcher = index_manager.reader().searcher();
+      let stored =
+        read_stored_documents(&searcher, index_manager.fields()).expect("Failed to read");
+      assert_eq!(stored.len(), docs.len());
+      assert!(stored.iter().all(|doc| doc.text == text));
+
+      searcher
+        .segment_readers()
+        .iter()
+        .map(|segment| segment.space_usage().expect("Failed to get space usage").store().total())
+        .map(|bytes| bytes.get_bytes())
+        .sum::<u64>()
+    };
+
+    let none = store_size(StoreCompression::None);
+    let lz4 = store_size(StoreCompression::Lz4);
+    let zstd = store_size(StoreCompression::Zstd);
+
+    assert!(lz4 < none, "lz4={lz4} none={none}");
+    assert!(zstd < none, "zstd={zstd} none={none}");
+  }
+
+  /// Confirm that a custom `text` tokenizer name is used for indexing and searching,
+  /// and that reopening requires the same name.
+  #[test]
+  fn custom_text_tokenizer_name_is_used_by_index_and_search() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let whitespace = || TextAnalyzer::from(SimpleTokenizer::default());
+    let options = IndexOptions {
+      text_tokenizer_name: Some("ja_whitespace".to_string()),
+      ..IndexOptions::default()
+    };
+    let index_manager = IndexManager::open_or_create_with_options(
+      tmp_dir.path(),
+      Language::Ja,
+      Some(whitespace()),
+      options.clone(),
+    )
+    .expect("Failed to create index");
+    let docs = vec![
+      Document::new("doc-1", "src-1", "東京 タワー"),
+      Document::new("doc-2", "src-1", "大阪 城"),
+    ];
+    index_manager.add_documents(&docs).expect("Failed to add documents");
+    index_manager.commit().expect("Failed to commit");
+
+    let schema = index_manager.index().schema();
+    let FieldType::Str(text_options) =
+      schema.get_field_entry(index_manager.fields().text).field_type()
+    else {
+      panic!("text must be a text field");
+    };
+    assert_eq!(
+      text_options.get_indexing_options().expect("text must be indexed").tokenizer(),
+      "ja_whitespace"
+    );
+
+    let search_engine = crate::searcher::SearchEngine::new(
+      index_manager.index(),
+      *index_manager.fields(),
+      Language::Ja,
+    )
+    .expect("Failed to create SearchEngine");
+    let results = search_engine.search("タワー", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+    index_manager.close().expect("Failed to close index");
+
+    // Reopening with the default name is rejected
+    let err =
+      IndexManager::open_or_create(tmp_dir.path(), Language::Ja, Some(whitespace())).unwrap_err();
+    assert!(matches!(err, IndexerError::LanguageSchemaMismatch { .. }));
+
+    let reopened = IndexManager::open_or_create_with_options(
+      tmp_dir.path(),
+      Language::Ja,
+      Some(whitespace()),
+      options,
+    )
+    .expect("Failed to reopen index");
+    assert_eq!(reopened.num_docs(), 2);
+  }
+
+  /// Confirm that `text` cannot reuse a tokenizer name registered for another field.
+  #[test]
+  fn reserved_text_tokenizer_name_is_rejected() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    for name in ["raw", "ja_ngram"] {
+      for open_mode in [OpenMode::CreateIfMissing, OpenMode::ReadOnly] {
+        let options = IndexOptions {
+          text_tokenizer_name: Some(name.to_string()),
+          open_mode,
+          ..IndexOptions::default()
+        };
+        let err = IndexManager::open_or_create_with_options(
+          tmp_dir.path(),
+          Language::Ja,
+          Some(TextAnalyzer::from(SimpleTokenizer::default())),
+          options,
+        )
+        .unwrap_err();
+        assert!(
+          matches!(&err, IndexerError::ReservedTokenizerName(reserved) if reserved == name),
+          "unexpected error for {name}: {err:?}"
+        );
+      }
+    }
+    assert!(!tmp_dir.path().join("meta.json").exists());
+  }
+
+  /// Confirm that reopening with another compression is rejected instead of ignored.
+  #[test]
+  fn store_compression_is_fixed_at_creation() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let open = |compression: StoreCompression| {
+      let options = IndexOptions {
+        store_compression: compression,
+        ..IndexOptions::default()
+      };
+      IndexManager::open_or_create_with_options(tmp_dir.path(), Language::En, None, options)
+    };
+
+    drop(open(StoreCompression::None).expect("Failed to create index"));
+    let err = open(StoreCompression::Zstd).unwrap_err();
+    assert!(matches!(err, IndexerError::StoreCompressionMismatch { .. }));
+
+    let reopened = open(StoreCompression::None).expect("Failed to reopen index");
+    assert_eq!(
+      reopened.index().settings().docstore_compression,
+      Compressor::None
+    );
+  }
+
+  /// Confirm that each OpenMode creates / opens / rejects indexes as documented.
+  #[test]
+  fn open_mode_controls_index_creation() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_path = tmp_dir.path().join("en");
+    let open = |open_mode| {
+      let options = IndexOptions {
+        open_mode,
+        ..IndexOptions::default()
+      };
+      IndexManager::open_or_create_with_options(&index_path, Language::En, None, options)
+    };
+
+    // OpenExisting: a missing index is an error, and nothing is created
+    let err = open(OpenMode::OpenExisting).unwrap_err();
+    assert!(matches!(err, IndexerError::IndexNotFound(ref path) if path == &index_path));
+    assert!(!index_path.exists());
+
+    // CreateNew: creates the index when missing
+    let created = open(OpenMode::CreateNew).expect("Failed to create index");
+    created.add_documents(&[Document::new("1", "src-1", "hello")]).expect("Failed to add");
+    created.commit().expect("Failed to commit");
+    drop(created);
+
+    // CreateNew: an existing index is an error
+    let err = open(OpenMode::CreateNew).unwrap_err();
+    assert!(matches!(err, IndexerError::IndexAlreadyExists(ref path) if path == &index_path));
+
+    // OpenExisting / CreateIfMissing: open the existing index
+    let opened = open(OpenMode::OpenExisting).expect("Failed to open index");
+    assert_eq!(opened.num_docs(), 1);
+    drop(opened);
+    let opened = open(OpenMode::CreateIfMissing).expect("Failed to open index");
+    assert_eq!(opened.num_docs(), 1);
+
+    // ReadOnly: opens next to the writer above without the index lock
+    let read_only = open(OpenMode::ReadOnly).expect("Failed to open index read-only");
+    assert!(read_only.is_read_only());
+    assert_eq!(read_only.num_docs(), 1);
+    assert!(matches!(
+      read_only.add_documents(&[Document::new("2", "src-1", "hello")]),
+      Err(IndexerError::ReadOnlyIndex)
+    ));
+
+    // ReadOnly: a missing index is an error
+    let options = IndexOptions {
+      open_mode: OpenMode::ReadOnly,
+      ..IndexOptions::default()
+    };
+    let missing = tmp_dir.path().join("missing");
+    let err =
+      IndexManager::open_or_create_with_options(&missing, Language::En, None, options).unwrap_err();
+    assert!(matches!(err, IndexerError::IndexNotFound(_)));
+  }
+
+  /// Confirm that iter_documents yields every committed document with its metadata.
+  #[test]
+  fn iter_documents_yields_all_stored_documents() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+
+    let docs = vec![
+      Document::new("1", "src-1", "Tokyo tower").with_metadata("page", serde_json::json!(1)),
+      Document::new("2", "src-1", "Kyoto temples")
+        .with_metadata("tags", serde_json::json!(["travel", "kansai"])),
+      Document::new("3", "src-2", "Osaka castle")
+        .with_metadata("author", serde_json::json!({"name": "alice"})),
+    ];
+    index_manager.add_documents(&docs).expect("Failed to add");
+    index_manager.commit().expect("Failed to commit");
+
+    let mut documents: Vec<Document> =
+      index_manager.iter_documents().collect::<Result<_, _>>().expect("Failed to read documents");
+    documents.sort_by(|a, b| a.id.cmp(&b.id));
+
+    assert_eq!(documents.len(), docs.len());
+    for (restored, original) in documents.iter().zip(&docs) {
+      assert_eq!(restored.id, original.id);
+      assert_eq!(restored.source_id, original.source_id);
+      assert_eq!(restored.text, original.text);
+      assert_eq!(restored.metadata, original.metadata);
+    }
+  }
+
+  /// Confirm that the configured stemmer language replaces English stemming.
+  #[test]
+  fn stemmer_language_is_applied_to_english_analyzer() {
+    let stems = |stemmer_language| {
+      let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+      let options = IndexOptions {
+        stemmer_language,
+        ..IndexOptions::default()
+      };
+      let index_manager =
+        IndexManager::open_or_create_with_options(tmp_dir.path(), Language::En, None, options)
+          .expect("Failed to create index");
+      let mut analyzer = index_manager
+        .index()
+        .tokenizers()
+        .get(Language::En.text_tokenizer_name())
+        .expect("English analyzer should be registered");
+      let mut stream = analyzer.token_stream("continuellement chevaux");
+      let mut tokens = Vec::new();
+      while stream.advance() {
+        tokens.push(stream.token().text.clone());
+      }
+      tokens
+    };
+
+    assert_eq!(
+      stems(tantivy::tokenizer::Language::French),
+      ["continuel", "cheval"]
+    );
+    assert_eq!(
+      stems(tantivy::tokenizer::Language::English),
+      ["continuel", "chevaux"]
+    );
+  }
+
+  /// Confirm that short English tokens are dropped on both index and query sides.
+  #[test]
+  fn min_token_len_filters_english_index_and_query_tokens() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let options = IndexOptions {
+      min_token_len: 2,
+      ..IndexOptions::default()
+    };
+    let index_manager =
+      IndexManager::open_or_create_with_options(tmp_dir.path(), Language::En, None, options)
+        .expect("Failed to create index");
+
+    let docs = vec![Document::new("1", "src-1", "A cat went to town")];
+    index_manager.add_documents(&docs).expect("Failed to add");
+    index_manager.commit().expect("Failed to commit");
+
+    // Index side
+    let searcher = index_manager.reader().searcher();
+    let doc_freq = |text: &str| {
+      let term = Term::from_field_text(index_manager.fields().text, text);
+      searcher.doc_freq(&term).expect("Failed to get doc_freq")
+    };
+    assert_eq!(doc_freq("a"), 0);
+    assert_eq!(doc_freq("to"), 1);
+
+    // Query side (same registered analyzer)
+    let mut analyzer = index_manager
+      .index()
+      .tokenizers()
+      .get(Language::En.text_tokenizer_name())
+      .expect("English analyzer should be registered");
+    let mut stream = analyzer.token_stream("a cat to");
+    let mut query_tokens = Vec::new();
+    while stream.advance() {
+      query_tokens.push(stream.token().text.clone());
+    }
+    assert_eq!(query_tokens, vec!["cat", "to"]);
+  }
+
+  /// Japanese index whose analyzer IndexManager builds from a small dictionary and `pos_filter`
+  fn open_japanese_with_filter(
+    path: &Path,
+    pos_filter: PosFilter,
+    open_mode: OpenMode,
+  ) -> Result<IndexManager, IndexerError> {
+    let dict = Dictionary::from_inner(crate::dictionary::in_memory_dictionary(
+      "東京,0,0,1,名詞,固有名詞,地名,*,*,*,東京,トウキョウ,トーキョー\n",
+    ));
+    let options = IndexOptions {
+      pos_filter,
+      open_mode,
+      ..IndexOptions::default()
+    };
+    IndexManager::open_or_create_with_dictionary(path, Language::Ja, Some(Arc::new(dict)), options)
+  }
+
+  /// Reopening with the same POS filter succeeds, in any prefix order
+  #[test]
+  fn pos_filter_is_recorded_and_matches_on_reopen() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let filter = PosFilter::default().with_allow("助詞").with_deny("名詞,固有名詞");
+    drop(
+      open_japanese_with_filter(tmp_dir.path(), filter.clone(), OpenMode::CreateIfMissing)
+        .expect("Failed to create"),
+    );
+
+    let meta = WakeruMeta::read(tmp_dir.path()).expect("Failed to read meta");
+    assert_eq!(
+      meta.and_then(|meta| meta.pos_filter),
+      Some(filter.normalized())
+    );
+
+    drop(
+      open_japanese_with_filter(tmp_dir.path(), filter.clone(), OpenMode::CreateIfMissing)
+        .expect("Failed to reopen"),
+    );
+
+    let reordered = PosFilter::new(
+      filter.allow().iter().rev().cloned(),
+      filter.deny().iter().rev().cloned(),
+    );
+    open_japanese_with_filter(tmp_dir.path(), reordered, OpenMode::ReadOnly)
+      .expect("Failed to open read-only");
+  }
+
+  /// Reopening with a different POS filter is rejected (read-write and read-only)
+  #[test]
+  fn pos_filter_mismatch_is_rejected() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    drop(
+      open_japanese_with_filter(
+        tmp_dir.path(),
+        PosFilter::default(),
+        OpenMode::CreateIfMissing,
+      )
+      .expect("Failed to create"),
+    );
+
+    let other = PosFilter::default().with_allow("助詞");
+    let err = open_japanese_with_filter(tmp_dir.path(), other.clone(), OpenMode::CreateIfMissing)
+      .unwrap_err();
+    assert!(matches!(err, IndexerError::FilterMismatch { .. }));
+
+    let err = open_japanese_with_filter(tmp_dir.path(), other, OpenMode::ReadOnly).unwrap_err();
+    assert!(matches!(err, IndexerError::FilterMismatch { .. }));
+  }
+
+  /// The filter of a caller-built analyzer is unknown: nothing is recorded or checked
+  #[test]
+  fn pos_filter_of_caller_built_analyzer_is_not_recorded() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let analyzer = || Some(TextAnalyzer::from(SimpleTokenizer::default()));
+    let options = IndexOptions {
+      pos_filter: PosFilter::default().with_allow("助詞"),
+      ..IndexOptions::default()
+    };
+    drop(
+      IndexManager::open_or_create_with_options(
+        tmp_dir.path(),
+        Language::Ja,
+        analyzer(),
+        options.clone(),
+      )
+      .expect("Failed to create"),
+    );
+    let meta = WakeruMeta::read(tmp_dir.path()).expect("Failed to read meta");
+    assert_eq!(meta.and_then(|meta| meta.pos_filter), None);
+
+    // A recorded filter cannot be checked against a caller-built analyzer either
+    drop(
+      open_japanese_with_filter(
+        tmp_dir.path(),
+        PosFilter::default(),
+        OpenMode::CreateIfMissing,
+      )
+      .expect("Failed to reopen with dictionary"),
+    );
+    IndexManager::open_or_create_with_options(tmp_dir.path(), Language::Ja, analyzer(), options)
+      .expect("Caller-built analyzer should open");
+  }
+
+  /// Indexes without `wakeru_meta.json` (created by older versions) are accepted
+  #[test]
+  fn missing_wakeru_meta_skips_pos_filter_check() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    drop(
+      open_japanese_with_filter(
+        tmp_dir.path(),
+        PosFilter::default(),
+        OpenMode::CreateIfMissing,
+      )
+      .expect("Failed to create"),
+    );
+    std::fs::remove_file(tmp_dir.path().join(crate::indexer::index_meta::WAKERU_META_JSON))
+      .expect("Failed to remove wakeru_meta.json");
+
+    open_japanese_with_filter(
+      tmp_dir.path(),
+      PosFilter::default().with_allow("助詞"),
+      OpenMode::CreateIfMissing,
+    )
+    .expect("Legacy index should open");
+  }
+
+  /// New indexes record the current schema version and reopen without migration
+  #[test]
+  fn schema_version_is_recorded_and_matches_on_reopen() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    drop(
+      IndexManager::open_or_create(tmp_dir.path(), Language::En, None).expect("Failed to create"),
+    );
+    let meta = WakeruMeta::read(tmp_dir.path()).expect("Failed to read meta");
+    assert_eq!(meta.map(|meta| meta.schema_version), Some(SCHEMA_VERSION));
+
+    drop(
+      IndexManager::open_or_create(tmp_dir.path(), Language::En, None).expect("Failed to reopen"),
+    );
+    IndexManager::open_read_only(tmp_dir.path(), Language::En, None)
+      .expect("Failed to open read-only");
+  }
+
+  /// Indexes of another schema version are rejected (read-write and read-only)
+  #[test]
+  fn schema_version_mismatch_is_rejected() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    drop(
+      IndexManager::open_or_create(tmp_dir.path(), Language::En, None).expect("Failed to create"),
+    );
+    WakeruMeta {
+      schema_version: SCHEMA_VERSION + 1,
+      pos_filter: None,
+      ngram_range: None,
+    }
+    .write(tmp_dir.path())
+    .expect("Failed to write meta");
+
+    let expected_err = |err: IndexerError| {
+      matches!(
+        err,
+        IndexerError::SchemaVersionMismatch { found, expected }
+          if found == SCHEMA_VERSION + 1 && expected == SCHEMA_VERSION
+      )
+    };
+    let err = IndexManager::open_or_create(tmp_dir.path(), Language::En, None).unwrap_err();
+    assert!(expected_err(err));
+    let err = IndexManager::open_read_only(tmp_dir.path(), Language::En, None).unwrap_err();
+    assert!(expected_err(err));
+  }
+
+  /// `wakeru_meta.json` without `schema_version` (older versions) is the legacy version
+  #[test]
+  fn missing_schema_version_is_legacy_version() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    drop(
+      IndexManager::open_or_create(tmp_dir.path(), Language::En, None).expect("Failed to create"),
+    );
+    std::fs::write(
+      tmp_dir.path().join(crate::indexer::index_meta::WAKERU_META_JSON),
+      r#"{"pos_filter": null}"#,
+    )
+    .expect("Failed to write meta");
+
+    assert_eq!(
+      WakeruMeta::read_schema_version(tmp_dir.path()).expect("Failed to read version"),
+      crate::indexer::index_meta::LEGACY_SCHEMA_VERSION
+    );
+    IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Legacy index should open");
+  }
+
+  /// The N-gram range is recorded on creation and a different one is rejected on reopen
+  #[test]
+  fn ngram_range_is_recorded_and_mismatch_is_rejected() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let options = |max_gram, open_mode| IndexOptions {
+      min_gram: 1,
+      max_gram,
+      open_mode,
+      ..IndexOptions::default()
+    };
+    drop(
+      IndexManager::open_or_create_with_options(
+        tmp_dir.path(),
+        Language::Ko,
+        None,
+        options(2, OpenMode::CreateIfMissing),
+      )
+      .expect("Failed to create"),
+    );
+    let meta = WakeruMeta::read(tmp_dir.path()).expect("Failed to read meta");
+    assert_eq!(
+      meta.and_then(|meta| meta.ngram_range),
+      Some(NgramRange {
+        min_gram: 1,
+        max_gram: 2
+      })
+    );
+
+    drop(
+      IndexManager::open_or_create_with_options(
+        tmp_dir.path(),
+        Language::Ko,
+        None,
+        options(2, OpenMode::CreateIfMissing),
+      )
+      .expect("Failed to reopen"),
+    );
+
+    for open_mode in [OpenMode::CreateIfMissing, OpenMode::ReadOnly] {
+      let err = IndexManager::open_or_create_with_options(
+        tmp_dir.path(),
+        Language::Ko,
+        None,
+        options(3, open_mode),
+      )
+      .unwrap_err();
+      assert!(
+        matches!(
+          &err,
+          IndexerError::NgramRangeMismatch { expected, actual }
+            if expected == "1..=2" && actual == "1..=3"
+        ),
+        "unexpected error: {err:?}"
+      );
+    }
+  }
+
+  /// The POS filter only applies to Japanese morphological indexes
+  #[test]
+  fn pos_filter_is_ignored_for_english_index() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    drop(
+      IndexManager::open_or_create(tmp_dir.path(), Language::En, None).expect("Failed to create"),
+    );
+    let meta = WakeruMeta::read(tmp_dir.path()).expect("Failed to read meta");
+    assert_eq!(meta.and_then(|meta| meta.pos_filter), None);
+
+    let options = IndexOptions {
+      pos_filter: PosFilter::default().with_allow("助詞"),
+      ..IndexOptions::default()
+    };
+    IndexManager::open_or_create_with_options(tmp_dir.path(), Language::En, None, options)
+      .expect("Failed to reopen");
+  }
 }
@@ -0,0 +1,88 @@
+//! wakeru Index Meta File
+//!
+//! `wakeru_meta.json` is written beside Tantivy's `meta.json` when an index is created.
+//! It records the schema version and analyzer settings that are not part of the Tantivy
+//! schema, so that an index is not silently reopened with a different configuration.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::IndexerError;
+use crate::tokenizer::PosFilter;
+
+/// wakeru meta file name (placed next to `meta.json`)
+pub(crate) const WAKERU_META_JSON: &str = "wakeru_meta.json";
+
+/// Schema version of indexes created by this version of wakeru
+///
+/// Bump when fields or analyzers change in a way `SchemaFields::from_schema` cannot
+/// absorb, and add the corresponding step to `IndexManager::migrate`.
+pub(crate) const SCHEMA_VERSION: u32 = 1;
+
+/// Schema version assumed for indexes created before it was recorded
+pub(crate) const LEGACY_SCHEMA_VERSION: u32 = 1;
+
+/// Contents of `wakeru_meta.json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct WakeruMeta {
+  /// Schema version the index was created with
+  #[serde(default = "legacy_schema_version")]
+  pub(crate) schema_version: u32,
+  /// POS filter of the Japanese tokenizer (`None` when the index does not use it)
+  #[serde(default)]
+  pub(crate) pos_filter: Option<PosFilter>,
+  /// N-gram range of the `ja_ngram` / `ko_ngram` analyzer (`None` for other languages)
+  #[serde(default)]
+  pub(crate) ngram_range: Option<NgramRange>,
+}
+
+/// N-gram range an index was created with (`IndexOptions::min_gram` / `max_gram`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct NgramRange {
+  pub(crate) min_gram: usize,
+  pub(crate) max_gram: usize,
+}
+
+impl std::fmt::Display for NgramRange {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}..={}", self.min_gram, self.max_gram)
+  }
+}
+
+/// Schema version of `wakeru_meta.json` files without `schema_version`
+fn legacy_schema_version() -> u32 {
+  LEGACY_SCHEMA_VERSION
+}
+
+impl WakeruMeta {
+  /// Reads `wakeru_meta.json` in `index_path` (`None` for indexes created without it)
+  pub(crate) fn read(index_path: &Path) -> Result<Option<Self>, IndexerError> {
+    let path = index_path.join(WAKERU_META_JSON);
+    if !path.exists() {
+      return Ok(None);
+    }
+    let invalid = |reason: String| IndexerError::InvalidIndexMeta {
+      path: path.clone(),
+      reason,
+    };
+    let json = std::fs::read_to_string(&path).map_err(|e| invalid(e.to_string()))?;
+    serde_json::from_str(&json).map(Some).map_err(|e| invalid(e.to_string()))
+  }
+
+  /// Schema version of the index in `index_path` (legacy version if not recorded)
+  pub(crate) fn read_schema_version(index_path: &Path) -> Result<u32, IndexerError> {
+    Ok(Self::read(index_path)?.map_or(LEGACY_SCHEMA_VERSION, |meta| meta.schema_version))
+  }
+
+  /// Writes `wakeru_meta.json` in `index_path`
+  pub(crate) fn write(&self, index_path: &Path) -> Result<(), IndexerError> {
+    let path = index_path.join(WAKERU_META_JSON);
+    let invalid = |reason: String| IndexerError::InvalidIndexMeta {
+      path: path.clone(),
+      reason,
+    };
+    let json = serde_json::to_string_pretty(self).map_err(|e| invalid(e.to_string()))?;
+    std::fs::write(&path, json).map_err(|e| invalid(e.to_string()))
+  }
+}
@@ -3,10 +3,15 @@
 //! Responsible for Tantivy index creation, management, and document addition.
 
 pub mod index_manager;
+mod index_meta;
+pub mod options;
 pub mod report;
 pub mod schema_builder;
+pub mod stats;
 
 /// Re-export major types
-pub use index_manager::IndexManager;
+pub use index_manager::{CommitCounter, IndexManager};
+pub use options::IndexOptions;
 pub use report::AddDocumentsReport;
-pub use schema_builder::{SchemaFields, build_schema};
+pub use schema_builder::{SchemaFields, build_schema, build_schema_with_options};
+pub use stats::{IndexStats, SegmentInfo};
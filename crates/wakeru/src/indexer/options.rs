@@ -0,0 +1,108 @@
+//! Index Option Type Definition
+//!
+//! Defines per-index options derived from `[index]` configuration.
+
+use tantivy::tokenizer::Language as StemmerLanguage;
+
+use crate::config::{Language, OpenMode, StoreCompression, TextRecordOption, TokenizerMode};
+use crate::tokenizer::PosFilter;
+
+/// Options applied when opening or creating an index
+///
+/// `min_token_len`, `min_gram` / `max_gram`, `store_compression`, `english_exact_field`,
+/// `japanese_reading_field`, `tokenizer_mode`, `stemmer_language`, `english_stop_words`,
+/// `case_insensitive_source_id`, `text_record_option` and `with_offsets` are baked into the
+/// index (analyzer output / doc store format / schema), so changing them for an existing index requires reindexing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexOptions {
+  /// Memory buffer size (bytes) of the IndexWriter
+  pub writer_memory_bytes: usize,
+  /// Number of staged documents that triggers an automatic commit in `add_documents`
+  pub batch_commit_size: usize,
+  /// Minimum token length (in characters) for the English analyzer (`1` = no filtering)
+  pub min_token_len: usize,
+  /// Minimum N-gram length of the Japanese / Korean `text_ngram` analyzer
+  /// (recorded in `wakeru_meta.json`; reindex required to change it)
+  pub min_gram: usize,
+  /// Maximum N-gram length of the Japanese / Korean `text_ngram` analyzer
+  /// (recorded in `wakeru_meta.json`; reindex required to change it)
+  pub max_gram: usize,
+  /// Compression of the stored fields (applied only when creating a new index)
+  pub store_compression: StoreCompression,
+  /// Add an unstemmed `text_exact` field (English only, applied only when creating a new index)
+  pub english_exact_field: bool,
+  /// Add a `text_reading` field (Japanese only, applied only when creating a new index)
+  pub japanese_reading_field: bool,
+  /// Tokenizer of the Japanese `text` field (applied only when creating a new index)
+  pub tokenizer_mode: TokenizerMode,
+  /// Stemmer language of the English analyzer
+  pub stemmer_language: StemmerLanguage,
+  /// Drop stop words of `stemmer_language` in the English analyzer (index and query sides)
+  pub english_stop_words: bool,
+  /// Index `source_id` lowercased (applied only when creating a new index)
+  pub case_insensitive_source_id: bool,
+  /// Postings recorded for the `text` field (applied only when creating a new index)
+  pub text_record_option: TextRecordOption,
+  /// Add a stored `text_offsets` field with token byte offsets (applied only when creating a new index)
+  pub with_offsets: bool,
+  /// Keep tags in the `metadata` JSON besides the dedicated `tags` field (default: true)
+  ///
+  /// With `false`, tags are stored only in the `tags` field and merged back into
+  /// `metadata` when documents are read. Ignored for indexes without a `tags` field.
+  pub tags_in_metadata: bool,
+  /// Tokenizer name of the `text` field (`None`: the language default, e.g. `lang_ja`)
+  ///
+  /// Recorded in the schema of a new index, and the analyzer of the language / tokenizer
+  /// mode is registered under it; `SearchEngine` reads the name back from the schema.
+  /// Existing indexes must be reopened with the same name. Names of other fields'
+  /// tokenizers (`raw`, `ja_ngram`, `en_exact`, ...) are rejected when opening.
+  pub text_tokenizer_name: Option<String>,
+  /// Whether a missing index is created or an existing one is rejected (see [`OpenMode`])
+  pub open_mode: OpenMode,
+  /// Part-of-speech filter of the Japanese tokenizer
+  ///
+  /// Only used by [`IndexManager::open_or_create_with_dictionary`], which builds the
+  /// tokenizer with it, records it in `wakeru_meta.json` when a Japanese index is created,
+  /// and checks it when the index is reopened. A passed `tokenizer_ja` keeps its own filter.
+  ///
+  /// [`IndexManager::open_or_create_with_dictionary`]: crate::indexer::IndexManager::open_or_create_with_dictionary
+  pub pos_filter: PosFilter,
+}
+
+impl IndexOptions {
+  /// Tokenizer name of the `text` field for `language`
+  ///
+  /// `text_tokenizer_name` if set, otherwise the default of `language` under `tokenizer_mode`.
+  #[must_use]
+  pub fn text_tokenizer_name(&self, language: Language) -> &str {
+    self
+      .text_tokenizer_name
+      .as_deref()
+      .unwrap_or_else(|| language.text_tokenizer_name_for(self.tokenizer_mode))
+  }
+}
+
+impl Default for IndexOptions {
+  fn default() -> Self {
+    Self {
+      writer_memory_bytes: 50_000_000,
+      batch_commit_size: 1_000,
+      min_token_len: 1,
+      min_gram: 1,
+      max_gram: 1,
+      store_compression: StoreCompression::default(),
+      english_exact_field: false,
+      japanese_reading_field: false,
+      tokenizer_mode: TokenizerMode::default(),
+      stemmer_language: StemmerLanguage::English,
+      english_stop_words: false,
+      case_insensitive_source_id: false,
+      text_record_option: TextRecordOption::default(),
+      with_offsets: false,
+      tags_in_metadata: true,
+      text_tokenizer_name: None,
+      open_mode: OpenMode::default(),
+      pos_filter: PosFilter::default(),
+    }
+  }
+}
@@ -16,12 +16,18 @@ pub struct AddDocumentsReport {
   pub added: usize,
   /// Number of documents skipped due to duplication
   pub skipped_duplicates: usize,
+  /// Number of input lines that could not be parsed as a document (NDJSON import only)
+  #[serde(default)]
+  pub malformed: usize,
+  /// Number of documents skipped by validation (blank `id` / `text`)
+  #[serde(default)]
+  pub invalid: usize,
 }
 
 impl AddDocumentsReport {
-  /// Whether all documents were added (skipped == 0)
+  /// Whether all documents were added (no skipped, malformed or invalid documents)
   pub fn is_all_added(&self) -> bool {
-    self.skipped_duplicates == 0
+    self.skipped_duplicates == 0 && self.malformed == 0 && self.invalid == 0
   }
 
   /// Record successful addition
@@ -38,4 +44,24 @@ impl AddDocumentsReport {
   pub fn record_total(&mut self) {
     self.total += 1;
   }
+
+  /// Record a document that failed validation
+  pub fn record_invalid(&mut self) {
+    self.invalid += 1;
+  }
+
+  /// Record malformed input (also counted in total)
+  pub fn record_malformed(&mut self) {
+    self.total += 1;
+    self.malformed += 1;
+  }
+
+  /// Add counts of another report (e.g. a sub-batch) to this one
+  pub fn merge(&mut self, other: &AddDocumentsReport) {
+    self.total += other.total;
+    self.added += other.added;
+    self.skipped_duplicates += other.skipped_duplicates;
+    self.malformed += other.malformed;
+    self.invalid += other.invalid;
+  }
 }
@@ -4,11 +4,35 @@
 //! Automatically selects appropriate tokenizer for each language.
 
 use tantivy::schema::{
-  Field, IndexRecordOption, JsonObjectOptions, STORED, STRING, Schema, TextFieldIndexing,
-  TextOptions,
+  Field, FieldType, IndexRecordOption, JsonObjectOptions, STORED, STRING, Schema,
+  TextFieldIndexing, TextOptions,
 };
+use tantivy::tokenizer::{TextAnalyzer, TokenStream};
+use tantivy::{Index, Term};
 
-use crate::config::Language;
+use crate::config::{Language, TokenizerMode};
+use crate::indexer::options::IndexOptions;
+use crate::models::model_definition::TAGS_KEY;
+
+/// Tokenizer of `source_id` in indexes created with `case_insensitive_source_id`
+/// (RawTokenizer + LowerCaser)
+pub const SOURCE_ID_LOWERCASE_TOKENIZER: &str = "source_id_lowercase";
+
+/// Tokenizer names used by fields other than `text`: Tantivy's built-in tokenizers
+/// (`raw` indexes `id`, `source_id` and `tags`) and the analyzers registered by wakeru
+///
+/// Registering the `text` analyzer under one of them would replace that field's analyzer.
+pub(crate) const RESERVED_TOKENIZER_NAMES: &[&str] = &[
+  "default",
+  "raw",
+  "en_stem",
+  "whitespace",
+  SOURCE_ID_LOWERCASE_TOKENIZER,
+  "ja_ngram",
+  "ko_ngram",
+  "en_exact",
+  "ja_reading",
+];
 
 /// Structure holding references to schema fields.
 ///
@@ -19,7 +43,7 @@ use crate::config::Language;
 pub struct SchemaFields {
   /// Chunk ID (STRING + STORED) - For exact match
   pub id: Field,
-  /// Source Document ID (STRING + STORED)
+  /// Source Document ID (STRING + STORED, lowercased with `case_insensitive_source_id`)
   pub source_id: Field,
   /// Body field (TEXT + STORED, language-specific tokenizer)
   pub text: Field,
@@ -31,6 +55,18 @@ pub struct SchemaFields {
   /// Used only in Japanese, None in English
   /// Option because it may not exist in existing indices
   pub text_ngram: Option<Field>,
+  /// Unstemmed body field (TEXT, en_exact tokenizer) for boosting exact matches
+  /// Created only for English indexes with `english_exact_field`, None otherwise
+  pub text_exact: Option<Field>,
+  /// Katakana reading field (TEXT, ja_reading tokenizer) for kana queries
+  /// Created only for Japanese indexes with `japanese_reading_field`, None otherwise
+  pub text_reading: Option<Field>,
+  /// Tags (STRING + STORED, one value per tag) for direct tag filtering
+  /// None in indexes created before the field was added (tags are then only in `metadata`)
+  pub tags: Option<Field>,
+  /// Token byte offsets of `text` (u64, STORED only; `[from, to]` pairs in token order)
+  /// Created only with `with_offsets`, None otherwise
+  pub text_offsets: Option<Field>,
 }
 
 impl SchemaFields {
@@ -65,12 +101,28 @@ impl SchemaFields {
     // N-gram field is only for Japanese index, or may not exist in old index
     let text_ngram = schema.get_field("text_ngram").ok();
 
+    // Exact field is optional (English indexes created with `english_exact_field` only)
+    let text_exact = schema.get_field("text_exact").ok();
+
+    // Reading field is optional (Japanese indexes created with `japanese_reading_field` only)
+    let text_reading = schema.get_field("text_reading").ok();
+
+    // Tags field does not exist in old indexes
+    let tags = schema.get_field("tags").ok();
+
+    // Offsets field is optional (indexes created with `with_offsets` only)
+    let text_offsets = schema.get_field("text_offsets").ok();
+
     Ok(Self {
       id,
       source_id,
       text,
       metadata,
       text_ngram,
+      text_exact,
+      text_reading,
+      tags,
+      text_offsets,
     })
   }
 }
@@ -80,19 +132,30 @@ impl SchemaFields {
 /// # Field Configuration
 ///
 /// - `id`: Chunk ID (STRING + STORED) For exact match
-/// - `source_id`: Source Document ID (STRING + STORED)
+/// - `source_id`: Source Document ID (STRING + STORED; indexed lowercased with
+///   `source_id_lowercase` tokenizer when `case_insensitive_source_id` is set)
 /// - `text`: Body (TEXT + STORED, language-specific tokenizer)
 /// - `metadata`: Structured metadata (JsonObject, STORED + INDEXED, raw tokenizer)
-/// - `text_ngram`: For 1-char N-gram (TEXT, ja_ngram tokenizer) - Japanese only
+/// - `text_ngram`: For N-gram (TEXT, ja_ngram / ko_ngram tokenizer) - Japanese and Korean only
+/// - `text_exact`: Unstemmed body (TEXT, en_exact tokenizer) - English only, opt-in
+///   (see [`build_schema_with_options`])
+/// - `text_reading`: Katakana reading (TEXT, ja_reading tokenizer) - Japanese only, opt-in
+///   (see [`build_schema_with_options`])
+/// - `tags`: Tags (STRING + STORED, multi-valued), one value per `Document::tags()` entry
+/// - `text_offsets`: Token byte offsets of `text` (u64, STORED) - opt-in
+///   (see [`build_schema_with_options`])
 ///
 /// # Tokenizer Settings (Language dependent)
 ///
 /// - Japanese (`Language::Ja`):
-///   - `lang_ja` tokenizer for `text` field
+///   - `lang_ja` tokenizer for `text` field (`cjk_bigram` under `TokenizerMode::CjkBigram`)
 ///   - `ja_ngram` tokenizer for `text_ngram` field
 /// - English (`Language::En`):
 ///   - `lang_en` tokenizer for `text` field (SimpleTokenizer + LowerCaser)
 ///   - `text_ngram` field is not created
+/// - Korean (`Language::Ko`):
+///   - `lang_ko` tokenizer for `text` field (SimpleTokenizer + LowerCaser)
+///   - `ko_ngram` tokenizer for `text_ngram` field
 ///
 /// Tokenizers must be registered when creating `IndexManager`.
 ///
@@ -103,6 +166,10 @@ impl SchemaFields {
 /// - Position information (Positions) is required for phrase search
 /// - Position information is also used for highlighting
 ///
+/// With `TextRecordOption::FreqsOnly`, the `text` field records `WithFreqs` instead:
+/// BM25 still works, but phrase search on `text` is unavailable (see
+/// [`build_schema_with_options`]).
+///
 /// # Metadata field design
 ///
 /// `metadata` is JsonObject type and has the following characteristics:
@@ -121,18 +188,44 @@ impl SchemaFields {
 /// // Use fields in IndexManager or SearchEngine
 /// ```
 pub fn build_schema(language: Language) -> (Schema, SchemaFields) {
+  build_schema_with_options(language, &IndexOptions::default())
+}
+
+/// Builds Tantivy schema, applying schema-related index options.
+///
+/// Same as [`build_schema`], except that `text_exact` is created for English
+/// when `options.english_exact_field` is set, `text_reading` is created for
+/// Japanese when `options.japanese_reading_field` is set (morphological mode only),
+/// `source_id` is indexed lowercased when `options.case_insensitive_source_id` is set,
+/// the `text` tokenizer follows `options.text_tokenizer_name` (or, for Japanese,
+/// `options.tokenizer_mode`), the `text`
+/// postings follow `options.text_record_option`, and `text_offsets` is created
+/// when `options.with_offsets` is set.
+pub fn build_schema_with_options(
+  language: Language,
+  options: &IndexOptions,
+) -> (Schema, SchemaFields) {
   let mut builder = Schema::builder();
 
   // ID field: Exact match search + Stored
   let id = builder.add_text_field("id", STRING | STORED);
 
-  // Source document ID
-  let source_id = builder.add_text_field("source_id", STRING | STORED);
+  // Source document ID: Exact match, optionally case-insensitive
+  let source_id = if options.case_insensitive_source_id {
+    let source_id_indexing = TextFieldIndexing::default()
+      .set_tokenizer(SOURCE_ID_LOWERCASE_TOKENIZER)
+      .set_index_option(IndexRecordOption::Basic);
+    let source_id_options =
+      TextOptions::default().set_indexing_options(source_id_indexing).set_stored();
+    builder.add_text_field("source_id", source_id_options)
+  } else {
+    builder.add_text_field("source_id", STRING | STORED)
+  };
 
-  // Body field: Language-specific tokenizer + Record frequency and position
+  // Body field: Language-specific tokenizer + Record frequency (and position by default)
   let text_indexing = TextFieldIndexing::default()
-    .set_tokenizer(language.text_tokenizer_name())
-    .set_index_option(IndexRecordOption::WithFreqsAndPositions);
+    .set_tokenizer(options.text_tokenizer_name(language))
+    .set_index_option(options.text_record_option.into());
   let text_options = TextOptions::default().set_indexing_options(text_indexing).set_stored();
   let text = builder.add_text_field("text", text_options);
 
@@ -145,7 +238,7 @@ pub fn build_schema(language: Language) -> (Schema, SchemaFields) {
     JsonObjectOptions::default().set_stored().set_indexing_options(json_indexing);
   let metadata = builder.add_json_field("metadata", metadata_options);
 
-  // 1-char N-gram field: Created only for Japanese
+  // N-gram field: Created only for Japanese and Korean
   // None for English
   let text_ngram = language.ngram_tokenizer_name().map(|tokenizer_name| {
     let text_ngram_indexing = TextFieldIndexing::default()
@@ -155,6 +248,35 @@ pub fn build_schema(language: Language) -> (Schema, SchemaFields) {
     builder.add_text_field("text_ngram", text_ngram_options)
   });
 
+  // Unstemmed field: English only, opt-in
+  let text_exact =
+    language.exact_tokenizer_name().filter(|_| options.english_exact_field).map(|tokenizer_name| {
+      let text_exact_indexing = TextFieldIndexing::default()
+        .set_tokenizer(tokenizer_name)
+        .set_index_option(IndexRecordOption::WithFreqsAndPositions);
+      let text_exact_options = TextOptions::default().set_indexing_options(text_exact_indexing);
+      builder.add_text_field("text_exact", text_exact_options)
+    });
+
+  // Reading field: Japanese only, opt-in (needs the dictionary)
+  let text_reading = language
+    .reading_tokenizer_name()
+    .filter(|_| options.japanese_reading_field)
+    .filter(|_| options.tokenizer_mode == TokenizerMode::Morphological)
+    .map(|tokenizer_name| {
+      let text_reading_indexing = TextFieldIndexing::default()
+        .set_tokenizer(tokenizer_name)
+        .set_index_option(IndexRecordOption::WithFreqsAndPositions);
+      let text_reading_options = TextOptions::default().set_indexing_options(text_reading_indexing);
+      builder.add_text_field("text_reading", text_reading_options)
+    });
+
+  // Tags field: Exact match per tag (multi-valued) + Stored
+  let tags = builder.add_text_field("tags", STRING | STORED);
+
+  // Offsets field: stored only, opt-in
+  let text_offsets = options.with_offsets.then(|| builder.add_u64_field("text_offsets", STORED));
+
   let schema = builder.build();
 
   (
@@ -165,6 +287,105 @@ pub fn build_schema(language: Language) -> (Schema, SchemaFields) {
       text,
       metadata,
       text_ngram,
+      text_exact,
+      text_reading,
+      tags: Some(tags),
+      text_offsets,
     },
   )
 }
+
+/// Returns whether `field` records term positions (required for phrase queries)
+pub(crate) fn has_positions(index: &Index, field: Field) -> bool {
+  match index.schema().get_field_entry(field).field_type() {
+    FieldType::Str(options) => {
+      options.get_indexing_options().is_some_and(|indexing| indexing.index_option().has_positions())
+    }
+    _ => false,
+  }
+}
+
+/// Returns the registered analyzer of the indexed text field `field`
+///
+/// `None` if the field is not an indexed text field or its tokenizer is not registered.
+pub(crate) fn field_analyzer(index: &Index, field: Field) -> Option<TextAnalyzer> {
+  let tokenizer_name = match index.schema().get_field_entry(field).field_type() {
+    FieldType::Str(options) => {
+      options.get_indexing_options().map(|indexing| indexing.tokenizer().to_string())
+    }
+    _ => None,
+  }?;
+  index.tokenizers().get(&tokenizer_name)
+}
+
+/// Builds the `source_id` term to look up `source_id`, normalized like indexed values
+///
+/// The value is passed through the field's own analyzer, so it is lowercased for indexes
+/// created with `case_insensitive_source_id` and kept as is otherwise.
+pub(crate) fn source_id_term(index: &Index, field: Field, source_id: &str) -> Term {
+  let normalized = field_analyzer(index, field)
+    .and_then(|mut analyzer| {
+      let mut stream = analyzer.token_stream(source_id);
+      stream.advance().then(|| stream.token().text.clone())
+    })
+    .unwrap_or_else(|| source_id.to_string());
+
+  Term::from_field_text(field, &normalized)
+}
+
+/// Builds the term matching documents tagged with exactly `tag`
+///
+/// Uses the dedicated `tags` field, or `metadata.tags` for indexes created without it.
+pub(crate) fn tag_term(fields: &SchemaFields, tag: &str) -> Term {
+  match fields.tags {
+    Some(tags_field) => Term::from_field_text(tags_field, tag),
+    None => {
+      let mut term = Term::from_field_json_path(fields.metadata, TAGS_KEY, false);
+      term.append_type_and_str(tag);
+      term
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::config::TextRecordOption;
+
+  /// Index option of the `text` field entry
+  fn text_index_option(schema: &Schema, fields: &SchemaFields) -> IndexRecordOption {
+    match schema.get_field_entry(fields.text).field_type() {
+      FieldType::Str(options) => {
+        options.get_indexing_options().expect("text must be indexed").index_option()
+      }
+      other => panic!("unexpected text field type: {other:?}"),
+    }
+  }
+
+  #[test]
+  fn text_field_records_positions_by_default() {
+    let (schema, fields) = build_schema(Language::En);
+    assert_eq!(
+      text_index_option(&schema, &fields),
+      IndexRecordOption::WithFreqsAndPositions
+    );
+  }
+
+  #[test]
+  fn text_field_honors_freqs_only_option() {
+    let options = IndexOptions {
+      text_record_option: TextRecordOption::FreqsOnly,
+      ..IndexOptions::default()
+    };
+    for language in [Language::Ja, Language::En] {
+      let (schema, fields) = build_schema_with_options(language, &options);
+      assert_eq!(
+        text_index_option(&schema, &fields),
+        IndexRecordOption::WithFreqs
+      );
+
+      let index = Index::create_in_ram(schema);
+      assert!(!has_positions(&index, fields.text));
+    }
+  }
+}
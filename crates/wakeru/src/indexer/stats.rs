@@ -0,0 +1,30 @@
+//! Index Statistics Type Definitions
+//!
+//! Defines types to report the physical state of an index (segments, sizes, etc.).
+
+use serde::{Deserialize, Serialize};
+
+/// Per-segment information of a Tantivy index
+///
+/// Useful to understand merge behavior and search performance
+/// (e.g. many tiny segments, high deletion ratio).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SegmentInfo {
+  /// Number of alive (non-deleted) documents in the segment
+  pub num_docs: u32,
+  /// Number of documents marked as deleted in the segment
+  pub num_deleted: u32,
+  /// Total size of the segment files (bytes)
+  pub size_bytes: u64,
+}
+
+/// Summary statistics of a Tantivy index
+///
+/// Cheap to compute (no file size scan), suitable for health dashboards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct IndexStats {
+  /// Number of alive (non-deleted) documents
+  pub num_docs: u64,
+  /// Number of segments
+  pub num_segments: usize,
+}
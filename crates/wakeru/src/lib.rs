@@ -2,9 +2,17 @@
 //!
 //! Performs morphological analysis for Japanese and other languages using vibrato-rkyv.
 
+/// Async service module - Provides AsyncWakeruService running WakeruService on blocking threads
+/// (requires the `async` feature)
+#[cfg(feature = "async")]
+pub mod async_service;
+
 /// Configuration module - Defines configuration structures such as WakeruConfig and Language
 pub mod config;
 
+/// Detection module - Heuristic language detection for routing documents to language indexes
+pub mod detect;
+
 /// Dictionary module - Provides management and loading functionality for morphological analysis dictionaries
 pub mod dictionary;
 
@@ -23,10 +31,16 @@ pub mod searcher;
 /// Service module - Provides high-level APIs such as WakeruService
 pub mod service;
 
+/// Span module - Tracing spans with timing around indexing and search
+pub(crate) mod span;
+
 /// Tokenizer module - Morphological analysis tokenizer using vibrato-rkyv
 pub mod tokenizer;
 
 /// Re-exports
-pub use config::{Language, WakeruConfig};
+#[cfg(feature = "async")]
+pub use async_service::AsyncWakeruService;
+pub use config::{Language, WakeruConfig, WakeruConfigBuilder};
+pub use detect::detect_language;
 pub use errors::{WakeruError, WakeruResult};
 pub use service::WakeruService;
@@ -2,4 +2,4 @@
 pub mod model_definition;
 
 /// Re-exports
-pub use model_definition::{Document, Metadata, SearchResult};
+pub use model_definition::{Document, MatchKind, Metadata, SearchResult};
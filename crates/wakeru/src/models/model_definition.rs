@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 
+use crate::errors::IndexerError;
+
 /// Reserved key for saving tag information within metadata.
 ///
 /// Tag filters during search (`metadata.tags:value`) assume an array saved under this key.
@@ -59,6 +61,31 @@ pub struct SearchResult {
   /// Arbitrary metadata
   #[serde(default)]
   pub metadata: Metadata,
+
+  /// How this result matched the query (set only by `smart_search`)
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub match_kind: Option<MatchKind>,
+
+  /// Analyzed query tokens found in `text`, in query order
+  ///
+  /// Set by token-based searches (`search_tokens_or`, `search_tokens_and`, `smart_search`, ...);
+  /// empty for `search`, `search_phrase` and `search_fuzzy`.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub matched_terms: Vec<String>,
+}
+
+/// How a search result matched the query
+///
+/// Ordered from strongest to weakest match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchKind {
+  /// Contains the query tokens as a contiguous phrase
+  Phrase,
+  /// Contains all query tokens (not necessarily adjacent)
+  AllTerms,
+  /// Contains only some of the query tokens
+  SomeTerms,
 }
 
 /// Implementation block for Document
@@ -73,6 +100,27 @@ impl Document {
     }
   }
 
+  /// Checks that the document can be indexed meaningfully.
+  ///
+  /// `source_id` may be empty (documents without a source share the empty source).
+  ///
+  /// # Errors
+  /// - `IndexerError::InvalidDocument`: `id` or `text` is blank
+  pub fn validate(&self) -> Result<(), IndexerError> {
+    let invalid = |reason: &str| IndexerError::InvalidDocument {
+      doc_id: self.id.clone(),
+      reason: reason.to_string(),
+    };
+
+    if self.id.trim().is_empty() {
+      return Err(invalid("id is blank"));
+    }
+    if self.text.trim().is_empty() {
+      return Err(invalid("text is blank"));
+    }
+    Ok(())
+  }
+
   /// Builder that adds one metadata item and returns Self
   #[must_use]
   pub fn with_metadata(mut self, key: impl Into<String>, value: JsonValue) -> Self {
@@ -143,15 +191,47 @@ impl Document {
   /// Returns string elements as `Vec<String>` only if `metadata[TAGS_KEY]` is a JSON array.
   /// Returns an empty vector in other cases or if unset.
   pub fn tags(&self) -> Vec<String> {
-    self
-      .metadata
-      .get(TAGS_KEY)
-      .and_then(|v| v.as_array())
-      .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
-      .unwrap_or_default()
+    tags_in(&self.metadata)
   }
 }
 
+/// Implementation block for SearchResult
+///
+/// Typed accessors for `metadata`. Each returns `None` if the key is absent or the value
+/// has another JSON type (no conversion such as `"42"` -> `42` is done).
+impl SearchResult {
+  /// Returns `metadata[key]` if it is a JSON string.
+  pub fn metadata_str(&self, key: &str) -> Option<&str> {
+    self.metadata.get(key).and_then(JsonValue::as_str)
+  }
+
+  /// Returns `metadata[key]` if it is a JSON integer representable as `i64`.
+  pub fn metadata_i64(&self, key: &str) -> Option<i64> {
+    self.metadata.get(key).and_then(JsonValue::as_i64)
+  }
+
+  /// Returns `metadata[key]` if it is a JSON boolean.
+  pub fn metadata_bool(&self, key: &str) -> Option<bool> {
+    self.metadata.get(key).and_then(JsonValue::as_bool)
+  }
+
+  /// Extracts the list of tags stored in metadata.
+  ///
+  /// Same as [`Document::tags`].
+  pub fn tags(&self) -> Vec<String> {
+    tags_in(&self.metadata)
+  }
+}
+
+/// String elements of `metadata[TAGS_KEY]` (empty if unset or not a JSON array)
+fn tags_in(metadata: &Metadata) -> Vec<String> {
+  metadata
+    .get(TAGS_KEY)
+    .and_then(|v| v.as_array())
+    .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
+    .unwrap_or_default()
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Test Module
 // ─────────────────────────────────────────────────────────────────────────────
@@ -188,6 +268,28 @@ mod tests {
     assert_eq!(doc2.id, "id2");
   }
 
+  // ─── Test Document::validate ──────────────────────────────────────────
+
+  #[test]
+  fn validate_accepts_non_blank_fields() {
+    assert!(Document::new("id", "src", "text").validate().is_ok());
+    // source_id is optional
+    assert!(Document::new("id", "", "text").validate().is_ok());
+  }
+
+  #[test]
+  fn validate_rejects_blank_fields() {
+    for doc in [
+      Document::new("", "src", "text"),
+      Document::new("id", "src", "\n\t"),
+    ] {
+      assert!(
+        matches!(doc.validate(), Err(IndexerError::InvalidDocument { .. })),
+        "expected InvalidDocument for {doc:?}"
+      );
+    }
+  }
+
   // ─── Test with_metadata / with_metadata_map ───────────────────────────
 
   #[test]
@@ -476,6 +578,8 @@ mod tests {
       score: 0.95,
       text: "result text".to_string(),
       metadata: Metadata::from([("key".to_string(), json!("value"))]),
+      match_kind: None,
+      matched_terms: Vec::new(),
     };
 
     let json_str = serde_json::to_string(&result).expect("should serialize");
@@ -483,6 +587,25 @@ mod tests {
     assert!(json_str.contains("doc-1"));
     assert!(json_str.contains("0.95"));
     assert!(json_str.contains("result text"));
+    assert!(!json_str.contains("match_kind"));
+    assert!(!json_str.contains("matched_terms"));
+  }
+
+  #[test]
+  fn search_result_serializes_match_kind_in_snake_case() {
+    let result = SearchResult {
+      doc_id: "doc-1".to_string(),
+      source_id: "src-1".to_string(),
+      score: 0.95,
+      text: "result text".to_string(),
+      metadata: Metadata::default(),
+      match_kind: Some(MatchKind::AllTerms),
+      matched_terms: Vec::new(),
+    };
+
+    let value = serde_json::to_value(&result).expect("should serialize");
+
+    assert_eq!(value["match_kind"], "all_terms");
   }
 
   #[test]
@@ -518,4 +641,72 @@ mod tests {
 
     assert!(result.metadata.is_empty());
   }
+
+  // ─── Test SearchResult metadata accessors ─────────────────────────────────
+
+  fn search_result_with_metadata(metadata: JsonValue) -> SearchResult {
+    SearchResult {
+      doc_id: "doc-1".to_string(),
+      source_id: "src-1".to_string(),
+      score: 1.0,
+      text: "result text".to_string(),
+      metadata: serde_json::from_value(metadata).expect("metadata should be an object"),
+      match_kind: None,
+      matched_terms: Vec::new(),
+    }
+  }
+
+  #[test]
+  fn metadata_accessors_return_present_values() {
+    let result = search_result_with_metadata(json!({
+      "title": "Guide",
+      "page": 42,
+      "draft": false,
+    }));
+
+    assert_eq!(result.metadata_str("title"), Some("Guide"));
+    assert_eq!(result.metadata_i64("page"), Some(42));
+    assert_eq!(result.metadata_bool("draft"), Some(false));
+  }
+
+  #[test]
+  fn metadata_accessors_return_none_for_absent_keys() {
+    let result = search_result_with_metadata(json!({}));
+
+    assert_eq!(result.metadata_str("title"), None);
+    assert_eq!(result.metadata_i64("page"), None);
+    assert_eq!(result.metadata_bool("draft"), None);
+    assert!(result.tags().is_empty());
+  }
+
+  #[test]
+  fn metadata_accessors_return_none_for_type_mismatch() {
+    let result = search_result_with_metadata(json!({
+      "title": 1,
+      "page": "42",
+      "ratio": 0.5,
+      "draft": "false",
+      "null": null,
+    }));
+
+    assert_eq!(result.metadata_str("title"), None);
+    assert_eq!(result.metadata_i64("page"), None);
+    assert_eq!(result.metadata_i64("ratio"), None);
+    assert_eq!(result.metadata_bool("draft"), None);
+    assert_eq!(result.metadata_str("null"), None);
+  }
+
+  #[test]
+  fn search_result_tags_mirror_document_tags() {
+    let doc = Document::new("doc-1", "src-1", "text").with_tags(["project:foo", "env:prod"]);
+    let mut metadata = doc.metadata.clone();
+    metadata.insert("other".to_string(), json!(1));
+    let result = search_result_with_metadata(json!(metadata));
+
+    assert_eq!(result.tags(), doc.tags());
+    assert_eq!(result.tags(), vec!["project:foo", "env:prod"]);
+
+    let result = search_result_with_metadata(json!({ "tags": ["a", 1, "b"] }));
+    assert_eq!(result.tags(), vec!["a", "b"]);
+  }
 }
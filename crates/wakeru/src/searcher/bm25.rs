@@ -0,0 +1,289 @@
+//! BM25 With Custom Parameters
+//!
+//! Tantivy scores terms with fixed BM25 parameters (`k1 = 1.2`, `b = 0.75`).
+//! [`Bm25RescoreQuery`] wraps a query and rescores the BM25 component of its terms with
+//! configurable parameters, using the same index statistics (IDF, average field length)
+//! as Tantivy.
+
+use tantivy::fieldnorm::FieldNormReader;
+use tantivy::postings::{Postings, SegmentPostings};
+use tantivy::query::{EnableScoring, Explanation, Query, Scorer, Weight};
+use tantivy::schema::{Field, IndexRecordOption};
+use tantivy::{DocId, DocSet, Score, SegmentReader, TantivyError, Term};
+
+use crate::config::Bm25Config;
+
+/// BM25 parameters hard-coded in Tantivy's term scorer
+const TANTIVY_PARAMS: Bm25Config = Bm25Config { k1: 1.2, b: 0.75 };
+
+/// Query matching the same documents as `inner`, with its `field` terms scored by `params`
+///
+/// For each `inner` query term of `field` found in the document, the difference between
+/// its BM25 score with `params` and with Tantivy's parameters is added to the `inner`
+/// score. When `inner` scores those terms with Tantivy's BM25 (term and boolean queries),
+/// only their component changes: clauses on other fields and constant-score clauses keep
+/// their contribution. Boosts applied inside `inner` are not reflected in the difference,
+/// so boost the wrapper instead.
+#[derive(Debug)]
+pub(crate) struct Bm25RescoreQuery {
+  inner: Box<dyn Query>,
+  field: Field,
+  params: Bm25Config,
+}
+
+impl Bm25RescoreQuery {
+  pub(crate) fn new(inner: Box<dyn Query>, field: Field, params: Bm25Config) -> Self {
+    Self {
+      inner,
+      field,
+      params,
+    }
+  }
+}
+
+impl Clone for Bm25RescoreQuery {
+  fn clone(&self) -> Self {
+    Self::new(self.inner.box_clone(), self.field, self.params)
+  }
+}
+
+/// IDF as computed by Tantivy
+fn idf(doc_freq: u64, doc_count: u64) -> Score {
+  let x = (doc_count.saturating_sub(doc_freq) as Score + 0.5) / (doc_freq as Score + 0.5);
+  (1.0 + x).ln()
+}
+
+/// Term frequency part of BM25: `(k1 + 1) * tf / (tf + k1 * (1 - b + b * dl / avgdl))`
+fn tf_norm(
+  params: Bm25Config,
+  term_freq: Score,
+  fieldnorm: Score,
+  average_fieldnorm: Score,
+) -> Score {
+  let Bm25Config { k1, b } = params;
+  (k1 + 1.0) * term_freq / (term_freq + k1 * (1.0 - b + b * fieldnorm / average_fieldnorm))
+}
+
+impl Query for Bm25RescoreQuery {
+  fn weight(&self, enable_scoring: EnableScoring<'_>) -> tantivy::Result<Box<dyn Weight>> {
+    let inner = self.inner.weight(enable_scoring)?;
+    let EnableScoring::Enabled {
+      statistics_provider,
+      ..
+    } = enable_scoring
+    else {
+      return Ok(inner);
+    };
+
+    let mut terms: Vec<Term> = Vec::new();
+    self.inner.query_terms(&mut |term, _| {
+      if term.field() == self.field && !terms.contains(term) {
+        terms.push(term.clone());
+      }
+    });
+
+    let num_docs = statistics_provider.total_num_docs()?;
+    if terms.is_empty() || num_docs == 0 {
+      return Ok(inner);
+    }
+
+    let average_fieldnorm =
+      statistics_provider.total_num_tokens(self.field)? as Score / num_docs as Score;
+    let terms = terms
+      .into_iter()
+      .map(|term| {
+        let doc_freq = statistics_provider.doc_freq(&term)?;
+        Ok(TermStats {
+          term,
+          doc_freq,
+          idf: idf(doc_freq, num_docs),
+        })
+      })
+      .collect::<tantivy::Result<Vec<_>>>()?;
+
+    Ok(Box::new(Bm25RescoreWeight {
+      inner,
+      field: self.field,
+      params: self.params,
+      terms,
+      num_docs,
+      average_fieldnorm,
+    }))
+  }
+
+  fn query_terms<'a>(&'a self, visitor: &mut dyn FnMut(&'a Term, bool)) {
+    self.inner.query_terms(visitor);
+  }
+}
+
+/// Scored term with its index statistics
+struct TermStats {
+  term: Term,
+  /// Number of documents containing the term
+  doc_freq: u64,
+  idf: Score,
+}
+
+/// Weight of [`Bm25RescoreQuery`]
+struct Bm25RescoreWeight {
+  inner: Box<dyn Weight>,
+  field: Field,
+  params: Bm25Config,
+  terms: Vec<TermStats>,
+  num_docs: u64,
+  average_fieldnorm: Score,
+}
+
+impl Bm25RescoreWeight {
+  /// Explains the BM25 term frequency part with `params` for one term of a document
+  fn explain_tf_norm(&self, params: Bm25Config, term_freq: Score, fieldnorm: Score) -> Explanation {
+    let Bm25Config { k1, b } = params;
+    let mut explanation = Explanation::new_with_string(
+      format!(
+        "tf (k1={k1}, b={b}), computed as (k1 + 1) * freq / (freq + k1 * (1 - b + b * dl / avgdl))"
+      ),
+      tf_norm(params, term_freq, fieldnorm, self.average_fieldnorm),
+    );
+    explanation.add_const("freq, occurrences of term within document", term_freq);
+    explanation.add_const("k1, term saturation parameter", k1);
+    explanation.add_const("b, length normalization parameter", b);
+    explanation.add_const("dl, length of field", fieldnorm);
+    explanation.add_const("avgdl, average length of field", self.average_fieldnorm);
+    explanation
+  }
+
+  /// Explains the score difference of `stats` for a document
+  fn explain_term(&self, stats: &TermStats, term_freq: Score, fieldnorm: Score) -> Explanation {
+    let custom = self.explain_tf_norm(self.params, term_freq, fieldnorm);
+    let tantivy = self.explain_tf_norm(TANTIVY_PARAMS, term_freq, fieldnorm);
+
+    let term_value = stats.term.value();
+    let term_text = term_value.as_str().unwrap_or_default();
+    let mut explanation = Explanation::new_with_string(
+      format!(
+        "BM25 difference for \"{term_text}\", computed as idf * (tf - tf with Tantivy params)"
+      ),
+      stats.idf * (custom.value() - tantivy.value()),
+    );
+    let mut idf_explanation = Explanation::new(
+      "idf, computed as log(1 + (N - n + 0.5) / (n + 0.5))",
+      stats.idf,
+    );
+    idf_explanation.add_const(
+      "n, number of docs containing this term",
+      stats.doc_freq as Score,
+    );
+    idf_explanation.add_const("N, total number of docs", self.num_docs as Score);
+    explanation.add_detail(idf_explanation);
+    explanation.add_detail(custom);
+    explanation.add_detail(tantivy);
+    explanation
+  }
+}
+
+impl Weight for Bm25RescoreWeight {
+  fn scorer(&self, reader: &SegmentReader, boost: Score) -> tantivy::Result<Box<dyn Scorer>> {
+    let inner = self.inner.scorer(reader, boost)?;
+    let inverted_index = reader.inverted_index(self.field)?;
+
+    let mut postings = Vec::with_capacity(self.terms.len());
+    for stats in &self.terms {
+      if let Some(segment_postings) =
+        inverted_index.read_postings(&stats.term, IndexRecordOption::WithFreqs)?
+      {
+        postings.push((segment_postings, stats.idf));
+      }
+    }
+
+    Ok(Box::new(Bm25RescoreScorer {
+      inner,
+      postings,
+      fieldnorms: reader.get_fieldnorms_reader(self.field)?,
+      params: self.params,
+      average_fieldnorm: self.average_fieldnorm,
+      boost,
+    }))
+  }
+
+  fn explain(&self, reader: &SegmentReader, doc: DocId) -> tantivy::Result<Explanation> {
+    let mut scorer = self.scorer(reader, 1.0)?;
+    if scorer.seek(doc) != doc {
+      return Err(TantivyError::InvalidArgument(format!(
+        "Document #({doc}) does not match"
+      )));
+    }
+    let Bm25Config { k1, b } = self.params;
+    let mut explanation = Explanation::new_with_string(
+      format!("BM25 rescored (k1={k1}, b={b}), sum of inner score and term differences"),
+      scorer.score(),
+    );
+    explanation.add_detail(self.inner.explain(reader, doc)?);
+
+    let inverted_index = reader.inverted_index(self.field)?;
+    let fieldnorm = reader.get_fieldnorms_reader(self.field)?.fieldnorm(doc) as Score;
+    for stats in &self.terms {
+      let Some(mut postings) =
+        inverted_index.read_postings(&stats.term, IndexRecordOption::WithFreqs)?
+      else {
+        continue;
+      };
+      if postings.doc() <= doc && postings.seek(doc) == doc {
+        let term_freq = postings.term_freq() as Score;
+        explanation.add_detail(self.explain_term(stats, term_freq, fieldnorm));
+      }
+    }
+
+    Ok(explanation)
+  }
+}
+
+/// Scorer of [`Bm25RescoreQuery`]: iterates `inner`, scores from the term postings
+struct Bm25RescoreScorer {
+  inner: Box<dyn Scorer>,
+  postings: Vec<(SegmentPostings, Score)>,
+  fieldnorms: FieldNormReader,
+  params: Bm25Config,
+  average_fieldnorm: Score,
+  boost: Score,
+}
+
+impl DocSet for Bm25RescoreScorer {
+  fn advance(&mut self) -> DocId {
+    self.inner.advance()
+  }
+
+  fn seek(&mut self, target: DocId) -> DocId {
+    self.inner.seek(target)
+  }
+
+  fn doc(&self) -> DocId {
+    self.inner.doc()
+  }
+
+  fn size_hint(&self) -> u32 {
+    self.inner.size_hint()
+  }
+}
+
+impl Scorer for Bm25RescoreScorer {
+  fn score(&mut self) -> Score {
+    let doc = self.inner.doc();
+    let fieldnorm = self.fieldnorms.fieldnorm(doc) as Score;
+
+    let mut difference = 0.0;
+    for (postings, idf) in &mut self.postings {
+      // Documents are scored in increasing order, so postings only move forward
+      if postings.doc() < doc {
+        postings.seek(doc);
+      }
+      if postings.doc() == doc {
+        let term_freq = postings.term_freq() as Score;
+        let custom = tf_norm(self.params, term_freq, fieldnorm, self.average_fieldnorm);
+        let tantivy = tf_norm(TANTIVY_PARAMS, term_freq, fieldnorm, self.average_fieldnorm);
+        difference += *idf * (custom - tantivy);
+      }
+    }
+
+    self.inner.score() + difference * self.boost
+  }
+}
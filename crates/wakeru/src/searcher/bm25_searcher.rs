@@ -1,17 +1,36 @@
 //! BM25 search module
 
-use tantivy::query::{BooleanQuery, Occur, TermSetQuery};
-use tantivy::schema::Value;
-use tantivy::schema::document::CompactDocValue;
-use tantivy::{Index, IndexReader, ReloadPolicy, Term, collector::TopDocs, query::QueryParser};
-use tracing::debug;
+use std::collections::HashMap;
+
+use std::ops::Bound;
+use std::sync::atomic::{AtomicU64, Ordering};
 
-use crate::config::Language;
+use tantivy::fastfield::FastValue;
+use tantivy::query::{
+  BooleanQuery, BoostQuery, ConstScoreQuery, EnableScoring, FuzzyTermQuery,
+  InvertedIndexRangeQuery, Occur, PhraseQuery, Query, RegexQuery, TermQuery, TermSetQuery, Weight,
+};
+use tantivy::schema::document::CompactDocValue;
+use tantivy::schema::{FieldType, IndexRecordOption, Value};
+use tantivy::tokenizer::{TextAnalyzer, TokenStream};
+use tantivy::{
+  DocAddress, DocSet, Index, IndexReader, ReloadPolicy, Searcher, Term,
+  collector::{Count, TopDocs},
+  query::QueryParser,
+};
+use tracing::{Span, debug, field, info_span};
+
+use crate::config::{Bm25Config, Language};
 use crate::errors::SearcherError;
-use crate::indexer::schema_builder::SchemaFields;
-use crate::models::SearchResult;
+use crate::indexer::CommitCounter;
+use crate::indexer::index_manager::restore_tags;
+use crate::indexer::schema_builder::{SchemaFields, has_positions, source_id_term, tag_term};
+use crate::models::{MatchKind, SearchResult};
+use crate::span::timed;
+use crate::tokenizer::to_katakana;
 
 // Use tokenization utilities
+use super::bm25::Bm25RescoreQuery;
 use super::tokenization::{TokenizationResult, tokenize_with_text_analyzer};
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -22,7 +41,7 @@ use super::tokenization::{TokenizationResult, tokenize_with_text_analyzer};
 ///
 /// Tantivy 0.25: CompactDocValue does not implement Serialize,
 /// so convert to OwnedValue first, then to serde_json::Value
-fn compact_value_to_json(value: &CompactDocValue<'_>) -> serde_json::Value {
+pub(crate) fn compact_value_to_json(value: &CompactDocValue<'_>) -> serde_json::Value {
   use tantivy::schema::OwnedValue;
 
   // Conversion from CompactDocValue to OwnedValue (using From trait)
@@ -36,6 +55,63 @@ fn compact_value_to_json(value: &CompactDocValue<'_>) -> serde_json::Value {
   })
 }
 
+/// Escapes regex meta characters so that `text` matches literally in a `RegexQuery`
+fn escape_regex(text: &str) -> String {
+  let mut escaped = String::with_capacity(text.len());
+  for c in text.chars() {
+    if "\\.+*?()|[]{}^$#&-~".contains(c) {
+      escaped.push('\\');
+    }
+    escaped.push(c);
+  }
+  escaped
+}
+
+/// Builds a conjunction query requiring every term (`Occur::Must`)
+fn build_and_query(terms: &[Term]) -> BooleanQuery {
+  BooleanQuery::new(
+    terms
+      .iter()
+      .map(|term| {
+        let query: Box<dyn Query> =
+          Box::new(TermQuery::new(term.clone(), IndexRecordOption::WithFreqs));
+        (Occur::Must, query)
+      })
+      .collect(),
+  )
+}
+
+/// Checks whether the document at `doc_address` matches `weight`
+fn matches_doc(
+  weight: &dyn Weight,
+  searcher: &Searcher,
+  doc_address: DocAddress,
+) -> Result<bool, SearcherError> {
+  let segment_reader = searcher.segment_reader(doc_address.segment_ord);
+  let mut scorer = weight.scorer(segment_reader, 1.0)?;
+  Ok(scorer.seek(doc_address.doc_id) == doc_address.doc_id)
+}
+
+/// Wraps `query` in a `BoostQuery` if a boost is given
+fn with_boost(query: Box<dyn Query>, boost: Option<f32>) -> Box<dyn Query> {
+  match boost {
+    Some(boost) => Box::new(BoostQuery::new(query, boost)),
+    None => query,
+  }
+}
+
+/// Default boost of exact (unstemmed) matches
+const DEFAULT_EXACT_BOOST: f32 = 2.0;
+
+/// Fields accepted as keys of `search_with_boosts`
+const BOOSTABLE_FIELDS: [&str; 4] = ["text", "text_ngram", "text_exact", "text_reading"];
+
+/// Upper bound of `search_fuzzy` edit distance (tantivy's Levenshtein automata support up to 2)
+const MAX_FUZZY_DISTANCE: u8 = 2;
+
+/// Number of most frequent document terms used as the `search_similar` query
+const SIMILAR_QUERY_TERMS: usize = 25;
+
 /// BM25 Search Engine
 pub struct SearchEngine {
   /// Tantivy IndexReader
@@ -46,6 +122,27 @@ pub struct SearchEngine {
 
   /// Language of this search engine
   language: Language,
+
+  /// Query tokens of at least this many characters are also searched in the N-gram field
+  ngram_query_min_chars: usize,
+
+  /// Query tokens up to this many characters are also searched in the N-gram field
+  ngram_query_max_chars: usize,
+
+  /// Boost of unstemmed matches in the `text_exact` field (English only)
+  exact_boost: f32,
+
+  /// Tokenizer deriving query readings for `search_with_reading_expansion` (Japanese only)
+  reading_analyzer: Option<TextAnalyzer>,
+
+  /// BM25 parameters of the `text` field (`None`: Tantivy's built-in scoring)
+  bm25: Option<Bm25Config>,
+
+  /// Commit counter of the paired `IndexManager` (lazy reload when it moves)
+  commit_counter: Option<CommitCounter>,
+
+  /// Commit count at the last lazy reload (`u64::MAX` before the first one)
+  reloaded_at_commit: AtomicU64,
 }
 
 /// Implementation block for BM25 Search Engine
@@ -70,12 +167,151 @@ impl SearchEngine {
       reader,
       fields,
       language,
+      ngram_query_min_chars: 1,
+      ngram_query_max_chars: 1,
+      exact_boost: DEFAULT_EXACT_BOOST,
+      reading_analyzer: None,
+      bm25: None,
+      commit_counter: None,
+      reloaded_at_commit: AtomicU64::new(u64::MAX),
     })
   }
 
+  /// Sets the boost applied to exact (unstemmed) matches.
+  ///
+  /// Default is 2.0. Only used when the index has a `text_exact` field
+  /// (English index created with `IndexOptions::english_exact_field`).
+  pub fn with_exact_boost(mut self, boost: f32) -> Self {
+    self.exact_boost = boost;
+    self
+  }
+
+  /// Sets the tokenizer used to derive query readings in `search_with_reading_expansion`.
+  ///
+  /// Pass the Japanese tokenizer with reading output (`VibratoTokenizer` with
+  /// `with_reading_output(true)`). Without it, the index's registered reading tokenizer
+  /// is used, which exists only for Japanese indexes with a `text_reading` field.
+  pub fn with_reading_analyzer(mut self, analyzer: TextAnalyzer) -> Self {
+    self.reading_analyzer = Some(analyzer);
+    self
+  }
+
+  /// Sets the range of query token lengths (in characters) that also trigger N-gram search.
+  ///
+  /// Default is `1..=1` (only 1-char tokens use the N-gram field).
+  /// Pass the index's N-gram range (`IndexOptions::min_gram` / `max_gram`), so that
+  /// tokens the N-gram field can represent are routed to it. Each qualifying token is
+  /// split with the index's registered N-gram tokenizer, so the generated grams always
+  /// follow the index's actual N-gram range.
+  /// Has no effect on indexes without an N-gram field (English).
+  pub fn with_ngram_range(mut self, min_chars: usize, max_chars: usize) -> Self {
+    self.ngram_query_min_chars = min_chars;
+    self.ngram_query_max_chars = max_chars;
+    self
+  }
+
+  /// Reloads the reader lazily after commits of the paired `IndexManager`.
+  ///
+  /// Before each search, the counter is compared to the count seen at the last reload,
+  /// and the reader is reloaded when they differ. Documents committed through the
+  /// manager are then searchable right away, without waiting for the
+  /// `OnCommitWithDelay` reload or calling [`SearchEngine::reload`].
+  pub fn with_commit_counter(mut self, counter: CommitCounter) -> Self {
+    self.commit_counter = Some(counter);
+    self
+  }
+
+  /// Searcher of the current reader, reloaded first if the paired manager committed since
+  fn searcher(&self) -> Result<Searcher, SearcherError> {
+    if let Some(counter) = &self.commit_counter {
+      let commits = counter.get();
+      if self.reloaded_at_commit.load(Ordering::Acquire) != commits {
+        self.reader.reload()?;
+        self.reloaded_at_commit.store(commits, Ordering::Release);
+      }
+    }
+    Ok(self.reader.searcher())
+  }
+
+  /// Sets the BM25 parameters of the `text` field.
+  ///
+  /// - `k1` (`>= 0.0`, Tantivy default 1.2): term frequency saturation. Higher values let
+  ///   repeated terms keep raising the score; `0.0` ignores term frequency.
+  /// - `b` (`0.0..=1.0`, Tantivy default 0.75): length normalization. `1.0` fully
+  ///   normalizes by document length (favoring short documents); `0.0` ignores it.
+  ///
+  /// Applies to every search scoring `text` terms: [`SearchEngine::search`] (and its
+  /// paged / counted variants), [`SearchEngine::search_tokens_or`] and the searches built on
+  /// it (smart, tags, boosts, ...), [`SearchEngine::search_tokens_and`],
+  /// [`SearchEngine::search_phrase`] and [`SearchEngine::search_similar`]. Their `text`
+  /// clause, matched with a constant score by default, is then BM25-scored; other clauses
+  /// (N-gram, exact, reading) and field boosts keep their contribution. Phrase matches are
+  /// scored like their terms. Fuzzy / prefix / regex and tag or source lookups stay
+  /// constant-score.
+  ///
+  /// # Errors
+  /// - `SearcherError::InvalidBm25Params` if `k1` or `b` is out of range
+  pub fn set_bm25_params(&mut self, k1: f32, b: f32) -> Result<(), SearcherError> {
+    let params = Bm25Config { k1, b };
+    if !params.is_valid() {
+      return Err(SearcherError::InvalidBm25Params { k1, b });
+    }
+    self.bm25 = Some(params);
+    Ok(())
+  }
+
+  /// Wraps `query` to be scored with the configured BM25 parameters (if any)
+  fn bm25_scored(&self, query: Box<dyn Query>) -> Box<dyn Query> {
+    match self.bm25 {
+      Some(params) => Box::new(Bm25RescoreQuery::new(query, self.fields.text, params)),
+      None => query,
+    }
+  }
+
+  /// OR query over `text` terms
+  ///
+  /// A constant-score `TermSetQuery`, or with custom BM25 parameters, the sum of the
+  /// BM25 scores of the matched terms.
+  fn text_terms_query(&self, terms: Vec<Term>) -> Box<dyn Query> {
+    if self.bm25.is_none() {
+      return Box::new(TermSetQuery::new(terms));
+    }
+    let term_queries = terms
+      .into_iter()
+      .map(|term| {
+        let query: Box<dyn Query> = Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs));
+        (Occur::Should, query)
+      })
+      .collect();
+    self.bm25_scored(Box::new(BooleanQuery::new(term_queries)))
+  }
+
   /// Search by BM25 score
   pub fn search(&self, query_str: &str, limit: usize) -> Result<Vec<SearchResult>, SearcherError> {
-    let searcher = self.reader.searcher();
+    let span = info_span!(
+      "search",
+      language = %self.language,
+      limit,
+      num_results = field::Empty,
+      elapsed_ms = field::Empty,
+    );
+    timed(&span, || {
+      let results = self.search_paged(query_str, limit, 0)?;
+      Span::current().record("num_results", results.len());
+      Ok(results)
+    })
+  }
+
+  /// Search by BM25 score, skipping the first `offset` hits (pagination)
+  ///
+  /// Page `n` (0-based) of size `limit` is `search_paged(query, limit, n * limit)`.
+  pub fn search_paged(
+    &self,
+    query_str: &str,
+    limit: usize,
+    offset: usize,
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    let searcher = self.searcher()?;
 
     // QueryParser: target text field
     let query_parser = QueryParser::for_index(searcher.index(), vec![self.fields.text]);
@@ -84,18 +320,66 @@ impl SearchEngine {
     let query = query_parser.parse_query(query_str).map_err(|e| SearcherError::InvalidQuery {
       reason: e.to_string(),
     })?;
+    let query = self.bm25_scored(query);
 
     // Get top documents (max < limit) by BM25 score
-    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit).and_offset(offset))?;
 
     // Convert results with helper method
-    self.convert_to_search_results(&searcher, top_docs)
+    self.convert_to_search_results(&searcher, top_docs, &[])
+  }
+
+  /// Returns the analyzer registered for the `text` field
+  ///
+  /// The tokenizer name is read from the schema, so indexes created with another
+  /// `TokenizerMode` (e.g. `cjk_bigram` for Japanese) are queried with their own analyzer.
+  fn text_analyzer(&self, index: &Index) -> Result<TextAnalyzer, SearcherError> {
+    let tokenizer_name = match index.schema().get_field_entry(self.fields.text).field_type() {
+      FieldType::Str(options) => {
+        options.get_indexing_options().map(|indexing| indexing.tokenizer())
+      }
+      _ => None,
+    }
+    .unwrap_or_else(|| self.language.text_tokenizer_name())
+    .to_string();
+
+    index.tokenizers().get(&tokenizer_name).ok_or_else(|| SearcherError::InvalidQuery {
+      reason: format!("tokenizer `{tokenizer_name}` is not registered"),
+    })
+  }
+
+  /// BM25 search that also returns the total number of matching documents
+  ///
+  /// Same query as [`SearchEngine::search`]. The total is counted with a `Count` collector
+  /// in the same pass, so it is not limited by `limit` (for pagination UIs).
+  ///
+  /// # Returns
+  /// `(results, total)`: Top `limit` results and the number of all matching documents
+  pub fn search_with_count(
+    &self,
+    query_str: &str,
+    limit: usize,
+  ) -> Result<(Vec<SearchResult>, usize), SearcherError> {
+    let searcher = self.searcher()?;
+
+    let query_parser = QueryParser::for_index(searcher.index(), vec![self.fields.text]);
+    let query = query_parser.parse_query(query_str).map_err(|e| SearcherError::InvalidQuery {
+      reason: e.to_string(),
+    })?;
+    let query = self.bm25_scored(query);
+
+    let (top_docs, total) = searcher.search(&query, &(TopDocs::with_limit(limit), Count))?;
+
+    Ok((
+      self.convert_to_search_results(&searcher, top_docs, &[])?,
+      total,
+    ))
   }
 
   /// Parses query string with language-specific tokenizer and extracts unique Terms
   ///
   /// # Process Flow
-  /// 1. Get tokenizer according to language
+  /// 1. Get tokenizer of the `text` field
   /// 2. Delegate to pure tokenization function (deduplication, empty string exclusion, Term conversion)
   ///
   /// # Arguments
@@ -109,14 +393,7 @@ impl SearchEngine {
     index: &Index,
     query_str: &str,
   ) -> Result<TokenizationResult, SearcherError> {
-    // Get tokenizer name according to language
-    let tokenizer_name = self.language.text_tokenizer_name();
-
-    // Get tokenizer
-    let mut analyzer =
-      index.tokenizers().get(tokenizer_name).ok_or_else(|| SearcherError::InvalidQuery {
-        reason: format!("tokenizer `{tokenizer_name}` is not registered"),
-      })?;
+    let mut analyzer = self.text_analyzer(index)?;
 
     // Delegate to tokenization function dedicated to TextAnalyzer
     Ok(tokenize_with_text_analyzer(
@@ -138,7 +415,8 @@ impl SearchEngine {
   /// # Behavior
   /// 1. Parse query string with language-specific tokenizer
   /// 2. Convert extracted tokens to Terms
-  /// 3. For Japanese, 1-char tokens are also searched in N-gram field
+  /// 3. For Japanese, tokens of `ngram_query_min_chars..=ngram_query_max_chars` chars
+  ///    (default 1) are also searched in N-gram field
   /// 4. Execute OR search with TermSetQuery / BooleanQuery
   ///
   /// # Examples
@@ -155,10 +433,31 @@ impl SearchEngine {
     &self,
     query_str: &str,
     limit: usize,
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    let span = info_span!(
+      "search_tokens_or",
+      language = %self.language,
+      limit,
+      num_terms = field::Empty,
+      num_results = field::Empty,
+      elapsed_ms = field::Empty,
+    );
+    timed(&span, || {
+      let results = self.search_tokens_or_inner(query_str, limit)?;
+      Span::current().record("num_results", results.len());
+      Ok(results)
+    })
+  }
+
+  /// [`SearchEngine::search_tokens_or`] inside its span
+  fn search_tokens_or_inner(
+    &self,
+    query_str: &str,
+    limit: usize,
   ) -> Result<Vec<SearchResult>, SearcherError> {
     debug!(query = %query_str, limit, language = ?self.language, "Start parsing search query");
 
-    let searcher = self.reader.searcher();
+    let searcher = self.searcher()?;
     let index = searcher.index();
 
     // Delegate tokenization process to dedicated method
@@ -174,572 +473,3291 @@ impl SearchEngine {
       num_terms = morph_terms.len(),
       "Search query parsing completed"
     );
+    Span::current().record("num_terms", morph_terms.len());
 
     if morph_terms.is_empty() {
       // Return empty result if all tokens are stop words etc.
       return Ok(vec![]);
     }
 
-    // Extract 1-char tokens and create Terms for N-gram field
-    // text_ngram field exists only for Japanese
-    let ngram_terms: Vec<Term> = self
-      .fields
-      .text_ngram
-      .map(|text_ngram_field| {
-        query_tokens
-          .iter()
-          .filter(|token| token.chars().count() == 1)
-          .map(|token| Term::from_field_text(text_ngram_field, token))
-          .collect()
-      })
-      .unwrap_or_default();
-
-    // Record presence of N-gram search for log output
-    let has_ngram = !ngram_terms.is_empty();
-
-    // Build query
-    let query: Box<dyn tantivy::query::Query> = if ngram_terms.is_empty() {
-      // No N-gram target: search only in morphological field
-      Box::new(TermSetQuery::new(morph_terms))
-    } else {
-      // With N-gram target: OR search of morphology + N-gram
-      let subqueries: Vec<(Occur, Box<dyn tantivy::query::Query>)> = vec![
-        // Morphological field search
-        (Occur::Should, Box::new(TermSetQuery::new(morph_terms))),
-        // N-gram field search
-        (Occur::Should, Box::new(TermSetQuery::new(ngram_terms))),
-      ];
-
-      Box::new(BooleanQuery::from(subqueries))
-    };
+    let query = self.build_or_query(index, query_str, morph_terms, &query_tokens, None)?;
 
-    debug!(
-      query = %query_str,
-      has_ngram,
-      "Search query construction completed"
-    );
+    debug!(query = %query_str, "Search query construction completed");
 
     // Execute search (with BM25 score)
     let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
 
     // Result conversion (reuse existing logic)
-    self.convert_to_search_results(&searcher, top_docs)
+    self.convert_to_search_results(&searcher, top_docs, &query_tokens)
   }
 
-  /// Helper method to convert top_docs to SearchResult vector
-  fn convert_to_search_results(
+  /// BM25 search that drops results scoring below `min_score`
+  ///
+  /// Same as [`SearchEngine::search`], then results whose `score < min_score`
+  /// are removed after the top-docs collection (so fewer than `limit` may be returned).
+  /// Order of the remaining results is preserved. `None` disables the threshold.
+  ///
+  /// BM25 scores are not normalized: they depend on the index (document count, lengths,
+  /// term statistics) and the query, so a threshold is only meaningful for one index
+  /// and should be tuned against it.
+  ///
+  /// # Errors
+  /// - `SearcherError::InvalidQuery` if `min_score` is NaN
+  pub fn search_filtered(
     &self,
-    searcher: &tantivy::Searcher,
-    top_docs: Vec<(f32, tantivy::DocAddress)>,
+    query_str: &str,
+    limit: usize,
+    min_score: Option<f32>,
   ) -> Result<Vec<SearchResult>, SearcherError> {
-    let mut results = Vec::with_capacity(top_docs.len());
+    if min_score.is_some_and(f32::is_nan) {
+      return Err(SearcherError::InvalidQuery {
+        reason: "min_score must be a number".to_string(),
+      });
+    }
 
-    for (score, doc_address) in top_docs {
-      let doc: tantivy::TantivyDocument = searcher.doc(doc_address)?;
+    let mut results = self.search(query_str, limit)?;
+    if let Some(min_score) = min_score {
+      results.retain(|result| result.score >= min_score);
+    }
 
-      // Get required fields (InvalidIndex if error)
-      let doc_id =
-        self.get_text_field(&doc, self.fields.id).ok_or_else(|| SearcherError::InvalidIndex {
-          field: "id".to_string(),
-          reason: "Required field not found".to_string(),
-        })?;
+    Ok(results)
+  }
 
-      let source_id = self.get_text_field(&doc, self.fields.source_id).ok_or_else(|| {
-        SearcherError::InvalidIndex {
-          field: "source_id".to_string(),
-          reason: "Required field not found".to_string(),
-        }
-      })?;
+  /// Builds the OR query used by `search_tokens_or` / `smart_search` / `search_with_tags`
+  ///
+  /// - For Japanese, tokens of `ngram_query_min_chars..=ngram_query_max_chars` characters are
+  ///   also searched in the N-gram field: a token must contain all of its grams there to match.
+  /// - For English indexes with a `text_exact` field, unstemmed matches are added
+  ///   with `exact_boost`, so exact forms rank above stemmed-only matches.
+  /// - For Japanese indexes with a `text_reading` field, the query reading is also
+  ///   matched against document readings (kana queries find kanji text).
+  /// - `boosts` multiplies the score of each field's clause (field name -> boost).
+  fn build_or_query(
+    &self,
+    index: &Index,
+    query_str: &str,
+    morph_terms: Vec<Term>,
+    query_tokens: &[String],
+    boosts: Option<&HashMap<String, f32>>,
+  ) -> Result<Box<dyn Query>, SearcherError> {
+    let boost_of = |field: &str| boosts.and_then(|boosts| boosts.get(field).copied());
 
-      // text is treated as Optional (fallback to empty string)
-      let text = self.get_text_field(&doc, self.fields.text).unwrap_or_default();
+    let ngram_queries = self.ngram_subqueries(index, query_tokens)?;
+    let exact_query = self.exact_subquery(index, query_str)?;
+    let reading_query = self.reading_subquery(index, query_str)?;
 
-      // Restore metadata: Get directly from JsonObject
-      let metadata = self.get_json_object_field(&doc, self.fields.metadata);
+    // Record presence of N-gram / exact / reading search for log output
+    let has_ngram = !ngram_queries.is_empty();
+    let has_exact = exact_query.is_some();
+    let has_reading = reading_query.is_some();
 
-      results.push(SearchResult {
-        doc_id,
-        source_id,
-        score,
-        text,
-        metadata,
-      });
+    debug!(has_ngram, has_exact, has_reading, "OR query constructed");
+
+    // Build query
+    let morph_query = with_boost(self.text_terms_query(morph_terms), boost_of("text"));
+
+    if !has_ngram && !has_exact && !has_reading {
+      // Search only in morphological field
+      return Ok(morph_query);
     }
 
-    Ok(results)
-  }
+    // OR search of morphology + N-gram / exact / reading
+    let ngram_boost = boost_of("text_ngram");
+    let mut subqueries: Vec<(Occur, Box<dyn Query>)> = vec![
+      // Morphological field search
+      (Occur::Should, morph_query),
+    ];
+    subqueries.extend(
+      ngram_queries.into_iter().map(|query| (Occur::Should, with_boost(query, ngram_boost))),
+    );
+    subqueries
+      .extend(exact_query.map(|query| (Occur::Should, with_boost(query, boost_of("text_exact")))));
+    subqueries.extend(
+      reading_query.map(|query| (Occur::Should, with_boost(query, boost_of("text_reading")))),
+    );
 
-  /// Get value of single text field from TantivyDocument
-  ///
-  /// # Returns
-  /// - `Some(String)`: If field value exists
-  /// - `None`: If field value does not exist
-  fn get_text_field(
-    &self,
-    doc: &tantivy::TantivyDocument,
-    field: tantivy::schema::Field,
-  ) -> Option<String> {
-    doc.get_first(field).and_then(|v| v.as_str().map(String::from))
+    Ok(Box::new(BooleanQuery::from(subqueries)))
   }
 
-  /// Get value of JsonObject field from TantivyDocument and convert to Metadata
-  ///
-  /// # Returns
-  /// - If field value exists: Converted Metadata
-  /// - If field value does not exist: Empty Metadata
-  fn get_json_object_field(
+  /// N-gram field queries (to be OR-ed) for short query tokens (Japanese / Korean only)
+  fn ngram_subqueries(
     &self,
-    doc: &tantivy::TantivyDocument,
-    field: tantivy::schema::Field,
-  ) -> crate::models::Metadata {
-    doc
-      .get_first(field)
-      .and_then(|value| value.as_object())
-      .map(|iter| {
-        // Tantivy 0.25: as_object() returns CompactDocObjectIter (iterator)
-        // iter: (key: &str, value: CompactDocValue<'_>)
-        let mut metadata = crate::models::Metadata::default();
+    index: &Index,
+    query_tokens: &[String],
+  ) -> Result<Vec<Box<dyn Query>>, SearcherError> {
+    // text_ngram field exists only for Japanese / Korean
+    let (Some(text_ngram_field), Some(tokenizer_name)) =
+      (self.fields.text_ngram, self.language.ngram_tokenizer_name())
+    else {
+      return Ok(vec![]);
+    };
 
-        for (k, v) in iter {
-          // Convert CompactDocValue to serde_json::Value
-          let json_val = compact_value_to_json(&v);
-          metadata.insert(k.to_string(), json_val);
-        }
+    let mut analyzer =
+      index.tokenizers().get(tokenizer_name).ok_or_else(|| SearcherError::InvalidQuery {
+        reason: format!("tokenizer `{tokenizer_name}` is not registered"),
+      })?;
 
-        metadata
-      })
-      .unwrap_or_default()
-  }
+    // Split short tokens into grams with the index's N-gram tokenizer
+    let mut single_gram_terms = Vec::new();
+    let mut subqueries: Vec<Box<dyn Query>> = Vec::new();
+    let char_range = self.ngram_query_min_chars..=self.ngram_query_max_chars;
+    for token in query_tokens.iter().filter(|token| char_range.contains(&token.chars().count())) {
+      let TokenizationResult { terms, .. } =
+        tokenize_with_text_analyzer(&mut analyzer, text_ngram_field, token);
+      match terms.len() {
+        0 => {}
+        1 => single_gram_terms.extend(terms),
+        _ => subqueries.push(Box::new(build_and_query(&terms))),
+      }
+    }
 
-  /// Returns the language of this search engine
-  pub fn language(&self) -> Language {
-    self.language
+    if !single_gram_terms.is_empty() {
+      subqueries.push(Box::new(TermSetQuery::new(single_gram_terms)));
+    }
+
+    Ok(subqueries)
   }
-}
 
-// ─────────────────────────────────────────────────────────────────────────────
-// Test Module
-// ─────────────────────────────────────────────────────────────────────────────
+  /// Boosted unstemmed clause against `text_exact` (English indexes created with it only)
+  fn exact_subquery(
+    &self,
+    index: &Index,
+    query_str: &str,
+  ) -> Result<Option<Box<dyn Query>>, SearcherError> {
+    let (Some(text_exact_field), Some(tokenizer_name)) =
+      (self.fields.text_exact, self.language.exact_tokenizer_name())
+    else {
+      return Ok(None);
+    };
 
-#[cfg(test)]
-mod tests {
-  use super::*;
-  use crate::config::Language;
-  use crate::indexer::index_manager::IndexManager;
-  use crate::models::Document;
-  use serde_json::json;
+    let mut analyzer =
+      index.tokenizers().get(tokenizer_name).ok_or_else(|| SearcherError::InvalidQuery {
+        reason: format!("tokenizer `{tokenizer_name}` is not registered"),
+      })?;
 
-  // ─── Test Helper Functions ───────────────────────────────────────────────────
+    let TokenizationResult { terms, .. } =
+      tokenize_with_text_analyzer(&mut analyzer, text_exact_field, query_str);
+    if terms.is_empty() {
+      return Ok(None);
+    }
 
-  /// Helper to create English index (SearchEngine created later)
-  fn create_english_index_manager() -> (tempfile::TempDir, IndexManager) {
-    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
-    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
-      .expect("Failed to create index");
-    (tmp_dir, index_manager)
+    Ok(Some(Box::new(BoostQuery::new(
+      Box::new(TermSetQuery::new(terms)),
+      self.exact_boost,
+    ))))
   }
 
-  /// Helper to create SearchEngine from IndexManager
+  /// Reading field query (to be OR-ed) for Japanese indexes with a `text_reading` field
   ///
-  /// Important: Call after adding documents (SearchEngine has its own Reader)
-  fn create_search_engine(index_manager: &IndexManager) -> SearchEngine {
-    SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::En)
-      .expect("Failed to create SearchEngine")
+  /// Hiragana in the query is converted to katakana first, so "とうきょう" is analyzed
+  /// like "トウキョウ" and matches the reading of "東京".
+  fn reading_subquery(
+    &self,
+    index: &Index,
+    query_str: &str,
+  ) -> Result<Option<Box<dyn Query>>, SearcherError> {
+    let (Some(text_reading_field), Some(tokenizer_name)) = (
+      self.fields.text_reading,
+      self.language.reading_tokenizer_name(),
+    ) else {
+      return Ok(None);
+    };
+
+    let mut analyzer =
+      index.tokenizers().get(tokenizer_name).ok_or_else(|| SearcherError::InvalidQuery {
+        reason: format!("tokenizer `{tokenizer_name}` is not registered"),
+      })?;
+
+    let TokenizationResult { terms, .. } =
+      tokenize_with_text_analyzer(&mut analyzer, text_reading_field, &to_katakana(query_str));
+    if terms.is_empty() {
+      return Ok(None);
+    }
+
+    Ok(Some(Box::new(TermSetQuery::new(terms))))
   }
 
-  /// Helper to add test documents
-  fn add_test_documents(index_manager: &IndexManager, docs: &[Document]) {
-    let report = index_manager.add_documents(docs).expect("Failed to add documents");
-    assert_eq!(
+  /// OR search that also matches the katakana reading of each query token
+  ///
+  /// Same candidates as [`SearchEngine::search_tokens_or`], plus the reading of every
+  /// query token (e.g. "東京" -> "トウキョウ") as an OR term against `text_reading`
+  /// (when the index has it) and `text`, so kanji queries also find kana spellings.
+  /// Readings come from [`SearchEngine::with_reading_analyzer`] or the index's reading
+  /// tokenizer; without either, this behaves like `search_tokens_or`.
+  ///
+  /// # Examples
+  /// ```ignore
+  /// let search_engine = SearchEngine::new(index, fields, Language::Ja)?
+  ///   .with_reading_analyzer(TextAnalyzer::from(tokenizer.with_reading_output(true)));
+  /// let results = search_engine.search_with_reading_expansion("東京", 10)?;
+  /// // Also matches documents spelled "トウキョウ"
+  /// ```
+  pub fn search_with_reading_expansion(
+    &self,
+    query_str: &str,
+    limit: usize,
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    let searcher = self.searcher()?;
+    let index = searcher.index();
+
+    let TokenizationResult {
+      terms: morph_terms,
+      query_tokens,
+    } = self.tokenize_query(index, query_str)?;
+    if morph_terms.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let reading_terms = self.reading_expansion_terms(index, &query_tokens)?;
+    debug!(
+      query = %query_str,
+      tokens = ?query_tokens,
+      num_reading_terms = reading_terms.len(),
+      "Reading expansion constructed"
+    );
+
+    let or_query = self.build_or_query(index, query_str, morph_terms, &query_tokens, None)?;
+    let query: Box<dyn Query> = if reading_terms.is_empty() {
+      or_query
+    } else {
+      Box::new(BooleanQuery::from(vec![
+        (Occur::Should, or_query),
+        (
+          Occur::Should,
+          Box::new(TermSetQuery::new(reading_terms)) as Box<dyn Query>,
+        ),
+      ]))
+    };
+
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+    self.convert_to_search_results(&searcher, top_docs, &query_tokens)
+  }
+
+  /// Reading terms of each query token against `text_reading` (if present) and `text`
+  fn reading_expansion_terms(
+    &self,
+    index: &Index,
+    query_tokens: &[String],
+  ) -> Result<Vec<Term>, SearcherError> {
+    let mut analyzer = match (
+      &self.reading_analyzer,
+      self.language.reading_tokenizer_name(),
+    ) {
+      (Some(analyzer), _) => analyzer.clone(),
+      (None, Some(tokenizer_name)) if self.fields.text_reading.is_some() => {
+        index.tokenizers().get(tokenizer_name).ok_or_else(|| SearcherError::InvalidQuery {
+          reason: format!("tokenizer `{tokenizer_name}` is not registered"),
+        })?
+      }
+      _ => return Ok(vec![]),
+    };
+
+    let mut terms = Vec::new();
+    for token in query_tokens {
+      let TokenizationResult {
+        query_tokens: readings,
+        ..
+      } = tokenize_with_text_analyzer(&mut analyzer, self.fields.text, &to_katakana(token));
+      for reading in readings {
+        terms.push(Term::from_field_text(self.fields.text, &reading));
+        if let Some(text_reading_field) = self.fields.text_reading {
+          terms.push(Term::from_field_text(text_reading_field, &reading));
+        }
+      }
+    }
+
+    Ok(terms)
+  }
+
+  /// OR search with per-field score boosts
+  ///
+  /// Same candidates as [`SearchEngine::search_tokens_or`], but the clause of each field
+  /// is wrapped in a `BoostQuery`. Keys are field names: `text` (morphemes),
+  /// `text_ngram`, `text_exact` and `text_reading`; fields missing from `boosts` keep
+  /// boost 1.0. For example, down-boosting `text_ngram` makes partial N-gram matches
+  /// rank below full morpheme matches.
+  ///
+  /// # Errors
+  /// - `SearcherError::InvalidQuery` if a key is not a boostable field,
+  ///   or a boost is negative or not finite
+  ///
+  /// # Examples
+  /// ```ignore
+  /// let boosts = HashMap::from([("text_ngram".to_string(), 0.2)]);
+  /// let results = search_engine.search_with_boosts("京", &boosts, 10)?;
+  /// ```
+  pub fn search_with_boosts(
+    &self,
+    query_str: &str,
+    boosts: &HashMap<String, f32>,
+    limit: usize,
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    for (field, &boost) in boosts {
+      if !BOOSTABLE_FIELDS.contains(&field.as_str()) {
+        return Err(SearcherError::InvalidQuery {
+          reason: format!(
+            "boost is not supported for field `{field}` (valid: {})",
+            BOOSTABLE_FIELDS.join(", ")
+          ),
+        });
+      }
+      if !boost.is_finite() || boost < 0.0 {
+        return Err(SearcherError::InvalidQuery {
+          reason: format!("boost of field `{field}` must be a non-negative number: {boost}"),
+        });
+      }
+    }
+
+    let searcher = self.searcher()?;
+    let index = searcher.index();
+
+    let TokenizationResult {
+      terms: morph_terms,
+      query_tokens,
+    } = self.tokenize_query(index, query_str)?;
+
+    if morph_terms.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let query = self.build_or_query(index, query_str, morph_terms, &query_tokens, Some(boosts))?;
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+
+    self.convert_to_search_results(&searcher, top_docs, &query_tokens)
+  }
+
+  /// OR search that also counts the values of a metadata field over the results
+  ///
+  /// The text query is the same as [`SearchEngine::search_tokens_or`]. For each result,
+  /// `metadata[facet_field]` is tallied: every string of an array (e.g. `TAGS_KEY`) or a
+  /// single string value. Other values and results without the field are not counted.
+  ///
+  /// Counts cover only the returned results (at most `limit`), not every matching
+  /// document, since metadata is not indexed as a Tantivy facet.
+  ///
+  /// # Examples
+  /// ```ignore
+  /// // Tag counts of the top 100 results, e.g. {"category:tourism": 12, ...}
+  /// let (results, counts) = search_engine.search_with_facets("kyoto", TAGS_KEY, 100)?;
+  /// ```
+  pub fn search_with_facets(
+    &self,
+    query_str: &str,
+    facet_field: &str,
+    limit: usize,
+  ) -> Result<(Vec<SearchResult>, HashMap<String, usize>), SearcherError> {
+    let results = self.search_tokens_or(query_str, limit)?;
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for value in results.iter().filter_map(|result| result.metadata.get(facet_field)) {
+      let values = match value {
+        serde_json::Value::Array(values) => values.iter().filter_map(|v| v.as_str()).collect(),
+        serde_json::Value::String(value) => vec![value.as_str()],
+        _ => Vec::new(),
+      };
+      for value in values {
+        *counts.entry(value.to_string()).or_default() += 1;
+      }
+    }
+
+    debug!(query = %query_str, facet_field, facets = counts.len(), "Facet counts computed");
+
+    Ok((results, counts))
+  }
+
+  /// Exact-match query for documents having `tag`
+  ///
+  /// Uses the dedicated `tags` field, or `metadata.tags` for indexes created without it.
+  fn tag_query(&self, tag: &str) -> Box<dyn Query> {
+    let term = tag_term(&self.fields, tag);
+    Box::new(TermQuery::new(term, IndexRecordOption::Basic))
+  }
+
+  /// Documents having `tag`, without a text query
+  ///
+  /// Matches `tag` exactly with a single `TermQuery` on the dedicated `tags` field
+  /// (`metadata.tags` for indexes created without it). All hits score the same,
+  /// so results are in index order.
+  ///
+  /// # Examples
+  /// ```ignore
+  /// let tourism = search_engine.filter_by_tag("category:tourism", 100)?;
+  /// ```
+  pub fn filter_by_tag(&self, tag: &str, limit: usize) -> Result<Vec<SearchResult>, SearcherError> {
+    let searcher = self.searcher()?;
+    let query = ConstScoreQuery::new(self.tag_query(tag), 1.0);
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+
+    debug!(tag, num_results = top_docs.len(), "Tag filter completed");
+
+    self.convert_to_search_results(&searcher, top_docs, &[])
+  }
+
+  /// OR search restricted to documents having every required tag
+  ///
+  /// The text query is the same as [`SearchEngine::search_tokens_or`]. Each tag is matched
+  /// exactly against the `tags` field (`metadata.tags` for indexes created without it).
+  ///
+  /// # Arguments
+  /// - `query_str`: Search query string
+  /// - `required_tags`: Tags a document must all have (e.g., `["category:tourism"]`)
+  /// - `limit`: Maximum number of results to return
+  ///
+  /// # Returns
+  /// Search result vector with BM25 score
+  /// (same as `search_tokens_or` when `required_tags` is empty)
+  pub fn search_with_tags(
+    &self,
+    query_str: &str,
+    required_tags: &[&str],
+    limit: usize,
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    if required_tags.is_empty() {
+      return self.search_tokens_or(query_str, limit);
+    }
+
+    let searcher = self.searcher()?;
+    let index = searcher.index();
+
+    let TokenizationResult {
+      terms: morph_terms,
+      query_tokens,
+    } = self.tokenize_query(index, query_str)?;
+
+    if morph_terms.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let mut subqueries: Vec<(Occur, Box<dyn Query>)> = vec![(
+      Occur::Must,
+      self.build_or_query(index, query_str, morph_terms, &query_tokens, None)?,
+    )];
+    subqueries.extend(required_tags.iter().map(|tag| (Occur::Must, self.tag_query(tag))));
+    let query = BooleanQuery::new(subqueries);
+
+    debug!(query = %query_str, tags = ?required_tags, "Tag-filtered query constructed");
+
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+
+    self.convert_to_search_results(&searcher, top_docs, &query_tokens)
+  }
+
+  /// OR search restricted to documents whose numeric metadata `field` is within `min..=max`
+  ///
+  /// The text query is the same as [`SearchEngine::search_tokens_or`]. `field` is a JSON path
+  /// in `metadata` (e.g. `"version"`, `"published.year"`); `None` leaves that side open.
+  /// Numbers are indexed as i64, u64 (above `i64::MAX`) or f64 depending on the stored
+  /// value, so the range is matched against all three. Documents without the field
+  /// (or with a non-numeric value) are excluded.
+  ///
+  /// # Errors
+  /// - `SearcherError::InvalidQuery` if a bound is NaN or `min > max`
+  ///
+  /// # Examples
+  /// ```ignore
+  /// // version >= 2
+  /// let results = search_engine.search_with_metadata_range("京都", "version", Some(2.0), None, 10)?;
+  /// ```
+  pub fn search_with_metadata_range(
+    &self,
+    query_str: &str,
+    field: &str,
+    min: Option<f64>,
+    max: Option<f64>,
+    limit: usize,
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    let min = min.unwrap_or(f64::NEG_INFINITY);
+    let max = max.unwrap_or(f64::INFINITY);
+    if min.is_nan() || max.is_nan() || min > max {
+      return Err(SearcherError::InvalidQuery {
+        reason: format!("invalid metadata range for `{field}`: min={min}, max={max}"),
+      });
+    }
+
+    let searcher = self.searcher()?;
+    let index = searcher.index();
+
+    let TokenizationResult {
+      terms: morph_terms,
+      query_tokens,
+    } = self.tokenize_query(index, query_str)?;
+
+    if morph_terms.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let query = BooleanQuery::new(vec![
+      (
+        Occur::Must,
+        self.build_or_query(index, query_str, morph_terms, &query_tokens, None)?,
+      ),
+      (Occur::Must, self.metadata_range_query(field, min, max)),
+    ]);
+
+    debug!(query = %query_str, field, min, max, "Metadata range query constructed");
+
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+
+    self.convert_to_search_results(&searcher, top_docs, &query_tokens)
+  }
+
+  /// Range over numeric metadata values at `path` (i64 / u64 / f64 terms, OR-ed)
+  ///
+  /// `metadata` is not a fast field, so the inverted index (term dictionary) is scanned.
+  fn metadata_range_query(&self, path: &str, min: f64, max: f64) -> Box<dyn Query> {
+    let mut ranges: Vec<(Occur, Box<dyn Query>)> =
+      vec![(Occur::Should, self.metadata_value_range(path, min, max))];
+
+    // Integers within the range (float-to-int `as` casts saturate)
+    let (int_min, int_max) = (min.ceil(), max.floor());
+    if int_min <= int_max {
+      if int_min <= i64::MAX as f64 {
+        ranges.push((
+          Occur::Should,
+          self.metadata_value_range(path, int_min as i64, int_max as i64),
+        ));
+      }
+      // Only integers above i64::MAX are indexed as u64
+      if int_max > i64::MAX as f64 {
+        let u64_min = int_min.max(i64::MAX as f64) as u64;
+        ranges.push((
+          Occur::Should,
+          self.metadata_value_range(path, u64_min, int_max as u64),
+        ));
+      }
+    }
+
+    Box::new(BooleanQuery::new(ranges))
+  }
+
+  /// Inclusive range of `T` typed terms at metadata `path`
+  fn metadata_value_range<T: FastValue>(&self, path: &str, min: T, max: T) -> Box<dyn Query> {
+    let term_of = |value: T| {
+      let mut term = Term::from_field_json_path(self.fields.metadata, path, false);
+      term.append_type_and_fast_value(value);
+      term
+    };
+    Box::new(InvertedIndexRangeQuery::new(
+      Bound::Included(term_of(min)),
+      Bound::Included(term_of(max)),
+    ))
+  }
+
+  /// Parses query with language-specific tokenizer and performs AND search with extracted tokens
+  ///
+  /// Tokenization is the same as [`SearchEngine::search_tokens_or`], but only documents
+  /// containing every token are returned. The N-gram field is not used.
+  ///
+  /// # Arguments
+  /// - `query_str`: Search query string (e.g., "京都の寺", "Tokyo temples")
+  /// - `limit`: Maximum number of results to return
+  ///
+  /// # Returns
+  /// Search result vector with BM25 score
+  /// (empty if no tokens remain after analysis)
+  pub fn search_tokens_and(
+    &self,
+    query_str: &str,
+    limit: usize,
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    let searcher = self.searcher()?;
+
+    let TokenizationResult {
+      terms: morph_terms,
+      query_tokens,
+    } = self.tokenize_query(searcher.index(), query_str)?;
+
+    debug!(
+      query = %query_str,
+      tokens = ?query_tokens,
+      num_terms = morph_terms.len(),
+      "Search query parsing completed"
+    );
+
+    if morph_terms.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let query = self.bm25_scored(Box::new(build_and_query(&morph_terms)));
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+
+    self.convert_to_search_results(&searcher, top_docs, &query_tokens)
+  }
+
+  /// OR search tolerating typos within `max_distance` edits per query token
+  ///
+  /// Each analyzed query token becomes a `FuzzyTermQuery` (Levenshtein distance,
+  /// transposition counts as one edit) and the tokens are OR-combined.
+  /// `max_distance` is clamped to `0..=2`; `0` behaves like exact term search.
+  ///
+  /// Only the text field is searched (no N-gram, exact or reading fields), so this is
+  /// mainly useful for `Language::En`.
+  ///
+  /// # Examples
+  /// ```ignore
+  /// // "tokio" matches documents containing "Tokyo"
+  /// let results = search_engine.search_fuzzy("tokio", 1, 10)?;
+  /// ```
+  pub fn search_fuzzy(
+    &self,
+    query_str: &str,
+    max_distance: u8,
+    limit: usize,
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    let searcher = self.searcher()?;
+
+    let TokenizationResult {
+      terms: morph_terms,
+      query_tokens,
+    } = self.tokenize_query(searcher.index(), query_str)?;
+
+    debug!(
+      query = %query_str,
+      tokens = ?query_tokens,
+      max_distance,
+      "Fuzzy search query parsing completed"
+    );
+
+    if morph_terms.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let distance = max_distance.min(MAX_FUZZY_DISTANCE);
+    let subqueries: Vec<(Occur, Box<dyn Query>)> = morph_terms
+      .into_iter()
+      .map(|term| {
+        let query: Box<dyn Query> = Box::new(FuzzyTermQuery::new(term, distance, true));
+        (Occur::Should, query)
+      })
+      .collect();
+    let query = BooleanQuery::new(subqueries);
+
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+
+    self.convert_to_search_results(&searcher, top_docs, &[])
+  }
+
+  /// Search-as-you-type: matches the last query token as a prefix
+  ///
+  /// The query is analyzed with the index's text analyzer. All tokens but the last must
+  /// match exactly in the text field, and the last (possibly incomplete) token must be the
+  /// prefix of a term. For Japanese / Korean, the prefix is also matched against the N-gram
+  /// field, so prefixes up to the index's `max_gram` characters can start in the middle of
+  /// a word; longer prefixes match from the start of a word in the text field.
+  ///
+  /// Prefix matches are not scored by BM25, so ranking comes from the complete tokens.
+  ///
+  /// # Examples
+  /// ```ignore
+  /// // "tokyo tow" matches documents containing "tokyo" and a word starting with "tow"
+  /// let results = search_engine.search_prefix("tokyo tow", 10)?;
+  /// ```
+  pub fn search_prefix(
+    &self,
+    query_str: &str,
+    limit: usize,
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    let searcher = self.searcher()?;
+
+    let mut analyzer = self.text_analyzer(searcher.index())?;
+    let mut stream = analyzer.token_stream(query_str);
+    let mut tokens = Vec::new();
+    while stream.advance() {
+      let token = &stream.token().text;
+      if !token.is_empty() {
+        tokens.push(token.clone());
+      }
+    }
+
+    let Some(prefix) = tokens.pop() else {
+      return Ok(vec![]);
+    };
+
+    debug!(query = %query_str, tokens = ?tokens, prefix = %prefix, "Prefix search query parsing completed");
+
+    let pattern = format!("{}.*", escape_regex(&prefix));
+    let regex_query = |field| -> Result<Box<dyn Query>, SearcherError> {
+      let query =
+        RegexQuery::from_pattern(&pattern, field).map_err(|e| SearcherError::InvalidQuery {
+          reason: e.to_string(),
+        })?;
+      Ok(Box::new(query))
+    };
+    // Whole words in `text`, plus N-grams for prefixes starting mid-word
+    let mut prefix_queries = vec![(Occur::Should, regex_query(self.fields.text)?)];
+    if let Some(ngram_field) = self.fields.text_ngram {
+      prefix_queries.push((Occur::Should, regex_query(ngram_field)?));
+    }
+    let prefix_query = BooleanQuery::new(prefix_queries);
+
+    let mut subqueries: Vec<(Occur, Box<dyn Query>)> = tokens
+      .iter()
+      .map(|token| {
+        let term = Term::from_field_text(self.fields.text, token);
+        let query: Box<dyn Query> = Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs));
+        (Occur::Must, query)
+      })
+      .collect();
+    subqueries.push((Occur::Must, Box::new(prefix_query)));
+    let query = BooleanQuery::new(subqueries);
+
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+
+    self.convert_to_search_results(&searcher, top_docs, &tokens)
+  }
+
+  /// Matches a regular expression against the indexed terms of the text field
+  ///
+  /// The pattern is not analyzed and must match a whole term: `tok.*` matches terms starting
+  /// with "tok", while `tok` matches only the term "tok". Terms are stored after analysis
+  /// (lowercased, and stemmed for English), so patterns should be written in lowercase.
+  /// Uses the `regex` syntax supported by Tantivy (no anchors or look-around).
+  ///
+  /// Regex matches are not scored by BM25. Broad patterns (`.*a.*`) walk the whole term
+  /// dictionary and can be slow on large indexes.
+  ///
+  /// # Errors
+  /// - `SearcherError::InvalidQuery` if `pattern` is not a valid regular expression
+  ///
+  /// # Examples
+  /// ```ignore
+  /// // "tokyo", "token", "tokenizer", ...
+  /// let results = search_engine.search_regex("tok.*", 10)?;
+  /// ```
+  pub fn search_regex(
+    &self,
+    pattern: &str,
+    limit: usize,
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    let searcher = self.searcher()?;
+
+    let query = RegexQuery::from_pattern(pattern, self.fields.text).map_err(|e| {
+      SearcherError::InvalidQuery {
+        reason: e.to_string(),
+      }
+    })?;
+    debug!(pattern = %pattern, "Regex query built");
+
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+
+    self.convert_to_search_results(&searcher, top_docs, &[])
+  }
+
+  /// Retrieves the chunks of the source document `source_id`
+  ///
+  /// Exact lookup on the `source_id` field, independent of any text query. Case is
+  /// ignored for indexes created with `case_insensitive_source_id`. All results have
+  /// the same score, so they are returned in index order.
+  ///
+  /// # Examples
+  /// ```ignore
+  /// // Every chunk of "manual.pdf" (up to 100)
+  /// let chunks = search_engine.search_by_source("manual.pdf", 100)?;
+  /// ```
+  pub fn search_by_source(
+    &self,
+    source_id: &str,
+    limit: usize,
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    let searcher = self.searcher()?;
+
+    let term = source_id_term(searcher.index(), self.fields.source_id, source_id);
+    debug!(source_id = %source_id, term = ?term, "Source lookup query built");
+    let query = TermQuery::new(term, IndexRecordOption::Basic);
+
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+
+    self.convert_to_search_results(&searcher, top_docs, &[])
+  }
+
+  /// Finds documents similar to the indexed document `doc_id` ("more like this")
+  ///
+  /// The stored text of `doc_id` is analyzed with the index's text analyzer, and its
+  /// `SIMILAR_QUERY_TERMS` most frequent terms (ties in order of first occurrence) are
+  /// OR-searched in the text field. `doc_id` itself is excluded from the results.
+  ///
+  /// # Errors
+  /// - `SearcherError::DocumentNotFound` if no committed document has `doc_id`
+  ///
+  /// # Examples
+  /// ```ignore
+  /// // Near-duplicates and related chunks of "chunk-42"
+  /// let results = search_engine.search_similar("chunk-42", 5)?;
+  /// ```
+  pub fn search_similar(
+    &self,
+    doc_id: &str,
+    limit: usize,
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    let searcher = self.searcher()?;
+    let id_term = Term::from_field_text(self.fields.id, doc_id);
+
+    let doc = self.find_doc(&searcher, doc_id)?;
+    let text = self.get_text_field(&doc, self.fields.text).unwrap_or_default();
+
+    let query_tokens = self.most_frequent_tokens(searcher.index(), &text)?;
+    debug!(doc_id, tokens = ?query_tokens, "Similar document query constructed");
+
+    if query_tokens.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let terms = query_tokens.iter().map(|token| Term::from_field_text(self.fields.text, token));
+    let query = BooleanQuery::new(vec![
+      (Occur::Should, self.text_terms_query(terms.collect())),
+      (
+        Occur::MustNot,
+        Box::new(TermQuery::new(id_term, IndexRecordOption::Basic)),
+      ),
+    ]);
+
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+
+    self.convert_to_search_results(&searcher, top_docs, &query_tokens)
+  }
+
+  /// Resolves `doc_id` to the address of its committed document
+  ///
+  /// # Errors
+  /// - `SearcherError::DocumentNotFound` if no committed document has `doc_id`
+  fn find_doc_address(
+    &self,
+    searcher: &Searcher,
+    doc_id: &str,
+  ) -> Result<DocAddress, SearcherError> {
+    let id_term = Term::from_field_text(self.fields.id, doc_id);
+    let found = searcher.search(
+      &TermQuery::new(id_term, IndexRecordOption::Basic),
+      &TopDocs::with_limit(1),
+    )?;
+    match found.first() {
+      Some(&(_, doc_address)) => Ok(doc_address),
+      None => Err(SearcherError::DocumentNotFound {
+        doc_id: doc_id.to_string(),
+      }),
+    }
+  }
+
+  /// Loads the stored document with `doc_id`
+  ///
+  /// # Errors
+  /// - `SearcherError::DocumentNotFound` if no committed document has `doc_id`
+  fn find_doc(
+    &self,
+    searcher: &Searcher,
+    doc_id: &str,
+  ) -> Result<tantivy::TantivyDocument, SearcherError> {
+    let doc_address = self.find_doc_address(searcher, doc_id)?;
+    Ok(searcher.doc(doc_address)?)
+  }
+
+  /// Explains the score of document `doc_id` for `query_str`
+  ///
+  /// The query is the same as [`SearchEngine::search`] (including custom BM25 parameters).
+  /// Returns Tantivy's explanation tree as pretty-printed JSON: for BM25 scoring, each term
+  /// lists its `idf` and its `freq` normalization (`k1`, `b`, `dl`, `avgdl`). With custom
+  /// BM25 parameters, each matched `text` term also lists the same components for the
+  /// custom and Tantivy parameters, whose difference is added to the score.
+  ///
+  /// # Errors
+  /// - `SearcherError::InvalidQuery` if `query_str` cannot be parsed
+  /// - `SearcherError::DocumentNotFound` if no committed document has `doc_id`
+  /// - `SearcherError::Tantivy` if the document does not match the query
+  ///
+  /// # Examples
+  /// ```ignore
+  /// println!("{}", search_engine.explain("tokyo tower", "doc-42")?);
+  /// ```
+  pub fn explain(&self, query_str: &str, doc_id: &str) -> Result<String, SearcherError> {
+    let searcher = self.searcher()?;
+
+    let query_parser = QueryParser::for_index(searcher.index(), vec![self.fields.text]);
+    let query = query_parser.parse_query(query_str).map_err(|e| SearcherError::InvalidQuery {
+      reason: e.to_string(),
+    })?;
+    let query = self.bm25_scored(query);
+
+    let doc_address = self.find_doc_address(&searcher, doc_id)?;
+    let explanation = query.explain(&searcher, doc_address)?;
+    Ok(explanation.to_pretty_json())
+  }
+
+  /// Byte offsets `(from, to)` of the indexed tokens of document `doc_id`, in token order
+  ///
+  /// Each range is a valid slice of the document's stored `text`, for client-side
+  /// highlighting. Requires an index created with `with_offsets`.
+  ///
+  /// # Errors
+  /// - `SearcherError::InvalidIndex` if the index has no `text_offsets` field
+  /// - `SearcherError::DocumentNotFound` if no committed document has `doc_id`
+  pub fn token_offsets(&self, doc_id: &str) -> Result<Vec<(usize, usize)>, SearcherError> {
+    let Some(text_offsets_field) = self.fields.text_offsets else {
+      return Err(SearcherError::InvalidIndex {
+        field: "text_offsets".to_string(),
+        reason: "index was created without with_offsets".to_string(),
+      });
+    };
+
+    let searcher = self.searcher()?;
+    let doc = self.find_doc(&searcher, doc_id)?;
+    let values: Vec<usize> = doc
+      .get_all(text_offsets_field)
+      .filter_map(|value| value.as_u64())
+      .map(|value| value as usize)
+      .collect();
+
+    Ok(values.chunks_exact(2).map(|pair| (pair[0], pair[1])).collect())
+  }
+
+  /// Up to `SIMILAR_QUERY_TERMS` analyzed tokens of `text`, most frequent first
+  fn most_frequent_tokens(&self, index: &Index, text: &str) -> Result<Vec<String>, SearcherError> {
+    let mut analyzer = self.text_analyzer(index)?;
+    let mut stream = analyzer.token_stream(text);
+
+    // Token -> (Frequency, Order of first occurrence)
+    let mut counts: HashMap<String, (usize, usize)> = HashMap::new();
+    while stream.advance() {
+      let token = &stream.token().text;
+      if token.is_empty() {
+        continue;
+      }
+      let order = counts.len();
+      counts.entry(token.clone()).or_insert((0, order)).0 += 1;
+    }
+
+    let mut tokens: Vec<(String, (usize, usize))> = counts.into_iter().collect();
+    tokens.sort_by(|(_, (count_a, order_a)), (_, (count_b, order_b))| {
+      count_b.cmp(count_a).then(order_a.cmp(order_b))
+    });
+
+    Ok(tokens.into_iter().take(SIMILAR_QUERY_TERMS).map(|(token, _)| token).collect())
+  }
+
+  /// OR search that also classifies how each hit matched the query
+  ///
+  /// Candidates and their ranking are the same as [`SearchEngine::search_tokens_or`].
+  /// Each result is tagged with `match_kind`:
+  /// - `Phrase`: contains the query tokens as a contiguous phrase
+  /// - `AllTerms`: contains all query tokens
+  /// - `SomeTerms`: contains only some of the query tokens
+  ///
+  /// Single-token queries are tagged `Phrase` when the token matches in the text field.
+  /// On indexes without positions (`text_record_option = "freqs-only"`), multi-token
+  /// queries are never tagged `Phrase`.
+  pub fn smart_search(
+    &self,
+    query_str: &str,
+    limit: usize,
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    let searcher = self.searcher()?;
+    let index = searcher.index();
+
+    let TokenizationResult {
+      terms: morph_terms,
+      query_tokens,
+    } = self.tokenize_query(index, query_str)?;
+
+    if morph_terms.is_empty() {
+      return Ok(vec![]);
+    }
+
+    // Queries used only for classification (not scored)
+    let all_terms_query = build_and_query(&morph_terms);
+    let phrase_terms = self.phrase_terms(index, query_str)?;
+    let phrase_query: Option<Box<dyn Query>> = if phrase_terms.len() <= 1 {
+      Some(Box::new(all_terms_query.clone()))
+    } else if has_positions(index, self.fields.text) {
+      Some(Box::new(PhraseQuery::new_with_offset(phrase_terms)))
+    } else {
+      None
+    };
+
+    let enable_scoring = EnableScoring::disabled_from_searcher(&searcher);
+    let all_terms_weight = all_terms_query.weight(enable_scoring)?;
+    let phrase_weight = phrase_query.map(|query| query.weight(enable_scoring)).transpose()?;
+
+    let query = self.build_or_query(index, query_str, morph_terms, &query_tokens, None)?;
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+
+    let match_kinds = top_docs
+      .iter()
+      .map(|(_, doc_address)| {
+        let is_phrase = match &phrase_weight {
+          Some(weight) => matches_doc(weight.as_ref(), &searcher, *doc_address)?,
+          None => false,
+        };
+        if is_phrase {
+          Ok(MatchKind::Phrase)
+        } else if matches_doc(all_terms_weight.as_ref(), &searcher, *doc_address)? {
+          Ok(MatchKind::AllTerms)
+        } else {
+          Ok(MatchKind::SomeTerms)
+        }
+      })
+      .collect::<Result<Vec<_>, SearcherError>>()?;
+
+    let mut results = self.convert_to_search_results(&searcher, top_docs, &query_tokens)?;
+    for (result, match_kind) in results.iter_mut().zip(match_kinds) {
+      result.match_kind = Some(match_kind);
+    }
+
+    debug!(query = %query_str, num_results = results.len(), "Smart search completed");
+
+    Ok(results)
+  }
+
+  /// Phrase search: matches documents containing the query tokens adjacently and in order
+  ///
+  /// The query is tokenized with the language-specific analyzer, and a `PhraseQuery`
+  /// is built from the emitted token positions. For Japanese, positions follow
+  /// morpheme boundaries, so "東京 タワー" matches "東京タワー" but not "タワーが東京に".
+  ///
+  /// # Arguments
+  /// - `query_str`: Search query string
+  /// - `limit`: Maximum number of results to return
+  ///
+  /// # Returns
+  /// Search result vector with BM25 score
+  ///
+  /// # Behavior
+  /// - Single-token queries fall back to a `TermQuery`
+  /// - Returns an empty result if no tokens remain after analysis
+  /// - The N-gram field is not used (it has no meaningful morpheme positions)
+  ///
+  /// # Errors
+  /// - `SearcherError::PositionsNotIndexed` if the query has several tokens and the
+  ///   index was created with `text_record_option = "freqs-only"`
+  pub fn search_phrase(
+    &self,
+    query_str: &str,
+    limit: usize,
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    let searcher = self.searcher()?;
+
+    let mut phrase_terms = self.phrase_terms(searcher.index(), query_str)?;
+
+    let query: Box<dyn Query> = match phrase_terms.len() {
+      0 => return Ok(vec![]),
+      1 => {
+        let (_, term) = phrase_terms.remove(0);
+        self.bm25_scored(Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs)))
+      }
+      _ => {
+        if !has_positions(searcher.index(), self.fields.text) {
+          return Err(SearcherError::PositionsNotIndexed {
+            field: "text".to_string(),
+          });
+        }
+        let terms = phrase_terms.iter().map(|(_, term)| term.clone()).collect();
+        let phrase_query: Box<dyn Query> = Box::new(PhraseQuery::new_with_offset(phrase_terms));
+        match self.bm25 {
+          // Tantivy's phrase scorer has fixed parameters: match the phrase, score its terms
+          Some(_) => Box::new(BooleanQuery::new(vec![
+            (
+              Occur::Must,
+              Box::new(ConstScoreQuery::new(phrase_query, 0.0)),
+            ),
+            (Occur::Should, self.text_terms_query(terms)),
+          ])),
+          None => phrase_query,
+        }
+      }
+    };
+
+    let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+
+    debug!(query = %query_str, num_results = top_docs.len(), "Phrase search completed");
+
+    self.convert_to_search_results(&searcher, top_docs, &[])
+  }
+
+  /// Shows how the query is normalized by the language-specific analyzer
+  ///
+  /// Returns `(input, indexed)` pairs in query order, where `input` is the original
+  /// query substring and `indexed` the form actually searched:
+  /// - English: lowercased and stemmed ("Running" -> "run")
+  /// - Japanese: morphological split ("東京タワー" -> "東京", "タワー")
+  ///
+  /// Tokens removed by the analyzer (stop words, particles, etc.) do not appear.
+  /// Unlike the search methods, duplicates are kept.
+  pub fn analyze_query(&self, query_str: &str) -> Result<Vec<(String, String)>, SearcherError> {
+    let searcher = self.searcher()?;
+    let mut analyzer = self.text_analyzer(searcher.index())?;
+
+    let mut token_stream = analyzer.token_stream(query_str);
+    let mut pairs = Vec::new();
+    while token_stream.advance() {
+      let token = token_stream.token();
+      if token.text.is_empty() {
+        continue;
+      }
+      let input = query_str.get(token.offset_from..token.offset_to).unwrap_or_default();
+      pairs.push((input.to_string(), token.text.clone()));
+    }
+
+    Ok(pairs)
+  }
+
+  /// Returns the tokens the query is searched with (for debugging unexpected matches)
+  ///
+  /// Same deduplicated token list as used by [`SearchEngine::search_tokens_or`] and
+  /// the other token-based searches, in order of first occurrence:
+  /// - English: "Tokyo tokyo towers" -> "tokyo", "tower"
+  /// - Japanese: "京都の寺" -> "京都", "寺"
+  ///
+  /// See [`SearchEngine::analyze_query`] to also get the original query substrings.
+  pub fn explain_query(&self, query_str: &str) -> Result<Vec<String>, SearcherError> {
+    let searcher = self.searcher()?;
+    let TokenizationResult { query_tokens, .. } =
+      self.tokenize_query(searcher.index(), query_str)?;
+    Ok(query_tokens)
+  }
+
+  /// Tokenizes query string into position-aware Terms for phrase matching
+  ///
+  /// Unlike `tokenize_query`, duplicates are kept and positions are preserved
+  /// (gaps left by filtered tokens are respected by `PhraseQuery`).
+  fn phrase_terms(
+    &self,
+    index: &Index,
+    query_str: &str,
+  ) -> Result<Vec<(usize, Term)>, SearcherError> {
+    let mut analyzer = self.text_analyzer(index)?;
+
+    let mut token_stream = analyzer.token_stream(query_str);
+    let mut terms = Vec::new();
+    while token_stream.advance() {
+      let token = token_stream.token();
+      if !token.text.is_empty() {
+        terms.push((
+          token.position,
+          Term::from_field_text(self.fields.text, &token.text),
+        ));
+      }
+    }
+
+    // PhraseQuery expects offsets relative to the first token
+    if let Some(&(first, _)) = terms.first() {
+      for (position, _) in &mut terms {
+        *position -= first;
+      }
+    }
+
+    Ok(terms)
+  }
+
+  /// Helper method to convert top_docs to SearchResult vector
+  ///
+  /// `matched_terms` of each result is set to the `query_tokens` indexed in its `text`
+  /// field (see [`SearchEngine::matched_terms`]); pass `&[]` to leave it empty.
+  fn convert_to_search_results(
+    &self,
+    searcher: &tantivy::Searcher,
+    top_docs: Vec<(f32, tantivy::DocAddress)>,
+    query_tokens: &[String],
+  ) -> Result<Vec<SearchResult>, SearcherError> {
+    let mut results = Vec::with_capacity(top_docs.len());
+
+    for (score, doc_address) in top_docs {
+      let doc: tantivy::TantivyDocument = searcher.doc(doc_address)?;
+
+      // Get required fields (InvalidIndex if error)
+      let doc_id =
+        self.get_text_field(&doc, self.fields.id).ok_or_else(|| SearcherError::InvalidIndex {
+          field: "id".to_string(),
+          reason: "Required field not found".to_string(),
+        })?;
+
+      let source_id = self.get_text_field(&doc, self.fields.source_id).ok_or_else(|| {
+        SearcherError::InvalidIndex {
+          field: "source_id".to_string(),
+          reason: "Required field not found".to_string(),
+        }
+      })?;
+
+      // text is treated as Optional (fallback to empty string)
+      let text = self.get_text_field(&doc, self.fields.text).unwrap_or_default();
+
+      // Restore metadata: Get directly from JsonObject (plus tags kept only in `tags`)
+      let mut metadata = self.get_json_object_field(&doc, self.fields.metadata);
+      restore_tags(&doc, &self.fields, &mut metadata);
+
+      let matched_terms = self.matched_terms(searcher, doc_address, query_tokens)?;
+
+      results.push(SearchResult {
+        doc_id,
+        source_id,
+        score,
+        text,
+        metadata,
+        match_kind: None,
+        matched_terms,
+      });
+    }
+
+    Ok(results)
+  }
+
+  /// Query tokens (in query order) indexed in the `text` field of the document at `doc_address`
+  ///
+  /// Each token is looked up in the postings of its term, so the stored text is not
+  /// re-analyzed (morphological analysis of every hit would dominate search time).
+  fn matched_terms(
+    &self,
+    searcher: &Searcher,
+    doc_address: DocAddress,
+    query_tokens: &[String],
+  ) -> Result<Vec<String>, SearcherError> {
+    if query_tokens.is_empty() {
+      return Ok(Vec::new());
+    }
+
+    let segment_reader = searcher.segment_reader(doc_address.segment_ord);
+    let inverted_index = segment_reader.inverted_index(self.fields.text)?;
+    let mut matched = Vec::new();
+    for token in query_tokens {
+      let term = Term::from_field_text(self.fields.text, token);
+      let Some(mut postings) = inverted_index
+        .read_postings(&term, IndexRecordOption::Basic)
+        .map_err(tantivy::TantivyError::from)?
+      else {
+        continue;
+      };
+      // `seek` requires a target at or after the current document
+      if postings.doc() <= doc_address.doc_id
+        && postings.seek(doc_address.doc_id) == doc_address.doc_id
+      {
+        matched.push(token.clone());
+      }
+    }
+
+    Ok(matched)
+  }
+
+  /// Get value of single text field from TantivyDocument
+  ///
+  /// # Returns
+  /// - `Some(String)`: If field value exists
+  /// - `None`: If field value does not exist
+  fn get_text_field(
+    &self,
+    doc: &tantivy::TantivyDocument,
+    field: tantivy::schema::Field,
+  ) -> Option<String> {
+    doc.get_first(field).and_then(|v| v.as_str().map(String::from))
+  }
+
+  /// Get value of JsonObject field from TantivyDocument and convert to Metadata
+  ///
+  /// # Returns
+  /// - If field value exists: Converted Metadata
+  /// - If field value does not exist: Empty Metadata
+  fn get_json_object_field(
+    &self,
+    doc: &tantivy::TantivyDocument,
+    field: tantivy::schema::Field,
+  ) -> crate::models::Metadata {
+    doc
+      .get_first(field)
+      .and_then(|value| value.as_object())
+      .map(|iter| {
+        // Tantivy 0.25: as_object() returns CompactDocObjectIter (iterator)
+        // iter: (key: &str, value: CompactDocValue<'_>)
+        let mut metadata = crate::models::Metadata::default();
+
+        for (k, v) in iter {
+          // Convert CompactDocValue to serde_json::Value
+          let json_val = compact_value_to_json(&v);
+          metadata.insert(k.to_string(), json_val);
+        }
+
+        metadata
+      })
+      .unwrap_or_default()
+  }
+
+  /// Returns the language of this search engine
+  pub fn language(&self) -> Language {
+    self.language
+  }
+
+  /// Reloads the reader so that the latest commit becomes visible immediately.
+  ///
+  /// The reader also reloads automatically (`OnCommitWithDelay`), but only after
+  /// a short delay; call this when a commit must be visible to the next search.
+  ///
+  /// # Errors
+  /// - Tantivy reader reload error
+  pub fn reload(&self) -> Result<(), SearcherError> {
+    self.reader.reload()?;
+    Ok(())
+  }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Test Module
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::config::Language;
+  use crate::indexer::index_manager::IndexManager;
+  use crate::models::Document;
+  use crate::models::model_definition::TAGS_KEY;
+  use serde_json::json;
+
+  // ─── Test Helper Functions ───────────────────────────────────────────────────
+
+  /// Helper to create English index (SearchEngine created later)
+  fn create_english_index_manager() -> (tempfile::TempDir, IndexManager) {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+      .expect("Failed to create index");
+    (tmp_dir, index_manager)
+  }
+
+  /// Helper to create SearchEngine from IndexManager
+  ///
+  /// Important: Call after adding documents (SearchEngine has its own Reader)
+  fn create_search_engine(index_manager: &IndexManager) -> SearchEngine {
+    SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::En)
+      .expect("Failed to create SearchEngine")
+  }
+
+  /// Helper to add test documents
+  fn add_test_documents(index_manager: &IndexManager, docs: &[Document]) {
+    let report = index_manager.add_documents(docs).expect("Failed to add documents");
+    index_manager.commit().expect("Failed to commit");
+    assert_eq!(
       report.added,
       docs.len(),
       "Expected number of documents to be added"
     );
   }
 
-  // ─── Basic Search Tests ────────────────────────────────────────────────────
+  // ─── Basic Search Tests ────────────────────────────────────────────────────
+
+  #[test]
+  fn search_engine_language() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    let search_engine = create_search_engine(&index_manager);
+    assert_eq!(search_engine.language(), Language::En);
+  }
+
+  #[test]
+  fn search_returns_empty_for_empty_index() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search("tokyo", 10).expect("Search failed");
+    assert!(results.is_empty());
+  }
+
+  #[test]
+  fn search_finds_matching_document() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Tokyo is the capital of Japan"),
+      Document::new("doc-2", "src-1", "Osaka is a major city"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    // Create SearchEngine after adding documents
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search("tokyo", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+    assert!(results[0].score > 0.0);
+  }
+
+  #[test]
+  fn search_is_case_insensitive() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![Document::new(
+      "doc-1",
+      "src-1",
+      "Tokyo is the capital of Japan",
+    )];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+
+    // Search in lowercase
+    let results_lower = search_engine.search("tokyo", 10).expect("Search failed");
+    // Search in uppercase
+    let results_upper = search_engine.search("TOKYO", 10).expect("Search failed");
+
+    // Both return the same document (LowerCaser is working)
+    assert_eq!(results_lower.len(), 1);
+    assert_eq!(results_upper.len(), 1);
+  }
+
+  // ─── BM25 Scoring Tests ─────────────────────────────────────────────────
+
+  #[test]
+  fn search_bm25_rare_term_scores_higher() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    // "rust" appears only in doc-1, "programming" appears in both
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Rust programming language"),
+      Document::new("doc-2", "src-1", "Python programming language"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search("rust", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+  }
+
+  #[test]
+  fn search_returns_results_sorted_by_score() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "programming programming programming"),
+      Document::new("doc-2", "src-1", "programming"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search("programming", 10).expect("Search failed");
+    assert_eq!(results.len(), 2);
+
+    // Confirm sorted by score (higher score first)
+    for i in 0..results.len().saturating_sub(1) {
+      assert!(results[i].score >= results[i + 1].score);
+    }
+  }
+
+  #[test]
+  fn explain_describes_bm25_components() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Tokyo tower is in Tokyo"),
+      Document::new("doc-2", "src-1", "Osaka castle"),
+    ];
+    add_test_documents(&index_manager, &docs);
+    let search_engine = create_search_engine(&index_manager);
+
+    let explanation = search_engine.explain("tokyo", "doc-1").expect("Explain failed");
+    assert!(!explanation.is_empty());
+    for component in ["idf", "freq", "k1", "avgdl"] {
+      assert!(
+        explanation.contains(component),
+        "missing {component}: {explanation}"
+      );
+    }
+
+    // Non-matching document and unknown document are errors
+    assert!(matches!(
+      search_engine.explain("tokyo", "doc-2"),
+      Err(SearcherError::Tantivy(_))
+    ));
+    assert!(matches!(
+      search_engine.explain("tokyo", "missing"),
+      Err(SearcherError::DocumentNotFound { .. })
+    ));
+  }
+
+  #[test]
+  fn explain_describes_custom_bm25_components() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Tokyo tower is in Tokyo"),
+      Document::new("doc-2", "src-1", "Osaka castle"),
+    ];
+    add_test_documents(&index_manager, &docs);
+    let mut search_engine = create_search_engine(&index_manager);
+    search_engine.set_bm25_params(2.0, 0.3).expect("Valid parameters");
+
+    let explanation = search_engine.explain("tokyo", "doc-1").expect("Explain failed");
+    let json: serde_json::Value = serde_json::from_str(&explanation).expect("valid json");
+    let score = search_engine.search("tokyo", 1).expect("Search failed")[0].score;
+    assert!((json["value"].as_f64().unwrap() as f32 - score).abs() < 1e-4);
+
+    for component in [
+      "k1=2",
+      "b=0.3",
+      "idf",
+      "freq",
+      "dl",
+      "avgdl",
+      "difference for",
+      "tokyo",
+    ] {
+      assert!(
+        explanation.contains(component),
+        "missing {component}: {explanation}"
+      );
+    }
+  }
+
+  // ─── search_tokens_or Tests ────────────────────────────────────────────────
+
+  #[test]
+  fn search_tokens_or_finds_documents() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Tokyo is the capital of Japan"),
+      Document::new("doc-2", "src-1", "Osaka is a major city"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search_tokens_or("tokyo", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+  }
+
+  #[test]
+  fn search_tokens_or_handles_multiple_tokens() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Tokyo tower is famous"),
+      Document::new("doc-2", "src-1", "Osaka castle is famous"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    // "tokyo" OR "osaka" hits both
+    let results = search_engine.search_tokens_or("tokyo osaka", 10).expect("Search failed");
+    assert_eq!(results.len(), 2);
+  }
+
+  #[test]
+  fn search_tokens_or_returns_empty_for_empty_tokens() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![Document::new("doc-1", "src-1", "Some content")];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    // Empty string -> No tokens -> Empty result
+    let results = search_engine.search_tokens_or("", 10).expect("Search failed");
+    assert!(results.is_empty());
+  }
+
+  #[test]
+  fn search_tokens_or_respects_limit() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "programming language"),
+      Document::new("doc-2", "src-1", "programming tutorial"),
+      Document::new("doc-3", "src-1", "programming guide"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search_tokens_or("programming", 2).expect("Search failed");
+    assert_eq!(results.len(), 2);
+  }
+
+  // ─── Metadata Restoration Tests ──────────────────────────────────────────────────
+
+  #[test]
+  fn search_restores_metadata() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Tokyo is the capital of Japan")
+        .with_metadata("author", json!("alice"))
+        .with_metadata("version", json!(1))
+        .with_tag("category:geo"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search("tokyo", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+
+    let result = &results[0];
+    assert_eq!(result.metadata["author"], json!("alice"));
+    assert_eq!(result.metadata["version"], json!(1));
+    assert_eq!(result.metadata["tags"], json!(["category:geo"]));
+  }
+
+  #[test]
+  fn search_returns_empty_metadata_when_not_set() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![Document::new("doc-1", "src-1", "Tokyo is the capital")];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search("tokyo", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert!(results[0].metadata.is_empty());
+  }
+
+  #[test]
+  fn search_handles_complex_metadata_types() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Test document")
+        .with_metadata("string", json!("value"))
+        .with_metadata("number", json!(42))
+        .with_metadata("boolean", json!(true))
+        .with_metadata("null", json!(null))
+        .with_metadata("array", json!([1, 2, 3]))
+        .with_metadata("object", json!({"nested": "value"})),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search("test", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+
+    let metadata = &results[0].metadata;
+    assert_eq!(metadata["string"], json!("value"));
+    assert_eq!(metadata["number"], json!(42));
+    assert_eq!(metadata["boolean"], json!(true));
+    assert_eq!(metadata["null"], json!(null));
+    assert_eq!(metadata["array"], json!([1, 2, 3]));
+    assert_eq!(metadata["object"], json!({"nested": "value"}));
+  }
+
+  // ─── SearchResult Structure Tests ────────────────────────────────────────────────
+
+  #[test]
+  fn search_result_contains_all_fields() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs =
+      vec![Document::new("doc-123", "src-456", "Hello world").with_metadata("key", json!("value"))];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search("hello", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+
+    let result = &results[0];
+    assert_eq!(result.doc_id, "doc-123");
+    assert_eq!(result.source_id, "src-456");
+    assert_eq!(result.text, "Hello world");
+    assert!(result.score > 0.0);
+    assert_eq!(result.metadata["key"], json!("value"));
+  }
+
+  // ─── Error Handling Tests ──────────────────────────────────────────────
+
+  #[test]
+  fn search_invalid_query_returns_error() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    let search_engine = create_search_engine(&index_manager);
+
+    // Invalid query syntax (unclosed parenthesis)
+    let result = search_engine.search("(", 10);
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert!(matches!(err, SearcherError::InvalidQuery { .. }));
+  }
+
+  // ─── English specific tokenization tests ────────────────────────────────────
+
+  #[test]
+  fn search_stemming_works_for_english() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![Document::new("doc-1", "src-1", "running and jumping")];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    // Should match "running" -> "run", "jumping" -> "jump" by Stemmer
+    // However, it depends on Tantivy Stemmer behavior,
+    // so here just checking that query does not error
+    let results = search_engine.search("run", 10);
+    assert!(results.is_ok());
+  }
+
+  #[test]
+  fn search_tokens_or_lowercases_query() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![Document::new("doc-1", "src-1", "TOKYO CAPITAL")];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    // Document found even if searching in lowercase
+    let results = search_engine.search_tokens_or("tokyo", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+  }
+
+  // ─── Multiple Document Search Tests ────────────────────────────────────────────
+
+  #[test]
+  fn search_finds_multiple_matching_documents() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Rust programming"),
+      Document::new("doc-2", "src-1", "Python programming"),
+      Document::new("doc-3", "src-1", "Java programming"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search("programming", 10).expect("Search failed");
+    assert_eq!(results.len(), 3);
+  }
+
+  #[test]
+  fn search_distinct_source_ids() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![
+      Document::new("doc-1", "source-alpha", "Alpha document"),
+      Document::new("doc-2", "source-beta", "Beta document"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search("document", 10).expect("Search failed");
+    assert_eq!(results.len(), 2);
+
+    let source_ids: std::collections::HashSet<&str> =
+      results.iter().map(|r| r.source_id.as_str()).collect();
+    assert!(source_ids.contains("source-alpha"));
+    assert!(source_ids.contains("source-beta"));
+  }
+
+  // ─── Edge Case Tests ─────────────────────────────────────────────────────
+
+  #[test]
+  fn search_special_characters_in_content() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![Document::new("doc-1", "src-1", "Price: $100 (50% off!)")];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    // Search possible even with content containing special characters
+    let results = search_engine.search("price", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].text, "Price: $100 (50% off!)");
+  }
+
+  #[test]
+  fn search_whitespace_handling() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![Document::new("doc-1", "src-1", "hello world")];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    // Multi-token query
+    let results = search_engine.search("hello world", 10).expect("Search failed");
+    assert!(!results.is_empty());
+  }
+
+  #[test]
+  fn search_long_text_content() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let long_text = "programming ".repeat(100);
+    let docs = vec![Document::new("doc-1", "src-1", &long_text)];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search("programming", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+  }
+
+  #[test]
+  fn search_unicode_content() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    // English index can save text containing Unicode characters
+    let docs = vec![Document::new("doc-1", "src-1", "Hello 世界 мир")];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search("hello", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert!(results[0].text.contains("世界"));
+  }
+
+  // ─── Smart Search Tests ────────────────────────────────────────────────────
+
+  #[test]
+  fn smart_search_classifies_match_kind() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![
+      Document::new("phrase", "src-1", "The tokyo tower is tall"),
+      Document::new("all", "src-2", "A tower in tokyo"),
+      Document::new("some", "src-3", "Tokyo station is busy"),
+      Document::new("none", "src-4", "Osaka castle"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.smart_search("Tokyo Tower", 10).expect("Search failed");
+
+    assert_eq!(results.len(), 3);
+    let kind_of =
+      |doc_id: &str| results.iter().find(|r| r.doc_id == doc_id).and_then(|r| r.match_kind);
+    assert_eq!(kind_of("phrase"), Some(MatchKind::Phrase));
+    assert_eq!(kind_of("all"), Some(MatchKind::AllTerms));
+    assert_eq!(kind_of("some"), Some(MatchKind::SomeTerms));
+  }
+
+  #[test]
+  fn smart_search_single_token_is_phrase() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+
+    let docs = vec![Document::new("doc-1", "src-1", "Tokyo is the capital")];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.smart_search("tokyo", 10).expect("Search failed");
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].match_kind, Some(MatchKind::Phrase));
+  }
+
+  // ─── Exact Field Tests ─────────────────────────────────────────────────────
+
+  /// "apples" stems to "appl", so both documents match the stemmed field;
+  /// the shorter one wins on BM25 unless exact matches are boosted.
+  fn index_apple_documents(english_exact_field: bool) -> (tempfile::TempDir, IndexManager) {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let options = crate::indexer::IndexOptions {
+      english_exact_field,
+      ..crate::indexer::IndexOptions::default()
+    };
+    let index_manager =
+      IndexManager::open_or_create_with_options(tmp_dir.path(), Language::En, None, options)
+        .expect("Failed to create index");
+    let docs = vec![
+      Document::new("stemmed", "src-1", "apple"),
+      Document::new("exact", "src-2", "I like apples very much every single day"),
+    ];
+    add_test_documents(&index_manager, &docs);
+    (tmp_dir, index_manager)
+  }
+
+  #[test]
+  fn exact_field_boosts_unstemmed_matches() {
+    let (_tmp_dir, index_manager) = index_apple_documents(true);
+    assert!(index_manager.fields().text_exact.is_some());
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search_tokens_or("apples", 10).expect("Search failed");
+
+    // Stemmed recall is kept, exact match ranks first
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].doc_id, "exact");
+    assert!(results[0].score > results[1].score);
+  }
+
+  #[test]
+  fn without_exact_field_stemmed_match_ranks_by_bm25_only() {
+    let (_tmp_dir, index_manager) = index_apple_documents(false);
+    assert!(index_manager.fields().text_exact.is_none());
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search_tokens_or("apples", 10).expect("Search failed");
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].doc_id, "stemmed");
+  }
+
+  // ─── Similar Document Tests ────────────────────────────────────────────────
+
+  #[test]
+  fn search_similar_finds_near_duplicates_only() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    let docs = vec![
+      Document::new(
+        "spring",
+        "src-1",
+        "Kyoto temples attract many tourists every spring",
+      ),
+      Document::new(
+        "autumn",
+        "src-2",
+        "Kyoto temples attract many tourists every autumn",
+      ),
+      Document::new("rust", "src-3", "Rust compiler release notes"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let similar_ids = |doc_id: &str| {
+      search_engine
+        .search_similar(doc_id, 10)
+        .expect("Search failed")
+        .into_iter()
+        .map(|result| result.doc_id)
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(similar_ids("spring"), ["autumn"]);
+    assert_eq!(similar_ids("autumn"), ["spring"]);
+    assert!(similar_ids("rust").is_empty());
+  }
+
+  #[test]
+  fn search_similar_unknown_document_is_not_found() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    let search_engine = create_search_engine(&index_manager);
+
+    let err = search_engine.search_similar("missing", 10).unwrap_err();
+    assert!(matches!(err, SearcherError::DocumentNotFound { doc_id } if doc_id == "missing"));
+  }
+
+  // ─── Stop Word Tests ───────────────────────────────────────────────────────
+
+  #[test]
+  fn english_stop_words_do_not_change_search_behavior() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let options = crate::indexer::IndexOptions {
+      english_stop_words: true,
+      english_exact_field: true,
+      ..crate::indexer::IndexOptions::default()
+    };
+    let index_manager =
+      IndexManager::open_or_create_with_options(tmp_dir.path(), Language::En, None, options)
+        .expect("Failed to create index");
+    let docs = vec![
+      Document::new("tokyo", "src-1", "Tokyo is the capital of Japan"),
+      Document::new("kyoto", "src-2", "The temples of Kyoto"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    // Stop words are not indexed
+    let searcher = index_manager.reader().searcher();
+    let the = Term::from_field_text(index_manager.fields().text, "the");
+    assert_eq!(searcher.doc_freq(&the).expect("Failed to get doc_freq"), 0);
+
+    let search_engine = create_search_engine(&index_manager);
+    let summary = |query: &str| {
+      search_engine
+        .search_tokens_or(query, 10)
+        .expect("Search failed")
+        .into_iter()
+        .map(|result| (result.doc_id, result.score))
+        .collect::<Vec<_>>()
+    };
+    let with_stop_word = summary("the tokyo");
+    assert_eq!(with_stop_word.len(), 1);
+    assert_eq!(with_stop_word, summary("tokyo"));
+  }
+
+  // ─── N-gram Query Threshold Tests ──────────────────────────────────────────
+
+  /// Japanese index whose morphological tokenizer keeps whole CJK runs as one token
+  /// (no dictionary needed), so only the N-gram field can match substrings.
+  fn create_whitespace_japanese_index_manager(
+    options: crate::indexer::IndexOptions,
+  ) -> (tempfile::TempDir, IndexManager) {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let analyzer =
+      tantivy::tokenizer::TextAnalyzer::from(tantivy::tokenizer::SimpleTokenizer::default());
+    let index_manager = IndexManager::open_or_create_with_options(
+      tmp_dir.path(),
+      Language::Ja,
+      Some(analyzer),
+      options,
+    )
+    .expect("Failed to create index");
+    (tmp_dir, index_manager)
+  }
+
+  #[test]
+  fn ngram_range_controls_ngram_fallback() {
+    let (_tmp_dir, index_manager) = create_whitespace_japanese_index_manager(Default::default());
+
+    let docs = vec![
+      Document::new("substring", "src-1", "東京都庁"),
+      Document::new("one-char", "src-2", "京阪"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    // Default (1): the 2-char token "京都" is searched only morphologically
+    let search_engine =
+      SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::Ja)
+        .expect("Failed to create SearchEngine");
+    let results = search_engine.search_tokens_or("京都", 10).expect("Search failed");
+    assert!(results.is_empty());
+
+    // Range 1..=2: all grams of "京都" must appear in the N-gram field
+    let search_engine = search_engine.with_ngram_range(1, 2);
+    let results = search_engine.search_tokens_or("京都", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "substring");
+  }
+
+  #[test]
+  fn bigram_range_matches_two_char_substring_only() {
+    let docs = vec![
+      Document::new("substring", "src-1", "東京都庁"),
+      Document::new("scattered", "src-2", "京阪と都"),
+    ];
+    let search = |min_gram: usize, max_gram: usize| {
+      let options = crate::indexer::IndexOptions {
+        min_gram,
+        max_gram,
+        ..Default::default()
+      };
+      let (_tmp_dir, index_manager) = create_whitespace_japanese_index_manager(options);
+      add_test_documents(&index_manager, &docs);
+
+      let search_engine =
+        SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::Ja)
+          .expect("Failed to create SearchEngine")
+          .with_ngram_range(min_gram, max_gram);
+      let results = search_engine.search_tokens_or("京都", 10).expect("Search failed");
+      results.into_iter().map(|r| r.doc_id).collect::<Vec<_>>()
+    };
+
+    // 1..=1: "京都" is not routed to the N-gram field
+    assert!(search(1, 1).is_empty());
+
+    // 2..=2: the 2-char token must appear contiguously
+    assert_eq!(search(2, 2), vec!["substring"]);
+
+    // 1..=2: 1-grams and the 2-gram are all required, so scattered chars still do not match
+    assert_eq!(search(1, 2), vec!["substring"]);
+  }
+
+  // ─── Korean Search Tests ───────────────────────────────────────────────────
+
+  /// Helper to create Korean index and SearchEngine with `min_gram..=max_gram` N-grams
+  fn create_korean_search_engine(
+    docs: &[Document],
+    min_gram: usize,
+    max_gram: usize,
+  ) -> (tempfile::TempDir, SearchEngine) {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let options = crate::indexer::IndexOptions {
+      min_gram,
+      max_gram,
+      ..crate::indexer::IndexOptions::default()
+    };
+    let index_manager =
+      IndexManager::open_or_create_with_options(tmp_dir.path(), Language::Ko, None, options)
+        .expect("Failed to create index");
+    add_test_documents(&index_manager, docs);
+
+    let search_engine =
+      SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::Ko)
+        .expect("Failed to create SearchEngine")
+        .with_ngram_range(min_gram, max_gram);
+    (tmp_dir, search_engine)
+  }
+
+  #[test]
+  fn search_korean_finds_matching_eojeol() {
+    let docs = vec![
+      Document::new("doc-1", "src-1", "서울 타워는 높습니다"),
+      Document::new("doc-2", "src-1", "부산 바다는 아름답습니다"),
+    ];
+    let (_tmp_dir, search_engine) = create_korean_search_engine(&docs, 1, 1);
+    assert_eq!(search_engine.language(), Language::Ko);
+
+    let results = search_engine.search("서울", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+
+    // Latin words in Korean text are lowercased
+    let docs = vec![Document::new("doc-1", "src-1", "Rust 프로그래밍 언어")];
+    let (_tmp_dir, search_engine) = create_korean_search_engine(&docs, 1, 1);
+    assert_eq!(
+      search_engine.search("RUST", 10).expect("Search failed").len(),
+      1
+    );
+  }
+
+  #[test]
+  fn search_korean_ngram_matches_eojeol_with_particles() {
+    let docs = vec![
+      Document::new("with-particle", "src-1", "서울에서 살고 있습니다"),
+      Document::new("other", "src-1", "부산에서 일합니다"),
+    ];
+
+    // 1..=1: "서울" only matches whole eojeol, and "서울에서" is a different term
+    let (_tmp_dir, search_engine) = create_korean_search_engine(&docs, 1, 1);
+    assert!(search_engine.search_tokens_or("서울", 10).expect("Search failed").is_empty());
+
+    // 1..=2: "서울" is also searched in the N-gram field
+    let (_tmp_dir, search_engine) = create_korean_search_engine(&docs, 1, 2);
+    let results = search_engine.search_tokens_or("서울", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "with-particle");
+  }
+
+  // ─── CJK Bigram Tests ──────────────────────────────────────────────────────
+
+  #[test]
+  fn search_cjk_bigram_japanese_without_dictionary() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let options = crate::indexer::IndexOptions {
+      tokenizer_mode: crate::config::TokenizerMode::CjkBigram,
+      ..crate::indexer::IndexOptions::default()
+    };
+    // No Japanese tokenizer (dictionary) is passed
+    let index_manager =
+      IndexManager::open_or_create_with_options(tmp_dir.path(), Language::Ja, None, options)
+        .expect("Failed to create index");
+    let docs = vec![
+      Document::new("tower", "src-1", "東京タワーに登る"),
+      Document::new("temple", "src-1", "京都の寺を歩く"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine =
+      SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::Ja)
+        .expect("Failed to create SearchEngine");
+
+    // "京タ" is one of the bigrams of "東京タワー"
+    let results = search_engine.search_tokens_or("京タ", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "tower");
+
+    // Query is split into bigrams with the index's analyzer
+    let pairs = search_engine.analyze_query("京都の寺").expect("Analyze failed");
+    let indexed: Vec<_> = pairs.into_iter().map(|(_, indexed)| indexed).collect();
+    assert_eq!(indexed, ["京都", "都の", "の寺"]);
+
+    // Phrase search follows bigram positions
+    let results = search_engine.search_phrase("京都の寺", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "temple");
+
+    // Reopening with the morphological mode is rejected
+    drop(search_engine);
+    drop(index_manager);
+    let err = IndexManager::open_or_create(tmp_dir.path(), Language::Ja, None).unwrap_err();
+    assert!(matches!(
+      err,
+      crate::errors::IndexerError::LanguageSchemaMismatch { .. }
+    ));
+  }
+
+  // ─── Field Boost Tests ─────────────────────────────────────────────────────
 
   #[test]
-  fn search_engine_language() {
+  fn search_with_boosts_downboosted_ngram_ranks_below_full_token() {
+    let options = crate::indexer::IndexOptions {
+      min_gram: 1,
+      max_gram: 2,
+      ..Default::default()
+    };
+    let (_tmp_dir, index_manager) = create_whitespace_japanese_index_manager(options);
+
+    let docs = vec![
+      // "京都" is a whole morpheme (also matches in the N-gram field once)
+      Document::new("full-token", "src-1", "京都 の 寺 を 歩 く 旅"),
+      // "京都" appears only inside a longer morpheme, many times (N-gram only)
+      Document::new("ngram-only", "src-2", "京都京都京都"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine =
+      SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::Ja)
+        .expect("Failed to create SearchEngine")
+        .with_ngram_range(1, 2);
+    let ids =
+      |results: Vec<SearchResult>| results.into_iter().map(|r| r.doc_id).collect::<Vec<_>>();
+
+    // Up-boosting the N-gram field lets the high N-gram term frequency win
+    let boosts = HashMap::from([("text_ngram".to_string(), 100.0)]);
+    let results = search_engine.search_with_boosts("京都", &boosts, 10).expect("Search failed");
+    assert_eq!(ids(results), vec!["ngram-only", "full-token"]);
+
+    // Down-boosting the N-gram field puts the full morpheme match first
+    let boosts = HashMap::from([("text_ngram".to_string(), 0.1)]);
+    let results = search_engine.search_with_boosts("京都", &boosts, 10).expect("Search failed");
+    assert_eq!(ids(results), vec!["full-token", "ngram-only"]);
+  }
+
+  #[test]
+  fn search_with_empty_boosts_behaves_like_search_tokens_or() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
+    add_test_documents(&index_manager, &tagged_documents());
     let search_engine = create_search_engine(&index_manager);
-    assert_eq!(search_engine.language(), Language::En);
+
+    let plain = search_engine.search_tokens_or("kyoto tourists", 10).expect("Search failed");
+    let boosted = search_engine
+      .search_with_boosts("kyoto tourists", &HashMap::new(), 10)
+      .expect("Search failed");
+
+    let plain: Vec<_> = plain.iter().map(|r| (&r.doc_id, r.score)).collect();
+    let boosted: Vec<_> = boosted.iter().map(|r| (&r.doc_id, r.score)).collect();
+    assert_eq!(plain, boosted);
   }
 
   #[test]
-  fn search_returns_empty_for_empty_index() {
+  fn search_with_boosts_rejects_unknown_field_and_invalid_boost() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
     let search_engine = create_search_engine(&index_manager);
-    let results = search_engine.search("tokyo", 10).expect("Search failed");
+
+    let boosts = HashMap::from([("title".to_string(), 2.0)]);
+    let err = search_engine.search_with_boosts("kyoto", &boosts, 10).unwrap_err();
+    assert!(matches!(err, SearcherError::InvalidQuery { .. }));
+
+    let boosts = HashMap::from([("text".to_string(), f32::NAN)]);
+    let err = search_engine.search_with_boosts("kyoto", &boosts, 10).unwrap_err();
+    assert!(matches!(err, SearcherError::InvalidQuery { .. }));
+  }
+
+  // ─── Reading Field Tests ───────────────────────────────────────────────────
+
+  /// Japanese index built with a minimal in-memory IPADIC-like dictionary
+  fn index_reading_documents(japanese_reading_field: bool) -> (tempfile::TempDir, IndexManager) {
+    use crate::tokenizer::VibratoTokenizer;
+    use std::sync::Arc;
+    use tantivy::tokenizer::TextAnalyzer;
+    use vibrato_rkyv::Dictionary;
+
+    let lexicon_csv = "東京,0,0,1,名詞,固有名詞,地域,一般,*,*,東京,トウキョウ,トーキョー
+京都,0,0,1,名詞,固有名詞,地域,一般,*,*,京都,キョウト,キョート
+へ,0,0,1,助詞,格助詞,一般,*,*,*,へ,ヘ,エ
+行く,0,0,1,動詞,自立,*,*,五段・カ行促音便,基本形,行く,イク,イク
+とうきょう,0,0,1,名詞,一般,*,*,*,*,とうきょう,トウキョウ,トーキョー
+トウキョウ,0,0,1,名詞,一般,*,*,*,*,トウキョウ,トウキョウ,トーキョー";
+    let inner = crate::dictionary::in_memory_dictionary(lexicon_csv);
+    let tokenizer =
+      VibratoTokenizer::from_shared_dictionary(Arc::new(Dictionary::from_inner(inner)));
+
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let options = crate::indexer::IndexOptions {
+      japanese_reading_field,
+      ..Default::default()
+    };
+    let index_manager = IndexManager::open_or_create_with_reading(
+      tmp_dir.path(),
+      Language::Ja,
+      Some(TextAnalyzer::from(tokenizer.clone())),
+      Some(TextAnalyzer::from(tokenizer.with_reading_output(true))),
+      options,
+    )
+    .expect("Failed to create index");
+
+    let docs = vec![
+      Document::new("tokyo", "src-1", "東京へ行く"),
+      Document::new("kyoto", "src-2", "京都へ行く"),
+    ];
+    add_test_documents(&index_manager, &docs);
+    (tmp_dir, index_manager)
+  }
+
+  #[test]
+  fn reading_field_matches_kana_query() {
+    let (_tmp_dir, index_manager) = index_reading_documents(true);
+    assert!(index_manager.fields().text_reading.is_some());
+
+    let search_engine =
+      SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::Ja)
+        .expect("Failed to create SearchEngine");
+    let results = search_engine.search_tokens_or("とうきょう", 10).expect("Search failed");
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "tokyo");
+  }
+
+  #[test]
+  fn without_reading_field_kana_query_does_not_match_kanji() {
+    let (_tmp_dir, index_manager) = index_reading_documents(false);
+    assert!(index_manager.fields().text_reading.is_none());
+
+    let search_engine =
+      SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::Ja)
+        .expect("Failed to create SearchEngine");
+    let results = search_engine.search_tokens_or("とうきょう", 10).expect("Search failed");
+
     assert!(results.is_empty());
   }
 
   #[test]
-  fn search_finds_matching_document() {
+  fn reading_expansion_matches_kana_spelling_of_kanji_query() {
+    let (_tmp_dir, index_manager) = index_reading_documents(true);
+    add_test_documents(
+      &index_manager,
+      &[Document::new("kana", "src-3", "トウキョウ")],
+    );
+
+    let search_engine =
+      SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::Ja)
+        .expect("Failed to create SearchEngine");
+    let results = search_engine.search_with_reading_expansion("東京", 10).expect("Search failed");
+
+    let mut doc_ids: Vec<&str> = results.iter().map(|result| result.doc_id.as_str()).collect();
+    doc_ids.sort_unstable();
+    assert_eq!(doc_ids, ["kana", "tokyo"]);
+  }
+
+  #[test]
+  fn reading_expansion_without_reading_analyzer_is_plain_or_search() {
+    let (_tmp_dir, index_manager) = index_reading_documents(false);
+    add_test_documents(
+      &index_manager,
+      &[Document::new("kana", "src-3", "トウキョウ")],
+    );
+
+    let search_engine =
+      SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::Ja)
+        .expect("Failed to create SearchEngine");
+    let results = search_engine.search_with_reading_expansion("東京", 10).expect("Search failed");
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "tokyo");
+  }
+
+  // ─── Tag Filter Tests ──────────────────────────────────────────────────────
+
+  fn tagged_documents() -> Vec<Document> {
+    vec![
+      Document::new("temple", "src-1", "Kyoto temples attract tourists")
+        .with_tags(["category:tourism", "region:kansai"]),
+      Document::new("station", "src-2", "Kyoto station is busy")
+        .with_tags(["category:transport", "region:kansai"]),
+      Document::new("tower", "src-3", "Tokyo tower attracts tourists")
+        .with_tags(["category:tourism", "region:kanto"]),
+    ]
+  }
+
+  #[test]
+  fn search_with_tags_narrows_results() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    add_test_documents(&index_manager, &tagged_documents());
+
+    let search_engine = create_search_engine(&index_manager);
+
+    let results =
+      search_engine.search_with_tags("kyoto", &["category:tourism"], 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "temple");
+
+    // Every tag is required
+    let results = search_engine
+      .search_with_tags("tourists", &["category:tourism", "region:kanto"], 10)
+      .expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "tower");
+
+    // Unknown tag -> no results
+    let results =
+      search_engine.search_with_tags("kyoto", &["category:food"], 10).expect("Search failed");
+    assert!(results.is_empty());
+  }
+
+  #[test]
+  fn search_with_facets_counts_tags_of_results() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
+    add_test_documents(&index_manager, &tagged_documents());
+    let search_engine = create_search_engine(&index_manager);
 
+    // All three documents match "kyoto" or "tourists"
+    let (results, counts) =
+      search_engine.search_with_facets("kyoto tourists", TAGS_KEY, 10).expect("Search failed");
+    assert_eq!(results.len(), 3);
+
+    let categories: HashMap<&str, usize> = counts
+      .iter()
+      .filter(|(tag, _)| tag.starts_with("category:"))
+      .map(|(tag, &count)| (tag.as_str(), count))
+      .collect();
+    assert_eq!(
+      categories,
+      HashMap::from([("category:tourism", 2), ("category:transport", 1)])
+    );
+    assert_eq!(counts["region:kansai"], 2);
+    assert_eq!(counts["region:kanto"], 1);
+
+    // Only the returned results are counted
+    let (results, counts) =
+      search_engine.search_with_facets("station", TAGS_KEY, 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+      counts,
+      HashMap::from([
+        ("category:transport".to_string(), 1),
+        ("region:kansai".to_string(), 1)
+      ])
+    );
+  }
+
+  #[test]
+  fn search_with_facets_counts_string_values() {
     let docs = vec![
-      Document::new("doc-1", "src-1", "Tokyo is the capital of Japan"),
-      Document::new("doc-2", "src-1", "Osaka is a major city"),
+      Document::new("doc-1", "src-1", "Kyoto temple").with_metadata("city", json!("kyoto")),
+      Document::new("doc-2", "src-1", "Kyoto station").with_metadata("city", json!("kyoto")),
+      Document::new("doc-3", "src-1", "Osaka temple").with_metadata("city", json!(3)),
     ];
+    let (_tmp_dir, index_manager) = create_english_index_manager();
     add_test_documents(&index_manager, &docs);
+    let search_engine = create_search_engine(&index_manager);
+
+    let (results, counts) =
+      search_engine.search_with_facets("temple station", "city", 10).expect("Search failed");
+    assert_eq!(results.len(), 3);
+    // Non-string values are ignored
+    assert_eq!(counts, HashMap::from([("kyoto".to_string(), 2)]));
+  }
+
+  #[test]
+  fn search_with_tags_empty_tags_behaves_like_plain_search() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    add_test_documents(&index_manager, &tagged_documents());
 
-    // Create SearchEngine after adding documents
     let search_engine = create_search_engine(&index_manager);
-    let results = search_engine.search("tokyo", 10).expect("Search failed");
-    assert_eq!(results.len(), 1);
-    assert_eq!(results[0].doc_id, "doc-1");
-    assert!(results[0].score > 0.0);
+
+    let doc_ids = |results: Vec<SearchResult>| -> Vec<String> {
+      results.into_iter().map(|r| r.doc_id).collect()
+    };
+    let tagged = search_engine.search_with_tags("kyoto", &[], 10).expect("Search failed");
+    let plain = search_engine.search_tokens_or("kyoto", 10).expect("Search failed");
+    assert_eq!(tagged.len(), 2);
+    assert_eq!(doc_ids(tagged), doc_ids(plain));
   }
 
   #[test]
-  fn search_is_case_insensitive() {
+  fn filter_by_tag_returns_documents_with_tag() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
+    add_test_documents(&index_manager, &tagged_documents());
+    let search_engine = create_search_engine(&index_manager);
 
-    let docs = vec![Document::new(
-      "doc-1",
-      "src-1",
-      "Tokyo is the capital of Japan",
-    )];
-    add_test_documents(&index_manager, &docs);
+    let mut doc_ids: Vec<String> = search_engine
+      .filter_by_tag("category:tourism", 10)
+      .expect("Filter failed")
+      .into_iter()
+      .map(|r| r.doc_id)
+      .collect();
+    doc_ids.sort();
+    assert_eq!(doc_ids, vec!["temple", "tower"]);
+
+    let results = search_engine.filter_by_tag("region:kanto", 10).expect("Filter failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "tower");
+    assert_eq!(results[0].tags(), vec!["category:tourism", "region:kanto"]);
+
+    // Exact match only
+    assert!(search_engine.filter_by_tag("category", 10).expect("Filter failed").is_empty());
+    assert_eq!(
+      search_engine.filter_by_tag("region:kansai", 1).expect("Filter failed").len(),
+      1
+    );
+  }
 
+  #[test]
+  fn tags_outside_metadata_are_filtered_and_restored() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let options = crate::indexer::IndexOptions {
+      tags_in_metadata: false,
+      ..crate::indexer::IndexOptions::default()
+    };
+    let index_manager =
+      IndexManager::open_or_create_with_options(tmp_dir.path(), Language::En, None, options)
+        .expect("Failed to create index");
+    add_test_documents(&index_manager, &tagged_documents());
     let search_engine = create_search_engine(&index_manager);
 
-    // Search in lowercase
-    let results_lower = search_engine.search("tokyo", 10).expect("Search failed");
-    // Search in uppercase
-    let results_upper = search_engine.search("TOKYO", 10).expect("Search failed");
+    let results =
+      search_engine.search_with_tags("kyoto", &["category:tourism"], 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "temple");
+    assert_eq!(results[0].tags(), vec!["category:tourism", "region:kansai"]);
 
-    // Both return the same document (LowerCaser is working)
-    assert_eq!(results_lower.len(), 1);
-    assert_eq!(results_upper.len(), 1);
+    let results = search_engine.filter_by_tag("category:transport", 10).expect("Filter failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "station");
+
+    let station = index_manager
+      .iter_documents()
+      .map(|doc| doc.expect("Failed to read document"))
+      .find(|doc| doc.id == "station")
+      .expect("Document not found");
+    assert_eq!(station.tags(), vec!["category:transport", "region:kansai"]);
   }
 
-  // ─── BM25 Scoring Tests ─────────────────────────────────────────────────
+  // ─── Hit Count Tests ───────────────────────────────────────────────────────
 
   #[test]
-  fn search_bm25_rare_term_scores_higher() {
+  fn search_with_count_returns_total_beyond_limit() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
+    let docs: Vec<_> = (0..5)
+      .map(|i| Document::new(format!("doc-{i}"), "src-1", format!("tokyo guide part {i}")))
+      .chain([Document::new("other", "src-1", "osaka guide")])
+      .collect();
+    add_test_documents(&index_manager, &docs);
+    let search_engine = create_search_engine(&index_manager);
 
-    // "rust" appears only in doc-1, "programming" appears in both
+    let (results, total) = search_engine.search_with_count("tokyo", 2).expect("Search failed");
+    assert_eq!(results.len(), 2);
+    assert_eq!(total, 5);
+    assert!(total > results.len());
+
+    // No hits
+    let (results, total) = search_engine.search_with_count("kyoto", 2).expect("Search failed");
+    assert!(results.is_empty());
+    assert_eq!(total, 0);
+  }
+
+  // ─── Score Threshold Tests ─────────────────────────────────────────────────
+
+  #[test]
+  fn search_filtered_drops_results_below_min_score() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
     let docs = vec![
-      Document::new("doc-1", "src-1", "Rust programming language"),
-      Document::new("doc-2", "src-1", "Python programming language"),
+      Document::new("both", "src-1", "rust search engine"),
+      Document::new("rust", "src-1", "rust language"),
+      Document::new("search", "src-1", "search box"),
+      Document::new("none", "src-1", "python"),
     ];
     add_test_documents(&index_manager, &docs);
+    let search_engine = create_search_engine(&index_manager);
+
+    let all = search_engine.search_filtered("rust search", 10, None).expect("Search failed");
+    assert_eq!(all.len(), 3);
+    assert_eq!(all[0].doc_id, "both");
+
+    // Threshold between the best score and the rest keeps only the best hit
+    let threshold = (all[0].score + all[1].score) / 2.0;
+    let filtered =
+      search_engine.search_filtered("rust search", 10, Some(threshold)).expect("Search failed");
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(filtered[0].doc_id, "both");
+
+    // Threshold equal to the lowest score keeps everything, in the same order
+    let lowest = all.last().unwrap().score;
+    let kept =
+      search_engine.search_filtered("rust search", 10, Some(lowest)).expect("Search failed");
+    let ids =
+      |results: &[SearchResult]| results.iter().map(|r| r.doc_id.clone()).collect::<Vec<_>>();
+    assert_eq!(ids(&kept), ids(&all));
+
+    let err = search_engine.search_filtered("rust", 10, Some(f32::NAN)).unwrap_err();
+    assert!(matches!(err, SearcherError::InvalidQuery { .. }));
+  }
+
+  // ─── Metadata Range Tests ──────────────────────────────────────────────────
 
+  #[test]
+  fn search_with_metadata_range_uses_inclusive_bounds() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    let docs = vec![
+      Document::new("v1", "src-1", "tokyo guide").with_metadata("version", json!(1)),
+      Document::new("v2", "src-1", "tokyo guide").with_metadata("version", json!(2)),
+      Document::new("v2.5", "src-1", "tokyo guide").with_metadata("version", json!(2.5)),
+      Document::new("v3", "src-1", "tokyo guide").with_metadata("version", json!(3)),
+      Document::new("neg", "src-1", "tokyo guide").with_metadata("version", json!(-4)),
+      // Above i64::MAX -> indexed as u64
+      Document::new("huge", "src-1", "tokyo guide").with_metadata("version", json!(u64::MAX)),
+      Document::new("nested", "src-1", "tokyo guide")
+        .with_metadata("release", json!({ "version": 2 })),
+      Document::new("text", "src-1", "tokyo guide").with_metadata("version", json!("2")),
+      Document::new("missing", "src-1", "tokyo guide"),
+      Document::new("other", "src-1", "osaka guide").with_metadata("version", json!(2)),
+    ];
+    add_test_documents(&index_manager, &docs);
     let search_engine = create_search_engine(&index_manager);
-    let results = search_engine.search("rust", 10).expect("Search failed");
+
+    let search = |field: &str, min: Option<f64>, max: Option<f64>| {
+      let results = search_engine
+        .search_with_metadata_range("tokyo", field, min, max, 20)
+        .expect("Search failed");
+      let mut ids: Vec<_> = results.into_iter().map(|r| r.doc_id).collect();
+      ids.sort();
+      ids
+    };
+
+    // Both bounds are inclusive, across i64 and f64 values
+    assert_eq!(
+      search("version", Some(2.0), Some(3.0)),
+      ["v2", "v2.5", "v3"]
+    );
+    assert_eq!(search("version", Some(2.5), Some(2.5)), ["v2.5"]);
+
+    // Open ranges include u64 and negative values; missing / text values are excluded
+    assert_eq!(search("version", Some(2.1), None), ["huge", "v2.5", "v3"]);
+    assert_eq!(search("version", None, Some(1.0)), ["neg", "v1"]);
+    assert_eq!(
+      search("version", None, None),
+      ["huge", "neg", "v1", "v2", "v2.5", "v3"]
+    );
+
+    // Nested path
+    assert_eq!(search("release.version", Some(2.0), None), ["nested"]);
+
+    let err = search_engine
+      .search_with_metadata_range("tokyo", "version", Some(3.0), Some(2.0), 10)
+      .unwrap_err();
+    assert!(matches!(err, SearcherError::InvalidQuery { .. }));
+  }
+
+  // ─── Matched Terms Tests ───────────────────────────────────────────────────
+
+  #[test]
+  fn matched_terms_reflect_query_tokens_in_japanese_document() {
+    let (_tmp_dir, index_manager) = create_whitespace_japanese_index_manager(Default::default());
+
+    let docs = vec![
+      Document::new("both", "src-1", "京都 の 寺 を 歩く"),
+      Document::new("city", "src-1", "京都 タワー"),
+      Document::new("other", "src-1", "大阪 城"),
+    ];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine =
+      SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::Ja)
+        .expect("Failed to create SearchEngine");
+
+    let results = search_engine.search_tokens_or("寺 京都 奈良", 10).expect("Search failed");
+    let matched =
+      |doc_id: &str| results.iter().find(|r| r.doc_id == doc_id).map(|r| r.matched_terms.clone());
+    // Query order, only tokens present in the document
+    assert_eq!(
+      matched("both"),
+      Some(vec!["寺".to_string(), "京都".to_string()])
+    );
+    assert_eq!(matched("city"), Some(vec!["京都".to_string()]));
+    assert_eq!(matched("other"), None);
+
+    let results = search_engine.search_tokens_and("京都 寺", 10).expect("Search failed");
     assert_eq!(results.len(), 1);
-    assert_eq!(results[0].doc_id, "doc-1");
+    assert_eq!(results[0].matched_terms, ["京都", "寺"]);
+
+    // QueryParser based search does not set matched terms
+    let results = search_engine.search("京都", 10).expect("Search failed");
+    assert!(results.iter().all(|r| r.matched_terms.is_empty()));
   }
 
+  // ─── AND Search Tests ──────────────────────────────────────────────────────
+
   #[test]
-  fn search_returns_results_sorted_by_score() {
+  fn search_tokens_and_excludes_documents_missing_a_token() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
 
     let docs = vec![
-      Document::new("doc-1", "src-1", "programming programming programming"),
-      Document::new("doc-2", "src-1", "programming"),
+      Document::new("both", "src-1", "A tower in tokyo"),
+      Document::new("tokyo-only", "src-2", "Tokyo station is busy"),
+      Document::new("tower-only", "src-3", "The Eiffel tower"),
     ];
     add_test_documents(&index_manager, &docs);
 
     let search_engine = create_search_engine(&index_manager);
-    let results = search_engine.search("programming", 10).expect("Search failed");
-    assert_eq!(results.len(), 2);
+    let and_results = search_engine.search_tokens_and("Tokyo Tower", 10).expect("Search failed");
+    let or_results = search_engine.search_tokens_or("Tokyo Tower", 10).expect("Search failed");
 
-    // Confirm sorted by score (higher score first)
-    for i in 0..results.len().saturating_sub(1) {
-      assert!(results[i].score >= results[i + 1].score);
-    }
+    assert_eq!(and_results.len(), 1);
+    assert_eq!(and_results[0].doc_id, "both");
+    assert!(and_results[0].score > 0.0);
+    assert_eq!(or_results.len(), 3);
   }
 
-  // ─── search_tokens_or Tests ────────────────────────────────────────────────
-
   #[test]
-  fn search_tokens_or_finds_documents() {
+  fn search_tokens_and_returns_empty_for_empty_tokens() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
 
-    let docs = vec![
+    let docs = vec![Document::new("doc-1", "src-1", "Tokyo is the capital")];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search_tokens_and("", 10).expect("Search failed");
+
+    assert!(results.is_empty());
+  }
+
+  // ─── Fuzzy Search Tests ────────────────────────────────────────────────────
+
+  fn fuzzy_documents() -> Vec<Document> {
+    vec![
       Document::new("doc-1", "src-1", "Tokyo is the capital of Japan"),
       Document::new("doc-2", "src-1", "Osaka is a major city"),
+    ]
+  }
+
+  #[test]
+  fn search_fuzzy_tolerates_one_typo() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    add_test_documents(&index_manager, &fuzzy_documents());
+    let search_engine = create_search_engine(&index_manager);
+
+    let results = search_engine.search_fuzzy("tokio", 1, 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+
+    // Without fuzziness the typo finds nothing
+    assert!(search_engine.search_tokens_or("tokio", 10).expect("Search failed").is_empty());
+  }
+
+  #[test]
+  fn search_fuzzy_distance_zero_is_exact() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    add_test_documents(&index_manager, &fuzzy_documents());
+    let search_engine = create_search_engine(&index_manager);
+
+    assert!(search_engine.search_fuzzy("tokio", 0, 10).expect("Search failed").is_empty());
+
+    let results = search_engine.search_fuzzy("Tokyo", 0, 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+  }
+
+  #[test]
+  fn search_fuzzy_clamps_max_distance() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    add_test_documents(&index_manager, &fuzzy_documents());
+    let search_engine = create_search_engine(&index_manager);
+
+    // "osxxx" is 3 edits away from "osaka": not matched even with a large distance
+    assert!(search_engine.search_fuzzy("osxxx", 10, 10).expect("Search failed").is_empty());
+    let results = search_engine.search_fuzzy("osaxx", 10, 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-2");
+  }
+
+  #[test]
+  fn search_fuzzy_returns_empty_for_empty_query() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    add_test_documents(&index_manager, &fuzzy_documents());
+    let search_engine = create_search_engine(&index_manager);
+
+    assert!(search_engine.search_fuzzy("", 1, 10).expect("Search failed").is_empty());
+  }
+
+  // ─── Prefix Search Tests ───────────────────────────────────────────────────
+
+  #[test]
+  fn search_prefix_matches_incomplete_last_token() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    add_test_documents(&index_manager, &fuzzy_documents());
+    let search_engine = create_search_engine(&index_manager);
+
+    let results = search_engine.search_prefix("Tok", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+
+    assert!(search_engine.search_prefix("tox", 10).expect("Search failed").is_empty());
+    assert!(search_engine.search_prefix("", 10).expect("Search failed").is_empty());
+  }
+
+  #[test]
+  fn search_prefix_requires_complete_tokens() {
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Tokyo tower at night"),
+      Document::new("doc-2", "src-1", "Osaka tower at night"),
+      Document::new("doc-3", "src-1", "Tokyo station"),
     ];
+    let (_tmp_dir, index_manager) = create_english_index_manager();
     add_test_documents(&index_manager, &docs);
-
     let search_engine = create_search_engine(&index_manager);
-    let results = search_engine.search_tokens_or("tokyo", 10).expect("Search failed");
+
+    let results = search_engine.search_prefix("tokyo tow", 10).expect("Search failed");
     assert_eq!(results.len(), 1);
     assert_eq!(results[0].doc_id, "doc-1");
+
+    let mut ids: Vec<String> = search_engine
+      .search_prefix("tow", 10)
+      .expect("Search failed")
+      .into_iter()
+      .map(|r| r.doc_id)
+      .collect();
+    ids.sort_unstable();
+    assert_eq!(ids, vec!["doc-1", "doc-2"]);
   }
 
   #[test]
-  fn search_tokens_or_handles_multiple_tokens() {
-    let (_tmp_dir, index_manager) = create_english_index_manager();
-
+  fn search_prefix_uses_ngram_field_for_korean() {
     let docs = vec![
-      Document::new("doc-1", "src-1", "Tokyo tower is famous"),
-      Document::new("doc-2", "src-1", "Osaka castle is famous"),
+      Document::new("doc-1", "src-1", "서울에서 살고 있습니다"),
+      Document::new("doc-2", "src-1", "부산에서 일합니다"),
     ];
-    add_test_documents(&index_manager, &docs);
+    let (_tmp_dir, search_engine) = create_korean_search_engine(&docs, 1, 2);
 
-    let search_engine = create_search_engine(&index_manager);
-    // "tokyo" OR "osaka" hits both
-    let results = search_engine.search_tokens_or("tokyo osaka", 10).expect("Search failed");
-    assert_eq!(results.len(), 2);
+    // N-grams start anywhere in a word, so "울" matches inside "서울에서"
+    let results = search_engine.search_prefix("울", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
   }
 
   #[test]
-  fn search_tokens_or_returns_empty_for_empty_tokens() {
-    let (_tmp_dir, index_manager) = create_english_index_manager();
-
-    let docs = vec![Document::new("doc-1", "src-1", "Some content")];
-    add_test_documents(&index_manager, &docs);
+  fn search_prefix_matches_long_japanese_prefix_with_default_ngram_range() {
+    // Default options: the N-gram field holds 1-character grams only
+    let (_tmp_dir, index_manager) = index_reading_documents(false);
+    let search_engine =
+      SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::Ja)
+        .expect("Failed to create SearchEngine");
+
+    // Prefixes longer than max_gram match whole words in the text field
+    let results = search_engine.search_prefix("東京", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "tokyo");
 
-    let search_engine = create_search_engine(&index_manager);
-    // Empty string -> No tokens -> Empty result
-    let results = search_engine.search_tokens_or("", 10).expect("Search failed");
+    let results = search_engine.search_prefix("東京へ京都", 10).expect("Search failed");
     assert!(results.is_empty());
+    let results = search_engine.search_prefix("京都", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "kyoto");
   }
 
   #[test]
-  fn search_tokens_or_respects_limit() {
-    let (_tmp_dir, index_manager) = create_english_index_manager();
-
-    let docs = vec![
-      Document::new("doc-1", "src-1", "programming language"),
-      Document::new("doc-2", "src-1", "programming tutorial"),
-      Document::new("doc-3", "src-1", "programming guide"),
-    ];
-    add_test_documents(&index_manager, &docs);
-
-    let search_engine = create_search_engine(&index_manager);
-    let results = search_engine.search_tokens_or("programming", 2).expect("Search failed");
-    assert_eq!(results.len(), 2);
+  fn escape_regex_escapes_meta_characters() {
+    assert_eq!(escape_regex("c++"), "c\\+\\+");
+    assert_eq!(escape_regex("東京"), "東京");
   }
 
-  // ─── Metadata Restoration Tests ──────────────────────────────────────────────────
+  // ─── Regex Search Tests ────────────────────────────────────────────────────
 
   #[test]
-  fn search_restores_metadata() {
-    let (_tmp_dir, index_manager) = create_english_index_manager();
-
+  fn search_regex_matches_terms() {
     let docs = vec![
-      Document::new("doc-1", "src-1", "Tokyo is the capital of Japan")
-        .with_metadata("author", json!("alice"))
-        .with_metadata("version", json!(1))
-        .with_tag("category:geo"),
+      Document::new("doc-1", "src-1", "Tokyo tower at night"),
+      Document::new("doc-2", "src-1", "Osaka castle"),
+      Document::new("doc-3", "src-1", "Kyoto temples"),
     ];
+    let (_tmp_dir, index_manager) = create_english_index_manager();
     add_test_documents(&index_manager, &docs);
-
     let search_engine = create_search_engine(&index_manager);
-    let results = search_engine.search("tokyo", 10).expect("Search failed");
+
+    let results = search_engine.search_regex("tok.*", 10).expect("Search failed");
     assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
 
-    let result = &results[0];
-    assert_eq!(result.metadata["author"], json!("alice"));
-    assert_eq!(result.metadata["version"], json!(1));
-    assert_eq!(result.metadata["tags"], json!(["category:geo"]));
+    let mut ids: Vec<String> = search_engine
+      .search_regex("(osaka|kyoto)", 10)
+      .expect("Search failed")
+      .into_iter()
+      .map(|r| r.doc_id)
+      .collect();
+    ids.sort_unstable();
+    assert_eq!(ids, vec!["doc-2", "doc-3"]);
   }
 
   #[test]
-  fn search_returns_empty_metadata_when_not_set() {
+  fn search_regex_without_match_returns_empty() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
+    add_test_documents(&index_manager, &fuzzy_documents());
+    let search_engine = create_search_engine(&index_manager);
 
-    let docs = vec![Document::new("doc-1", "src-1", "Tokyo is the capital")];
-    add_test_documents(&index_manager, &docs);
+    assert!(search_engine.search_regex("xyz.*", 10).expect("Search failed").is_empty());
+    // Whole-term match: "tok" alone is not a term
+    assert!(search_engine.search_regex("tok", 10).expect("Search failed").is_empty());
+  }
 
+  #[test]
+  fn search_regex_rejects_invalid_pattern() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
     let search_engine = create_search_engine(&index_manager);
-    let results = search_engine.search("tokyo", 10).expect("Search failed");
-    assert_eq!(results.len(), 1);
-    assert!(results[0].metadata.is_empty());
+
+    let err = search_engine.search_regex("tok(", 10).unwrap_err();
+    assert!(matches!(err, SearcherError::InvalidQuery { .. }));
+  }
+
+  // ─── Source Lookup Tests ───────────────────────────────────────────────────
+
+  fn source_documents() -> Vec<Document> {
+    vec![
+      Document::new("doc-1", "Manual-A", "Tokyo tower at night"),
+      Document::new("doc-2", "Manual-A", "Completely unrelated paragraph"),
+      Document::new("doc-3", "Manual-B", "Tokyo station"),
+    ]
   }
 
   #[test]
-  fn search_handles_complex_metadata_types() {
+  fn search_by_source_returns_all_chunks_of_source() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
+    add_test_documents(&index_manager, &source_documents());
+    let search_engine = create_search_engine(&index_manager);
 
-    let docs = vec![
-      Document::new("doc-1", "src-1", "Test document")
-        .with_metadata("string", json!("value"))
-        .with_metadata("number", json!(42))
-        .with_metadata("boolean", json!(true))
-        .with_metadata("null", json!(null))
-        .with_metadata("array", json!([1, 2, 3]))
-        .with_metadata("object", json!({"nested": "value"})),
-    ];
-    add_test_documents(&index_manager, &docs);
+    // Chunks are returned whether or not their text matches a query
+    let mut ids: Vec<String> = search_engine
+      .search_by_source("Manual-A", 10)
+      .expect("Search failed")
+      .into_iter()
+      .map(|r| r.doc_id)
+      .collect();
+    ids.sort_unstable();
+    assert_eq!(ids, vec!["doc-1", "doc-2"]);
+
+    // Case-sensitive by default
+    assert!(search_engine.search_by_source("manual-a", 10).expect("Search failed").is_empty());
+    assert!(search_engine.search_by_source("Manual", 10).expect("Search failed").is_empty());
+  }
 
+  #[test]
+  fn search_by_source_ignores_case_when_enabled() {
+    let tmp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let options = crate::indexer::IndexOptions {
+      case_insensitive_source_id: true,
+      ..crate::indexer::IndexOptions::default()
+    };
+    let index_manager =
+      IndexManager::open_or_create_with_options(tmp_dir.path(), Language::En, None, options)
+        .expect("Failed to create index");
+    add_test_documents(&index_manager, &source_documents());
     let search_engine = create_search_engine(&index_manager);
-    let results = search_engine.search("test", 10).expect("Search failed");
-    assert_eq!(results.len(), 1);
 
-    let metadata = &results[0].metadata;
-    assert_eq!(metadata["string"], json!("value"));
-    assert_eq!(metadata["number"], json!(42));
-    assert_eq!(metadata["boolean"], json!(true));
-    assert_eq!(metadata["null"], json!(null));
-    assert_eq!(metadata["array"], json!([1, 2, 3]));
-    assert_eq!(metadata["object"], json!({"nested": "value"}));
+    for source_id in ["Manual-A", "manual-a", "MANUAL-A"] {
+      let results = search_engine.search_by_source(source_id, 10).expect("Search failed");
+      assert_eq!(results.len(), 2, "source_id = {source_id}");
+      // The stored value keeps its original case
+      assert!(results.iter().all(|r| r.source_id == "Manual-A"));
+    }
+
+    // Still an exact match, not a prefix or token match
+    assert!(search_engine.search_by_source("manual", 10).expect("Search failed").is_empty());
   }
 
-  // ─── SearchResult Structure Tests ────────────────────────────────────────────────
+  // ─── Pagination Tests ──────────────────────────────────────────────────────
 
   #[test]
-  fn search_result_contains_all_fields() {
+  fn search_paged_returns_disjoint_consecutive_pages() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
 
-    let docs =
-      vec![Document::new("doc-123", "src-456", "Hello world").with_metadata("key", json!("value"))];
+    // Different term frequencies -> distinct scores
+    let docs: Vec<Document> = (1..=5)
+      .map(|i| Document::new(format!("doc-{i}"), "src-1", "tokyo ".repeat(i) + "city"))
+      .collect();
     add_test_documents(&index_manager, &docs);
 
     let search_engine = create_search_engine(&index_manager);
-    let results = search_engine.search("hello", 10).expect("Search failed");
-    assert_eq!(results.len(), 1);
+    let all = search_engine.search("tokyo", 10).expect("Search failed");
+    assert_eq!(all.len(), 5);
+
+    let mut paged = Vec::new();
+    for page in 0..3 {
+      let results = search_engine.search_paged("tokyo", 2, page * 2).expect("Search failed");
+      assert!(results.len() <= 2);
+      paged.extend(results);
+    }
 
-    let result = &results[0];
-    assert_eq!(result.doc_id, "doc-123");
-    assert_eq!(result.source_id, "src-456");
-    assert_eq!(result.text, "Hello world");
-    assert!(result.score > 0.0);
-    assert_eq!(result.metadata["key"], json!("value"));
+    let ids = |results: &[SearchResult]| -> Vec<String> {
+      results.iter().map(|r| r.doc_id.clone()).collect()
+    };
+    // Concatenated pages == single full result (no overlap, same order)
+    assert_eq!(ids(&paged), ids(&all));
+
+    // Offset past the end -> empty
+    assert!(search_engine.search_paged("tokyo", 2, 10).expect("Search failed").is_empty());
   }
 
-  // ─── Error Handling Tests ──────────────────────────────────────────────
+  // ─── Query Analysis Tests ──────────────────────────────────────────────────
 
   #[test]
-  fn search_invalid_query_returns_error() {
+  fn analyze_query_shows_stemmed_and_lowercased_forms() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
     let search_engine = create_search_engine(&index_manager);
 
-    // Invalid query syntax (unclosed parenthesis)
-    let result = search_engine.search("(", 10);
-    assert!(result.is_err());
+    let pairs = search_engine.analyze_query("Running TOKYO Towers").expect("Analysis failed");
 
-    let err = result.unwrap_err();
-    assert!(matches!(err, SearcherError::InvalidQuery { .. }));
+    assert_eq!(
+      pairs,
+      vec![
+        ("Running".to_string(), "run".to_string()),
+        ("TOKYO".to_string(), "tokyo".to_string()),
+        ("Towers".to_string(), "tower".to_string()),
+      ]
+    );
   }
 
-  // ─── English specific tokenization tests ────────────────────────────────────
-
   #[test]
-  fn search_stemming_works_for_english() {
+  fn analyze_query_returns_empty_for_blank_query() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
+    let search_engine = create_search_engine(&index_manager);
 
-    let docs = vec![Document::new("doc-1", "src-1", "running and jumping")];
-    add_test_documents(&index_manager, &docs);
+    assert!(search_engine.analyze_query("  ").expect("Analysis failed").is_empty());
+  }
 
+  #[test]
+  fn explain_query_returns_deduplicated_search_tokens() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
     let search_engine = create_search_engine(&index_manager);
-    // Should match "running" -> "run", "jumping" -> "jump" by Stemmer
-    // However, it depends on Tantivy Stemmer behavior,
-    // so here just checking that query does not error
-    let results = search_engine.search("run", 10);
-    assert!(results.is_ok());
+
+    let tokens = search_engine.explain_query("Tokyo tokyo  Towers").expect("Analysis failed");
+    assert_eq!(tokens, vec!["tokyo", "tower"]);
+    assert!(search_engine.explain_query("").expect("Analysis failed").is_empty());
   }
 
+  // ─── Phrase Search Tests ───────────────────────────────────────────────────
+
   #[test]
-  fn search_tokens_or_lowercases_query() {
+  fn search_phrase_matches_only_adjacent_tokens() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
 
-    let docs = vec![Document::new("doc-1", "src-1", "TOKYO CAPITAL")];
+    let docs = vec![
+      Document::new("phrase", "src-1", "The tokyo tower is tall"),
+      Document::new("reversed", "src-2", "A tower in tokyo"),
+      Document::new("some", "src-3", "Tokyo station is busy"),
+    ];
     add_test_documents(&index_manager, &docs);
 
     let search_engine = create_search_engine(&index_manager);
-    // Document found even if searching in lowercase
-    let results = search_engine.search_tokens_or("tokyo", 10).expect("Search failed");
-    assert_eq!(results.len(), 1);
-  }
+    let phrase_results = search_engine.search_phrase("Tokyo Tower", 10).expect("Search failed");
+    let or_results = search_engine.search_tokens_or("Tokyo Tower", 10).expect("Search failed");
 
-  // ─── Multiple Document Search Tests ────────────────────────────────────────────
+    assert_eq!(phrase_results.len(), 1);
+    assert_eq!(phrase_results[0].doc_id, "phrase");
+    assert_eq!(or_results.len(), 3);
+  }
 
   #[test]
-  fn search_finds_multiple_matching_documents() {
+  fn search_phrase_single_token_falls_back_to_term_query() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
 
     let docs = vec![
-      Document::new("doc-1", "src-1", "Rust programming"),
-      Document::new("doc-2", "src-1", "Python programming"),
-      Document::new("doc-3", "src-1", "Java programming"),
+      Document::new("doc-1", "src-1", "Tokyo is the capital"),
+      Document::new("doc-2", "src-2", "Osaka is a major city"),
     ];
     add_test_documents(&index_manager, &docs);
 
     let search_engine = create_search_engine(&index_manager);
-    let results = search_engine.search("programming", 10).expect("Search failed");
-    assert_eq!(results.len(), 3);
+    let results = search_engine.search_phrase("TOKYO", 10).expect("Search failed");
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+    assert!(results[0].score > 0.0);
   }
 
   #[test]
-  fn search_distinct_source_ids() {
+  fn search_phrase_returns_empty_for_empty_tokens() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
 
+    let docs = vec![Document::new("doc-1", "src-1", "Tokyo is the capital")];
+    add_test_documents(&index_manager, &docs);
+
+    let search_engine = create_search_engine(&index_manager);
+    let results = search_engine.search_phrase("   ", 10).expect("Search failed");
+
+    assert!(results.is_empty());
+  }
+
+  /// English index created with `text_record_option = "freqs-only"` (no positions)
+  fn create_freqs_only_index_manager() -> (tempfile::TempDir, IndexManager) {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let options = crate::indexer::IndexOptions {
+      text_record_option: crate::config::TextRecordOption::FreqsOnly,
+      ..crate::indexer::IndexOptions::default()
+    };
+    let index_manager =
+      IndexManager::open_or_create_with_options(tmp_dir.path(), Language::En, None, options)
+        .expect("Failed to create index");
     let docs = vec![
-      Document::new("doc-1", "source-alpha", "Alpha document"),
-      Document::new("doc-2", "source-beta", "Beta document"),
+      Document::new("phrase", "src-1", "The tokyo tower is tall"),
+      Document::new("reversed", "src-2", "A tower in tokyo"),
     ];
     add_test_documents(&index_manager, &docs);
+    (tmp_dir, index_manager)
+  }
 
+  #[test]
+  fn search_phrase_errors_without_positions() {
+    let (_tmp_dir, index_manager) = create_freqs_only_index_manager();
     let search_engine = create_search_engine(&index_manager);
-    let results = search_engine.search("document", 10).expect("Search failed");
+
+    let err = search_engine.search_phrase("Tokyo Tower", 10).unwrap_err();
+    assert!(matches!(err, SearcherError::PositionsNotIndexed { ref field } if field == "text"));
+
+    // Single tokens need no positions
+    let results = search_engine.search_phrase("tokyo", 10).expect("Search failed");
     assert_eq!(results.len(), 2);
+  }
 
-    let source_ids: std::collections::HashSet<&str> =
-      results.iter().map(|r| r.source_id.as_str()).collect();
-    assert!(source_ids.contains("source-alpha"));
-    assert!(source_ids.contains("source-beta"));
+  #[test]
+  fn smart_search_without_positions_skips_phrase_kind() {
+    let (_tmp_dir, index_manager) = create_freqs_only_index_manager();
+    let search_engine = create_search_engine(&index_manager);
+
+    let results = search_engine.smart_search("Tokyo Tower", 10).expect("Search failed");
+
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.match_kind == Some(MatchKind::AllTerms)));
   }
 
-  // ─── Edge Case Tests ─────────────────────────────────────────────────────
+  // ─── Reload Tests ────────────────────────────────────────────────────────────
 
   #[test]
-  fn search_special_characters_in_content() {
+  fn commit_counter_makes_new_commits_searchable_without_reload() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
+    let search_engine =
+      create_search_engine(&index_manager).with_commit_counter(index_manager.commit_counter());
+    assert!(search_engine.search("tokyo", 10).expect("Search failed").is_empty());
 
-    let docs = vec![Document::new("doc-1", "src-1", "Price: $100 (50% off!)")];
+    add_test_documents(
+      &index_manager,
+      &[Document::new("doc-1", "src-1", "Tokyo tower")],
+    );
+    let results = search_engine.search("tokyo", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+
+    add_test_documents(
+      &index_manager,
+      &[Document::new("doc-2", "src-1", "Tokyo station")],
+    );
+    assert_eq!(
+      search_engine.search("tokyo", 10).expect("Search failed").len(),
+      2
+    );
+  }
+
+  // ─── BM25 Parameter Tests ────────────────────────────────────────────────────
+
+  /// Short document with one "tokyo" vs long document with two
+  fn index_short_and_long_documents() -> (tempfile::TempDir, IndexManager) {
+    let (tmp_dir, index_manager) = create_english_index_manager();
+    let docs = vec![
+      Document::new("short", "src-1", "Tokyo tower"),
+      Document::new(
+        "long",
+        "src-2",
+        "Tokyo is a large city and there are many things to see in tokyo for every visitor",
+      ),
+    ];
     add_test_documents(&index_manager, &docs);
+    (tmp_dir, index_manager)
+  }
 
-    let search_engine = create_search_engine(&index_manager);
-    // Search possible even with content containing special characters
-    let results = search_engine.search("price", 10).expect("Search failed");
-    assert_eq!(results.len(), 1);
-    assert_eq!(results[0].text, "Price: $100 (50% off!)");
+  #[test]
+  fn bm25_b_changes_short_and_long_document_ranking() {
+    let (_tmp_dir, index_manager) = index_short_and_long_documents();
+    let mut search_engine = create_search_engine(&index_manager);
+    let ranking = |search_engine: &SearchEngine| -> Vec<String> {
+      let results = search_engine.search("tokyo", 10).expect("Search failed");
+      results.into_iter().map(|r| r.doc_id).collect()
+    };
+
+    // Length normalization favors the short document
+    assert_eq!(ranking(&search_engine), ["short", "long"]);
+
+    // Without length normalization, the higher term frequency wins
+    search_engine.set_bm25_params(1.2, 0.0).expect("Valid parameters");
+    assert_eq!(ranking(&search_engine), ["long", "short"]);
   }
 
   #[test]
-  fn search_whitespace_handling() {
-    let (_tmp_dir, index_manager) = create_english_index_manager();
+  fn bm25_default_params_reproduce_tantivy_scores() {
+    let (_tmp_dir, index_manager) = index_short_and_long_documents();
+    let mut search_engine = create_search_engine(&index_manager);
+    let builtin = search_engine.search("tokyo tower", 10).expect("Search failed");
+
+    search_engine.set_bm25_params(1.2, 0.75).expect("Valid parameters");
+    let custom = search_engine.search("tokyo tower", 10).expect("Search failed");
+
+    assert_eq!(builtin.len(), custom.len());
+    for (a, b) in builtin.iter().zip(&custom) {
+      assert_eq!(a.doc_id, b.doc_id);
+      assert!(
+        (a.score - b.score).abs() < 1e-4,
+        "{} vs {}",
+        a.score,
+        b.score
+      );
+    }
+  }
 
-    let docs = vec![Document::new("doc-1", "src-1", "hello world")];
-    add_test_documents(&index_manager, &docs);
+  #[test]
+  fn bm25_params_apply_to_or_smart_and_phrase_searches() {
+    let (_tmp_dir, index_manager) = index_short_and_long_documents();
+    let mut search_engine = create_search_engine(&index_manager);
+    let rankings = |search_engine: &SearchEngine| -> Vec<Vec<String>> {
+      let doc_ids =
+        |results: Vec<SearchResult>| results.into_iter().map(|r| r.doc_id).collect::<Vec<_>>();
+      vec![
+        doc_ids(search_engine.search_tokens_or("tokyo", 10).expect("Search failed")),
+        doc_ids(search_engine.smart_search("tokyo", 10).expect("Search failed")),
+        doc_ids(search_engine.search_with_tags("tokyo", &[], 10).expect("Search failed")),
+        doc_ids(search_engine.search_phrase("tokyo", 10).expect("Search failed")),
+      ]
+    };
 
-    let search_engine = create_search_engine(&index_manager);
-    // Multi-token query
-    let results = search_engine.search("hello world", 10).expect("Search failed");
-    assert!(!results.is_empty());
+    // Full length normalization favors the short document
+    search_engine.set_bm25_params(1.2, 1.0).expect("Valid parameters");
+    assert!(rankings(&search_engine).iter().all(|ranking| ranking == &["short", "long"]));
+
+    // Without length normalization, the higher term frequency wins
+    search_engine.set_bm25_params(1.2, 0.0).expect("Valid parameters");
+    assert!(rankings(&search_engine).iter().all(|ranking| ranking == &["long", "short"]));
   }
 
   #[test]
-  fn search_long_text_content() {
+  fn bm25_params_keep_exact_field_boost() {
+    let (_tmp_dir, index_manager) = index_apple_documents(true);
+    let mut search_engine = create_search_engine(&index_manager);
+    search_engine.set_bm25_params(1.2, 0.75).expect("Valid parameters");
+
+    // The shorter "stemmed" document wins on BM25 alone; the exact clause still counts
+    let results = search_engine.search_tokens_or("apples", 10).expect("Search failed");
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].doc_id, "exact");
+    assert!(results[0].score - results[1].score > search_engine.exact_boost - 1.0);
+  }
+
+  #[test]
+  fn set_bm25_params_rejects_out_of_range_values() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
+    let mut search_engine = create_search_engine(&index_manager);
 
-    let long_text = "programming ".repeat(100);
-    let docs = vec![Document::new("doc-1", "src-1", &long_text)];
-    add_test_documents(&index_manager, &docs);
+    for (k1, b) in [(-1.0, 0.75), (1.2, 1.1), (f32::INFINITY, 0.5)] {
+      let err = search_engine.set_bm25_params(k1, b).unwrap_err();
+      assert!(matches!(err, SearcherError::InvalidBm25Params { .. }));
+    }
+  }
+
+  // ─── Token Offset Tests ──────────────────────────────────────────────────────
+
+  #[test]
+  fn token_offsets_map_to_slices_of_japanese_text() {
+    let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let options = crate::indexer::IndexOptions {
+      tokenizer_mode: crate::config::TokenizerMode::CjkBigram,
+      with_offsets: true,
+      ..crate::indexer::IndexOptions::default()
+    };
+    let index_manager =
+      IndexManager::open_or_create_with_options(tmp_dir.path(), Language::Ja, None, options)
+        .expect("Failed to create index");
+    let text = "東京タワーは有名です。Tokyo Tower";
+    add_test_documents(&index_manager, &[Document::new("doc-1", "src-1", text)]);
+
+    let search_engine =
+      SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::Ja)
+        .expect("Failed to create SearchEngine");
+    let offsets = search_engine.token_offsets("doc-1").expect("Failed to get offsets");
+
+    assert!(!offsets.is_empty());
+    let slices: Vec<&str> = offsets
+      .iter()
+      .map(|&(from, to)| text.get(from..to).expect("offsets must be valid char boundaries"))
+      .collect();
+    assert_eq!(slices[0], "東京");
+    assert!(slices.contains(&"Tokyo"));
+  }
 
+  #[test]
+  fn token_offsets_require_with_offsets_index() {
+    let (_tmp_dir, index_manager) = create_english_index_manager();
+    add_test_documents(&index_manager, &[Document::new("doc-1", "src-1", "Tokyo")]);
     let search_engine = create_search_engine(&index_manager);
-    let results = search_engine.search("programming", 10).expect("Search failed");
-    assert_eq!(results.len(), 1);
+
+    let err = search_engine.token_offsets("doc-1").unwrap_err();
+    assert!(
+      matches!(err, SearcherError::InvalidIndex { ref field, .. } if field == "text_offsets")
+    );
   }
 
   #[test]
-  fn search_unicode_content() {
+  fn search_tokens_or_does_not_set_match_kind() {
     let (_tmp_dir, index_manager) = create_english_index_manager();
 
-    // English index can save text containing Unicode characters
-    let docs = vec![Document::new("doc-1", "src-1", "Hello 世界 мир")];
+    let docs = vec![Document::new("doc-1", "src-1", "Tokyo is the capital")];
     add_test_documents(&index_manager, &docs);
 
     let search_engine = create_search_engine(&index_manager);
-    let results = search_engine.search("hello", 10).expect("Search failed");
+    let results = search_engine.search_tokens_or("tokyo", 10).expect("Search failed");
+
     assert_eq!(results.len(), 1);
-    assert!(results[0].text.contains("世界"));
+    assert!(results[0].match_kind.is_none());
   }
 }
@@ -1,7 +1,10 @@
 //! searcher module
 
+mod bm25;
 pub mod bm25_searcher;
+pub mod overlap_penalty;
 mod tokenization;
 
 /// Re-exports
 pub use bm25_searcher::SearchEngine;
+pub use overlap_penalty::apply_overlap_penalty;
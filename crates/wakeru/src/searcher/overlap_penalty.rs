@@ -0,0 +1,159 @@
+//! Overlap Penalty Module
+//!
+//! Heuristic post-retrieval re-ranking that demotes redundant chunks.
+//!
+//! In RAG pipelines, overlapping chunks from the same source share text and
+//! therefore match the same query terms, crowding the top-k with near-duplicates.
+//! This module multiplies the score of such a chunk by a configurable factor.
+
+use std::collections::HashSet;
+
+use crate::config::OverlapPenaltyConfig;
+use crate::models::SearchResult;
+
+/// Builds the set of character bigrams of `text` (whitespace ignored)
+///
+/// Character bigrams work for both Japanese (no spaces) and English.
+fn char_bigrams(text: &str) -> HashSet<(char, char)> {
+  let chars: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+  chars.windows(2).map(|w| (w[0], w[1])).collect()
+}
+
+/// Overlap ratio between two bigram sets (0.0 - 1.0)
+///
+/// Ratio of shared bigrams to the smaller set, so a chunk fully contained
+/// in another chunk is treated as fully overlapping.
+fn overlap_ratio(a: &HashSet<(char, char)>, b: &HashSet<(char, char)>) -> f32 {
+  let smaller = a.len().min(b.len());
+  if smaller == 0 {
+    return 0.0;
+  }
+  let shared = a.intersection(b).count();
+  shared as f32 / smaller as f32
+}
+
+/// Applies the overlap penalty to results sorted by descending score.
+///
+/// # Behavior
+/// 1. Walks results in rank order
+/// 2. If a result shares at least `min_overlap` of its text with a higher-ranked result
+///    from the same `source_id`, its score is multiplied by `factor`
+/// 3. Re-sorts by score (stable, so ties keep the original order)
+///
+/// This is a heuristic: overlap is measured with character bigrams, not exact spans.
+pub fn apply_overlap_penalty(
+  mut results: Vec<SearchResult>,
+  config: &OverlapPenaltyConfig,
+) -> Vec<SearchResult> {
+  let bigrams: Vec<HashSet<(char, char)>> = results.iter().map(|r| char_bigrams(&r.text)).collect();
+
+  for i in 1..results.len() {
+    let redundant = (0..i).any(|j| {
+      results[j].source_id == results[i].source_id
+        && overlap_ratio(&bigrams[i], &bigrams[j]) >= config.min_overlap
+    });
+
+    if redundant {
+      results[i].score *= config.factor;
+    }
+  }
+
+  results.sort_by(|a, b| b.score.total_cmp(&a.score));
+  results
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::models::Metadata;
+
+  fn result(doc_id: &str, source_id: &str, score: f32, text: &str) -> SearchResult {
+    SearchResult {
+      doc_id: doc_id.to_string(),
+      source_id: source_id.to_string(),
+      score,
+      text: text.to_string(),
+      metadata: Metadata::default(),
+      match_kind: None,
+      matched_terms: Vec::new(),
+    }
+  }
+
+  fn penalty(factor: f32) -> OverlapPenaltyConfig {
+    OverlapPenaltyConfig {
+      factor,
+      min_overlap: 0.5,
+    }
+  }
+
+  fn doc_ids(results: &[SearchResult]) -> Vec<&str> {
+    results.iter().map(|r| r.doc_id.as_str()).collect()
+  }
+
+  #[test]
+  fn overlapping_same_source_chunk_is_demoted_below_distinct_source() {
+    let results = vec![
+      result(
+        "a-1",
+        "src-a",
+        3.0,
+        "Tokyo tower is a famous landmark in Tokyo",
+      ),
+      result(
+        "a-2",
+        "src-a",
+        2.8,
+        "a famous landmark in Tokyo with a great view",
+      ),
+      result("b-1", "src-b", 2.0, "Tokyo has many parks and museums"),
+    ];
+
+    let reranked = apply_overlap_penalty(results, &penalty(0.5));
+
+    assert_eq!(doc_ids(&reranked), vec!["a-1", "b-1", "a-2"]);
+    assert!((reranked[2].score - 1.4).abs() < 1e-6);
+  }
+
+  #[test]
+  fn overlapping_chunks_from_different_sources_are_not_penalized() {
+    let results = vec![
+      result("a-1", "src-a", 3.0, "Tokyo tower is a famous landmark"),
+      result("b-1", "src-b", 2.8, "Tokyo tower is a famous landmark"),
+    ];
+
+    let reranked = apply_overlap_penalty(results, &penalty(0.5));
+
+    assert_eq!(doc_ids(&reranked), vec!["a-1", "b-1"]);
+    assert!((reranked[1].score - 2.8).abs() < 1e-6);
+  }
+
+  #[test]
+  fn distinct_chunks_from_same_source_are_not_penalized() {
+    let results = vec![
+      result("a-1", "src-a", 3.0, "東京タワーは東京の観光名所です"),
+      result("a-2", "src-a", 2.8, "大阪はたこ焼きが有名です"),
+    ];
+
+    let reranked = apply_overlap_penalty(results, &penalty(0.5));
+
+    assert!((reranked[1].score - 2.8).abs() < 1e-6);
+  }
+
+  #[test]
+  fn factor_one_keeps_scores() {
+    let results = vec![
+      result("a-1", "src-a", 3.0, "same text"),
+      result("a-2", "src-a", 2.0, "same text"),
+    ];
+
+    let reranked = apply_overlap_penalty(results, &penalty(1.0));
+
+    assert_eq!(doc_ids(&reranked), vec!["a-1", "a-2"]);
+    assert!((reranked[1].score - 2.0).abs() < 1e-6);
+  }
+
+  #[test]
+  fn overlap_ratio_is_zero_for_empty_text() {
+    assert_eq!(overlap_ratio(&char_bigrams(""), &char_bigrams("text")), 0.0);
+  }
+}
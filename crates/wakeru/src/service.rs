@@ -15,17 +15,26 @@
 //! - English: `data/index/en/` (SimpleTokenizer + LowerCaser)
 
 use std::collections::HashMap;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use tantivy::tokenizer::TextAnalyzer;
+use tantivy::Index;
+use tracing::{debug, warn};
+use vibrato_rkyv::Dictionary;
 
-use crate::config::{Language, WakeruConfig};
+use crate::config::{Language, OverlapPenaltyConfig, TokenizerMode, WakeruConfig};
+use crate::detect::detect_language;
 use crate::dictionary::DictionaryManager;
+use crate::errors::IndexerError;
 use crate::errors::error_definition::{WakeruError, WakeruResult};
-use crate::indexer::IndexManager;
+use crate::indexer::index_manager::{META_JSON, read_stored_documents};
+use crate::indexer::{
+  AddDocumentsReport, IndexManager, IndexOptions, IndexStats, SchemaFields, SegmentInfo,
+};
 use crate::models::{Document, SearchResult};
-use crate::searcher::SearchEngine;
-use crate::tokenizer::vibrato_tokenizer::VibratoTokenizer;
+use crate::searcher::{SearchEngine, apply_overlap_penalty};
+use crate::tokenizer::PosFilter;
 
 /// Structure pairing Index and SearchEngine per language.
 ///
@@ -53,11 +62,26 @@ pub struct WakeruService {
 
   /// Dictionary Manager (for Japanese)
   dictionary_manager: Option<DictionaryManager>,
+
+  /// Legacy single index directory (`<data_dir>/default`), referenced only for migration
+  legacy_index_path: PathBuf,
+
+  /// Penalty for redundant chunks from the same source (disabled if `None`)
+  overlap_penalty: Option<OverlapPenaltyConfig>,
+
+  /// Limit used when a search is called with `limit == 0` (`search.default_limit`)
+  default_search_limit: usize,
+
+  /// Hard cap on `limit` for every search (`search.max_limit`)
+  max_search_limit: usize,
 }
 
 impl WakeruService {
   /// Initialization (Load dictionary + Open/Create index for each language + Build SearchEngine)
   ///
+  /// The preset dictionary is cached in `dictionary.cache_dir`, or in the OS default cache
+  /// directory if it is not configured.
+  ///
   /// # Process Flow
   /// 1. Validate configuration
   /// 2. Build DictionaryManager only when Japanese is supported
@@ -68,37 +92,123 @@ impl WakeruService {
   /// - Dictionary load failure
   /// - Index creation/open failure
   pub fn init(config: &WakeruConfig) -> WakeruResult<Self> {
+    Self::init_inner(config, config.dictionary_cache_dir())
+  }
+
+  /// Initialization with an explicit dictionary cache directory
+  ///
+  /// Overrides `dictionary.cache_dir`, e.g. to point tests at a fixture dictionary
+  /// directory (`cache_dir/<preset name>`) instead of the global cache.
+  ///
+  /// # Errors
+  /// Same as [`init`](Self::init).
+  pub fn init_with_cache_dir<P: AsRef<Path>>(
+    config: &WakeruConfig,
+    cache_dir: P,
+  ) -> WakeruResult<Self> {
+    Self::init_inner(config, Some(cache_dir.as_ref()))
+  }
+
+  /// Initialization with an already loaded dictionary
+  ///
+  /// Lets a process that also runs morphological analysis (e.g. the HTTP API) load the
+  /// dictionary once and share the `Arc<Dictionary>`, instead of holding two copies.
+  /// `dict` is used as is: `[dictionary]` settings (preset, cache directory, user
+  /// dictionary) are ignored, and [`dictionary_manager`](Self::dictionary_manager) returns
+  /// `None`. `dict` is not used when no index needs it (no Japanese, or `cjk-bigram`).
+  ///
+  /// # Errors
+  /// Same as [`init`](Self::init), except for dictionary load failures.
+  pub fn init_with_dictionary(config: &WakeruConfig, dict: Arc<Dictionary>) -> WakeruResult<Self> {
+    config.validate()?;
+
+    let dict = Self::needs_dictionary(config).then_some(dict);
+    Self::build(config, None, dict)
+  }
+
+  /// Whether `config` has a Japanese index using morphological analysis
+  fn needs_dictionary(config: &WakeruConfig) -> bool {
+    config.supported_languages().contains(&Language::Ja)
+      && config.tokenizer_mode() == TokenizerMode::Morphological
+  }
+
+  /// Common implementation of [`init`](Self::init) and [`init_with_cache_dir`](Self::init_with_cache_dir)
+  fn init_inner(config: &WakeruConfig, cache_dir: Option<&Path>) -> WakeruResult<Self> {
     // Validate configuration (ConfigError is automatically converted to WakeruError with #[from])
     config.validate()?;
 
-    let default_language = config.default_language();
+    // Load dictionary only when Japanese is supported with morphological analysis
+    if !Self::needs_dictionary(config) {
+      return Self::build(config, None, None);
+    }
 
-    // Build dictionary manager only when Japanese is supported
-    let (dictionary_manager, ja_analyzer) = if config.supported_languages().contains(&Language::Ja)
-    {
-      let manager = DictionaryManager::with_preset(config.dictionary_preset())?;
-      let dict = manager.load()?;
-      let tokenizer = VibratoTokenizer::from_shared_dictionary(dict);
-      let analyzer = TextAnalyzer::from(tokenizer);
-      (Some(manager), Some(Arc::new(analyzer)))
-    } else {
-      (None, None)
+    let manager = match cache_dir {
+      Some(cache_dir) => {
+        DictionaryManager::with_preset_and_cache_dir(config.dictionary_preset(), cache_dir)
+      }
+      None => DictionaryManager::with_preset(config.dictionary_preset())?,
+    };
+    let manager = match config.dictionary_user_dict() {
+      Some(user_dict) => manager.with_user_dict(user_dict)?,
+      None => manager,
     };
+    let dict = manager.load()?;
+
+    Self::build(config, Some(manager), Some(dict))
+  }
+
+  /// Opens / creates the index and builds the SearchEngine of each language
+  ///
+  /// `dict` is the Japanese dictionary (`None` if no index needs it), and
+  /// `dictionary_manager` the manager that loaded it, if any.
+  fn build(
+    config: &WakeruConfig,
+    dictionary_manager: Option<DictionaryManager>,
+    dict: Option<Arc<Dictionary>>,
+  ) -> WakeruResult<Self> {
+    let default_language = config.default_language();
 
     let mut langs = HashMap::new();
+    let options = IndexOptions {
+      writer_memory_bytes: config.writer_memory_bytes(),
+      batch_commit_size: config.batch_commit_size(),
+      min_token_len: config.min_token_len(),
+      min_gram: config.min_gram(),
+      max_gram: config.max_gram(),
+      store_compression: config.store_compression(),
+      english_exact_field: config.english_exact_field(),
+      japanese_reading_field: config.japanese_reading_field(),
+      tokenizer_mode: config.tokenizer_mode(),
+      stemmer_language: config.stemmer_language(),
+      english_stop_words: config.english_stop_words(),
+      case_insensitive_source_id: config.case_insensitive_source_id(),
+      text_record_option: config.text_record_option(),
+      with_offsets: config.with_offsets(),
+      tags_in_metadata: config.tags_in_metadata(),
+      text_tokenizer_name: None,
+      open_mode: config.open_mode(),
+      pos_filter: PosFilter::default(),
+    };
 
     // Build IndexManager + SearchEngine for each language
     for &lang in config.supported_languages() {
       let index_path = config.index_path_for_language(lang);
 
-      // Prepare tokenizer according to language
-      let lang_analyzer = match lang {
-        Language::Ja => ja_analyzer.as_ref().map(|a| (**a).clone()),
-        Language::En => None, // English is created inside IndexManager
-      };
-
-      let index_manager = IndexManager::open_or_create(&index_path, lang, lang_analyzer)?;
-      let search_engine = SearchEngine::new(index_manager.index(), *index_manager.fields(), lang)?;
+      // All analyzers (Japanese ones from `dict` + `options.pos_filter`) are built inside
+      // IndexManager, so the POS filter recorded in `wakeru_meta.json` is the one in use
+      let index_manager = IndexManager::open_or_create_with_dictionary(
+        &index_path,
+        lang,
+        dict.clone(),
+        options.clone(),
+      )?;
+      let mut search_engine =
+        SearchEngine::new(index_manager.index(), *index_manager.fields(), lang)?
+          .with_ngram_range(config.min_gram(), config.max_gram())
+          .with_commit_counter(index_manager.commit_counter());
+      if let Some(bm25) = config.bm25() {
+        search_engine.set_bm25_params(bm25.k1, bm25.b)?;
+      }
 
       langs.insert(
         lang,
@@ -109,10 +219,18 @@ impl WakeruService {
       );
     }
 
+    // Never opened by the service itself (see `migrate_legacy_default_index`)
+    #[allow(deprecated)]
+    let legacy_index_path = config.index_path();
+
     Ok(Self {
       default_language,
       langs,
       dictionary_manager,
+      legacy_index_path,
+      overlap_penalty: config.overlap_penalty(),
+      default_search_limit: config.default_search_limit(),
+      max_search_limit: config.max_search_limit(),
     })
   }
 
@@ -122,6 +240,9 @@ impl WakeruService {
   /// - `language`: Target language
   /// - `documents`: Documents to add
   ///
+  /// # Returns
+  /// Report of added / skipped (duplicate) document counts
+  ///
   /// # Errors
   /// - Unsupported language
   /// - Index write error
@@ -129,19 +250,103 @@ impl WakeruService {
     &self,
     language: Language,
     documents: &[Document],
-  ) -> WakeruResult<()> {
+  ) -> WakeruResult<AddDocumentsReport> {
     let per_lang =
       self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
-    per_lang.index_manager.add_documents(documents).map(|_| ()).map_err(WakeruError::from)
+    let report = per_lang.index_manager.add_documents(documents)?;
+    per_lang.index_manager.commit()?;
+    Ok(report)
   }
 
   /// Adds documents to index in default language.
   ///
-  /// `AddDocumentsReport` is not returned currently, only error propagates to upper layer.
-  pub fn index_documents(&self, documents: &[Document]) -> WakeruResult<()> {
+  /// See [`WakeruService::index_documents_with_language`].
+  pub fn index_documents(&self, documents: &[Document]) -> WakeruResult<AddDocumentsReport> {
     self.index_documents_with_language(self.default_language, documents)
   }
 
+  /// Adds documents to the index of each document's detected language.
+  ///
+  /// The language of each document's text is detected with [`detect_language`]
+  /// (see its limitations). Documents detected as a language without an index in
+  /// this service go to the default language index.
+  ///
+  /// # Returns
+  /// Report of added / skipped (duplicate) document counts across all languages
+  ///
+  /// # Errors
+  /// - Index write error
+  pub fn index_documents_auto(&self, documents: &[Document]) -> WakeruResult<AddDocumentsReport> {
+    let mut by_language: HashMap<Language, Vec<Document>> = HashMap::new();
+    for document in documents {
+      let detected = detect_language(&document.text);
+      let language = if self.is_language_supported(detected) {
+        detected
+      } else {
+        self.default_language
+      };
+      by_language.entry(language).or_default().push(document.clone());
+    }
+
+    let mut report = AddDocumentsReport::default();
+    for (language, documents) in by_language {
+      debug!(
+        ?language,
+        count = documents.len(),
+        "Indexing auto-detected documents"
+      );
+      report.merge(&self.index_documents_with_language(language, &documents)?);
+    }
+    Ok(report)
+  }
+
+  /// Non-blocking variant of [`WakeruService::index_documents_with_language`].
+  ///
+  /// Returns `IndexerError::WriterBusy` without indexing anything if another call is
+  /// writing to the index, e.g. to answer "busy" from a request handler instead of waiting.
+  ///
+  /// # Errors
+  /// - Unsupported language
+  /// - `IndexerError::WriterBusy` if the index writer is in use
+  /// - Index write error
+  pub fn try_index_documents_with_language(
+    &self,
+    language: Language,
+    documents: &[Document],
+  ) -> WakeruResult<AddDocumentsReport> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    Ok(per_lang.index_manager.try_add_documents_and_commit(documents)?)
+  }
+
+  /// Non-blocking variant of [`WakeruService::index_documents`].
+  ///
+  /// See [`WakeruService::try_index_documents_with_language`].
+  pub fn try_index_documents(&self, documents: &[Document]) -> WakeruResult<AddDocumentsReport> {
+    self.try_index_documents_with_language(self.default_language, documents)
+  }
+
+  /// Adds documents read from JSON Lines (one `Document` JSON per line) to index in
+  /// specified language and commits them.
+  ///
+  /// Blank lines are ignored, input is ingested in `batch_commit_size` batches, and
+  /// lines that cannot be parsed are counted in the report's `malformed` instead of
+  /// aborting. See [`IndexManager::import_ndjson`].
+  ///
+  /// # Errors
+  /// - Unsupported language
+  /// - Failed to read from `reader`
+  /// - Index write error
+  pub fn index_jsonl_reader(
+    &self,
+    language: Language,
+    reader: impl BufRead,
+  ) -> WakeruResult<AddDocumentsReport> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    Ok(per_lang.index_manager.import_ndjson(reader)?)
+  }
+
   /// Executes BM25 search in specified language.
   ///
   /// # Arguments
@@ -158,19 +363,63 @@ impl WakeruService {
     query: &str,
     limit: usize,
   ) -> WakeruResult<Vec<SearchResult>> {
-    let per_lang =
-      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
-    per_lang.search_engine.search(query, limit).map_err(WakeruError::from)
+    self.search_paged_with_language(language, query, limit, 0)
   }
 
   /// Executes BM25 search in default language.
   ///
-  /// `limit` is passed to `SearchEngine::search` as is.
-  /// (Caller should consider `default_limit` / `max_limit` as needed).
+  /// As in every search method, `limit == 0` falls back to `search.default_limit`
+  /// and larger limits are clamped to `search.max_limit`.
   pub fn search(&self, query: &str, limit: usize) -> WakeruResult<Vec<SearchResult>> {
     self.search_with_language(self.default_language, query, limit)
   }
 
+  /// Executes BM25 search in specified language, skipping the first `offset` hits.
+  ///
+  /// With an overlap penalty configured, the top `offset + limit` hits are fetched and
+  /// re-ranked before the page is cut, so hits on earlier pages still penalize the ones
+  /// on this page. Hits ranked below `offset + limit` by BM25 are not considered, so
+  /// pages of different sizes may re-rank slightly differently.
+  ///
+  /// # Arguments
+  /// - `language`: Search target language
+  /// - `query`: Search query
+  /// - `limit`: Maximum number of results (page size)
+  /// - `offset`: Number of top hits to skip
+  ///
+  /// # Errors
+  /// - Unsupported language
+  /// - Query parse error
+  pub fn search_paged_with_language(
+    &self,
+    language: Language,
+    query: &str,
+    limit: usize,
+    offset: usize,
+  ) -> WakeruResult<Vec<SearchResult>> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    let limit = self.clamp_limit(limit);
+    if self.overlap_penalty.is_none() {
+      return Ok(per_lang.search_engine.search_paged(query, limit, offset)?);
+    }
+
+    // Re-rank everything up to the end of the page, so a hit demoted past the page
+    // boundary does not show up on both pages
+    let results = per_lang.search_engine.search_paged(query, offset.saturating_add(limit), 0)?;
+    Ok(self.rerank(results).into_iter().skip(offset).take(limit).collect())
+  }
+
+  /// Executes BM25 search in default language, skipping the first `offset` hits.
+  pub fn search_paged(
+    &self,
+    query: &str,
+    limit: usize,
+    offset: usize,
+  ) -> WakeruResult<Vec<SearchResult>> {
+    self.search_paged_with_language(self.default_language, query, limit, offset)
+  }
+
   /// Executes OR search of morphologically analyzed tokens in specified language.
   ///
   /// # Arguments
@@ -189,7 +438,8 @@ impl WakeruService {
   ) -> WakeruResult<Vec<SearchResult>> {
     let per_lang =
       self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
-    per_lang.search_engine.search_tokens_or(query, limit).map_err(WakeruError::from)
+    let results = per_lang.search_engine.search_tokens_or(query, self.clamp_limit(limit))?;
+    Ok(self.rerank(results))
   }
 
   /// Helper to execute OR search of morphologically analyzed tokens in default language.
@@ -199,275 +449,1584 @@ impl WakeruService {
     self.search_tokens_or_with_language(self.default_language, query, limit)
   }
 
-  // ===== Accessors =====
+  /// Executes BM25 search in specified language, also returning the total hit count.
+  ///
+  /// The total is the number of all matching documents (not capped by `limit`),
+  /// for pagination UIs.
+  ///
+  /// # Errors
+  /// - Unsupported language
+  /// - Query parse error
+  pub fn search_with_count_with_language(
+    &self,
+    language: Language,
+    query: &str,
+    limit: usize,
+  ) -> WakeruResult<(Vec<SearchResult>, usize)> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    let (results, total) =
+      per_lang.search_engine.search_with_count(query, self.clamp_limit(limit))?;
+    Ok((self.rerank(results), total))
+  }
 
-  /// Returns default language.
-  pub fn default_language(&self) -> Language {
-    self.default_language
+  /// Executes BM25 search in default language, also returning the total hit count.
+  pub fn search_with_count(
+    &self,
+    query: &str,
+    limit: usize,
+  ) -> WakeruResult<(Vec<SearchResult>, usize)> {
+    self.search_with_count_with_language(self.default_language, query, limit)
   }
 
-  /// Returns list of supported languages.
-  pub fn supported_languages(&self) -> Vec<Language> {
-    self.langs.keys().copied().collect()
+  /// Executes BM25 search in specified language, dropping results below `min_score`.
+  ///
+  /// The threshold applies to raw BM25 scores, which are index-relative (not normalized),
+  /// so tune it per index. See [`SearchEngine::search_filtered`].
+  ///
+  /// # Errors
+  /// - Unsupported language
+  /// - Query parse error (including NaN `min_score`)
+  pub fn search_filtered_with_language(
+    &self,
+    language: Language,
+    query: &str,
+    limit: usize,
+    min_score: Option<f32>,
+  ) -> WakeruResult<Vec<SearchResult>> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    let results =
+      per_lang.search_engine.search_filtered(query, self.clamp_limit(limit), min_score)?;
+    Ok(self.rerank(results))
   }
 
-  /// Checks if the specified language is supported.
-  pub fn is_language_supported(&self, language: Language) -> bool {
-    self.langs.contains_key(&language)
+  /// Executes BM25 search in default language, dropping results below `min_score`.
+  pub fn search_filtered(
+    &self,
+    query: &str,
+    limit: usize,
+    min_score: Option<f32>,
+  ) -> WakeruResult<Vec<SearchResult>> {
+    self.search_filtered_with_language(self.default_language, query, limit, min_score)
   }
 
-  /// Returns reference to internal DictionaryManager (only when Japanese is supported).
-  pub fn dictionary_manager(&self) -> Option<&DictionaryManager> {
-    self.dictionary_manager.as_ref()
+  /// Executes OR search against every supported language and merges the results.
+  ///
+  /// Results are ranked by raw BM25 score. Scores from different indexes are not
+  /// strictly comparable; use [`WakeruService::search_all_languages_with_options`]
+  /// with `normalize_scores = true` when one language dominates unfairly.
+  ///
+  /// # Errors
+  /// - Query parse error
+  pub fn search_all_languages(&self, query: &str, limit: usize) -> WakeruResult<Vec<SearchResult>> {
+    self.search_all_languages_with_options(query, limit, false)
   }
 
-  /// Returns reference to IndexManager of specified language.
-  pub fn index_manager(&self, language: Language) -> Option<&IndexManager> {
-    self.langs.get(&language).map(|p| &p.index_manager)
+  /// Executes OR search against every supported language and merges the results.
+  ///
+  /// # Arguments
+  /// - `query`: Search query
+  /// - `limit`: Maximum number of merged results
+  /// - `normalize_scores`: Min-max normalize scores to `[0, 1]` per language before merging
+  ///
+  /// # Errors
+  /// - Query parse error
+  pub fn search_all_languages_with_options(
+    &self,
+    query: &str,
+    limit: usize,
+    normalize_scores: bool,
+  ) -> WakeruResult<Vec<SearchResult>> {
+    let per_language = self
+      .langs
+      .keys()
+      .map(|&language| self.search_tokens_or_with_language(language, query, limit))
+      .collect::<WakeruResult<Vec<_>>>()?;
+    Ok(merge_language_results(
+      per_language,
+      self.clamp_limit(limit),
+      normalize_scores,
+    ))
   }
 
-  /// Returns reference to SearchEngine of specified language.
-  pub fn search_engine(&self, language: Language) -> Option<&SearchEngine> {
-    self.langs.get(&language).map(|p| &p.search_engine)
+  /// Executes AND search of morphologically analyzed tokens in specified language.
+  ///
+  /// Only documents containing every query token are returned.
+  ///
+  /// # Errors
+  /// - Unsupported language
+  /// - Query parse error
+  pub fn search_tokens_and_with_language(
+    &self,
+    language: Language,
+    query: &str,
+    limit: usize,
+  ) -> WakeruResult<Vec<SearchResult>> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    let results = per_lang.search_engine.search_tokens_and(query, self.clamp_limit(limit))?;
+    Ok(self.rerank(results))
   }
-}
 
-// ─────────────────────────────────────────────────────────────────────────────
-// Test Module
-// ─────────────────────────────────────────────────────────────────────────────
+  /// Executes AND search of morphologically analyzed tokens in default language.
+  pub fn search_tokens_and(&self, query: &str, limit: usize) -> WakeruResult<Vec<SearchResult>> {
+    self.search_tokens_and_with_language(self.default_language, query, limit)
+  }
 
-#[cfg(test)]
-mod tests {
-  use super::*;
-  use crate::config::{
-    DictionaryConfig, DictionaryPreset, IndexConfig, LogLevel, LoggingConfig, SearchConfig,
-  };
-  use crate::models::Document;
-  use serde_json::json;
+  /// Executes OR search in specified language, tagging each result with how it matched.
+  ///
+  /// See [`SearchEngine::smart_search`] for the `match_kind` classification.
+  ///
+  /// # Errors
+  /// - Unsupported language
+  /// - Query parse error
+  pub fn smart_search_with_language(
+    &self,
+    language: Language,
+    query: &str,
+    limit: usize,
+  ) -> WakeruResult<Vec<SearchResult>> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    let results = per_lang.search_engine.smart_search(query, self.clamp_limit(limit))?;
+    Ok(self.rerank(results))
+  }
 
-  // ─── Test Helper Functions ───────────────────────────────────────────────────
+  /// Executes OR search with match classification in default language.
+  pub fn smart_search(&self, query: &str, limit: usize) -> WakeruResult<Vec<SearchResult>> {
+    self.smart_search_with_language(self.default_language, query, limit)
+  }
 
-  /// Create WakeruConfig for testing with English only
+  /// Finds chunks similar to the indexed document `doc_id` in specified language.
   ///
-  /// Dictionary manager is unnecessary because Japanese is not included
-  fn create_english_only_config(temp_dir: &tempfile::TempDir) -> WakeruConfig {
-    WakeruConfig {
-      dictionary: DictionaryConfig {
-        preset: DictionaryPreset::Ipadic,
-        cache_dir: Some(temp_dir.path().join("dict")),
-      },
-      index: IndexConfig {
-        data_dir: temp_dir.path().join("index"),
-        writer_memory_bytes: 50_000_000,
-        batch_commit_size: 1000,
-        languages: vec![Language::En],
-        default_language: Language::En,
-      },
-      search: SearchConfig {
-        default_limit: 10,
-        max_limit: 100,
-      },
-      logging: LoggingConfig {
-        level: LogLevel::Info,
-      },
-    }
+  /// See [`SearchEngine::search_similar`]. Useful for deduplication and related content.
+  ///
+  /// # Errors
+  /// - Unsupported language
+  /// - No committed document has `doc_id`
+  pub fn find_similar(
+    &self,
+    language: Language,
+    doc_id: &str,
+    limit: usize,
+  ) -> WakeruResult<Vec<SearchResult>> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    let results = per_lang.search_engine.search_similar(doc_id, self.clamp_limit(limit))?;
+    Ok(self.rerank(results))
   }
 
-  /// Create WakeruService with English only
-  fn create_english_service() -> (tempfile::TempDir, WakeruService) {
-    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
-    let config = create_english_only_config(&temp_dir);
-    let service = WakeruService::init(&config).expect("Failed to initialize WakeruService");
-    (temp_dir, service)
+  /// Returns `(input, indexed)` pairs showing how a query is normalized in specified language.
+  ///
+  /// See [`SearchEngine::analyze_query`].
+  ///
+  /// # Errors
+  /// - Unsupported language
+  pub fn analyze_query_with_language(
+    &self,
+    language: Language,
+    query: &str,
+  ) -> WakeruResult<Vec<(String, String)>> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    Ok(per_lang.search_engine.analyze_query(query)?)
+  }
+
+  /// Returns `(input, indexed)` pairs showing how a query is normalized in default language.
+  pub fn analyze_query(&self, query: &str) -> WakeruResult<Vec<(String, String)>> {
+    self.analyze_query_with_language(self.default_language, query)
+  }
+
+  /// Returns the tokens a query is searched with in specified language.
+  ///
+  /// See [`SearchEngine::explain_query`].
+  ///
+  /// # Errors
+  /// - Unsupported language
+  pub fn explain_query_with_language(
+    &self,
+    language: Language,
+    query: &str,
+  ) -> WakeruResult<Vec<String>> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    Ok(per_lang.search_engine.explain_query(query)?)
+  }
+
+  /// Returns the tokens a query is searched with in default language.
+  pub fn explain_query(&self, query: &str) -> WakeruResult<Vec<String>> {
+    self.explain_query_with_language(self.default_language, query)
+  }
+
+  /// Resolves the effective search limit.
+  ///
+  /// `0` means "not specified" and falls back to `search.default_limit`; other values are
+  /// clamped to `search.max_limit` so callers cannot request unbounded results.
+  fn clamp_limit(&self, limit: usize) -> usize {
+    if limit == 0 {
+      return self.default_search_limit;
+    }
+    if limit > self.max_search_limit {
+      debug!(
+        requested = limit,
+        max_limit = self.max_search_limit,
+        "Search limit clamped to max_limit"
+      );
+    }
+    limit.min(self.max_search_limit)
+  }
+
+  /// Applies post-retrieval re-ranking (overlap penalty) if configured.
+  fn rerank(&self, results: Vec<SearchResult>) -> Vec<SearchResult> {
+    match &self.overlap_penalty {
+      Some(penalty) => apply_overlap_penalty(results, penalty),
+      None => results,
+    }
+  }
+
+  /// Returns per-segment information of the index in specified language.
+  ///
+  /// # Errors
+  /// - Unsupported language
+  /// - Failed to compute segment file sizes
+  pub fn segment_info_with_language(&self, language: Language) -> WakeruResult<Vec<SegmentInfo>> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    per_lang.index_manager.segment_info().map_err(WakeruError::from)
+  }
+
+  /// Returns per-segment information of the index in default language.
+  pub fn segment_info(&self) -> WakeruResult<Vec<SegmentInfo>> {
+    self.segment_info_with_language(self.default_language)
+  }
+
+  /// Returns the number of documents in the index of specified language.
+  ///
+  /// # Errors
+  /// - Unsupported language
+  pub fn document_count(&self, language: Language) -> WakeruResult<u64> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    Ok(per_lang.index_manager.num_docs())
+  }
+
+  /// Returns document and segment counts for every supported language.
+  ///
+  /// An empty index (`num_docs == 0`) may indicate that indexing silently failed.
+  pub fn index_stats(&self) -> HashMap<Language, IndexStats> {
+    self
+      .langs
+      .iter()
+      .map(|(&language, per_lang)| (language, per_lang.index_manager.stats()))
+      .collect()
+  }
+
+  /// Deletes all chunks of `source_id` from the index of specified language.
+  ///
+  /// Call this before re-ingesting a source document to avoid stale chunks.
+  ///
+  /// # Errors
+  /// - Unsupported language
+  /// - Index write error
+  pub fn delete_source(&self, language: Language, source_id: &str) -> WakeruResult<()> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    per_lang.index_manager.delete_by_source_id(source_id)?;
+    Ok(())
+  }
+
+  /// Deletes all documents having `tag` from the index of specified language.
+  ///
+  /// e.g. `delete_by_tag(Language::Ja, "env:staging")` for content lifecycle cleanup.
+  ///
+  /// # Errors
+  /// - Unsupported language
+  /// - Index write error
+  pub fn delete_by_tag(&self, language: Language, tag: &str) -> WakeruResult<()> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    per_lang.index_manager.delete_by_tag(tag)?;
+    Ok(())
+  }
+
+  /// Deletes all documents from the index of specified language.
+  ///
+  /// The index directory and schema are kept, so the index can be refilled right away
+  /// (e.g. for reindexing). Searches no longer return the deleted documents.
+  ///
+  /// # Errors
+  /// - Unsupported language
+  /// - Index write error
+  pub fn clear_index(&self, language: Language) -> WakeruResult<()> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    per_lang.index_manager.clear()?;
+    Ok(())
+  }
+
+  /// Reloads the readers of specified language so that the latest commit is visible.
+  ///
+  /// The search reader picks up commits only after a delay, so documents committed
+  /// by another process (e.g. a separate ingestion service) may not be visible yet.
+  /// This reloads both the search reader and the reader used for duplicate checks
+  /// and statistics.
+  ///
+  /// # Errors
+  /// - Unsupported language
+  /// - Tantivy reader reload error
+  pub fn reload(&self, language: Language) -> WakeruResult<()> {
+    let per_lang =
+      self.langs.get(&language).ok_or(WakeruError::UnsupportedLanguage { language })?;
+    per_lang.index_manager.reader().reload().map_err(IndexerError::from)?;
+    per_lang.search_engine.reload()?;
+    Ok(())
+  }
+
+  /// Reloads the readers of all supported languages.
+  ///
+  /// # Errors
+  /// - Tantivy reader reload error
+  pub fn reload_all(&self) -> WakeruResult<()> {
+    for &language in self.langs.keys() {
+      self.reload(language)?;
+    }
+    Ok(())
+  }
+
+  /// Commits outstanding writes and releases the indexes of all supported languages.
+  ///
+  /// See [`IndexManager::close`]. Use this before removing `data_dir` or at process exit,
+  /// instead of relying on drop order.
+  ///
+  /// # Errors
+  /// - Tantivy commit / merge thread error (remaining indexes are dropped without flushing)
+  pub fn close(self) -> WakeruResult<()> {
+    for (_, per_lang) in self.langs {
+      drop(per_lang.search_engine);
+      per_lang.index_manager.close()?;
+    }
+    Ok(())
+  }
+
+  /// Migrates documents from a legacy `default` index into the index of specified language.
+  ///
+  /// Before multi-language support, a single index was stored in `<data_dir>/default`
+  /// (see deprecated `WakeruConfig::index_path()`). `WakeruService` never opens that directory,
+  /// so documents left there are not searchable. This helper copies all stored documents
+  /// into the per-language index (duplicate IDs are skipped as usual) and then removes
+  /// the legacy directory. If any document is skipped as invalid, the legacy directory is
+  /// kept so that no document is lost (migrating again skips the already copied ones).
+  ///
+  /// # Returns
+  /// - `Ok(Some(report))`: Migration performed (check `report.invalid`)
+  /// - `Ok(None)`: No legacy index exists (nothing to do)
+  ///
+  /// # Errors
+  /// - Unsupported language
+  /// - Failed to read the legacy index or write to the target index
+  /// - Failed to remove the legacy directory
+  pub fn migrate_legacy_default_index(
+    &self,
+    into: Language,
+  ) -> WakeruResult<Option<AddDocumentsReport>> {
+    let per_lang =
+      self.langs.get(&into).ok_or(WakeruError::UnsupportedLanguage { language: into })?;
+
+    if !self.legacy_index_path.join(META_JSON).exists() {
+      return Ok(None);
+    }
+
+    // Read stored documents (no tokenizer needed), then release the legacy index
+    let documents = {
+      let legacy_index = Index::open_in_dir(&self.legacy_index_path).map_err(IndexerError::from)?;
+      let fields = SchemaFields::from_schema(&legacy_index.schema()).map_err(IndexerError::from)?;
+      let reader = legacy_index.reader().map_err(IndexerError::from)?;
+      read_stored_documents(&reader.searcher(), &fields)?
+    };
+
+    let report = per_lang.index_manager.add_documents(&documents)?;
+    per_lang.index_manager.commit()?;
+
+    // Documents skipped as invalid exist only in the legacy index, so keep it
+    if report.invalid > 0 {
+      warn!(
+        path = ?self.legacy_index_path,
+        invalid = report.invalid,
+        "Some legacy documents could not be migrated; keeping the legacy index"
+      );
+      return Ok(Some(report));
+    }
+
+    std::fs::remove_dir_all(&self.legacy_index_path).map_err(|e| {
+      IndexerError::InvalidIndexPath {
+        path: self.legacy_index_path.clone(),
+        source: Arc::new(e),
+      }
+    })?;
+
+    Ok(Some(report))
+  }
+
+  // ===== Accessors =====
+
+  /// Returns default language.
+  pub fn default_language(&self) -> Language {
+    self.default_language
+  }
+
+  /// Returns list of supported languages.
+  pub fn supported_languages(&self) -> Vec<Language> {
+    self.langs.keys().copied().collect()
+  }
+
+  /// Checks if the specified language is supported.
+  pub fn is_language_supported(&self, language: Language) -> bool {
+    self.langs.contains_key(&language)
+  }
+
+  /// Returns reference to internal DictionaryManager (only when Japanese is supported).
+  pub fn dictionary_manager(&self) -> Option<&DictionaryManager> {
+    self.dictionary_manager.as_ref()
+  }
+
+  /// Returns reference to IndexManager of specified language.
+  pub fn index_manager(&self, language: Language) -> Option<&IndexManager> {
+    self.langs.get(&language).map(|p| &p.index_manager)
+  }
+
+  /// Returns reference to SearchEngine of specified language.
+  pub fn search_engine(&self, language: Language) -> Option<&SearchEngine> {
+    self.langs.get(&language).map(|p| &p.search_engine)
+  }
+}
+
+/// Merges per-language results into the global top-`limit` by score.
+///
+/// With `normalize_scores`, each language's scores are min-max normalized to `[0, 1]`
+/// first (a language whose hits all share one score maps them to `1.0`).
+fn merge_language_results(
+  per_language: Vec<Vec<SearchResult>>,
+  limit: usize,
+  normalize_scores: bool,
+) -> Vec<SearchResult> {
+  let mut merged: Vec<SearchResult> = Vec::new();
+  for mut results in per_language {
+    if normalize_scores {
+      normalize_min_max(&mut results);
+    }
+    merged.append(&mut results);
+  }
+
+  // Tie-break by doc_id so that the order does not depend on language iteration order
+  merged.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.doc_id.cmp(&b.doc_id)));
+  merged.truncate(limit);
+  merged
+}
+
+/// Min-max normalizes scores to `[0, 1]` in place
+fn normalize_min_max(results: &mut [SearchResult]) {
+  let (min, max) = results.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), r| {
+    (min.min(r.score), max.max(r.score))
+  });
+  let range = max - min;
+  for result in results {
+    result.score = if range > 0.0 {
+      (result.score - min) / range
+    } else {
+      1.0
+    };
+  }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Test Module
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::config::{
+    DictionaryConfig, DictionaryPreset, IndexConfig, LogLevel, LoggingConfig, OpenMode,
+    SearchConfig, StoreCompression, TextRecordOption,
+  };
+  use crate::models::{Document, MatchKind};
+  use serde_json::json;
+
+  // ─── Test Helper Functions ───────────────────────────────────────────────────
+
+  /// Create WakeruConfig for testing with English only
+  ///
+  /// Dictionary manager is unnecessary because Japanese is not included
+  fn create_english_only_config(temp_dir: &tempfile::TempDir) -> WakeruConfig {
+    WakeruConfig {
+      dictionary: DictionaryConfig {
+        preset: DictionaryPreset::Ipadic,
+        cache_dir: Some(temp_dir.path().join("dict")),
+        user_dict: None,
+      },
+      index: IndexConfig {
+        data_dir: temp_dir.path().join("index"),
+        writer_memory_bytes: 50_000_000,
+        batch_commit_size: 1000,
+        languages: vec![Language::En],
+        default_language: Language::En,
+        min_token_len: 1,
+        min_gram: 1,
+        max_gram: 1,
+        store_compression: StoreCompression::Lz4,
+        english_exact_field: false,
+        japanese_reading_field: false,
+        tokenizer_mode: TokenizerMode::Morphological,
+        stemmer_language: None,
+        english_stop_words: false,
+        case_insensitive_source_id: false,
+        text_record_option: TextRecordOption::WithPositions,
+        with_offsets: false,
+        tags_in_metadata: true,
+        open_mode: OpenMode::CreateIfMissing,
+      },
+      search: SearchConfig {
+        default_limit: 10,
+        max_limit: 100,
+        overlap_penalty: None,
+        bm25: None,
+      },
+      logging: LoggingConfig {
+        level: LogLevel::Info,
+      },
+    }
+  }
+
+  /// Create WakeruService with English only
+  fn create_english_service() -> (tempfile::TempDir, WakeruService) {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let config = create_english_only_config(&temp_dir);
+    let service = WakeruService::init(&config).expect("Failed to initialize WakeruService");
+    (temp_dir, service)
+  }
+
+  // ─── Initialization Tests ──────────────────────────────────────────────────────────
+
+  #[test]
+  fn service_initializes_with_english_only() {
+    let (_temp_dir, service) = create_english_service();
+
+    // Confirm default language is English
+    assert_eq!(service.default_language(), Language::En);
+
+    // Confirm English is supported
+    assert!(service.is_language_supported(Language::En));
+
+    // Japanese is not supported (no dictionary)
+    assert!(!service.is_language_supported(Language::Ja));
+  }
+
+  #[test]
+  fn service_supported_languages() {
+    let (_temp_dir, service) = create_english_service();
+
+    let languages = service.supported_languages();
+    assert_eq!(languages.len(), 1);
+    assert!(languages.contains(&Language::En));
+  }
+
+  #[test]
+  fn service_dictionary_manager_is_none_for_english_only() {
+    let (_temp_dir, service) = create_english_service();
+
+    // Dictionary manager does not exist for English only
+    assert!(service.dictionary_manager().is_none());
+  }
+
+  #[test]
+  fn service_initializes_with_korean_without_dictionary() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let mut config = create_english_only_config(&temp_dir);
+    config.index.languages = vec![Language::En, Language::Ko];
+    config.index.default_language = Language::Ko;
+    let service = WakeruService::init(&config).expect("Failed to initialize WakeruService");
+
+    assert_eq!(service.default_language(), Language::Ko);
+    assert!(service.is_language_supported(Language::Ko));
+    assert!(service.dictionary_manager().is_none());
+    assert!(temp_dir.path().join("index").join("ko").exists());
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "서울 타워"),
+      Document::new("doc-2", "src-1", "부산 바다"),
+    ];
+    service.index_documents_with_language(Language::Ko, &docs).expect("Indexing failed");
+    service.reload(Language::Ko).expect("Reload failed");
+
+    let results = service.search("서울", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+  }
+
+  #[test]
+  fn service_initializes_cjk_bigram_japanese_without_dictionary() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let mut config = create_english_only_config(&temp_dir);
+    config.index.languages = vec![Language::Ja];
+    config.index.default_language = Language::Ja;
+    config.index.tokenizer_mode = TokenizerMode::CjkBigram;
+    let service = WakeruService::init(&config).expect("Failed to initialize WakeruService");
+
+    // No dictionary is loaded (nor downloaded)
+    assert!(service.dictionary_manager().is_none());
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "東京タワー"),
+      Document::new("doc-2", "src-1", "大阪城"),
+    ];
+    service.index_documents(&docs).expect("Indexing failed");
+    service.reload(Language::Ja).expect("Reload failed");
+
+    let results = service.search("京タ", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+  }
+
+  #[test]
+  fn read_only_service_opens_next_to_writer_service() {
+    let (temp_dir, writer) = create_english_service();
+    let mut config = create_english_only_config(&temp_dir);
+    config.index.open_mode = OpenMode::ReadOnly;
+
+    // A second writer would fail on the index lock; a read-only service does not take it
+    let reader = WakeruService::init(&config).expect("Failed to initialize read-only service");
+
+    writer.index_documents(&[Document::new("doc-1", "src-1", "Hello world")]).unwrap();
+    reader.reload(Language::En).expect("Reload failed");
+    assert_eq!(reader.search("hello", 10).unwrap().len(), 1);
+
+    let err = reader.index_documents(&[Document::new("doc-2", "src-1", "Hello again")]);
+    assert!(matches!(
+      err,
+      Err(WakeruError::Indexer(IndexerError::ReadOnlyIndex))
+    ));
+  }
+
+  // ─── Dictionary Cache Dir Tests ──────────────────────────────────────────────
+
+  /// Create WakeruConfig for testing with Japanese only
+  fn create_japanese_only_config(temp_dir: &tempfile::TempDir) -> WakeruConfig {
+    let mut config = create_english_only_config(temp_dir);
+    config.index.languages = vec![Language::Ja];
+    config.index.default_language = Language::Ja;
+    config
+  }
+
+  /// Copy the globally cached IPADIC into `temp_dir/fixture_dict` as a fixture cache dir
+  ///
+  /// Returns `None` (skip) if the global cache does not exist.
+  fn prepare_fixture_cache_dir(temp_dir: &tempfile::TempDir) -> Option<PathBuf> {
+    let kind = vibrato_rkyv::dictionary::PresetDictionaryKind::Ipadic;
+    let manager = DictionaryManager::with_preset(kind).ok()?;
+    let cached = manager.cache_dir().join(kind.name());
+    if !cached.exists() {
+      eprintln!("No dictionary cache -> Skip test");
+      return None;
+    }
+
+    let fixture_dir = temp_dir.path().join("fixture_dict");
+    copy_dir_all(&cached, &fixture_dir.join(kind.name())).expect("Failed to copy dictionary");
+    Some(fixture_dir)
+  }
+
+  fn copy_dir_all(src: &Path, dst: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(dst)?;
+    for entry in std::fs::read_dir(src)? {
+      let entry = entry?;
+      let target = dst.join(entry.file_name());
+      if entry.file_type()?.is_dir() {
+        copy_dir_all(&entry.path(), &target)?;
+      } else {
+        std::fs::copy(entry.path(), target)?;
+      }
+    }
+    Ok(())
+  }
+
+  #[test]
+  fn service_init_uses_configured_cache_dir() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let Some(fixture_dir) = prepare_fixture_cache_dir(&temp_dir) else {
+      return;
+    };
+
+    let mut config = create_japanese_only_config(&temp_dir);
+    config.dictionary.cache_dir = Some(fixture_dir.clone());
+
+    let service = WakeruService::init(&config).expect("Initialization failed");
+    let manager = service.dictionary_manager().expect("Dictionary manager should exist");
+    assert_eq!(manager.cache_dir(), fixture_dir);
+  }
+
+  #[test]
+  fn service_init_with_cache_dir_indexes_and_searches_japanese() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let Some(fixture_dir) = prepare_fixture_cache_dir(&temp_dir) else {
+      return;
+    };
+    let config = create_japanese_only_config(&temp_dir);
+
+    {
+      let service =
+        WakeruService::init_with_cache_dir(&config, &fixture_dir).expect("Initialization failed");
+      assert_eq!(
+        service.dictionary_manager().map(|m| m.cache_dir()),
+        Some(fixture_dir.as_path())
+      );
+
+      let docs = vec![
+        Document::new("ja-1", "src-1", "京都には多くの歴史的な寺院があります。"),
+        Document::new("ja-2", "src-2", "東京は日本の首都です。"),
+      ];
+      service.index_documents(&docs).expect("Failed to add documents");
+    }
+
+    {
+      let service =
+        WakeruService::init_with_cache_dir(&config, &fixture_dir).expect("Initialization failed");
+      let results = service.search("寺院", 10).expect("Search failed");
+
+      assert_eq!(results.len(), 1);
+      assert_eq!(results[0].doc_id, "ja-1");
+    }
+  }
+
+  #[test]
+  fn service_init_with_dictionary_indexes_and_searches_japanese() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let Some(fixture_dir) = prepare_fixture_cache_dir(&temp_dir) else {
+      return;
+    };
+    let config = create_japanese_only_config(&temp_dir);
+
+    // Load once, as a process sharing the dictionary with the analysis API would
+    let dict =
+      DictionaryManager::with_preset_and_cache_dir(config.dictionary_preset(), &fixture_dir)
+        .load()
+        .expect("Failed to load dictionary");
+
+    let service = WakeruService::init_with_dictionary(&config, Arc::clone(&dict))
+      .expect("Initialization failed");
+    assert!(service.dictionary_manager().is_none());
+    // Shared, not copied
+    assert!(Arc::strong_count(&dict) > 1);
+
+    let docs = vec![
+      Document::new("ja-1", "src-1", "京都には多くの歴史的な寺院があります。"),
+      Document::new("ja-2", "src-2", "東京は日本の首都です。"),
+    ];
+    service.index_documents(&docs).expect("Failed to add documents");
+    service.reload(Language::Ja).expect("Reload failed");
+
+    let results = service.search("寺院", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "ja-1");
+  }
+
+  // ─── Accessor Tests ────────────────────────────────────────────────────────
+
+  #[test]
+  fn service_index_manager_accessor() {
+    let (_temp_dir, service) = create_english_service();
+
+    // English IndexManager can be retrieved
+    let index_manager = service.index_manager(Language::En);
+    assert!(index_manager.is_some());
+    assert_eq!(index_manager.unwrap().language(), Language::En);
+
+    // Japanese IndexManager does not exist
+    assert!(service.index_manager(Language::Ja).is_none());
+  }
+
+  #[test]
+  fn service_index_manager_uses_configured_writer_memory() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let mut config = create_english_only_config(&temp_dir);
+    config.index.writer_memory_bytes = 30_000_000;
+    let service = WakeruService::init(&config).expect("Failed to initialize WakeruService");
+
+    let index_manager = service.index_manager(Language::En).unwrap();
+    assert_eq!(index_manager.writer_memory_bytes(), Some(30_000_000));
+  }
+
+  #[test]
+  fn service_search_engine_accessor() {
+    let (_temp_dir, service) = create_english_service();
+
+    // English SearchEngine can be retrieved
+    let search_engine = service.search_engine(Language::En);
+    assert!(search_engine.is_some());
+    assert_eq!(search_engine.unwrap().language(), Language::En);
+
+    // Japanese SearchEngine does not exist
+    assert!(service.search_engine(Language::Ja).is_none());
+  }
+
+  #[test]
+  fn service_is_language_supported() {
+    let (_temp_dir, service) = create_english_service();
+
+    assert!(service.is_language_supported(Language::En));
+    assert!(!service.is_language_supported(Language::Ja));
+  }
+
+  // ─── Document Addition Tests ────────────────────────────────────────────────
+
+  #[test]
+  fn service_index_documents_default_language() {
+    let (_temp_dir, service) = create_english_service();
+
+    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
+
+    let report = service.index_documents(&docs).expect("Indexing failed");
+    assert_eq!(report.total, 1);
+    assert_eq!(report.added, 1);
+  }
+
+  #[test]
+  fn service_index_documents_with_language() {
+    let (_temp_dir, service) = create_english_service();
+
+    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
+
+    let report =
+      service.index_documents_with_language(Language::En, &docs).expect("Indexing failed");
+    assert_eq!(report.added, 1);
+  }
+
+  #[test]
+  fn service_index_documents_unsupported_language() {
+    let (_temp_dir, service) = create_english_service();
+
+    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
+
+    let result = service.index_documents_with_language(Language::Ja, &docs);
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
+  }
+
+  #[test]
+  fn service_index_documents_with_metadata() {
+    let (_temp_dir, service) = create_english_service();
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Tokyo is the capital")
+        .with_metadata("author", json!("alice"))
+        .with_tag("category:geo"),
+    ];
+
+    let report = service.index_documents(&docs).expect("Indexing failed");
+    assert_eq!(report.added, 1);
+  }
+
+  #[test]
+  fn service_index_documents_from_multiple_threads() {
+    let (_temp_dir, service) = create_english_service();
+
+    std::thread::scope(|scope| {
+      for t in 0..4 {
+        let service = &service;
+        scope.spawn(move || {
+          for i in 0..10 {
+            let docs = vec![Document::new(
+              format!("{t}-{i}"),
+              "src-1",
+              "concurrent chunk",
+            )];
+            if t % 2 == 0 {
+              service.index_documents(&docs).expect("Indexing failed");
+              continue;
+            }
+            // Non-blocking variant: retry while another thread holds the writer
+            loop {
+              match service.try_index_documents(&docs) {
+                Ok(report) => {
+                  assert_eq!(report.added, 1);
+                  break;
+                }
+                Err(WakeruError::Indexer(IndexerError::WriterBusy)) => std::thread::yield_now(),
+                Err(err) => panic!("Indexing failed: {err}"),
+              }
+            }
+          }
+        });
+      }
+    });
+
+    assert_eq!(
+      service.document_count(Language::En).expect("Count failed"),
+      4 * 10
+    );
+  }
+
+  #[test]
+  fn service_index_jsonl_reader_counts_malformed_lines() {
+    let (_temp_dir, service) = create_english_service();
+
+    let jsonl = r#"{"id":"doc-1","source_id":"src-1","text":"Tokyo tower"}
+
+{"id":"doc-2","source_id":"src-1","text":"Kyoto temples","metadata":{"page":2}}
+{"id":"doc-3","source_id":
+{"id":"doc-1","source_id":"src-1","text":"Tokyo tower again"}
+"#;
+    let report = service
+      .index_jsonl_reader(Language::En, std::io::Cursor::new(jsonl))
+      .expect("Indexing failed");
+
+    assert_eq!(report.total, 4);
+    assert_eq!(report.added, 2);
+    assert_eq!(report.malformed, 1);
+    assert_eq!(report.skipped_duplicates, 1);
+    assert_eq!(
+      service.document_count(Language::En).expect("Count failed"),
+      2
+    );
+
+    let err = service.index_jsonl_reader(Language::Ja, std::io::Cursor::new("")).unwrap_err();
+    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
+  }
+
+  #[test]
+  fn service_index_documents_auto_falls_back_to_default_language() {
+    let (_temp_dir, service) = create_english_service();
+
+    // Japanese is detected for doc-2, but only English is indexed in this service
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Tokyo tower"),
+      Document::new("doc-2", "src-1", "東京タワーは東京の観光名所です"),
+    ];
+    let report = service.index_documents_auto(&docs).expect("Indexing failed");
+
+    assert_eq!(report.added, 2);
+    assert_eq!(
+      service.document_count(Language::En).expect("Count failed"),
+      2
+    );
+  }
+
+  // ─── Search Tests ────────────────────────────────────────────────────────────
+
+  #[test]
+  fn service_search_default_language() {
+    let (_temp_dir, service) = create_english_service();
+
+    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
+    service.index_documents(&docs).expect("Indexing failed");
+
+    // SearchEngine reloads its reader with a delay, so the document may not be
+    // visible yet (see `service_reload_makes_new_documents_visible`)
+    // Here we just check that no error occurs
+    let result = service.search("hello", 10);
+    assert!(result.is_ok());
+  }
+
+  #[test]
+  fn service_reload_makes_new_documents_visible() {
+    let (temp_dir, writer) = create_english_service();
+    let mut config = create_english_only_config(&temp_dir);
+    config.index.open_mode = OpenMode::ReadOnly;
+    let reader = WakeruService::init(&config).expect("Failed to initialize read-only service");
+    assert!(reader.search("hello", 10).expect("Search failed").is_empty());
+
+    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
+    writer.index_documents(&docs).expect("Indexing failed");
+
+    // The other service keeps its snapshot until it reloads
+    assert!(reader.search("hello", 10).expect("Search failed").is_empty());
+
+    reader.reload(Language::En).expect("Reload failed");
+    let results = reader.search("hello", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+  }
+
+  #[test]
+  fn service_delete_source_removes_only_that_source() {
+    let (_temp_dir, service) = create_english_service();
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Hello world"),
+      Document::new("doc-2", "src-1", "Hello again"),
+      Document::new("doc-3", "src-2", "Hello there"),
+    ];
+    service.index_documents(&docs).expect("Indexing failed");
+
+    service.delete_source(Language::En, "src-1").expect("Delete failed");
+    let results = service.search("hello", 10).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-3");
+
+    let err = service.delete_source(Language::Ja, "src-1").unwrap_err();
+    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
+  }
+
+  #[test]
+  fn service_delete_by_tag_removes_only_tagged_documents() {
+    let (_temp_dir, service) = create_english_service();
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Hello world").with_tags(["env:staging"]),
+      Document::new("doc-2", "src-2", "Hello again").with_tags(["env:production"]),
+      Document::new("doc-3", "src-3", "Hello there"),
+    ];
+    service.index_documents(&docs).expect("Indexing failed");
+
+    service.delete_by_tag(Language::En, "env:staging").expect("Delete failed");
+    let mut doc_ids: Vec<String> =
+      service.search("hello", 10).unwrap().into_iter().map(|r| r.doc_id).collect();
+    doc_ids.sort();
+    assert_eq!(doc_ids, vec!["doc-2", "doc-3"]);
+
+    let err = service.delete_by_tag(Language::Ja, "env:staging").unwrap_err();
+    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
+  }
+
+  #[test]
+  fn service_clear_index_removes_documents() {
+    let (temp_dir, service) = create_english_service();
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Hello world"),
+      Document::new("doc-2", "src-1", "Hello again"),
+    ];
+    service.index_documents(&docs).expect("Indexing failed");
+    service.reload(Language::En).expect("Reload failed");
+    assert_eq!(service.search("hello", 10).unwrap().len(), 2);
+
+    service.clear_index(Language::En).expect("Clear failed");
+    assert_eq!(service.document_count(Language::En).unwrap(), 0);
+    assert!(service.search("hello", 10).unwrap().is_empty());
+
+    // Index still opens with the same schema after clearing
+    drop(service);
+    let service = WakeruService::init(&create_english_only_config(&temp_dir))
+      .expect("Failed to reopen service");
+    assert_eq!(service.document_count(Language::En).unwrap(), 0);
+
+    let err = service.clear_index(Language::Ja).unwrap_err();
+    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
+  }
+
+  #[test]
+  fn service_reload_unsupported_language() {
+    let (_temp_dir, service) = create_english_service();
+
+    let err = service.reload(Language::Ja).unwrap_err();
+    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
+  }
+
+  #[test]
+  fn service_search_with_language() {
+    let (_temp_dir, service) = create_english_service();
+
+    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
+    service.index_documents(&docs).expect("Indexing failed");
+
+    let result = service.search_with_language(Language::En, "hello", 10);
+    assert!(result.is_ok());
+  }
+
+  #[test]
+  fn service_search_unsupported_language() {
+    let (_temp_dir, service) = create_english_service();
+
+    let result = service.search_with_language(Language::Ja, "hello", 10);
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
+  }
+
+  #[test]
+  fn service_search_tokens_or_default_language() {
+    let (_temp_dir, service) = create_english_service();
+
+    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
+    service.index_documents(&docs).expect("Indexing failed");
+
+    let result = service.search_tokens_or("hello", 10);
+    assert!(result.is_ok());
+  }
+
+  #[test]
+  fn service_search_tokens_or_with_language() {
+    let (_temp_dir, service) = create_english_service();
+
+    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
+    service.index_documents(&docs).expect("Indexing failed");
+
+    let result = service.search_tokens_or_with_language(Language::En, "hello", 10);
+    assert!(result.is_ok());
+  }
+
+  #[test]
+  fn service_find_similar_excludes_source_document() {
+    let (_temp_dir, service) = create_english_service();
+
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Tokyo tower at night"),
+      Document::new("doc-2", "src-2", "Tokyo tower at dawn"),
+      Document::new("doc-3", "src-3", "Kyoto temples"),
+    ];
+    service.index_documents(&docs).expect("Indexing failed");
+    service.reload(Language::En).expect("Reload failed");
+
+    let results = service.find_similar(Language::En, "doc-1", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-2");
+
+    let err = service.find_similar(Language::Ja, "doc-1", 10).unwrap_err();
+    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
+  }
+
+  #[test]
+  fn service_search_tokens_or_unsupported_language() {
+    let (_temp_dir, service) = create_english_service();
+
+    let result = service.search_tokens_or_with_language(Language::Ja, "hello", 10);
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
   }
 
-  // ─── Initialization Tests ──────────────────────────────────────────────────────────
-
   #[test]
-  fn service_initializes_with_english_only() {
+  fn service_search_with_count_returns_total() {
     let (_temp_dir, service) = create_english_service();
 
-    // Confirm default language is English
-    assert_eq!(service.default_language(), Language::En);
+    let docs: Vec<_> = (0..4)
+      .map(|i| Document::new(format!("doc-{i}"), "src-1", format!("Hello number {i}")))
+      .collect();
+    service.index_documents(&docs).expect("Indexing failed");
+    service.reload(Language::En).expect("Reload failed");
 
-    // Confirm English is supported
-    assert!(service.is_language_supported(Language::En));
+    let (results, total) = service.search_with_count("hello", 3).unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(total, 4);
 
-    // Japanese is not supported (no dictionary)
-    assert!(!service.is_language_supported(Language::Ja));
+    let err = service.search_with_count_with_language(Language::Ja, "hello", 3).unwrap_err();
+    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
   }
 
   #[test]
-  fn service_supported_languages() {
+  fn service_search_filtered_applies_min_score() {
     let (_temp_dir, service) = create_english_service();
 
-    let languages = service.supported_languages();
-    assert_eq!(languages.len(), 1);
-    assert!(languages.contains(&Language::En));
+    let docs = vec![
+      Document::new("doc-1", "src-1", "Hello world"),
+      Document::new("doc-2", "src-1", "Hello there"),
+    ];
+    service.index_documents(&docs).expect("Indexing failed");
+    service.reload(Language::En).expect("Reload failed");
+
+    let results = service.search_filtered("hello world", 10, None).unwrap();
+    assert_eq!(results.len(), 2);
+
+    let results = service.search_filtered("hello world", 10, Some(results[0].score)).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+
+    let err = service.search_filtered_with_language(Language::Ja, "hello", 10, None).unwrap_err();
+    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
   }
 
   #[test]
-  fn service_dictionary_manager_is_none_for_english_only() {
-    let (_temp_dir, service) = create_english_service();
+  fn service_search_limit_is_capped_at_max_limit() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let mut config = create_english_only_config(&temp_dir);
+    config.search.default_limit = 2;
+    config.search.max_limit = 3;
 
-    // Dictionary manager does not exist for English only
-    assert!(service.dictionary_manager().is_none());
+    {
+      let service = WakeruService::init(&config).expect("Initialization failed");
+      let docs: Vec<Document> = (0..5)
+        .map(|i| Document::new(format!("doc-{i}"), format!("src-{i}"), "Kyoto old temples"))
+        .collect();
+      service.index_documents(&docs).expect("Indexing failed");
+    }
+
+    let service = WakeruService::init(&config).expect("Initialization failed");
+    let huge = 1_000_000;
+
+    assert_eq!(
+      service.search("kyoto", huge).expect("Search failed").len(),
+      3
+    );
+    assert_eq!(
+      service.search_tokens_or("kyoto", huge).expect("Search failed").len(),
+      3
+    );
+    assert_eq!(
+      service.search_tokens_and("kyoto temples", huge).expect("Search failed").len(),
+      3
+    );
+    assert_eq!(
+      service.smart_search("kyoto", huge).expect("Search failed").len(),
+      3
+    );
+
+    // Limits within the cap are kept
+    assert_eq!(
+      service.search_tokens_or("kyoto", 2).expect("Search failed").len(),
+      2
+    );
   }
 
-  // ─── Accessor Tests ────────────────────────────────────────────────────────
+  #[test]
+  fn service_search_zero_limit_uses_default_limit() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let mut config = create_english_only_config(&temp_dir);
+    config.search.default_limit = 2;
+    config.search.max_limit = 4;
+
+    let service = WakeruService::init(&config).expect("Initialization failed");
+    let docs: Vec<Document> = (0..5)
+      .map(|i| Document::new(format!("doc-{i}"), format!("src-{i}"), "Kyoto old temples"))
+      .collect();
+    service.index_documents(&docs).expect("Indexing failed");
+    service.reload(Language::En).expect("Reload failed");
+
+    assert_eq!(service.search("kyoto", 0).expect("Search failed").len(), 2);
+    assert_eq!(
+      service.search_tokens_or("kyoto", 0).expect("Search failed").len(),
+      2
+    );
+    // Oversized limits are still capped
+    assert_eq!(
+      service.search("kyoto", 100).expect("Search failed").len(),
+      4
+    );
+  }
 
   #[test]
-  fn service_index_manager_accessor() {
-    let (_temp_dir, service) = create_english_service();
+  fn service_search_paged_splits_results_into_pages() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let config = create_english_only_config(&temp_dir);
 
-    // English IndexManager can be retrieved
-    let index_manager = service.index_manager(Language::En);
-    assert!(index_manager.is_some());
-    assert_eq!(index_manager.unwrap().language(), Language::En);
+    {
+      let service = WakeruService::init(&config).expect("Initialization failed");
+      let docs: Vec<Document> = (1..=5)
+        .map(|i| Document::new(format!("doc-{i}"), format!("src-{i}"), "kyoto ".repeat(i)))
+        .collect();
+      service.index_documents(&docs).expect("Indexing failed");
+    }
 
-    // Japanese IndexManager does not exist
-    assert!(service.index_manager(Language::Ja).is_none());
+    let service = WakeruService::init(&config).expect("Initialization failed");
+    let page1 = service.search_paged("kyoto", 2, 0).expect("Search failed");
+    let page2 = service.search_paged("kyoto", 2, 2).expect("Search failed");
+    let page3 = service.search_paged("kyoto", 2, 4).expect("Search failed");
+
+    assert_eq!(page1.len(), 2);
+    assert_eq!(page2.len(), 2);
+    assert_eq!(page3.len(), 1);
+
+    // No overlap, and scores never increase across page boundaries
+    let pages = [page1, page2, page3].concat();
+    let mut ids: Vec<&str> = pages.iter().map(|r| r.doc_id.as_str()).collect();
+    assert!(pages.windows(2).all(|w| w[0].score >= w[1].score));
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(ids.len(), 5);
   }
 
-  #[test]
-  fn service_search_engine_accessor() {
-    let (_temp_dir, service) = create_english_service();
+  // ─── Multi-Language Search Tests ─────────────────────────────────────────────
+
+  fn scored(doc_id: &str, score: f32) -> SearchResult {
+    SearchResult {
+      doc_id: doc_id.to_string(),
+      source_id: "src".to_string(),
+      score,
+      text: String::new(),
+      metadata: Default::default(),
+      match_kind: None,
+      matched_terms: Vec::new(),
+    }
+  }
 
-    // English SearchEngine can be retrieved
-    let search_engine = service.search_engine(Language::En);
-    assert!(search_engine.is_some());
-    assert_eq!(search_engine.unwrap().language(), Language::En);
+  #[test]
+  fn merge_language_results_ranks_by_raw_score() {
+    let ja = vec![scored("ja-1", 12.0), scored("ja-2", 10.0)];
+    let en = vec![scored("en-1", 3.0), scored("en-2", 1.0)];
 
-    // Japanese SearchEngine does not exist
-    assert!(service.search_engine(Language::Ja).is_none());
+    let merged = merge_language_results(vec![ja, en], 3, false);
+    let ids: Vec<&str> = merged.iter().map(|r| r.doc_id.as_str()).collect();
+    assert_eq!(ids, ["ja-1", "ja-2", "en-1"]);
   }
 
   #[test]
-  fn service_is_language_supported() {
-    let (_temp_dir, service) = create_english_service();
+  fn merge_language_results_normalizes_per_language() {
+    let ja = vec![scored("ja-1", 12.0), scored("ja-2", 10.0)];
+    let en = vec![
+      scored("en-1", 3.0),
+      scored("en-2", 2.5),
+      scored("en-3", 1.0),
+    ];
 
-    assert!(service.is_language_supported(Language::En));
-    assert!(!service.is_language_supported(Language::Ja));
+    let merged = merge_language_results(vec![ja, en], 4, true);
+    let ids: Vec<&str> = merged.iter().map(|r| r.doc_id.as_str()).collect();
+    // Top hits of each language tie at 1.0 (doc_id order), bottoms drop to 0.0
+    assert_eq!(ids, ["en-1", "ja-1", "en-2", "en-3"]);
+    assert_eq!(merged[0].score, 1.0);
+    assert!((merged[2].score - 0.75).abs() < 1e-6);
   }
 
-  // ─── Document Addition Tests ────────────────────────────────────────────────
+  #[test]
+  fn merge_language_results_single_hit_normalizes_to_one() {
+    let merged = merge_language_results(vec![vec![scored("en-1", 0.3)]], 10, true);
+    assert_eq!(merged[0].score, 1.0);
+  }
 
   #[test]
-  fn service_index_documents_default_language() {
-    let (_temp_dir, service) = create_english_service();
+  fn search_all_languages_merges_japanese_and_english_hits() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let Some(fixture_dir) = prepare_fixture_cache_dir(&temp_dir) else {
+      return;
+    };
 
-    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
+    let mut config = create_english_only_config(&temp_dir);
+    config.index.languages = vec![Language::Ja, Language::En];
+    config.dictionary.cache_dir = Some(fixture_dir);
+
+    let service = WakeruService::init(&config).expect("Initialization failed");
+    service
+      .index_documents_with_language(
+        Language::Ja,
+        &[Document::new("ja-1", "src-1", "東京は日本の首都です。")],
+      )
+      .expect("Indexing failed");
+    service
+      .index_documents_with_language(
+        Language::En,
+        &[Document::new(
+          "en-1",
+          "src-2",
+          "Tokyo is the capital of Japan",
+        )],
+      )
+      .expect("Indexing failed");
+    service.reload_all().expect("Reload failed");
+
+    for normalize_scores in [false, true] {
+      let results = service
+        .search_all_languages_with_options("東京 Tokyo", 10, normalize_scores)
+        .expect("Search failed");
+      let mut ids: Vec<&str> = results.iter().map(|r| r.doc_id.as_str()).collect();
+      ids.sort_unstable();
+      assert_eq!(ids, ["en-1", "ja-1"]);
+    }
 
-    let result = service.index_documents(&docs);
-    assert!(result.is_ok());
+    let top = service.search_all_languages("東京 Tokyo", 1).expect("Search failed");
+    assert_eq!(top.len(), 1);
   }
 
   #[test]
-  fn service_index_documents_with_language() {
+  fn search_all_languages_with_single_language() {
     let (_temp_dir, service) = create_english_service();
-
-    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
-
-    let result = service.index_documents_with_language(Language::En, &docs);
-    assert!(result.is_ok());
+    service
+      .index_documents(&[Document::new("doc-1", "src-1", "Hello world")])
+      .expect("Indexing failed");
+    service.reload_all().expect("Reload failed");
+
+    let results = service.search_all_languages("hello", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
   }
 
+  // ─── Segment Info Tests ──────────────────────────────────────────────────────
+
   #[test]
-  fn service_index_documents_unsupported_language() {
+  fn service_segment_info_reflects_commits() {
     let (_temp_dir, service) = create_english_service();
 
-    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
-
-    let result = service.index_documents_with_language(Language::Ja, &docs);
-    assert!(result.is_err());
+    service
+      .index_documents(&[Document::new("doc-1", "src-1", "Hello world")])
+      .expect("Indexing failed");
+    service
+      .index_documents(&[Document::new("doc-2", "src-1", "Hello again")])
+      .expect("Indexing failed");
 
-    let err = result.unwrap_err();
-    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
+    let segments = service.segment_info().expect("Failed to get segment info");
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments.iter().map(|s| s.num_docs).sum::<u32>(), 2);
   }
 
   #[test]
-  fn service_index_documents_with_metadata() {
+  fn service_segment_info_unsupported_language() {
     let (_temp_dir, service) = create_english_service();
 
-    let docs = vec![
-      Document::new("doc-1", "src-1", "Tokyo is the capital")
-        .with_metadata("author", json!("alice"))
-        .with_tag("category:geo"),
-    ];
-
-    let result = service.index_documents(&docs);
-    assert!(result.is_ok());
+    let err = service.segment_info_with_language(Language::Ja).unwrap_err();
+    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
   }
 
-  // ─── Search Tests ────────────────────────────────────────────────────────────
+  // ─── Index Stats Tests ───────────────────────────────────────────────────────
 
   #[test]
-  fn service_search_default_language() {
+  fn service_document_count_and_index_stats() {
     let (_temp_dir, service) = create_english_service();
 
-    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
-    service.index_documents(&docs).expect("Indexing failed");
-
-    // SearchEngine is created at indexing time,
-    // so documents added afterwards cannot be searched (Reader is not reloaded)
-    // Here we just check that no error occurs
-    let result = service.search("hello", 10);
-    assert!(result.is_ok());
+    assert_eq!(service.document_count(Language::En).unwrap(), 0);
+
+    service
+      .index_documents(&[
+        Document::new("doc-1", "src-1", "Hello world"),
+        Document::new("doc-2", "src-1", "Hello again"),
+      ])
+      .expect("Indexing failed");
+
+    assert_eq!(service.document_count(Language::En).unwrap(), 2);
+
+    let stats = service.index_stats();
+    assert_eq!(stats.len(), 1);
+    assert_eq!(
+      stats[&Language::En],
+      IndexStats {
+        num_docs: 2,
+        num_segments: 1
+      }
+    );
   }
 
   #[test]
-  fn service_search_with_language() {
+  fn service_document_count_unsupported_language() {
     let (_temp_dir, service) = create_english_service();
 
-    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
-    service.index_documents(&docs).expect("Indexing failed");
+    let err = service.document_count(Language::Ja).unwrap_err();
+    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
+  }
 
-    let result = service.search_with_language(Language::En, "hello", 10);
-    assert!(result.is_ok());
+  // ─── Close Tests ─────────────────────────────────────────────────────────────
+
+  #[test]
+  fn close_then_reopen_preserves_documents() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let config = create_english_only_config(&temp_dir);
+
+    let service = WakeruService::init(&config).expect("Failed to initialize WakeruService");
+    let documents = vec![
+      Document::new("doc-1", "src-1", "Tokyo Tower is a famous landmark"),
+      Document::new("doc-2", "src-2", "Osaka castle"),
+    ];
+    service.index_documents(&documents).expect("Failed to index documents");
+    service.close().expect("Failed to close service");
+
+    let reopened = WakeruService::init(&config).expect("Failed to reopen WakeruService");
+    let results = reopened.search("tokyo", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+    reopened.close().expect("Failed to close service");
   }
 
+  // ─── Legacy Index Migration Tests ────────────────────────────────────────────
+
   #[test]
-  fn service_search_unsupported_language() {
-    let (_temp_dir, service) = create_english_service();
+  fn service_init_does_not_create_legacy_default_index() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let config = create_english_only_config(&temp_dir);
 
-    let result = service.search_with_language(Language::Ja, "hello", 10);
-    assert!(result.is_err());
+    let _service = WakeruService::init(&config).expect("Initialization failed");
 
-    let err = result.unwrap_err();
-    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
+    assert!(config.index_path_for_language(Language::En).exists());
+    assert!(!temp_dir.path().join("index").join("default").exists());
   }
 
   #[test]
-  fn service_search_tokens_or_default_language() {
+  fn service_migrate_legacy_default_index_without_legacy_index() {
     let (_temp_dir, service) = create_english_service();
 
-    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
-    service.index_documents(&docs).expect("Indexing failed");
-
-    let result = service.search_tokens_or("hello", 10);
-    assert!(result.is_ok());
+    let report = service.migrate_legacy_default_index(Language::En).expect("Migration failed");
+    assert!(report.is_none());
   }
 
   #[test]
-  fn service_search_tokens_or_with_language() {
-    let (_temp_dir, service) = create_english_service();
+  fn service_migrate_legacy_default_index_relocates_documents() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let config = create_english_only_config(&temp_dir);
+    let legacy_path = temp_dir.path().join("index").join("default");
 
-    let docs = vec![Document::new("doc-1", "src-1", "Hello world")];
-    service.index_documents(&docs).expect("Indexing failed");
+    // 1. Create a legacy `default` index
+    {
+      let legacy = IndexManager::open_or_create(&legacy_path, Language::En, None)
+        .expect("Failed to create legacy index");
+      let docs = vec![
+        Document::new("doc-1", "src-1", "Tokyo is the capital").with_tag("category:geo"),
+        Document::new("doc-2", "src-2", "Osaka is a major city"),
+      ];
+      legacy.add_documents(&docs).expect("Indexing failed");
+      legacy.commit().expect("Commit failed");
+    }
 
-    let result = service.search_tokens_or_with_language(Language::En, "hello", 10);
-    assert!(result.is_ok());
+    // 2. Migrate into the English index (one document already exists there)
+    {
+      let service = WakeruService::init(&config).expect("Initialization failed");
+      service
+        .index_documents(&[Document::new("doc-2", "src-2", "Osaka is a major city")])
+        .expect("Indexing failed");
+
+      let report = service
+        .migrate_legacy_default_index(Language::En)
+        .expect("Migration failed")
+        .expect("Legacy index should be found");
+      assert_eq!(report.total, 2);
+      assert_eq!(report.added, 1);
+      assert_eq!(report.skipped_duplicates, 1);
+    }
+
+    // 3. Legacy directory is removed and documents are searchable
+    assert!(!legacy_path.exists());
+    {
+      let service = WakeruService::init(&config).expect("Initialization failed");
+      let results = service.search("tokyo", 10).expect("Search failed");
+      assert_eq!(results.len(), 1);
+      assert_eq!(results[0].doc_id, "doc-1");
+      assert_eq!(results[0].source_id, "src-1");
+      assert_eq!(results[0].metadata["tags"], json!(["category:geo"]));
+    }
   }
 
   #[test]
-  fn service_search_tokens_or_unsupported_language() {
-    let (_temp_dir, service) = create_english_service();
+  fn service_migrate_legacy_default_index_keeps_legacy_index_with_invalid_documents() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let config = create_english_only_config(&temp_dir);
+    let legacy_path = temp_dir.path().join("index").join("default");
 
-    let result = service.search_tokens_or_with_language(Language::Ja, "hello", 10);
-    assert!(result.is_err());
+    // Legacy index with a valid document and one without text (invalid today)
+    {
+      let legacy = IndexManager::open_or_create(&legacy_path, Language::En, None)
+        .expect("Failed to create legacy index");
+      legacy
+        .add_documents(&[Document::new("doc-1", "src-1", "Tokyo is the capital")])
+        .expect("Indexing failed");
+      // Keep the index with its registered tokenizers, but release the writer lock
+      let index = legacy.index().clone();
+      let fields = *legacy.fields();
+      legacy.close().expect("Close failed");
+
+      let mut writer: tantivy::IndexWriter =
+        index.writer(15_000_000).expect("Failed to create writer");
+      writer
+        .add_document(tantivy::doc!(fields.id => "doc-2", fields.source_id => "src-2"))
+        .expect("Indexing failed");
+      writer.commit().expect("Commit failed");
+    }
 
-    let err = result.unwrap_err();
-    assert!(matches!(err, WakeruError::UnsupportedLanguage { .. }));
+    let service = WakeruService::init(&config).expect("Initialization failed");
+    let report = service
+      .migrate_legacy_default_index(Language::En)
+      .expect("Migration failed")
+      .expect("Legacy index should be found");
+    assert_eq!(report.added, 1);
+    assert_eq!(report.invalid, 1);
+
+    assert!(legacy_path.join(META_JSON).exists());
+    assert_eq!(service.search("tokyo", 10).expect("Search failed").len(), 1);
   }
 
   // ─── Integration Tests (Index -> Search) ──────────────────────────────────────
@@ -527,6 +2086,192 @@ mod tests {
     }
   }
 
+  #[test]
+  fn service_search_applies_overlap_penalty() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let mut config = create_english_only_config(&temp_dir);
+
+    {
+      let service = WakeruService::init(&config).expect("Initialization failed");
+      let docs = vec![
+        Document::new("a-1", "src-a", "tokyo tokyo tower landmark"),
+        Document::new("a-2", "src-a", "tokyo tokyo tower landmark view"),
+        Document::new(
+          "b-1",
+          "src-b",
+          "tokyo has many parks and museums and gardens",
+        ),
+      ];
+      service.index_documents(&docs).expect("Indexing failed");
+    }
+
+    let doc_ids = |config: &WakeruConfig| -> Vec<String> {
+      let service = WakeruService::init(config).expect("Initialization failed");
+      let results = service.search("tokyo", 10).expect("Search failed");
+      results.into_iter().map(|r| r.doc_id).collect()
+    };
+
+    // Without penalty: overlapping same-source chunks crowd the top
+    assert_eq!(doc_ids(&config), vec!["a-1", "a-2", "b-1"]);
+
+    // With penalty: the redundant chunk falls behind the distinct-source chunk
+    config.search.overlap_penalty = Some(OverlapPenaltyConfig {
+      factor: 0.1,
+      min_overlap: 0.5,
+    });
+    assert_eq!(doc_ids(&config), vec!["a-1", "b-1", "a-2"]);
+
+    // A page is cut after re-ranking, so a-2 is still penalized for overlapping a-1
+    // even though a-1 is on the previous page
+    let service = WakeruService::init(&config).expect("Initialization failed");
+    let results = service.search_paged("tokyo", 2, 1).expect("Search failed");
+    let doc_ids: Vec<String> = results.into_iter().map(|r| r.doc_id).collect();
+    assert_eq!(doc_ids, vec!["b-1", "a-2"]);
+  }
+
+  #[test]
+  fn service_smart_search_tags_match_kind() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let config = create_english_only_config(&temp_dir);
+
+    {
+      let service = WakeruService::init(&config).expect("Initialization failed");
+      let docs = vec![
+        Document::new("phrase", "src-1", "Kyoto old temples"),
+        Document::new("some", "src-2", "Kyoto station"),
+      ];
+      service.index_documents(&docs).expect("Indexing failed");
+    }
+
+    let service = WakeruService::init(&config).expect("Initialization failed");
+    let results = service.smart_search("kyoto old", 10).expect("Search failed");
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].doc_id, "phrase");
+    assert_eq!(results[0].match_kind, Some(MatchKind::Phrase));
+    assert_eq!(results[1].match_kind, Some(MatchKind::SomeTerms));
+  }
+
+  #[test]
+  fn service_search_tokens_and_requires_all_tokens() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let config = create_english_only_config(&temp_dir);
+
+    {
+      let service = WakeruService::init(&config).expect("Initialization failed");
+      let docs = vec![
+        Document::new("both", "src-1", "Kyoto old temples"),
+        Document::new("kyoto-only", "src-2", "Kyoto station"),
+      ];
+      service.index_documents(&docs).expect("Indexing failed");
+    }
+
+    let service = WakeruService::init(&config).expect("Initialization failed");
+    let results = service.search_tokens_and("kyoto temples", 10).expect("Search failed");
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "both");
+
+    let result = service.search_tokens_and_with_language(Language::Ja, "test", 10);
+    assert!(matches!(
+      result,
+      Err(WakeruError::UnsupportedLanguage {
+        language: Language::Ja
+      })
+    ));
+  }
+
+  #[test]
+  fn service_analyze_query_maps_input_to_indexed_form() {
+    let (_temp_dir, service) = create_english_service();
+
+    let pairs = service.analyze_query("Running").expect("Analysis failed");
+    assert_eq!(pairs, vec![("Running".to_string(), "run".to_string())]);
+
+    let result = service.analyze_query_with_language(Language::Ja, "test");
+    assert!(matches!(
+      result,
+      Err(WakeruError::UnsupportedLanguage {
+        language: Language::Ja
+      })
+    ));
+  }
+
+  #[test]
+  fn service_explain_query_returns_search_tokens() {
+    let (_temp_dir, service) = create_english_service();
+
+    let tokens = service.explain_query("Tokyo Towers").expect("Analysis failed");
+    assert_eq!(tokens, vec!["tokyo", "tower"]);
+
+    let result = service.explain_query_with_language(Language::Ja, "test");
+    assert!(matches!(
+      result,
+      Err(WakeruError::UnsupportedLanguage {
+        language: Language::Ja
+      })
+    ));
+  }
+
+  #[test]
+  fn service_smart_search_unsupported_language() {
+    let (_temp_dir, service) = create_english_service();
+
+    let result = service.smart_search_with_language(Language::Ja, "test", 10);
+    assert!(matches!(
+      result,
+      Err(WakeruError::UnsupportedLanguage {
+        language: Language::Ja
+      })
+    ));
+  }
+
+  #[test]
+  fn service_index_documents_larger_than_batch_commit_size() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let mut config = create_english_only_config(&temp_dir);
+    config.index.batch_commit_size = 10;
+
+    {
+      let service = WakeruService::init(&config).expect("Initialization failed");
+      let docs: Vec<Document> =
+        (0..25).map(|i| Document::new(format!("doc-{i}"), "src-1", "bulk indexed chunk")).collect();
+      service.index_documents(&docs).expect("Indexing failed");
+
+      // All documents are committed when the call returns
+      let index_manager = service.index_manager(Language::En).expect("English index");
+      assert_eq!(index_manager.reader().searcher().num_docs(), 25);
+    }
+
+    let service = WakeruService::init(&config).expect("Initialization failed");
+    let results = service.search("bulk", 100).expect("Search failed");
+    assert_eq!(results.len(), 25);
+  }
+
+  #[test]
+  fn service_search_respects_min_token_len() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+    let mut config = create_english_only_config(&temp_dir);
+    config.index.min_token_len = 2;
+
+    {
+      let service = WakeruService::init(&config).expect("Initialization failed");
+      let docs = vec![Document::new("doc-1", "src-1", "A cat went to town")];
+      service.index_documents(&docs).expect("Indexing failed");
+    }
+
+    let service = WakeruService::init(&config).expect("Initialization failed");
+
+    // "a" is dropped on both sides -> no query terms
+    let results = service.search_tokens_or("a", 10).expect("Search failed");
+    assert!(results.is_empty());
+
+    // "to" survives
+    let results = service.search_tokens_or("to", 10).expect("Search failed");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].doc_id, "doc-1");
+  }
+
   // ─── Error Handling Tests ────────────────────────────────────────────
 
   #[test]
@@ -549,10 +2294,13 @@ mod tests {
     {
       let service = WakeruService::init(&config).expect("Initialization failed");
       let docs1 = vec![Document::new("doc-1", "src-1", "First content")];
-      service.index_documents(&docs1).expect("Indexing failed");
+      let report = service.index_documents(&docs1).expect("Indexing failed");
+      assert_eq!(report.added, 1);
 
       let docs2 = vec![Document::new("doc-1", "src-1", "Second content")];
-      service.index_documents(&docs2).expect("Indexing failed"); // Duplicates are skipped
+      let report = service.index_documents(&docs2).expect("Indexing failed"); // Duplicates are skipped
+      assert_eq!(report.added, 0);
+      assert_eq!(report.skipped_duplicates, 1);
     }
 
     // 2. Confirm first content is retained
@@ -576,6 +2324,7 @@ mod tests {
       dictionary: DictionaryConfig {
         preset: DictionaryPreset::Ipadic,
         cache_dir: Some(temp_dir.path().join("dict")),
+        user_dict: None,
       },
       index: IndexConfig {
         data_dir: temp_dir.path().join("index"),
@@ -583,10 +2332,26 @@ mod tests {
         batch_commit_size: 1000,
         languages: vec![], // Invalid: Empty language list
         default_language: Language::En,
+        min_token_len: 1,
+        min_gram: 1,
+        max_gram: 1,
+        store_compression: StoreCompression::Lz4,
+        english_exact_field: false,
+        japanese_reading_field: false,
+        tokenizer_mode: TokenizerMode::Morphological,
+        stemmer_language: None,
+        english_stop_words: false,
+        case_insensitive_source_id: false,
+        text_record_option: TextRecordOption::WithPositions,
+        with_offsets: false,
+        tags_in_metadata: true,
+        open_mode: OpenMode::CreateIfMissing,
       },
       search: SearchConfig {
         default_limit: 10,
         max_limit: 100,
+        overlap_penalty: None,
+        bm25: None,
       },
       logging: LoggingConfig {
         level: LogLevel::Info,
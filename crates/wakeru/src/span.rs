@@ -0,0 +1,129 @@
+//! Tracing Spans
+//!
+//! Indexing and search operations run inside `info` spans carrying the language, input
+//! size and result size, plus the elapsed time recorded when the operation finishes.
+//! Any `tracing` subscriber (e.g. `tracing-subscriber` fmt, OpenTelemetry) can then show
+//! per-operation timing without API changes.
+
+use std::time::Instant;
+
+use tracing::Span;
+
+/// Runs `f` inside `span` and records its duration in the span's `elapsed_ms` field
+///
+/// The span must declare `elapsed_ms = tracing::field::Empty`. Other declared fields
+/// can be recorded from `f` through `Span::current()`.
+pub(crate) fn timed<T>(span: &Span, f: impl FnOnce() -> T) -> T {
+  let _entered = span.enter();
+  let start = Instant::now();
+  let result = f();
+  span.record("elapsed_ms", start.elapsed().as_secs_f64() * 1000.0);
+  result
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+  use std::fmt::Debug;
+  use std::sync::{Arc, Mutex};
+
+  use tracing::field::{Field, Visit};
+  use tracing::span::{Attributes, Id, Record};
+  use tracing::{Subscriber, subscriber};
+  use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+  use tracing_subscriber::registry::LookupSpan;
+
+  use crate::config::Language;
+  use crate::indexer::IndexManager;
+  use crate::models::Document;
+  use crate::searcher::SearchEngine;
+
+  /// Recorded span: name and fields (values formatted with `Debug`)
+  type RecordedSpan = (String, HashMap<String, String>);
+
+  /// Layer recording every span and the fields recorded on it
+  #[derive(Clone, Default)]
+  struct RecordingLayer {
+    spans: Arc<Mutex<Vec<RecordedSpan>>>,
+  }
+
+  struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+  impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+      self.0.insert(field.name().to_string(), format!("{value:?}"));
+    }
+  }
+
+  impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for RecordingLayer {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+      let mut fields = HashMap::new();
+      attrs.record(&mut FieldVisitor(&mut fields));
+      let mut spans = self.spans.lock().unwrap();
+      spans.push((attrs.metadata().name().to_string(), fields));
+      // Remember where this span is stored, for `on_record`
+      ctx.span(id).unwrap().extensions_mut().insert(spans.len() - 1);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+      let span = ctx.span(id).unwrap();
+      let position = *span.extensions().get::<usize>().unwrap();
+      values.record(&mut FieldVisitor(
+        &mut self.spans.lock().unwrap()[position].1,
+      ));
+    }
+  }
+
+  impl RecordingLayer {
+    fn span(&self, name: &str) -> HashMap<String, String> {
+      let spans = self.spans.lock().unwrap();
+      let (_, fields) = spans
+        .iter()
+        .find(|(span_name, _)| span_name == name)
+        .unwrap_or_else(|| panic!("span {name} was not emitted"));
+      fields.clone()
+    }
+  }
+
+  #[test]
+  fn indexing_and_search_emit_timed_spans() {
+    let layer = RecordingLayer::default();
+    let subscriber = tracing_subscriber::registry().with(layer.clone());
+
+    subscriber::with_default(subscriber, || {
+      let tmp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+      let index_manager = IndexManager::open_or_create(tmp_dir.path(), Language::En, None)
+        .expect("Failed to create index");
+      let docs = vec![
+        Document::new("doc-1", "src-1", "Tokyo tower"),
+        Document::new("doc-2", "src-1", "Osaka castle"),
+      ];
+      index_manager.add_documents(&docs).expect("Failed to add documents");
+      index_manager.commit().expect("Failed to commit");
+
+      let search_engine =
+        SearchEngine::new(index_manager.index(), *index_manager.fields(), Language::En)
+          .expect("Failed to create search engine");
+      search_engine.search("tokyo", 10).expect("Search failed");
+      search_engine.search_tokens_or("tokyo tower", 10).expect("Search failed");
+    });
+
+    let add = layer.span("add_documents");
+    assert_eq!(add["language"], "en");
+    assert_eq!(add["num_docs"], "2");
+    assert_eq!(add["added"], "2");
+    assert!(add.contains_key("elapsed_ms"));
+
+    let search = layer.span("search");
+    assert_eq!(search["language"], "en");
+    assert_eq!(search["limit"], "10");
+    assert_eq!(search["num_results"], "1");
+    assert!(search.contains_key("elapsed_ms"));
+
+    let search_or = layer.span("search_tokens_or");
+    assert_eq!(search_or["language"], "en");
+    assert_eq!(search_or["num_terms"], "2");
+    assert_eq!(search_or["num_results"], "1");
+    assert!(search_or.contains_key("elapsed_ms"));
+  }
+}
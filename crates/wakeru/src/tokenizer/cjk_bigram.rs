@@ -0,0 +1,171 @@
+//! Dictionary-free CJK Bigram Tokenizer
+//!
+//! Tantivy `Tokenizer` that splits CJK runs into overlapping 2-grams and other
+//! alphanumeric runs into words. Used by `TokenizerMode::CjkBigram` for Japanese
+//! search without a morphological dictionary.
+
+use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
+
+/// Splits text into overlapping CJK bigrams and alphanumeric words
+///
+/// - CJK runs (kanji, kana, hangul): overlapping 2-grams ("東京タワー" -> "東京", "京タ",
+///   "タワ", "ワー"); a single-char run is emitted as is
+/// - Other alphanumeric runs: one token per run ("Rust2024")
+/// - Everything else (spaces, punctuation, symbols) separates tokens
+///
+/// Does not lowercase; combine with `LowerCaser` for mixed Latin text.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CjkBigramTokenizer;
+
+/// Token stream produced by [`CjkBigramTokenizer`]
+pub struct CjkBigramTokenStream {
+  /// Iterator of (Token text, Start byte, End byte)
+  tokens: std::vec::IntoIter<(String, usize, usize)>,
+
+  /// Tantivy's Token (overwritten and reused every time)
+  token: Token,
+}
+
+impl Tokenizer for CjkBigramTokenizer {
+  type TokenStream<'a> = CjkBigramTokenStream;
+
+  fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+      if is_cjk(c) {
+        // Collect (Start byte, End byte) of each char in the CJK run
+        let mut run = Vec::new();
+        while let Some(&(offset, c)) = chars.peek().filter(|(_, c)| is_cjk(*c)) {
+          run.push((offset, offset + c.len_utf8()));
+          chars.next();
+        }
+
+        if let [(from, to)] = run[..] {
+          tokens.push((text[from..to].to_string(), from, to));
+        }
+        for pair in run.windows(2) {
+          let (from, to) = (pair[0].0, pair[1].1);
+          tokens.push((text[from..to].to_string(), from, to));
+        }
+      } else if c.is_alphanumeric() {
+        let mut end = start;
+        while let Some(&(offset, c)) =
+          chars.peek().filter(|(_, c)| c.is_alphanumeric() && !is_cjk(*c))
+        {
+          end = offset + c.len_utf8();
+          chars.next();
+        }
+        tokens.push((text[start..end].to_string(), start, end));
+      } else {
+        chars.next();
+      }
+    }
+
+    CjkBigramTokenStream {
+      tokens: tokens.into_iter(),
+      token: Token::default(),
+    }
+  }
+}
+
+impl TokenStream for CjkBigramTokenStream {
+  fn advance(&mut self) -> bool {
+    if let Some((text, start, end)) = self.tokens.next() {
+      self.token.text = text;
+      self.token.offset_from = start;
+      self.token.offset_to = end;
+      // Token::default() starts at usize::MAX, so wrap around to 0 for the first token
+      self.token.position = self.token.position.wrapping_add(1);
+      self.token.position_length = 1;
+      true
+    } else {
+      false
+    }
+  }
+
+  fn token(&self) -> &Token {
+    &self.token
+  }
+
+  fn token_mut(&mut self) -> &mut Token {
+    &mut self.token
+  }
+}
+
+/// Whether `c` is a CJK ideograph, kana or hangul character
+fn is_cjk(c: char) -> bool {
+  matches!(
+    c,
+    // Kanji (CJK Unified Ideographs, Extension A, Compatibility, Extension B+) and `々`
+    '\u{4E00}'..='\u{9FFF}'
+      | '\u{3400}'..='\u{4DBF}'
+      | '\u{F900}'..='\u{FAFF}'
+      | '\u{20000}'..='\u{2FFFF}'
+      | '\u{3005}'
+      // Hiragana, Katakana (including `ー`), Katakana Phonetic Extensions, half-width katakana
+      | '\u{3041}'..='\u{309F}'
+      | '\u{30A0}'..='\u{30FF}'
+      | '\u{31F0}'..='\u{31FF}'
+      | '\u{FF66}'..='\u{FF9F}'
+      // Hangul syllables and jamo
+      | '\u{AC00}'..='\u{D7AF}'
+      | '\u{1100}'..='\u{11FF}'
+      | '\u{3130}'..='\u{318F}'
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn tokens(text: &str) -> Vec<(String, usize, usize)> {
+    let mut tokenizer = CjkBigramTokenizer;
+    let mut stream = tokenizer.token_stream(text);
+    let mut tokens = Vec::new();
+    while stream.advance() {
+      let token = stream.token();
+      tokens.push((token.text.clone(), token.offset_from, token.offset_to));
+    }
+    tokens
+  }
+
+  fn texts(text: &str) -> Vec<String> {
+    tokens(text).into_iter().map(|(text, _, _)| text).collect()
+  }
+
+  #[test]
+  fn splits_cjk_run_into_overlapping_bigrams() {
+    assert_eq!(texts("東京タワー"), ["東京", "京タ", "タワ", "ワー"]);
+    // Byte offsets of each bigram
+    assert_eq!(tokens("東京タ")[1], ("京タ".to_string(), 3, 9));
+  }
+
+  #[test]
+  fn single_cjk_char_is_emitted_as_is() {
+    assert_eq!(texts("寺"), ["寺"]);
+    assert_eq!(texts("寺、京都"), ["寺", "京都"]);
+  }
+
+  #[test]
+  fn non_cjk_runs_are_words() {
+    assert_eq!(
+      texts("Rust2024で東京を歩く!"),
+      ["Rust2024", "で東", "東京", "京を", "を歩", "歩く"]
+    );
+    assert_eq!(texts("hello, world"), ["hello", "world"]);
+    assert!(texts("  、。!? ").is_empty());
+  }
+
+  #[test]
+  fn positions_are_sequential() {
+    let mut tokenizer = CjkBigramTokenizer;
+    let mut stream = tokenizer.token_stream("京都 temple");
+    let mut positions = Vec::new();
+    while stream.advance() {
+      positions.push(stream.token().position);
+    }
+    assert_eq!(positions, [0, 1]);
+  }
+}
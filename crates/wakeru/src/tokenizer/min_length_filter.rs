@@ -0,0 +1,112 @@
+//! Minimum Token Length Filter
+//!
+//! Tantivy `TokenFilter` that drops tokens shorter than a given number of characters.
+//! Used by the English analyzer to remove noisy short tokens such as "a" or "i".
+
+use tantivy::tokenizer::{Token, TokenFilter, TokenStream, Tokenizer};
+
+/// Removes tokens whose length (in characters) is less than `min_len`.
+///
+/// `min_len <= 1` keeps every non-empty token (no filtering).
+#[derive(Debug, Clone, Copy)]
+pub struct MinLengthFilter {
+  min_len: usize,
+}
+
+impl MinLengthFilter {
+  /// Creates a filter that keeps only tokens with at least `min_len` characters.
+  pub fn new(min_len: usize) -> Self {
+    Self { min_len }
+  }
+}
+
+impl TokenFilter for MinLengthFilter {
+  type Tokenizer<T: Tokenizer> = MinLengthFilterWrapper<T>;
+
+  fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+    MinLengthFilterWrapper {
+      min_len: self.min_len,
+      inner: tokenizer,
+    }
+  }
+}
+
+/// Tokenizer wrapper produced by [`MinLengthFilter`]
+#[derive(Clone)]
+pub struct MinLengthFilterWrapper<T> {
+  min_len: usize,
+  inner: T,
+}
+
+impl<T: Tokenizer> Tokenizer for MinLengthFilterWrapper<T> {
+  type TokenStream<'a> = MinLengthFilterStream<T::TokenStream<'a>>;
+
+  fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+    MinLengthFilterStream {
+      min_len: self.min_len,
+      tail: self.inner.token_stream(text),
+    }
+  }
+}
+
+/// Token stream produced by [`MinLengthFilterWrapper`]
+pub struct MinLengthFilterStream<T> {
+  min_len: usize,
+  tail: T,
+}
+
+impl<T: TokenStream> TokenStream for MinLengthFilterStream<T> {
+  fn advance(&mut self) -> bool {
+    while self.tail.advance() {
+      if self.tail.token().text.chars().count() >= self.min_len {
+        return true;
+      }
+    }
+    false
+  }
+
+  fn token(&self) -> &Token {
+    self.tail.token()
+  }
+
+  fn token_mut(&mut self) -> &mut Token {
+    self.tail.token_mut()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use tantivy::tokenizer::{SimpleTokenizer, TextAnalyzer};
+
+  fn collect_tokens(min_len: usize, text: &str) -> Vec<String> {
+    let mut analyzer = TextAnalyzer::builder(SimpleTokenizer::default())
+      .filter(MinLengthFilter::new(min_len))
+      .build();
+    let mut stream = analyzer.token_stream(text);
+    let mut tokens = Vec::new();
+    while stream.advance() {
+      tokens.push(stream.token().text.clone());
+    }
+    tokens
+  }
+
+  #[test]
+  fn min_length_filter_drops_short_tokens() {
+    let tokens = collect_tokens(2, "a cat went to town i think");
+    assert_eq!(tokens, vec!["cat", "went", "to", "town", "think"]);
+  }
+
+  #[test]
+  fn min_length_filter_of_one_keeps_all_tokens() {
+    let tokens = collect_tokens(1, "a cat went to town");
+    assert_eq!(tokens, vec!["a", "cat", "went", "to", "town"]);
+  }
+
+  #[test]
+  fn min_length_filter_counts_characters_not_bytes() {
+    // "é" is 2 bytes but 1 character
+    let tokens = collect_tokens(2, "é café");
+    assert_eq!(tokens, vec!["café"]);
+  }
+}
@@ -1,5 +1,15 @@
 //! tokenizer module
+pub mod cjk_bigram;
+pub mod min_length_filter;
+pub mod nbest;
+pub mod pos_filter;
+pub mod reading;
 pub mod vibrato_tokenizer;
 
 /// Re-exports
+pub use cjk_bigram::CjkBigramTokenizer;
+pub use min_length_filter::MinLengthFilter;
+pub use nbest::{NbestToken, analyze_nbest};
+pub use pos_filter::PosFilter;
+pub use reading::{to_katakana, token_reading};
 pub use vibrato_tokenizer::{VibratoTokenStream, VibratoTokenizer, should_index};
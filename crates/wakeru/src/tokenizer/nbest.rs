@@ -0,0 +1,115 @@
+//! N-best Morphological Analysis
+//!
+//! Returns alternative segmentations of a text together with their path costs,
+//! for disambiguation in downstream NLP (not used for indexing).
+
+use std::sync::Arc;
+
+use vibrato_rkyv::Dictionary;
+use vibrato_rkyv::Tokenizer as VibratoImpl;
+
+/// One token of an N-best segmentation path (owned)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NbestToken {
+  /// Surface form (string appearing in original text)
+  pub surface: String,
+  /// Feature (complete string including part-of-speech info)
+  pub feature: String,
+  /// Start byte offset in the original text
+  pub start_byte: usize,
+  /// End byte offset in the original text
+  pub end_byte: usize,
+}
+
+/// Analyzes `text` and returns up to `n` segmentation candidates with their path costs.
+///
+/// Candidates are ordered by cost in ascending order. **Lower cost = more likely**
+/// (the first candidate is the same as the regular 1-best result).
+///
+/// No part-of-speech filtering is applied; every token of each path is returned.
+/// Fewer than `n` candidates are returned if the lattice has fewer paths,
+/// and an empty vector is returned for empty text or `n == 0`.
+///
+/// # Arguments
+/// - `dict`: Shared dictionary (e.g. from `DictionaryManager::load()`)
+/// - `text`: Text to analyze
+/// - `n`: Maximum number of candidates
+pub fn analyze_nbest(dict: Arc<Dictionary>, text: &str, n: usize) -> Vec<(Vec<NbestToken>, i32)> {
+  if n == 0 {
+    return Vec::new();
+  }
+
+  let tokenizer = VibratoImpl::from_shared_dictionary(dict);
+  let mut worker = tokenizer.new_worker();
+  worker.reset_sentence(text);
+  worker.tokenize_nbest(n);
+
+  (0..worker.num_nbest_paths())
+    .filter_map(|path_idx| {
+      let cost = worker.path_cost(path_idx)?;
+      let tokens = worker
+        .nbest_token_iter(path_idx)?
+        .map(|token| {
+          let range = token.range_byte();
+          NbestToken {
+            surface: token.surface().to_string(),
+            feature: token.feature().to_string(),
+            start_byte: range.start,
+            end_byte: range.end,
+          }
+        })
+        .collect();
+      Some((tokens, cost))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::dictionary::in_memory_dictionary;
+
+  /// Minimal in-memory dictionary (no download required)
+  fn build_test_dictionary() -> Arc<Dictionary> {
+    let lexicon_csv = "自然,0,0,1,sizen
+言語,0,0,4,gengo
+処理,0,0,3,shori
+自然言語,0,0,6,sizengengo
+言語処理,0,0,5,gengoshori";
+    Arc::new(Dictionary::from_inner(in_memory_dictionary(lexicon_csv)))
+  }
+
+  fn surfaces(tokens: &[NbestToken]) -> Vec<&str> {
+    tokens.iter().map(|t| t.surface.as_str()).collect()
+  }
+
+  #[test]
+  fn analyze_nbest_returns_paths_ordered_by_cost() {
+    let candidates = analyze_nbest(build_test_dictionary(), "自然言語処理", 5);
+
+    assert_eq!(candidates.len(), 3);
+    assert_eq!(surfaces(&candidates[0].0), vec!["自然", "言語処理"]);
+    assert_eq!(candidates[0].1, 6);
+    assert_eq!(surfaces(&candidates[1].0), vec!["自然", "言語", "処理"]);
+    assert_eq!(candidates[1].1, 8);
+    assert_eq!(surfaces(&candidates[2].0), vec!["自然言語", "処理"]);
+    assert_eq!(candidates[2].1, 9);
+
+    // Byte offsets and features are kept
+    let second = &candidates[0].0[1];
+    assert_eq!((second.start_byte, second.end_byte), (6, 18));
+    assert_eq!(second.feature, "gengoshori");
+  }
+
+  #[test]
+  fn analyze_nbest_limits_to_n() {
+    let candidates = analyze_nbest(build_test_dictionary(), "自然言語処理", 2);
+    assert_eq!(candidates.len(), 2);
+  }
+
+  #[test]
+  fn analyze_nbest_empty_input() {
+    assert!(analyze_nbest(build_test_dictionary(), "", 5).is_empty());
+    assert!(analyze_nbest(build_test_dictionary(), "自然言語処理", 0).is_empty());
+  }
+}
@@ -0,0 +1,181 @@
+//! Part-of-speech filter deciding which morphemes are indexed
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Part-of-speech filter based on feature prefixes
+///
+/// A token is indexed when its feature string (e.g. `"名詞,固有名詞,地域,一般,..."`)
+/// starts with at least one `allow` prefix and with no `deny` prefix.
+/// `deny` takes precedence, so a broad allow (`"名詞"`) can be narrowed (`"名詞,代名詞"`).
+///
+/// [`PosFilter::default`] reproduces the built-in indexing policy (see [`should_index`]).
+///
+/// # Examples
+/// ```rust
+/// # use wakeru::tokenizer::PosFilter;
+/// // Default policy, but also index particles and drop proper nouns
+/// let filter = PosFilter::default().with_allow("助詞").with_deny("名詞,固有名詞");
+/// assert!(filter.should_index("助詞,格助詞,一般,*,*,*,が,ガ,ガ"));
+/// assert!(!filter.should_index("名詞,固有名詞,地域,一般,*,*,東京,トウキョウ,トーキョー"));
+/// ```
+///
+/// [`should_index`]: crate::tokenizer::should_index
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PosFilter {
+  /// Feature prefixes to index
+  allow: Vec<String>,
+  /// Feature prefixes never to index (takes precedence over `allow`)
+  deny: Vec<String>,
+}
+
+impl PosFilter {
+  /// Creates a filter from allow / deny feature prefixes
+  pub fn new<A, D>(allow: A, deny: D) -> Self
+  where
+    A: IntoIterator,
+    A::Item: Into<String>,
+    D: IntoIterator,
+    D::Item: Into<String>,
+  {
+    Self {
+      allow: allow.into_iter().map(Into::into).collect(),
+      deny: deny.into_iter().map(Into::into).collect(),
+    }
+  }
+
+  /// Adds a feature prefix to index (builder)
+  ///
+  /// The same prefix is removed from `deny`, so a category denied by the default
+  /// policy (e.g. `"助詞"`) can be re-enabled.
+  pub fn with_allow(mut self, prefix: impl Into<String>) -> Self {
+    let prefix = prefix.into();
+    self.deny.retain(|denied| *denied != prefix);
+    self.allow.push(prefix);
+    self
+  }
+
+  /// Adds a feature prefix never to index (builder)
+  pub fn with_deny(mut self, prefix: impl Into<String>) -> Self {
+    self.deny.push(prefix.into());
+    self
+  }
+
+  /// Feature prefixes to index
+  pub fn allow(&self) -> &[String] {
+    &self.allow
+  }
+
+  /// Feature prefixes never to index
+  pub fn deny(&self) -> &[String] {
+    &self.deny
+  }
+
+  /// Whether a token with `feature` should be indexed
+  pub fn should_index(&self, feature: &str) -> bool {
+    if self.deny.iter().any(|prefix| feature.starts_with(prefix.as_str())) {
+      return false;
+    }
+    self.allow.iter().any(|prefix| feature.starts_with(prefix.as_str()))
+  }
+
+  /// Same filter with sorted, deduplicated prefixes (order does not affect [`should_index`](Self::should_index))
+  pub(crate) fn normalized(&self) -> Self {
+    let sorted = |prefixes: &[String]| {
+      let mut prefixes = prefixes.to_vec();
+      prefixes.sort();
+      prefixes.dedup();
+      prefixes
+    };
+    Self {
+      allow: sorted(&self.allow),
+      deny: sorted(&self.deny),
+    }
+  }
+}
+
+impl fmt::Display for PosFilter {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "allow={:?}, deny={:?}", self.allow, self.deny)
+  }
+}
+
+impl Default for PosFilter {
+  /// Built-in policy: content words only
+  ///
+  /// - Deny: particles, auxiliary verbs, symbols, fillers, interjections, conjunctions,
+  ///   prefixes, adnominals, pronouns and non-independent nouns
+  /// - Allow: nouns, UniDic `Suffix,Nominal` ("寺", "駅", ...), verbs, adjectives,
+  ///   adjectival nouns (UniDic) and general adverbs
+  fn default() -> Self {
+    Self::new(
+      [
+        "名詞",
+        "接尾辞,名詞的",
+        "動詞",
+        "形容詞",
+        "形状詞",
+        "副詞,一般",
+      ],
+      [
+        "助詞",
+        "助動詞",
+        "記号",
+        "フィラー",
+        "感動詞",
+        "接続詞",
+        "接頭詞",
+        "連体詞",
+        "名詞,代名詞",
+        "名詞,非自立",
+      ],
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn default_filter_keeps_content_words_only() {
+    let filter = PosFilter::default();
+
+    assert!(filter.should_index("名詞,一般,*,*,*,*,東京,トウキョウ,トーキョー"));
+    assert!(filter.should_index("接尾辞,名詞的,一般,*,*,*,寺,テラ,寺,テラ,*,*,*,*,*,*"));
+    assert!(filter.should_index("副詞,一般,*,*,*,*,とても,トテモ,トテモ"));
+    assert!(!filter.should_index("副詞,助詞類接続,*,*,*,*,すぐ,スグ,スグ"));
+    assert!(!filter.should_index("名詞,代名詞,一般,*,*,*,これ,コレ,コレ"));
+    assert!(!filter.should_index("助詞,格助詞,一般,*,*,*,が,ガ,ガ"));
+    assert!(!filter.should_index("補助記号,句点,*,*,*,*,*,。,。"));
+  }
+
+  #[test]
+  fn deny_takes_precedence_over_allow() {
+    let filter = PosFilter::default().with_deny("名詞,固有名詞");
+
+    assert!(filter.should_index("名詞,一般,*,*,*,*,寺院,ジイン,ジイン"));
+    assert!(!filter.should_index("名詞,固有名詞,地域,一般,*,*,東京,トウキョウ,トーキョー"));
+  }
+
+  #[test]
+  fn with_allow_removes_same_deny_prefix() {
+    let filter = PosFilter::default().with_allow("助詞");
+
+    assert!(filter.should_index("助詞,格助詞,一般,*,*,*,が,ガ,ガ"));
+    assert!(!filter.deny().contains(&"助詞".to_string()));
+    // Other denied categories stay denied
+    assert!(!filter.should_index("助動詞,*,*,*,特殊・デス,基本形,です,デス,デス"));
+  }
+
+  #[test]
+  fn custom_filter_can_keep_particles() {
+    let filter = PosFilter::new(["助詞"], Vec::<String>::new());
+
+    assert!(filter.should_index("助詞,格助詞,一般,*,*,*,が,ガ,ガ"));
+    assert!(!filter.should_index("名詞,一般,*,*,*,*,東京,トウキョウ,トーキョー"));
+    assert_eq!(filter.allow(), ["助詞".to_string()]);
+    assert!(filter.deny().is_empty());
+  }
+}
@@ -0,0 +1,88 @@
+//! Reading (yomi) extraction for Japanese tokens
+
+/// Converts hiragana to katakana (other characters are kept as is)
+///
+/// Used to normalize kana queries ("とうきょう") to the katakana readings
+/// stored in the dictionary ("トウキョウ").
+///
+/// # Examples
+/// ```rust
+/// # use wakeru::tokenizer::to_katakana;
+/// assert_eq!(to_katakana("とうきょうタワー"), "トウキョウタワー");
+/// ```
+pub fn to_katakana(text: &str) -> String {
+  text
+    .chars()
+    .map(|c| match c {
+      // ぁ..ゖ and ゝゞ are 0x60 below their katakana counterparts
+      '\u{3041}'..='\u{3096}' | '\u{309D}'..='\u{309E}' => {
+        char::from_u32(c as u32 + 0x60).unwrap_or(c)
+      }
+      _ => c,
+    })
+    .collect()
+}
+
+/// Returns the katakana reading of a token
+///
+/// The reading is the first katakana-only feature from the 7th field onwards
+/// (IPADIC: `読み`, UniDic: `語彙素読み`). Tokens without a reading
+/// (e.g. unknown words) fall back to the surface converted with [`to_katakana`].
+///
+/// # Examples
+/// ```rust
+/// # use wakeru::tokenizer::token_reading;
+/// let feature = "名詞,固有名詞,地域,一般,*,*,東京,トウキョウ,トーキョー";
+/// assert_eq!(token_reading("東京", feature), "トウキョウ");
+/// assert_eq!(token_reading("すし", "名詞,一般,*,*,*,*,*"), "スシ");
+/// ```
+pub fn token_reading(surface: &str, feature: &str) -> String {
+  feature
+    .split(',')
+    .skip(6)
+    .find(|field| is_katakana(field))
+    .map_or_else(|| to_katakana(surface), str::to_string)
+}
+
+/// Whether `text` is non-empty and consists of katakana only (including `ー`)
+fn is_katakana(text: &str) -> bool {
+  !text.is_empty()
+    && text.chars().all(|c| matches!(c, '\u{30A1}'..='\u{30FA}' | '\u{30FC}'..='\u{30FE}'))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn to_katakana_converts_hiragana_only() {
+    assert_eq!(to_katakana("きょうと"), "キョウト");
+    assert_eq!(to_katakana("京都へ行く"), "京都ヘ行ク");
+    assert_eq!(to_katakana("abc ゝ"), "abc ヽ");
+  }
+
+  #[test]
+  fn token_reading_uses_ipadic_reading() {
+    // Base form (6th) is kanji, reading (7th) is katakana
+    assert_eq!(
+      token_reading(
+        "行っ",
+        "動詞,自立,*,*,五段・カ行促音便,連用タ接続,行く,イッ,イッ"
+      ),
+      "イッ"
+    );
+    assert_eq!(
+      token_reading("タワー", "名詞,一般,*,*,*,*,タワー,タワー,タワー"),
+      "タワー"
+    );
+  }
+
+  #[test]
+  fn token_reading_falls_back_to_surface() {
+    assert_eq!(
+      token_reading("とうきょう", "名詞,一般,*,*,*,*,*"),
+      "トウキョウ"
+    );
+    assert_eq!(token_reading("Rust", "名詞,固有名詞,組織,*,*,*,*"), "Rust");
+  }
+}
@@ -1,19 +1,32 @@
 //! Tokenizer for Tantivy using vibrato
 
-use std::sync::Arc;
+use std::collections::BTreeSet;
+use std::sync::{Arc, LazyLock};
 use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
 use tracing::debug;
 use vibrato_rkyv::Dictionary;
 use vibrato_rkyv::Tokenizer as VibratoImpl;
 
+use super::pos_filter::PosFilter;
+use super::reading::token_reading;
+
+/// Built-in part-of-speech filter used by `should_index`
+static DEFAULT_POS_FILTER: LazyLock<PosFilter> = LazyLock::new(PosFilter::default);
+
 /// Japanese Tokenizer for Tantivy using Vibrato-rkyv
 ///
-/// - Stateless (only holds dictionary reference)
+/// - Stateless (only holds dictionary and part-of-speech filter references)
 /// - `Clone + Send + Sync`
 /// - Implements Tantivy's `Tokenizer` trait
 #[derive(Clone)]
 pub struct VibratoTokenizer {
   inner: VibratoImpl,
+  /// Decides which morphemes are emitted (default: [`PosFilter::default`])
+  filter: Arc<PosFilter>,
+  /// Emit katakana readings instead of surface forms (default: false)
+  reading: bool,
+  /// Number of segmentation paths merged by `token_stream` (default: 1 = single-best)
+  nbest: usize,
 }
 
 /// Implementation of Tantivy's TokenStream trait
@@ -22,7 +35,7 @@ pub struct VibratoTokenizer {
 /// - Consumes token sequence sequentially with `IntoIter`
 /// - Performs `token.position += 1` with `advance`
 pub struct VibratoTokenStream {
-  /// Iterator of (Surface form, Start byte, End byte)
+  /// Iterator of (Surface form or reading, Start byte, End byte)
   tokens: std::vec::IntoIter<(String, usize, usize)>,
 
   /// Tantivy's Token (overwritten and reused every time)
@@ -36,6 +49,9 @@ impl VibratoTokenizer {
   pub fn from_dictionary(dict: Dictionary) -> Self {
     Self {
       inner: VibratoImpl::new(dict),
+      filter: Arc::new(PosFilter::default()),
+      reading: false,
+      nbest: 1,
     }
   }
 
@@ -55,8 +71,107 @@ impl VibratoTokenizer {
   /// let tokenizer = VibratoTokenizer::from_shared_dictionary(dict);
   /// ```
   pub fn from_shared_dictionary(dict: Arc<Dictionary>) -> Self {
+    Self::from_shared_dictionary_with_filter(dict, Arc::new(PosFilter::default()))
+  }
+
+  /// Constructs a tokenizer from a shared dictionary with a custom part-of-speech filter.
+  ///
+  /// Use this for domain-specific indexing policies (e.g. keeping particles).
+  /// The same filter must be used for indexing and querying.
+  pub fn from_shared_dictionary_with_filter(dict: Arc<Dictionary>, filter: Arc<PosFilter>) -> Self {
     Self {
       inner: VibratoImpl::from_shared_dictionary(dict),
+      filter,
+      reading: false,
+      nbest: 1,
+    }
+  }
+
+  /// Emits the katakana reading of each token instead of its surface form (builder)
+  ///
+  /// Used for the Japanese `text_reading` field, so that kana queries ("とうきょう")
+  /// match kanji text ("東京"). See [`token_reading`](super::token_reading) for how
+  /// readings are extracted. Offsets and positions are those of the surface form.
+  pub fn with_reading_output(mut self, enabled: bool) -> Self {
+    self.reading = enabled;
+    self
+  }
+
+  /// Whether this tokenizer emits readings instead of surface forms
+  pub fn emits_reading(&self) -> bool {
+    self.reading
+  }
+
+  /// Merges the top-`n` segmentation paths in `token_stream` (builder)
+  ///
+  /// Opt-in: the default (`1`) keeps single-best analysis. With `n > 1`, alternative
+  /// segmentations of ambiguous text are indexed as well (see
+  /// [`nbest_token_stream`](Self::nbest_token_stream)), which improves recall at the cost
+  /// of a larger term set. `0` is treated as `1`.
+  pub fn with_nbest(mut self, n: usize) -> Self {
+    self.nbest = n.max(1);
+    self
+  }
+
+  /// Number of segmentation paths merged by `token_stream`
+  pub fn nbest(&self) -> usize {
+    self.nbest
+  }
+
+  /// Part-of-speech filter of this tokenizer
+  pub fn pos_filter(&self) -> &PosFilter {
+    &self.filter
+  }
+
+  /// Generates a TokenStream merging the unique tokens of the top-`n` segmentation paths
+  ///
+  /// - Tokens are deduplicated by (start byte, end byte, text) and ordered by offsets
+  /// - The part-of-speech filter and reading output apply as in `token_stream`
+  /// - Positions are sequential over the merged tokens, so overlapping alternatives
+  ///   get distinct positions (phrase matching is less strict than with single-best)
+  pub fn nbest_token_stream(&mut self, input_text: &str, n: usize) -> VibratoTokenStream {
+    let mut worker = self.inner.new_worker();
+    worker.reset_sentence(input_text);
+    worker.tokenize_nbest(n.max(1));
+
+    let mut merged = BTreeSet::new();
+    for path_idx in 0..worker.num_nbest_paths() {
+      let Some(path) = worker.nbest_token_iter(path_idx) else {
+        continue;
+      };
+      for token in path.filter(|token| self.filter.should_index(token.feature())) {
+        let range = token.range_byte();
+        merged.insert((
+          range.start,
+          range.end,
+          self.token_text(token.surface(), token.feature()),
+        ));
+      }
+    }
+
+    debug!(
+      input_text = %input_text,
+      paths = worker.num_nbest_paths(),
+      indexed_tokens = merged.len(),
+      "N-best morphological analysis completed"
+    );
+
+    VibratoTokenStream {
+      tokens: merged
+        .into_iter()
+        .map(|(start, end, text)| (text, start, end))
+        .collect::<Vec<_>>()
+        .into_iter(),
+      token: Token::default(),
+    }
+  }
+
+  /// Text emitted for a token (surface form, or reading with `with_reading_output`)
+  fn token_text(&self, surface: &str, feature: &str) -> String {
+    if self.reading {
+      token_reading(surface, feature)
+    } else {
+      surface.to_string()
     }
   }
 }
@@ -67,6 +182,11 @@ impl Tokenizer for VibratoTokenizer {
 
   /// Generates TokenStream from `&mut self` (mutable reference)
   fn token_stream<'a>(&'a mut self, input_text: &'a str) -> Self::TokenStream<'a> {
+    // Opt-in N-best analysis
+    if self.nbest > 1 {
+      return self.nbest_token_stream(input_text, self.nbest);
+    }
+
     // worker holds lattice for analysis and calculation area.
     // Created each time
     let mut worker = self.inner.new_worker();
@@ -80,12 +200,11 @@ impl Tokenizer for VibratoTokenizer {
 
     // Accumulate Vibrato results in Vec once, then convert to IntoIter
     let mut tokens = Vec::with_capacity(worker.num_tokens());
-    // Part-of-speech filtering etc. can be added in this code block if needed
-    // e.g.) Exclude particles and symbols to reduce index size
+    // Part-of-speech filtering (excludes particles, symbols, etc. by default)
     for token in worker.token_iter() {
       let surface = token.surface();
       let feature = token.feature();
-      let indexed = should_index(feature);
+      let indexed = self.filter.should_index(feature);
 
       // Debug log for each token
       debug!(
@@ -99,7 +218,7 @@ impl Tokenizer for VibratoTokenizer {
 
       if indexed {
         tokens.push((
-          surface.to_string(),
+          self.token_text(surface, feature),
           // Manage offset in bytes instead of characters to match tantivy specification
           // range_char() is prohibited
           token.range_byte().start,
@@ -132,56 +251,11 @@ impl Tokenizer for VibratoTokenizer {
 /// In UniDic-based dictionaries, "Kinkakuji" is split into "Kinkaku/ji", and "ji" is analyzed as `Suffix,Nominal`.
 /// We want to treat "ji", "eki" (station), "onsen" (hot spring), etc. attached to place names as meaningful content words,
 /// so `Suffix,Nominal` is included in the index target.
+///
+/// Equivalent to `PosFilter::default().should_index(feature)`; use [`PosFilter`] with
+/// [`VibratoTokenizer::from_shared_dictionary_with_filter`] for a different policy.
 pub fn should_index(feature: &str) -> bool {
-  // ─── Highest priority: Parts of speech to exclude ───
-  // Particle, Auxiliary verb, Symbol, Filler, Interjection, Conjunction, Prefix, Adnominal
-  if feature.starts_with("助詞")
-    || feature.starts_with("助動詞")
-    || feature.starts_with("記号")
-    || feature.starts_with("フィラー")
-    || feature.starts_with("感動詞")
-    || feature.starts_with("接続詞")
-    || feature.starts_with("接頭詞")
-    || feature.starts_with("連体詞")
-  {
-    return false;
-  }
-
-  // ─── UniDic: Treat Suffix,Nominal as noun equivalent ───
-  // Example: "接尾辞,名詞的,一般,*,*,*,寺,テラ,寺,テラ,*,*,*,*,*,*"
-  // Treat "ji", "eki", "onsen" etc. attached to place names as meaningful content words
-  if feature.starts_with("接尾辞,名詞的") {
-    return true;
-  }
-
-  // ─── Detailed classification check for Nouns ───
-  if feature.starts_with("名詞") {
-    // Exclude: Pronoun, Non-independent
-    if feature.starts_with("名詞,代名詞") || feature.starts_with("名詞,非自立") {
-      return false;
-    }
-    // Include other nouns
-    return true;
-  }
-
-  // ─── Include all Verbs and Adjectives ───
-  if feature.starts_with("動詞") || feature.starts_with("形容詞") {
-    return true;
-  }
-
-  // ─── Include Adjectival Nouns (UniDic) as content words ───
-  // Words like "kireida", "shizukada" (adjectival verbs)
-  if feature.starts_with("形状詞") {
-    return true;
-  }
-
-  // ─── Adverbs: Include only General ───
-  if feature.starts_with("副詞") {
-    return feature.starts_with("副詞,一般");
-  }
-
-  // ─── Exclude others ───
-  false
+  DEFAULT_POS_FILTER.should_index(feature)
 }
 
 impl TokenStream for VibratoTokenStream {
@@ -221,6 +295,120 @@ impl TokenStream for VibratoTokenStream {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::dictionary::in_memory_dictionary;
+
+  /// Minimal in-memory dictionary with IPADIC-style features (no download required)
+  fn build_test_dictionary() -> Arc<Dictionary> {
+    let lexicon_csv = "東京,0,0,1,名詞,固有名詞,地域,一般,*,*,東京,トウキョウ,トーキョー
+へ,0,0,1,助詞,格助詞,一般,*,*,*,へ,ヘ,エ
+行く,0,0,1,動詞,自立,*,*,五段・カ行促音便,基本形,行く,イク,イク";
+    Arc::new(Dictionary::from_inner(in_memory_dictionary(lexicon_csv)))
+  }
+
+  fn token_texts(tokenizer: &mut VibratoTokenizer, text: &str) -> Vec<String> {
+    let mut stream = tokenizer.token_stream(text);
+    let mut texts = Vec::new();
+    while stream.advance() {
+      texts.push(stream.token().text.clone());
+    }
+    texts
+  }
+
+  /// Verify that the default filter drops particles
+  #[test]
+  fn tokenizer_uses_default_filter() {
+    let mut tokenizer = VibratoTokenizer::from_shared_dictionary(build_test_dictionary());
+    assert_eq!(tokenizer.pos_filter(), &PosFilter::default());
+    assert_eq!(
+      token_texts(&mut tokenizer, "東京へ行く"),
+      vec!["東京", "行く"]
+    );
+  }
+
+  /// Verify that a custom filter changes the emitted tokens
+  #[test]
+  fn tokenizer_with_custom_filter() {
+    let filter = PosFilter::default().with_allow("助詞").with_deny("名詞,固有名詞");
+    let mut tokenizer = VibratoTokenizer::from_shared_dictionary_with_filter(
+      build_test_dictionary(),
+      Arc::new(filter),
+    );
+    assert_eq!(
+      token_texts(&mut tokenizer, "東京へ行く"),
+      vec!["へ", "行く"]
+    );
+  }
+
+  /// Dictionary where "自然言語処理" has several segmentations
+  fn build_ambiguous_dictionary() -> Arc<Dictionary> {
+    let lexicon_csv = "自然,0,0,1,名詞,一般,*,*,*,*,自然,シゼン,シゼン
+言語,0,0,4,名詞,一般,*,*,*,*,言語,ゲンゴ,ゲンゴ
+処理,0,0,3,名詞,サ変接続,*,*,*,*,処理,ショリ,ショリ
+自然言語,0,0,6,名詞,一般,*,*,*,*,自然言語,シゼンゲンゴ,シゼンゲンゴ
+言語処理,0,0,5,名詞,一般,*,*,*,*,言語処理,ゲンゴショリ,ゲンゴショリ";
+    Arc::new(Dictionary::from_inner(in_memory_dictionary(lexicon_csv)))
+  }
+
+  /// Verify that N-best merges alternative segmentations into more unique tokens
+  #[test]
+  fn nbest_token_stream_yields_more_unique_tokens_than_single_best() {
+    let mut tokenizer = VibratoTokenizer::from_shared_dictionary(build_ambiguous_dictionary());
+    assert_eq!(tokenizer.nbest(), 1);
+    let single_best = token_texts(&mut tokenizer, "自然言語処理");
+    assert_eq!(single_best, vec!["自然", "言語処理"]);
+
+    let mut stream = tokenizer.nbest_token_stream("自然言語処理", 3);
+    let mut merged = Vec::new();
+    while stream.advance() {
+      let token = stream.token();
+      merged.push((token.text.clone(), token.offset_from, token.offset_to));
+    }
+
+    assert!(merged.len() > single_best.len());
+    assert_eq!(
+      merged,
+      vec![
+        ("自然".to_string(), 0, 6),
+        ("自然言語".to_string(), 0, 12),
+        ("言語".to_string(), 6, 12),
+        ("言語処理".to_string(), 6, 18),
+        ("処理".to_string(), 12, 18),
+      ]
+    );
+  }
+
+  /// Verify that the opt-in flag switches `token_stream` to N-best
+  #[test]
+  fn tokenizer_with_nbest_uses_nbest_in_token_stream() {
+    let mut tokenizer =
+      VibratoTokenizer::from_shared_dictionary(build_ambiguous_dictionary()).with_nbest(3);
+    assert_eq!(tokenizer.nbest(), 3);
+    assert_eq!(token_texts(&mut tokenizer, "自然言語処理").len(), 5);
+
+    // 0 falls back to single-best
+    let mut tokenizer = tokenizer.with_nbest(0);
+    assert_eq!(tokenizer.nbest(), 1);
+    assert_eq!(token_texts(&mut tokenizer, "自然言語処理").len(), 2);
+  }
+
+  /// Verify that reading output emits katakana readings with surface offsets
+  #[test]
+  fn tokenizer_with_reading_output() {
+    let mut tokenizer =
+      VibratoTokenizer::from_shared_dictionary(build_test_dictionary()).with_reading_output(true);
+    assert!(tokenizer.emits_reading());
+
+    let mut stream = tokenizer.token_stream("東京へ行く");
+    assert!(stream.advance());
+    assert_eq!(stream.token().text, "トウキョウ");
+    assert_eq!(
+      (stream.token().offset_from, stream.token().offset_to),
+      (0, "東京".len())
+    );
+    assert!(stream.advance());
+    assert_eq!(stream.token().text, "イク");
+    assert!(!stream.advance());
+  }
 
   /// Verify that content words (Noun, General) are indexed
   #[test]
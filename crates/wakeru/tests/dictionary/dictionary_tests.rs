@@ -2,7 +2,7 @@
 //! Integration tests for dictionary management
 
 use vibrato_rkyv::dictionary::PresetDictionaryKind;
-use wakeru::dictionary::DictionaryManager;
+use wakeru::dictionary::{DictionaryManager, DownloadProgress};
 use wakeru::errors::DictionaryError;
 
 /// Verify that the constructor of DictionaryManager works correctly.
@@ -18,6 +18,17 @@ fn create_dictionary_manager_with_preset() {
   );
 }
 
+/// Verify that an explicit cache directory is used instead of the OS default.
+#[test]
+fn create_dictionary_manager_with_cache_dir() {
+  let temp_dir = tempfile::TempDir::new().unwrap();
+  let manager =
+    DictionaryManager::with_preset_and_cache_dir(PresetDictionaryKind::Ipadic, temp_dir.path());
+
+  assert_eq!(manager.cache_dir(), temp_dir.path());
+  assert!(manager.dictionary().is_none());
+}
+
 /// Verify that an error returns when a non-existent path is specified.
 #[test]
 fn from_local_path_with_nonexistent_file() {
@@ -87,6 +98,64 @@ fn load_cached_dictionary() {
   );
 }
 
+/// Verify that `dictionary()` returns the cached dictionary only after `load()`.
+///
+/// The `Some` part requires dictionary cache beforehand.
+#[test]
+fn dictionary_accessor_returns_cached_dictionary_after_load() {
+  let manager = DictionaryManager::with_preset(PresetDictionaryKind::Ipadic)
+    .expect("Failed to build DictionaryManager");
+
+  // No load is triggered by the accessor itself
+  assert!(manager.dictionary().is_none());
+  assert!(manager.dictionary().is_none());
+
+  let cache_dir = manager.cache_dir();
+  let dict_subdir = cache_dir.join(PresetDictionaryKind::Ipadic.name());
+
+  if !dict_subdir.exists() {
+    eprintln!("Skipping as dictionary cache does not exist");
+    return;
+  }
+
+  let loaded = manager.load().expect("Failed to load dictionary");
+
+  // Returns the same shared instance as load()
+  let cached = manager.dictionary().expect("Dictionary should be cached after load()");
+  assert!(std::sync::Arc::ptr_eq(&loaded, &cached));
+}
+
+/// Verify that the progress callback reports every load phase when loading from cache.
+///
+/// Requires dictionary cache beforehand.
+#[test]
+fn progress_callback_reports_phases_from_cache() {
+  let phases = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+  let recorded = std::sync::Arc::clone(&phases);
+  let manager =
+    DictionaryManager::with_preset_and_progress(PresetDictionaryKind::Ipadic, move |progress| {
+      recorded.lock().unwrap().push(progress)
+    })
+    .expect("Failed to build DictionaryManager");
+
+  let dict_subdir = manager.cache_dir().join(PresetDictionaryKind::Ipadic.name());
+  if !dict_subdir.exists() {
+    eprintln!("Skipping as dictionary cache does not exist");
+    return;
+  }
+
+  manager.load().expect("Failed to load dictionary");
+
+  assert_eq!(
+    *phases.lock().unwrap(),
+    [
+      DownloadProgress::Downloading,
+      DownloadProgress::Loading,
+      DownloadProgress::Loaded
+    ]
+  );
+}
+
 /// Verify that basic morphological analysis is possible with the loaded dictionary.
 ///
 /// Requires dictionary cache beforehand.
@@ -130,3 +199,52 @@ fn basic_tokenize_with_cached_dictionary() {
     );
   }
 }
+
+/// Verify that an error returns when a non-existent user dictionary is specified.
+#[test]
+fn with_preset_and_user_dict_with_nonexistent_file() {
+  let result = DictionaryManager::with_preset_and_user_dict(
+    PresetDictionaryKind::Ipadic,
+    "/nonexistent/user.csv",
+  );
+
+  assert!(matches!(
+    result.unwrap_err(),
+    DictionaryError::DictionaryNotFound(_)
+  ));
+}
+
+/// Verify that a term present only in the user dictionary is tokenized as a single token.
+///
+/// Requires dictionary cache beforehand.
+#[test]
+fn user_dictionary_term_is_single_token() {
+  let temp_dir = tempfile::TempDir::new().unwrap();
+  let user_csv = temp_dir.path().join("user.csv");
+  std::fs::write(
+    &user_csv,
+    "東京スカイツリー,1288,1288,-1000,名詞,固有名詞,一般,*,*,*,東京スカイツリー,トウキョウスカイツリー,トーキョースカイツリー\n",
+  )
+  .unwrap();
+
+  let manager =
+    DictionaryManager::with_preset_and_user_dict(PresetDictionaryKind::Ipadic, &user_csv)
+      .expect("Failed to build DictionaryManager");
+  assert_eq!(manager.user_dict_path(), Some(user_csv.as_path()));
+
+  let dict_subdir = manager.cache_dir().join(PresetDictionaryKind::Ipadic.name());
+  if !dict_subdir.exists() {
+    eprintln!("Skipping as dictionary cache does not exist");
+    return;
+  }
+
+  let dict = manager.load().expect("Failed to load dictionary with user dictionary");
+  let tokenizer = vibrato_rkyv::Tokenizer::from_shared_dictionary(dict);
+  let mut worker = tokenizer.new_worker();
+
+  worker.reset_sentence("東京スカイツリーに行く");
+  worker.tokenize();
+
+  let surfaces: Vec<&str> = worker.token_iter().map(|t| t.surface()).collect();
+  assert_eq!(surfaces.first(), Some(&"東京スカイツリー"));
+}
@@ -96,6 +96,7 @@ fn end_to_end_search_flow() {
 
   // Add documents
   index_manager.add_documents(&sample_documents()).expect("Failed to add documents");
+  index_manager.commit().expect("Failed to commit");
 
   // Initialize SearchEngine
   let search_engine = SearchEngine::new(
@@ -163,3 +164,133 @@ fn search_on_empty_index() {
     "Result returned on empty index (should be 0)"
   );
 }
+
+/// Phrase search respects Japanese morpheme adjacency, unlike OR search.
+#[test]
+fn phrase_search_respects_morpheme_adjacency() {
+  // Skip test if no dictionary cache
+  let analyzer = match setup_tokenizer() {
+    Some(t) => t,
+    None => return,
+  };
+
+  let tmp_dir = TempDir::new().expect("Failed to create temporary directory");
+  let index_manager =
+    IndexManager::open_or_create(tmp_dir.path(), Language::Ja, Some((*analyzer).clone()))
+      .expect("Failed to create index");
+
+  let docs = vec![
+    Document::new("adjacent", "src-1", "東京タワーに登りました。"),
+    Document::new("apart", "src-2", "タワーの写真を東京で撮りました。"),
+  ];
+  index_manager.add_documents(&docs).expect("Failed to add documents");
+  index_manager.commit().expect("Failed to commit");
+
+  let search_engine = SearchEngine::new(
+    index_manager.index(),
+    *index_manager.fields(),
+    index_manager.language(),
+  )
+  .expect("Failed to initialize SearchEngine");
+
+  let phrase_results = search_engine.search_phrase("東京 タワー", 5).expect("Search failed");
+  let or_results = search_engine.search_tokens_or("東京 タワー", 5).expect("Search failed");
+
+  assert_eq!(phrase_results.len(), 1);
+  assert_eq!(phrase_results[0].doc_id, "adjacent");
+  assert_eq!(or_results.len(), 2);
+}
+
+/// With an N-gram query range of `1..=2`, 2-char tokens also query the N-gram field.
+#[test]
+fn ngram_query_threshold_includes_two_char_tokens() {
+  // Skip test if no dictionary cache
+  let analyzer = match setup_tokenizer() {
+    Some(t) => t,
+    None => return,
+  };
+
+  let tmp_dir = TempDir::new().expect("Failed to create temporary directory");
+  let index_manager =
+    IndexManager::open_or_create(tmp_dir.path(), Language::Ja, Some((*analyzer).clone()))
+      .expect("Failed to create index");
+
+  // "京都" is not a morpheme of this text, but its characters appear adjacent
+  let docs = vec![Document::new("tocho", "src-1", "東京都庁に行きました。")];
+  index_manager.add_documents(&docs).expect("Failed to add documents");
+  index_manager.commit().expect("Failed to commit");
+
+  let search_engine = SearchEngine::new(
+    index_manager.index(),
+    *index_manager.fields(),
+    index_manager.language(),
+  )
+  .expect("Failed to initialize SearchEngine");
+
+  let results = search_engine.search_tokens_or("京都", 5).expect("Search failed");
+  assert!(
+    results.is_empty(),
+    "Default range should not use N-gram for 2-char tokens"
+  );
+
+  let search_engine = search_engine.with_ngram_range(1, 2);
+  let results = search_engine.search_tokens_or("京都", 5).expect("Search failed");
+  assert_eq!(results.len(), 1);
+  assert_eq!(results[0].doc_id, "tocho");
+}
+
+/// Query analysis shows the Japanese morphological split of each query token.
+#[test]
+fn analyze_query_shows_japanese_morphological_split() {
+  // Skip test if no dictionary cache
+  let analyzer = match setup_tokenizer() {
+    Some(t) => t,
+    None => return,
+  };
+
+  let tmp_dir = TempDir::new().expect("Failed to create temporary directory");
+  let index_manager =
+    IndexManager::open_or_create(tmp_dir.path(), Language::Ja, Some((*analyzer).clone()))
+      .expect("Failed to create index");
+
+  let search_engine = SearchEngine::new(
+    index_manager.index(),
+    *index_manager.fields(),
+    index_manager.language(),
+  )
+  .expect("Failed to initialize SearchEngine");
+
+  let pairs = search_engine.analyze_query("東京タワーの写真").expect("Analysis failed");
+  let indexed: Vec<&str> = pairs.iter().map(|(_, indexed)| indexed.as_str()).collect();
+
+  // The particle "の" is dropped, nouns are split at morpheme boundaries
+  assert_eq!(indexed, vec!["東京", "タワー", "写真"]);
+  // Input substrings match the indexed surface forms for nouns
+  assert!(pairs.iter().all(|(input, indexed)| input == indexed));
+}
+
+/// Query explanation shows the deduplicated tokens a Japanese query is searched with.
+#[test]
+fn explain_query_shows_japanese_search_tokens() {
+  // Skip test if no dictionary cache
+  let analyzer = match setup_tokenizer() {
+    Some(t) => t,
+    None => return,
+  };
+
+  let tmp_dir = TempDir::new().expect("Failed to create temporary directory");
+  let index_manager =
+    IndexManager::open_or_create(tmp_dir.path(), Language::Ja, Some((*analyzer).clone()))
+      .expect("Failed to create index");
+
+  let search_engine = SearchEngine::new(
+    index_manager.index(),
+    *index_manager.fields(),
+    index_manager.language(),
+  )
+  .expect("Failed to initialize SearchEngine");
+
+  // The particle "の" is dropped
+  let tokens = search_engine.explain_query("京都の寺").expect("Analysis failed");
+  assert_eq!(tokens, vec!["京都", "寺"]);
+}
@@ -98,3 +98,45 @@ fn verify_byte_offsets() {
     assert_eq!(slice, token.text, "Offset slice does not match token text");
   }
 }
+
+/// Verify that analyze_nbest returns multiple distinct paths ordered by cost.
+#[test]
+fn analyze_nbest_returns_distinct_paths_ordered_by_cost() {
+  let manager = DictionaryManager::with_preset(PresetDictionaryKind::Ipadic)
+    .expect("Failed to build DictionaryManager");
+
+  let cache_dir = manager.cache_dir();
+  if !cache_dir.join(PresetDictionaryKind::Ipadic.name()).exists() {
+    eprintln!("Skipping as dictionary cache does not exist");
+    return;
+  }
+
+  let dict = manager.load().expect("Failed to load dictionary");
+
+  // Ambiguous string with many possible segmentations
+  let text = "すもももももももものうち";
+  let candidates = wakeru::tokenizer::analyze_nbest(dict, text, 5);
+
+  assert!(candidates.len() >= 2, "Expected multiple candidates");
+
+  // Ordered by cost (lower = more likely)
+  let costs: Vec<i32> = candidates.iter().map(|(_, cost)| *cost).collect();
+  assert!(
+    costs.windows(2).all(|w| w[0] <= w[1]),
+    "Costs not sorted: {costs:?}"
+  );
+
+  // Every path covers the whole text, and segmentations are distinct
+  let segmentations: Vec<Vec<&str>> = candidates
+    .iter()
+    .map(|(tokens, _)| tokens.iter().map(|t| t.surface.as_str()).collect())
+    .collect();
+  for segmentation in &segmentations {
+    assert_eq!(segmentation.concat(), text);
+  }
+  for (i, a) in segmentations.iter().enumerate() {
+    for b in &segmentations[i + 1..] {
+      assert_ne!(a, b, "Duplicate segmentation");
+    }
+  }
+}